@@ -14,7 +14,7 @@ fn main() -> io::Result<()> {
     let reader = listener.accept()?.0;
     let decoder = Decoder::from_buffer(Vec::new());
     let mut frames = decoder.read_frames(reader);
-    while let Ok(frame) = frames.next() {
+    while let Some(frame) = frames.next() {
         let frame = frame.unwrap();
         let message = str::from_utf8(frame.message()).unwrap();
         println!("Received message '{}'", message);
@@ -0,0 +1,60 @@
+//! Compares [`write_int`] against `format!` for encoding integer field values.
+//! Run with `cargo bench --bench int_format`.
+
+use fefix::tagvalue::write_int;
+use std::time::{Duration, Instant};
+
+const VALUES: i64 = 1_000_000;
+
+// A mix of short and long values, e.g. sequence numbers and order IDs.
+fn value(i: i64) -> i64 {
+    if i % 2 == 0 {
+        i
+    } else {
+        -i * 7_919_081
+    }
+}
+
+fn with_format() -> (Duration, usize) {
+    let mut buffer = Vec::new();
+    let mut total_len = 0;
+    let start = Instant::now();
+    for i in 0..VALUES {
+        buffer.clear();
+        buffer.extend_from_slice(format!("{}", value(i)).as_bytes());
+        total_len += buffer.len();
+    }
+    (start.elapsed(), total_len)
+}
+
+fn with_write_int() -> (Duration, usize) {
+    let mut buffer = Vec::new();
+    let mut total_len = 0;
+    let start = Instant::now();
+    for i in 0..VALUES {
+        buffer.clear();
+        write_int(&mut buffer, value(i));
+        total_len += buffer.len();
+    }
+    (start.elapsed(), total_len)
+}
+
+fn main() {
+    for (name, bench) in [
+        ("format!", with_format as fn() -> (Duration, usize)),
+        ("write_int", with_write_int),
+    ]
+    .iter()
+    {
+        // The total length keeps the formatted values from being optimized
+        // away.
+        let (elapsed, total_len) = bench();
+        println!(
+            "{:>9}: {:?} ({:.1} ns/value, {} bytes)",
+            name,
+            elapsed,
+            elapsed.as_nanos() as f64 / VALUES as f64,
+            total_len
+        );
+    }
+}
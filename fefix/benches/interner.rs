@@ -0,0 +1,90 @@
+//! Counts the allocations needed to keep `MsgType <35>` and `Currency <15>`
+//! values around after decoding, with and without an [`Interner`]. Run with
+//! `cargo bench --bench interner`.
+
+use fefix::tagvalue::{Config, Decoder, Interner};
+use fefix::{AppVersion, Dictionary};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+const MESSAGES: usize = 1_000_000;
+const MESSAGE: &[u8] = b"8=FIX.4.4|9=102|35=D|49=A|56=B|34=1|52=20210101-00:00:00|11=X|15=USD|55=EUR/USD|54=1|60=20210101-00:00:00|38=100|40=1|10=113|";
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// Elapsed time, number of allocations and total length of all values.
+type Outcome = (Duration, usize, usize);
+
+fn decoder(config: Config) -> Decoder<Config> {
+    let dict = Dictionary::from_version(AppVersion::Fix44);
+    Decoder::with_config(dict, config.with_separator(b'|'))
+}
+
+fn with_strings() -> Outcome {
+    let decoder = &mut decoder(Config::default());
+    let mut total_len = 0;
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..MESSAGES {
+        let msg = decoder.decode(MESSAGE).unwrap();
+        let msg_type = msg.field_as_str(35).unwrap().to_string();
+        let currency = msg.field_as_str(15).unwrap().to_string();
+        total_len += msg_type.len() + currency.len();
+    }
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+    (elapsed, allocations, total_len)
+}
+
+fn with_interner() -> Outcome {
+    let decoder = &mut decoder(Config::default().with_interner(Interner::common()));
+    let mut total_len = 0;
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..MESSAGES {
+        let msg = decoder.decode(MESSAGE).unwrap();
+        let msg_type: &'static str = msg.field_interned(35).unwrap();
+        let currency: &'static str = msg.field_interned(15).unwrap();
+        total_len += msg_type.len() + currency.len();
+    }
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+    (elapsed, allocations, total_len)
+}
+
+fn main() {
+    for (name, bench) in [
+        ("with strings", with_strings as fn() -> Outcome),
+        ("with interner", with_interner),
+    ]
+    .iter()
+    {
+        // The total length keeps the values from being optimized away.
+        let (elapsed, allocations, total_len) = bench();
+        println!(
+            "{:>13}: {:?} ({:.1} ns/message, {:.2} allocations/message, {} bytes)",
+            name,
+            elapsed,
+            elapsed.as_nanos() as f64 / MESSAGES as f64,
+            allocations as f64 / MESSAGES as f64,
+            total_len
+        );
+    }
+}
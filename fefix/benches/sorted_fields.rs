@@ -0,0 +1,72 @@
+//! Compares field lookups on [`SortedFields`] against the [`HashMap`]-based
+//! [`Decoder`] for a 30-field message that is queried repeatedly. Run with
+//! `cargo bench --bench sorted_fields`.
+//!
+//! [`HashMap`]: std::collections::HashMap
+
+use fefix::tagvalue::{decode_sorted, Config, Decoder, SortedFields};
+use fefix::{AppVersion, Dictionary};
+use std::time::{Duration, Instant};
+
+const MESSAGES: usize = 200_000;
+// Every field of each message is looked up this many times.
+const ROUNDS: usize = 10;
+const MESSAGE: &[u8] = b"8=FIX.4.4|9=261|35=D|49=CLIENT12|56=BROKER|34=215|52=20100225-19:41:57.316|1=Marcel|11=13346|15=USD|18=G|21=1|22=8|38=100|40=2|44=5|48=AAPL|54=1|55=AAPL|58=note|59=0|60=20100225-19:39:52.020|100=XNAS|110=10|111=50|114=N|126=20100226-19:39:52|167=CS|207=XNAS|432=20100226|528=A|10=014|";
+const TAGS: &[u32] = &[
+    8, 35, 49, 56, 34, 52, 1, 11, 15, 18, 21, 22, 38, 40, 44, 48, 54, 55, 58, 59, 60, 100, 110,
+    111, 114, 126, 167, 207, 432, 528,
+];
+
+fn config() -> Config {
+    Config::default().with_separator(b'|')
+}
+
+fn with_hash_map() -> (Duration, usize) {
+    let dict = Dictionary::from_version(AppVersion::Fix44);
+    let decoder = &mut Decoder::with_config(dict, config());
+    let mut total_len = 0;
+    let start = Instant::now();
+    for _ in 0..MESSAGES {
+        let msg = decoder.decode(MESSAGE).unwrap();
+        for _ in 0..ROUNDS {
+            for tag in TAGS {
+                total_len += msg.field_raw(*tag).unwrap().len();
+            }
+        }
+    }
+    (start.elapsed(), total_len)
+}
+
+fn with_sorted_fields() -> (Duration, usize) {
+    let config = config();
+    let mut total_len = 0;
+    let start = Instant::now();
+    for _ in 0..MESSAGES {
+        let fields: SortedFields = decode_sorted(MESSAGE, &config).unwrap();
+        for _ in 0..ROUNDS {
+            for tag in TAGS {
+                total_len += fields.get(*tag).unwrap().len();
+            }
+        }
+    }
+    (start.elapsed(), total_len)
+}
+
+fn main() {
+    for (name, bench) in [
+        ("hash map", with_hash_map as fn() -> (Duration, usize)),
+        ("sorted fields", with_sorted_fields),
+    ]
+    .iter()
+    {
+        // The total length keeps the lookups from being optimized away.
+        let (elapsed, total_len) = bench();
+        println!(
+            "{:>13}: {:?} ({:.1} ns/message, {} bytes)",
+            name,
+            elapsed,
+            elapsed.as_nanos() as f64 / MESSAGES as f64,
+            total_len
+        );
+    }
+}
@@ -0,0 +1,71 @@
+//! Compares extracting a few fields with a [`StaticSchema`] against the general
+//! [`Decoder`] path, which looks up every field in the dictionary. Run with
+//! `cargo bench --bench static_schema`.
+
+use fefix::tagvalue::{BaseValue, Config, Decoder, StaticSchema};
+use fefix::{AppVersion, DataType, Dictionary};
+use std::time::{Duration, Instant};
+
+const MESSAGES: usize = 1_000_000;
+const MESSAGE: &[u8] = b"8=FIX.4.4|9=105|35=W|49=A|56=B|34=1|52=20210101-00:00:00|262=req-1|55=EUR/USD|48=EURUSD|22=8|268=1|269=0|270=1.1|271=100|10=113|";
+
+fn config() -> Config {
+    Config::default().with_separator(b'|')
+}
+
+fn with_decoder() -> (Duration, usize) {
+    let dict = Dictionary::from_version(AppVersion::Fix44);
+    let decoder = &mut Decoder::with_config(dict, config());
+    let mut total_len = 0;
+    let start = Instant::now();
+    for _ in 0..MESSAGES {
+        let msg = decoder.decode(MESSAGE).unwrap();
+        total_len += msg.field_raw(55).unwrap().len();
+        total_len += msg.field_raw(262).unwrap().len();
+        total_len += msg.field_raw(52).unwrap().len();
+        total_len += msg.field_as_i64(34).unwrap() as usize;
+    }
+    (start.elapsed(), total_len)
+}
+
+fn with_static_schema() -> (Duration, usize) {
+    let config = config();
+    let schema = StaticSchema::new(&[
+        (55, DataType::String),
+        (262, DataType::String),
+        (52, DataType::UtcTimestamp),
+        (34, DataType::SeqNum),
+    ]);
+    let mut total_len = 0;
+    let start = Instant::now();
+    for _ in 0..MESSAGES {
+        for value in schema.decode(MESSAGE, &config).unwrap() {
+            total_len += match value.unwrap() {
+                BaseValue::Str(s) => s.len(),
+                BaseValue::Int(int) => int as usize,
+                _ => unreachable!(),
+            };
+        }
+    }
+    (start.elapsed(), total_len)
+}
+
+fn main() {
+    for (name, bench) in [
+        ("decoder", with_decoder as fn() -> (Duration, usize)),
+        ("static schema", with_static_schema),
+    ]
+    .iter()
+    {
+        // The total length keeps the decoded values from being optimized
+        // away.
+        let (elapsed, total_len) = bench();
+        println!(
+            "{:>13}: {:?} ({:.1} ns/message, {} bytes)",
+            name,
+            elapsed,
+            elapsed.as_nanos() as f64 / MESSAGES as f64,
+            total_len
+        );
+    }
+}
@@ -10,6 +10,20 @@ const NAME_FIX50SP1: &str = "FIX-5.0-SP1";
 const NAME_FIX50SP2: &str = "FIX-5.0-SP2";
 const NAME_FIXT11: &str = "FIXT-1.1";
 
+/// `BeginString <8>` value of FIX 4.0 messages.
+pub const BEGIN_STRING_FIX40: &[u8] = b"FIX.4.0";
+/// `BeginString <8>` value of FIX 4.1 messages.
+pub const BEGIN_STRING_FIX41: &[u8] = b"FIX.4.1";
+/// `BeginString <8>` value of FIX 4.2 messages.
+pub const BEGIN_STRING_FIX42: &[u8] = b"FIX.4.2";
+/// `BeginString <8>` value of FIX 4.3 messages.
+pub const BEGIN_STRING_FIX43: &[u8] = b"FIX.4.3";
+/// `BeginString <8>` value of FIX 4.4 messages.
+pub const BEGIN_STRING_FIX44: &[u8] = b"FIX.4.4";
+/// `BeginString <8>` value of FIXT 1.1 messages, which also carry all FIX 5.0
+/// (and later) application messages.
+pub const BEGIN_STRING_FIXT11: &[u8] = b"FIXT.1.1";
+
 /// Which [`Dictionary`](fefix::Dictionary) version to use.
 #[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
 #[non_exhaustive]
@@ -52,6 +66,80 @@ impl AppVersion {
         }
     }
 
+    /// Returns the `BeginString <8>` value of messages of `self`. All FIX 5.0
+    /// versions share [`BEGIN_STRING_FIXT11`] with FIXT 1.1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::AppVersion;
+    ///
+    /// assert_eq!(AppVersion::Fix42.begin_string(), b"FIX.4.2");
+    /// assert_eq!(AppVersion::Fix50SP2.begin_string(), b"FIXT.1.1");
+    /// ```
+    pub const fn begin_string(&self) -> &'static [u8] {
+        match self {
+            Self::Fix40 => BEGIN_STRING_FIX40,
+            Self::Fix41 => BEGIN_STRING_FIX41,
+            Self::Fix42 => BEGIN_STRING_FIX42,
+            Self::Fix43 => BEGIN_STRING_FIX43,
+            Self::Fix44 => BEGIN_STRING_FIX44,
+            Self::Fix50 | Self::Fix50SP1 | Self::Fix50SP2 | Self::Fixt11 => BEGIN_STRING_FIXT11,
+        }
+    }
+
+    /// Parses the value of `BeginString <8>`. `FIXT.1.1` always maps to
+    /// [`AppVersion::Fixt11`], as the actual application version of FIX 5.0
+    /// messages is determined by `ApplVerID <1128>` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::AppVersion;
+    ///
+    /// assert_eq!(AppVersion::from_begin_string(b"FIX.4.4"), Some(AppVersion::Fix44));
+    /// assert_eq!(AppVersion::from_begin_string(b"FIXT.1.1"), Some(AppVersion::Fixt11));
+    /// assert_eq!(AppVersion::from_begin_string(b"FIX.5.0"), None);
+    /// ```
+    pub fn from_begin_string(begin_string: &[u8]) -> Option<Self> {
+        Some(match begin_string {
+            BEGIN_STRING_FIX40 => Self::Fix40,
+            BEGIN_STRING_FIX41 => Self::Fix41,
+            BEGIN_STRING_FIX42 => Self::Fix42,
+            BEGIN_STRING_FIX43 => Self::Fix43,
+            BEGIN_STRING_FIX44 => Self::Fix44,
+            BEGIN_STRING_FIXT11 => Self::Fixt11,
+            _ => return None,
+        })
+    }
+
+    /// Parses the value of `ApplVerID <1128>` (or `DefaultApplVerID <1137>`),
+    /// which FIXT 1.1 sessions use to tell the application version of
+    /// messages. FIX 2.7 and FIX 3.0 are not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::AppVersion;
+    ///
+    /// assert_eq!(AppVersion::from_appl_ver_id(b"9"), Some(AppVersion::Fix50SP2));
+    /// assert_eq!(AppVersion::from_appl_ver_id(b"6"), Some(AppVersion::Fix44));
+    /// assert_eq!(AppVersion::from_appl_ver_id(b"1"), None);
+    /// ```
+    pub fn from_appl_ver_id(appl_ver_id: &[u8]) -> Option<Self> {
+        Some(match appl_ver_id {
+            b"2" => Self::Fix40,
+            b"3" => Self::Fix41,
+            b"4" => Self::Fix42,
+            b"5" => Self::Fix43,
+            b"6" => Self::Fix44,
+            b"7" => Self::Fix50,
+            b"8" => Self::Fix50SP1,
+            b"9" => Self::Fix50SP2,
+            _ => return None,
+        })
+    }
+
     /// Matches
     pub fn from_str(s: &str) -> Option<Self> {
         Some(match s {
@@ -74,3 +162,101 @@ impl fmt::Display for AppVersion {
         write!(f, "{}", self.name())
     }
 }
+
+/// The protocol version announced by `BeginString <8>`, which may also be a
+/// non-standard value used by proprietary protocols.
+///
+/// [`AppVersion`] remains the version type of dictionaries and of everything
+/// derived from them, which a custom protocol doesn't have. APIs that also make
+/// sense for custom protocols, such as [`tags::all_tags`](crate::tags::all_tags),
+/// accept both types through `Into<FixVersion>`.
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FixVersion<'a> {
+    /// A standard `BeginString <8>` value. See
+    /// [`AppVersion::from_begin_string`].
+    Standard(AppVersion),
+    /// Any other `BeginString <8>` value.
+    Custom(&'a [u8]),
+}
+
+impl<'a> FixVersion<'a> {
+    /// Parses the value of `BeginString <8>`. Unlike
+    /// [`AppVersion::from_begin_string`], this never fails: unknown values map
+    /// to [`FixVersion::Custom`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::{AppVersion, FixVersion};
+    ///
+    /// assert_eq!(
+    ///     FixVersion::from_begin_string(b"FIX.4.4"),
+    ///     FixVersion::Standard(AppVersion::Fix44)
+    /// );
+    /// assert_eq!(
+    ///     FixVersion::from_begin_string(b"MYPROTO.1.0"),
+    ///     FixVersion::Custom(b"MYPROTO.1.0")
+    /// );
+    /// ```
+    pub fn from_begin_string(begin_string: &'a [u8]) -> Self {
+        match AppVersion::from_begin_string(begin_string) {
+            Some(version) => Self::Standard(version),
+            None => Self::Custom(begin_string),
+        }
+    }
+
+    /// Returns the `BeginString <8>` value of `self`.
+    pub fn begin_string(&self) -> &'a [u8] {
+        match self {
+            Self::Standard(version) => version.begin_string(),
+            Self::Custom(begin_string) => begin_string,
+        }
+    }
+
+    /// Returns the [`AppVersion`] of `self`, unless it's custom.
+    pub fn app_version(&self) -> Option<AppVersion> {
+        match self {
+            Self::Standard(version) => Some(*version),
+            Self::Custom(_) => None,
+        }
+    }
+}
+
+impl<'a> From<AppVersion> for FixVersion<'a> {
+    fn from(version: AppVersion) -> Self {
+        Self::Standard(version)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn begin_string_round_trip() {
+        for version in AppVersion::ALL {
+            let parsed = AppVersion::from_begin_string(version.begin_string()).unwrap();
+            assert_eq!(parsed.begin_string(), version.begin_string());
+        }
+    }
+
+    #[test]
+    fn fix_version_of_custom_begin_string() {
+        let version = FixVersion::from_begin_string(b"MYPROTO.1.0");
+        assert_eq!(version, FixVersion::Custom(b"MYPROTO.1.0"));
+        assert_eq!(version.begin_string(), b"MYPROTO.1.0");
+        assert_eq!(version.app_version(), None);
+        let version = FixVersion::from_begin_string(b"FIXT.1.1");
+        assert_eq!(version.app_version(), Some(AppVersion::Fixt11));
+        assert_eq!(version.begin_string(), b"FIXT.1.1");
+    }
+
+    #[test]
+    fn fix_version_from_app_version() {
+        for version in AppVersion::ALL {
+            let fix_version = FixVersion::from(*version);
+            assert_eq!(fix_version.app_version(), Some(*version));
+            assert_eq!(fix_version.begin_string(), version.begin_string());
+        }
+    }
+}
@@ -1,5 +1,6 @@
 //! Zero-copy buffering utilities.
 
+use std::fmt;
 use std::io;
 
 /// Operations on a growable in-memory buffer. Superset of [`std::io::Write`].
@@ -23,6 +24,34 @@ pub trait Buffer: io::Write {
         self.as_slice().len()
     }
 
+    /// Returns the current position within `self`, to be later passed to
+    /// [`Buffer::slice_from`] to access everything written since then.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::Buffer;
+    ///
+    /// let mut buffer = b"8=FIX.4.4|".to_vec();
+    /// let mark = buffer.mark();
+    /// buffer.extend_from_slice(b"35=0|");
+    /// assert_eq!(buffer.slice_from(mark), b"35=0|");
+    /// ```
+    fn mark(&self) -> usize {
+        self.len()
+    }
+
+    /// Returns the contents of `self` after the position `mark`, which was
+    /// obtained from [`Buffer::mark`].
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `mark` is greater than [`Buffer::len`], e.g.
+    /// because `self` was cleared in the meantime.
+    fn slice_from(&self, mark: usize) -> &[u8] {
+        &self.as_slice()[mark..]
+    }
+
     /// Returns the number of bytes that `self` can hold without reallocating.
     fn capacity(&self) -> usize;
 
@@ -33,6 +62,23 @@ pub trait Buffer: io::Write {
     /// necessary.
     fn extend_from_slice(&mut self, extend: &[u8]);
 
+    /// Like [`Buffer::extend_from_slice`], but fails with [`BufferFull`]
+    /// rather than discarding data when `self` has fixed-capacity storage and
+    /// `extend` doesn't fit. In that case `self` is left unchanged.
+    ///
+    /// Growable buffers always succeed.
+    fn try_extend_from_slice(&mut self, extend: &[u8]) -> Result<(), BufferFull> {
+        self.extend_from_slice(extend);
+        Ok(())
+    }
+
+    /// Returns `true` if and only if some data was discarded since the last
+    /// call to [`Buffer::clear`], because `self` ran out of storage. Always
+    /// `false` for growable buffers.
+    fn overflowed(&self) -> bool {
+        false
+    }
+
     fn resize(&mut self, new_len: usize, filler: u8) {
         for _ in 0..new_len - self.as_slice().len() {
             self.extend_from_slice(&[filler]);
@@ -66,6 +112,210 @@ impl Buffer for Vec<u8> {
     }
 }
 
+/// The error returned when a fixed-capacity [`Buffer`] runs out of storage.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BufferFull;
+
+impl fmt::Display for BufferFull {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The buffer is full.")
+    }
+}
+
+impl std::error::Error for BufferFull {}
+
+/// A [`Buffer`] with fixed capacity `N`, backed by an array, i.e. it never
+/// allocates.
+///
+/// [`Buffer::extend_from_slice`] and [`std::io::Write::write`] only write as
+/// many bytes as they fit and discard the rest, which is then reported by
+/// [`Buffer::overflowed`]. Use [`Buffer::try_extend_from_slice`] to detect it
+/// right away.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::{ArrayBuffer, Buffer};
+///
+/// let mut buffer = ArrayBuffer::<8>::new();
+/// buffer.extend_from_slice(b"35=0|");
+/// assert!(buffer.try_extend_from_slice(b"49=A|").is_err());
+/// assert_eq!(buffer.as_slice(), b"35=0|");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArrayBuffer<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+    overflowed: bool,
+}
+
+impl<const N: usize> ArrayBuffer<N> {
+    /// Creates a new, empty [`ArrayBuffer`].
+    pub fn new() -> Self {
+        Self {
+            bytes: [0; N],
+            len: 0,
+            overflowed: false,
+        }
+    }
+}
+
+impl<const N: usize> Default for ArrayBuffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> io::Write for ArrayBuffer<N> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = buf.len().min(N - self.len);
+        self.bytes[self.len..self.len + len].copy_from_slice(&buf[..len]);
+        self.len += len;
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<const N: usize> Buffer for ArrayBuffer<N> {
+    fn as_slice(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.bytes[..self.len]
+    }
+
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn clear(&mut self) {
+        self.len = 0;
+        self.overflowed = false;
+    }
+
+    fn extend_from_slice(&mut self, extend: &[u8]) {
+        let written = io::Write::write(self, extend).unwrap_or(0);
+        if written < extend.len() {
+            self.overflowed = true;
+        }
+    }
+
+    fn try_extend_from_slice(&mut self, extend: &[u8]) -> Result<(), BufferFull> {
+        if extend.len() > N - self.len {
+            return Err(BufferFull);
+        }
+        self.extend_from_slice(extend);
+        Ok(())
+    }
+
+    fn overflowed(&self) -> bool {
+        self.overflowed
+    }
+
+    fn resize(&mut self, new_len: usize, filler: u8) {
+        if new_len > N {
+            self.overflowed = true;
+        }
+        let new_len = new_len.min(N);
+        if new_len > self.len {
+            for byte in &mut self.bytes[self.len..new_len] {
+                *byte = filler;
+            }
+        }
+        self.len = new_len;
+    }
+}
+
+/// A [`Buffer`] backed by [`bytes::BytesMut`], for zero-copy handoff to async
+/// network stacks: encode into it, then [`split`](bytes::BytesMut::split) and
+/// [`freeze`](bytes::BytesMut::freeze) the inner [`BytesMut`](bytes::BytesMut).
+///
+/// A newtype is necessary because [`Buffer`] requires [`std::io::Write`],
+/// which [`BytesMut`](bytes::BytesMut) doesn't implement. Only available with
+/// the `bytes` feature.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::encode_raw;
+/// use fefix::{Buffer, BytesMutBuffer};
+///
+/// let mut buffer = BytesMutBuffer::default();
+/// encode_raw(b"FIX.4.4", |b: &mut BytesMutBuffer| {
+///     b.extend_from_slice(b"35=0|");
+///     5
+/// }, &mut buffer, b'|')
+/// .unwrap();
+/// let frozen = buffer.0.split().freeze();
+/// assert!(frozen.starts_with(b"8=FIX.4.4|9=000005|35=0|10="));
+/// ```
+#[cfg(feature = "bytes")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BytesMutBuffer(pub bytes::BytesMut);
+
+#[cfg(feature = "bytes")]
+impl BytesMutBuffer {
+    /// Creates a new, empty [`BytesMutBuffer`] with the specified capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(bytes::BytesMut::with_capacity(capacity))
+    }
+
+    /// Consumes `self` and returns the inner [`BytesMut`](bytes::BytesMut).
+    pub fn into_inner(self) -> bytes::BytesMut {
+        self.0
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl From<bytes::BytesMut> for BytesMutBuffer {
+    fn from(bytes: bytes::BytesMut) -> Self {
+        Self(bytes)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl io::Write for BytesMutBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl Buffer for BytesMutBuffer {
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.0[..]
+    }
+
+    fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    fn clear(&mut self) {
+        self.0.clear()
+    }
+
+    fn extend_from_slice(&mut self, extend: &[u8]) {
+        self.0.extend_from_slice(extend)
+    }
+
+    fn resize(&mut self, new_len: usize, filler: u8) {
+        self.0.resize(new_len, filler)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -83,4 +333,62 @@ mod test {
             .tests(1000)
             .quickcheck(prop as fn(Vec<u8>) -> bool)
     }
+
+    #[test]
+    fn array_buffer_discards_overflowing_data() {
+        let mut buffer = ArrayBuffer::<4>::new();
+        buffer.extend_from_slice(b"abc");
+        assert!(!buffer.overflowed());
+        buffer.extend_from_slice(b"def");
+        assert!(buffer.overflowed());
+        assert_eq!(buffer.as_slice(), b"abcd");
+        buffer.clear();
+        assert!(!buffer.overflowed());
+        buffer.resize(2, b'x');
+        assert_eq!(buffer.as_slice(), b"xx");
+    }
+
+    #[test]
+    fn encode_into_undersized_array_buffer() {
+        use crate::tagvalue::{encode_raw, EncodeError};
+
+        fn encode<B: Buffer>(buffer: &mut B) -> Result<usize, EncodeError> {
+            let body_writer = |buffer: &mut B| {
+                buffer.extend_from_slice(b"35=D|55=AAPL|54=1|");
+                18
+            };
+            encode_raw(b"FIX.4.4", body_writer, buffer, b'|')
+        }
+        let mut vec = Vec::new();
+        encode(&mut vec).unwrap();
+        let mut large = ArrayBuffer::<64>::new();
+        assert_eq!(encode(&mut large), Ok(vec.len()));
+        assert_eq!(large.as_slice(), &vec[..]);
+        // Too small for the body.
+        let mut small = ArrayBuffer::<24>::new();
+        assert_eq!(encode(&mut small), Err(EncodeError::BufferFull));
+        // Too small for the trailer only.
+        let mut small = ArrayBuffer::<40>::new();
+        assert_eq!(encode(&mut small), Err(EncodeError::BufferFull));
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn encode_into_bytes_mut_matches_vec() {
+        use crate::tagvalue::encode_raw;
+
+        fn encode<B: Buffer>(buffer: &mut B) {
+            let body_writer = |buffer: &mut B| {
+                buffer.extend_from_slice(b"35=D|55=AAPL|54=1|");
+                18
+            };
+            encode_raw(b"FIX.4.4", body_writer, buffer, b'|').unwrap();
+        }
+        let mut vec = Vec::new();
+        encode(&mut vec);
+        let mut bytes = BytesMutBuffer::default();
+        encode(&mut bytes);
+        assert_eq!(&bytes.0.split().freeze()[..], &vec[..]);
+        assert!(bytes.0.is_empty());
+    }
 }
@@ -10,29 +10,116 @@ use std::io;
 use std::ops::Range;
 use std::sync::Arc;
 
-/// Value for the field `MsgType (35)`.
-#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
-pub struct MsgType(u16);
+macro_rules! define_msg_types {
+    ($($(#[$attr:meta])* $name:ident => $value:literal),* $(,)?) => {
+        /// Value for the field `MsgType (35)`, i.e. a string of one or two
+        /// characters. Well-known message types have their own variants; all
+        /// others are [`MsgType::Custom`].
+        #[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum MsgType {
+            $($(#[$attr])* $name,)*
+            /// Any other message type. Single-character message types are
+            /// padded with a trailing zero byte.
+            Custom([u8; 2]),
+        }
+
+        impl MsgType {
+            /// Parses a [`MsgType`] out of the value of `MsgType (35)`. Returns
+            /// `None` if `bytes` isn't one or two characters long or if it
+            /// contains zero bytes.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use fefix::dictionary::MsgType;
+            ///
+            /// assert_eq!(MsgType::from_bytes(b"AE"), Some(MsgType::TradeCaptureReport));
+            /// assert_eq!(MsgType::from_bytes(b"U1"), Some(MsgType::Custom(*b"U1")));
+            /// assert_eq!(MsgType::from_bytes(b"ABC"), None);
+            /// ```
+            pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+                match bytes {
+                    $($value => Some(Self::$name),)*
+                    [a] if *a != 0 => Some(Self::Custom([*a, 0])),
+                    [a, b] if *a != 0 && *b != 0 => Some(Self::Custom([*a, *b])),
+                    _ => None,
+                }
+            }
+
+            /// Returns the value of `MsgType (35)` for `self`.
+            pub fn as_bytes(&self) -> &[u8] {
+                match self {
+                    $(Self::$name => $value,)*
+                    Self::Custom(bytes) if bytes[1] == 0 => &bytes[..1],
+                    Self::Custom(bytes) => &bytes[..],
+                }
+            }
+        }
+    };
+}
+
+define_msg_types! {
+    Heartbeat => b"0",
+    TestRequest => b"1",
+    ResendRequest => b"2",
+    Reject => b"3",
+    SequenceReset => b"4",
+    Logout => b"5",
+    ExecutionReport => b"8",
+    OrderCancelReject => b"9",
+    Logon => b"A",
+    NewOrderSingle => b"D",
+    NewOrderList => b"E",
+    OrderCancelRequest => b"F",
+    OrderCancelReplaceRequest => b"G",
+    OrderStatusRequest => b"H",
+    MarketDataRequest => b"V",
+    MarketDataSnapshotFullRefresh => b"W",
+    MarketDataIncrementalRefresh => b"X",
+    MarketDataRequestReject => b"Y",
+    BusinessMessageReject => b"j",
+    XmlNonFix => b"n",
+    TradeCaptureReportRequest => b"AD",
+    TradeCaptureReport => b"AE",
+    TradeCaptureReportAck => b"AR",
+    UserRequest => b"BE",
+    UserResponse => b"BF",
+}
 
 impl MsgType {
     pub fn write(&self, writer: &mut impl io::Write) -> io::Result<()> {
-        let bytes = self.0.to_be_bytes();
-        for byte in bytes.iter() {
-            writer.write(&[*byte])?;
-        }
-        Ok(())
+        writer.write_all(self.as_bytes())
     }
 
-    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
-        if bytes.is_empty() || bytes.len() >= 3 {
-            None
-        } else {
-            let mut value: u16 = 0;
-            for byte in bytes {
-                value = (value << 8) + (*byte as u16);
-            }
-            Some(Self(value))
-        }
+    /// Returns `true` if `self` is a session-level (a.k.a. administrative)
+    /// message type, as defined by FIXT 1.1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::dictionary::MsgType;
+    ///
+    /// assert!(MsgType::from_bytes(b"A").unwrap().is_admin());
+    /// assert!(!MsgType::from_bytes(b"AE").unwrap().is_admin());
+    /// ```
+    pub fn is_admin(&self) -> bool {
+        matches!(
+            self,
+            Self::Heartbeat
+                | Self::TestRequest
+                | Self::ResendRequest
+                | Self::Reject
+                | Self::SequenceReset
+                | Self::Logout
+                | Self::Logon
+                | Self::XmlNonFix
+        )
+    }
+}
+
+impl fmt::Display for MsgType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", String::from_utf8_lossy(self.as_bytes()))
     }
 }
 
@@ -644,12 +731,12 @@ pub struct FieldEnum<'a>(&'a Dictionary, &'a FieldEnumData);
 
 impl<'a> FieldEnum<'a> {
     /// Returns the string representation of this field variant.
-    pub fn value(&self) -> &str {
+    pub fn value(&self) -> &'a str {
         &self.1.value[..]
     }
 
     /// Returns the documentation description for `self`.
-    pub fn description(&self) -> &str {
+    pub fn description(&self) -> &'a str {
         &self.1.description[..]
     }
 }
@@ -700,11 +787,12 @@ impl<'a> Field<'a> {
         self.1.tag
     }
 
-    pub fn enums(&self) -> Option<impl Iterator<Item = FieldEnum>> {
+    pub fn enums(&self) -> Option<impl Iterator<Item = FieldEnum<'a>>> {
+        let dict = self.0;
         self.1
             .value_restrictions
             .as_ref()
-            .map(move |v| v.iter().map(move |f| FieldEnum(self.0, f)))
+            .map(move |v| v.iter().map(move |f| FieldEnum(dict, f)))
     }
 
     /// Returns the [`Datatype`] of `self`.
@@ -1144,7 +1232,15 @@ mod quickfix {
                         .attribute("name")
                         .ok_or(ParseDictionaryError::InvalidFormat)?
                         .to_string();
-                    import_component(&mut reader.builder, child, name)?;
+                    // Components that are referenced by other components
+                    // have already been imported.
+                    if reader
+                        .builder
+                        .symbol(KeyRef::ComponentByName(name.as_str()))
+                        .is_none()
+                    {
+                        import_component(&mut reader.builder, child, name)?;
+                    }
                 }
             }
             for child in reader.node_with_messages.children() {
@@ -1243,15 +1339,7 @@ mod quickfix {
     ) -> ParseResult<InternalId> {
         debug_assert_eq!(node.tag_name().name(), "message");
         let category_iid = import_category(builder, node)?;
-        let layout_start = builder.layout_items.len() as u32;
-        for child in node.children() {
-            if child.is_element() {
-                // We don't need to generate new IID's because we're dealing
-                // with ranges.
-                import_layout_item(builder, child)?;
-            }
-        }
-        let layout_end = builder.layout_items.len() as u32;
+        let layout_items = import_layout_items(builder, node)?;
         let message = MessageData {
             name: node
                 .attribute("name")
@@ -1264,7 +1352,7 @@ mod quickfix {
             component_id: 0,
             category_iid,
             section_id: String::new(),
-            layout_items: layout_start..layout_end,
+            layout_items,
             abbr_name: None,
             required: true,
             elaboration: None,
@@ -1278,31 +1366,58 @@ mod quickfix {
         node: roxmltree::Node,
         name: S,
     ) -> ParseResult<InternalId> {
-        let layout_start = builder.layout_items.len() as u32;
-        for child in node.children() {
-            if child.is_element() {
-                import_layout_item(builder, child)?;
-            }
-        }
-        let layout_end = builder.layout_items.len() as u32;
+        let layout_items_iid_range = import_layout_items(builder, node)?;
         let component = ComponentData {
             id: 0,
             component_type: ComponentType::Block,
-            layout_items_iid_range: layout_start..layout_end,
+            layout_items_iid_range,
             category_iid: 0, // FIXME
             name: name.as_ref().to_string(),
             abbr_name: None,
         };
-        let iid = builder.add_component(component);
-        match builder.symbol(KeyRef::ComponentByName(name.as_ref())) {
-            Some(x) => Ok(*x),
-            None => {
-                builder
-                    .symbol_table
-                    .insert(Key::ComponentByName(name.as_ref().to_string()), iid);
-                Ok(iid)
+        Ok(builder.add_component(component))
+    }
+
+    /// Imports the children of `node` as a contiguous range of layout items.
+    /// Nested components are imported first, so that they don't end up in
+    /// the middle of the range.
+    fn import_layout_items(
+        builder: &mut DictionaryBuilder,
+        node: roxmltree::Node,
+    ) -> ParseResult<Range<u32>> {
+        let mut items = Vec::new();
+        for child in node.children() {
+            if child.is_element() {
+                items.push(import_layout_item(builder, child)?);
             }
         }
+        let layout_start = builder.layout_items.len() as u32;
+        builder.layout_items.extend(items);
+        let layout_end = builder.layout_items.len() as u32;
+        Ok(layout_start..layout_end)
+    }
+
+    /// Returns the [`InternalId`] of the component `name`, which is imported
+    /// from the `<components>` section if needed.
+    fn import_component_reference(
+        builder: &mut DictionaryBuilder,
+        node: roxmltree::Node,
+        name: &str,
+    ) -> ParseResult<InternalId> {
+        if let Some(iid) = builder.symbol(KeyRef::ComponentByName(name)) {
+            return Ok(*iid);
+        }
+        let definition = node
+            .document()
+            .root_element()
+            .children()
+            .filter(|n| n.has_tag_name("components"))
+            .flat_map(|n| n.children())
+            .find(|n| n.is_element() && n.attribute("name") == Some(name))
+            .ok_or_else(|| {
+                ParseDictionaryError::InvalidData(format!("Component '{}' not found.", name))
+            })?;
+        import_component(builder, definition, name)
     }
 
     fn import_datatype(builder: &mut DictionaryBuilder, node: roxmltree::Node) -> InternalId {
@@ -1360,7 +1475,7 @@ mod quickfix {
     fn import_layout_item(
         builder: &mut DictionaryBuilder,
         node: roxmltree::Node,
-    ) -> ParseResult<InternalId> {
+    ) -> ParseResult<LayoutItemData> {
         // This processing step requires on fields being already present in
         // the dictionary.
         debug_assert_ne!(builder.fields.len(), 0);
@@ -1374,28 +1489,25 @@ mod quickfix {
             }
             "component" => {
                 // Components may *not* be already present.
-                let component_iid = import_component(builder, node, name)?;
+                let component_iid = import_component_reference(builder, node, name)?;
                 LayoutItemKindData::Component { iid: component_iid }
             }
             "group" => {
                 let len_field_iid = *builder.symbol(KeyRef::FieldByName(name)).unwrap();
-                let mut iids = Vec::new();
+                let mut items = Vec::new();
                 for child in node.children().filter(|n| n.is_element()) {
-                    iids.push(import_layout_item(builder, child)?);
+                    items.push(import_layout_item(builder, child)?);
                 }
                 LayoutItemKindData::Group {
                     len_field_iid,
-                    items: Vec::new(),
+                    items,
                 }
             }
             _ => {
                 return Err(ParseDictionaryError::InvalidFormat);
             }
         };
-        let item = LayoutItemData { required, kind };
-        let iid = builder.layout_items.len() as InternalId;
-        builder.layout_items.push(item);
-        Ok(iid)
+        Ok(LayoutItemData { required, kind })
     }
 
     fn import_category(
@@ -1443,7 +1555,10 @@ mod test {
     fn msg_type_conversion() {
         fn prop(val: u16) -> bool {
             let bytes = val.to_le_bytes();
-            let msg_type = MsgType::from_bytes(&bytes[..]).unwrap();
+            let msg_type = match MsgType::from_bytes(&bytes[..]) {
+                Some(msg_type) => msg_type,
+                None => return bytes.contains(&0),
+            };
             let mut buffer = vec![0, 0];
             msg_type.write(&mut &mut buffer[..]).unwrap();
             val == u16::from_le_bytes((&buffer[..]).try_into().unwrap())
@@ -1453,6 +1568,32 @@ mod test {
             .quickcheck(prop as fn(u16) -> bool)
     }
 
+    #[test]
+    fn single_char_msg_type_conversion() {
+        let msg_type = MsgType::from_bytes(b"U").unwrap();
+        assert_eq!(msg_type, MsgType::Custom([b'U', 0]));
+        assert_eq!(msg_type.as_bytes(), b"U");
+        let mut buffer = Vec::new();
+        MsgType::Logon.write(&mut buffer).unwrap();
+        assert_eq!(buffer, b"A");
+    }
+
+    #[test]
+    fn known_msg_types_match_dictionary_names() {
+        let dict = Dictionary::from_version(AppVersion::Fix44);
+        for (msg_type, name) in &[
+            (MsgType::Heartbeat, "Heartbeat"),
+            (MsgType::Logon, "Logon"),
+            (MsgType::NewOrderSingle, "NewOrderSingle"),
+            (MsgType::TradeCaptureReport, "TradeCaptureReport"),
+            (MsgType::TradeCaptureReportAck, "TradeCaptureReportAck"),
+            (MsgType::UserResponse, "UserResponse"),
+        ] {
+            let msg_type = std::str::from_utf8(msg_type.as_bytes()).unwrap();
+            assert_eq!(dict.message_by_msgtype(msg_type).unwrap().name(), *name);
+        }
+    }
+
     #[test]
     fn fixt11_quickfix_is_ok() {
         let dict = Dictionary::from_version(AppVersion::Fixt11);
@@ -1507,6 +1648,26 @@ mod test {
         }
     }
 
+    #[test]
+    fn fix44_components_and_groups_keep_their_items() {
+        let dict = Dictionary::from_version(AppVersion::Fix44);
+        let instrument = dict.component_by_name("Instrument").unwrap();
+        assert!(instrument.contains_field(&dict.field_by_tag(55).unwrap()));
+        let header = dict.component_by_name("StandardHeader").unwrap();
+        // `HopCompID <628>` only belongs to the `NoHops <627>` group.
+        assert!(!header.contains_field(&dict.field_by_tag(628).unwrap()));
+        let hops: Vec<String> = header
+            .items()
+            .find_map(|item| match item.kind() {
+                LayoutItemKind::Group(field, items) if field.tag() == 627 => {
+                    Some(items.iter().map(|i| i.tag_text().to_string()).collect())
+                }
+                _ => None,
+            })
+            .unwrap();
+        assert!(hops.contains(&"HopCompID".to_string()));
+    }
+
     #[test]
     fn fix44_field_28_has_three_variants() {
         let dict = Dictionary::from_version(AppVersion::Fix44);
@@ -1,8 +1,44 @@
 //! Datatype specification.
 
+use crate::AppVersion;
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
+/// All [`DataType`]s, indexed by their [`DataType::discriminant`]. New data
+/// types must only ever be appended, so that discriminants stay stable.
+const BY_DISCRIMINANT: &[DataType] = &[
+    DataType::Char,
+    DataType::Boolean,
+    DataType::Float,
+    DataType::Amt,
+    DataType::Price,
+    DataType::PriceOffset,
+    DataType::Qty,
+    DataType::Percentage,
+    DataType::Int,
+    DataType::DayOfMonth,
+    DataType::Length,
+    DataType::NumInGroup,
+    DataType::SeqNum,
+    DataType::TagNum,
+    DataType::String,
+    DataType::Data,
+    DataType::MonthYear,
+    DataType::MultipleCharValue,
+    DataType::Currency,
+    DataType::Exchange,
+    DataType::Language,
+    DataType::LocalMktDate,
+    DataType::MultipleStringValue,
+    DataType::UtcDateOnly,
+    DataType::UtcTimeOnly,
+    DataType::UtcTimestamp,
+    DataType::TzTimeOnly,
+    DataType::TzTimestamp,
+    DataType::XmlData,
+    DataType::Country,
+];
+
 /// Sum type for all possible data types ever defined across all versions of the
 /// FIX protocol.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, EnumIter)]
@@ -123,7 +159,9 @@ pub enum DataType {
     /// 0000-9999, MM = 01-12, DD = 01-31, HH = 00-23, MM = 00-59, SS = 00-60 (60
     /// only if UTC leap second) (without milliseconds). * YYYY = 0000-9999, MM =
     /// 01-12, DD = 01-31, HH = 00-23, MM = 00-59, SS = 00-60 (60 only if UTC
-    /// leap second), sss=000-999 (indicating milliseconds). Leap Seconds: Note
+    /// leap second), sss=000-999 (indicating milliseconds). Since FIX 5.0 SP2,
+    /// microseconds (YYYYMMDD-HH:MM:SS.ssssss) and nanoseconds
+    /// (YYYYMMDD-HH:MM:SS.sssssssss) are allowed as well. Leap Seconds: Note
     /// that UTC includes corrections for leap seconds, which are inserted to
     /// account for slowing of the rotation of the earth. Leap second insertion
     /// is declared by the International Earth Rotation Service (IERS) and has,
@@ -134,6 +172,17 @@ pub enum DataType {
     /// "19981231-23:59:60", "19990101-00:00:00". (see
     /// http://tycho.usno.navy.mil/leapsec.html)
     UtcTimestamp,
+    /// string field representing a time of day with an offset from UTC, in
+    /// either HH:MM[:SS[.sss]]Z or HH:MM[:SS[.sss]][+|-]hh[:mm] format, e.g.
+    /// "07:39Z" or "02:39-05:00". Seconds are limited to millisecond
+    /// precision.
+    TzTimeOnly,
+    /// string field representing a time/date combination with an offset from
+    /// UTC, in either YYYYMMDD-HH:MM[:SS[.sss]]Z or
+    /// YYYYMMDD-HH:MM[:SS[.sss]][+|-]hh[:mm] format, e.g.
+    /// "20060901-07:39Z" or "20060901-13:09+05:30". Seconds may also be given
+    /// with microsecond or nanosecond precision.
+    TzTimestamp,
     /// Contains an XML document raw data with no format or content restrictions.
     /// XMLData fields are always immediately preceded by a length field. The
     /// length field should specify the number of bytes of the value of the data
@@ -185,8 +234,8 @@ impl DataType {
             "PRICEOFFSET" => DataType::PriceOffset,
             "QTY" => DataType::Qty,
             "STRING" => DataType::String,
-            "TZTIMEONLY" => DataType::UtcTimeOnly,   // FIXME
-            "TZTIMESTAMP" => DataType::UtcTimestamp, // FIXME
+            "TZTIMEONLY" => DataType::TzTimeOnly,
+            "TZTIMESTAMP" => DataType::TzTimestamp,
             "UTCDATE" => DataType::UtcDateOnly,
             "UTCDATEONLY" => DataType::UtcDateOnly,
             "UTCTIMEONLY" => DataType::UtcTimeOnly,
@@ -227,6 +276,8 @@ impl DataType {
             DataType::MultipleStringValue => "MultipleStringValue",
             DataType::UtcTimeOnly => "UTCTimeOnly",
             DataType::UtcTimestamp => "UTCTimestamp",
+            DataType::TzTimeOnly => "TZTimeOnly",
+            DataType::TzTimestamp => "TZTimestamp",
             DataType::UtcDateOnly => "UTCDateOnly",
             DataType::Country => "Country",
             DataType::MultipleCharValue => "MultipleCharValue",
@@ -279,6 +330,8 @@ impl DataType {
             DataType::MultipleStringValue => "MultipleStringValue",
             DataType::UtcTimeOnly => "UTCTimeOnly",
             DataType::UtcTimestamp => "UTCTimestamp",
+            DataType::TzTimeOnly => "TZTimeOnly",
+            DataType::TzTimestamp => "TZTimestamp",
             DataType::UtcDateOnly => "UTCDateOnly",
             DataType::Country => "Country",
             DataType::MultipleCharValue => "MultipleCharValue",
@@ -336,6 +389,218 @@ impl DataType {
         dt
     }
 
+    /// Returns how many fields before a field of type `self` its `Length`
+    /// field must appear, or `None` if `self` is not length-prefixed.
+    ///
+    /// `Data` and `XmlData` values may contain the separator, so they must be
+    /// *immediately* preceded by their length (i.e. the offset is always 1).
+    /// Use [`length_tag_for`](crate::tagvalue::length_tag_for) to find out the
+    /// expected length tag of standard data fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::DataType;
+    ///
+    /// assert_eq!(DataType::Data.requires_preceding_length_tag(), Some(1));
+    /// assert_eq!(DataType::String.requires_preceding_length_tag(), None);
+    /// ```
+    pub fn requires_preceding_length_tag(&self) -> Option<usize> {
+        match self {
+            Self::Data | Self::XmlData => Some(1),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`StorageKind`] that best fits values of `self` in columnar
+    /// storage formats (e.g. Apache Arrow, Parquet).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::{DataType, StorageKind};
+    ///
+    /// assert_eq!(DataType::Price.storage_kind(), StorageKind::Float64);
+    /// assert_eq!(DataType::Data.storage_kind(), StorageKind::Binary);
+    /// ```
+    pub fn storage_kind(&self) -> StorageKind {
+        match self {
+            Self::Boolean => StorageKind::Bool,
+            Self::Data | Self::XmlData => StorageKind::Binary,
+            Self::UtcTimestamp | Self::TzTimestamp => StorageKind::Timestamp,
+            Self::UtcDateOnly | Self::LocalMktDate => StorageKind::Date,
+            dt => match dt.base_type() {
+                Self::Int => StorageKind::Int64,
+                Self::Float => StorageKind::Float64,
+                _ => StorageKind::Utf8,
+            },
+        }
+    }
+
+    /// Returns `true` if values of `self` may be negative according to the
+    /// specification, i.e. if `self` is a numeric type and its documentation
+    /// doesn't restrict it to non-negative values. Returns `false` for
+    /// non-numeric types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::DataType;
+    ///
+    /// assert_eq!(DataType::Price.allows_negative_values(), true);
+    /// assert_eq!(DataType::Qty.allows_negative_values(), false);
+    /// assert_eq!(DataType::String.allows_negative_values(), false);
+    /// ```
+    pub fn allows_negative_values(&self) -> bool {
+        matches!(
+            self,
+            Self::Float | Self::Amt | Self::Price | Self::PriceOffset | Self::Int
+        )
+    }
+
+    /// Performs a shallow syntax check of `value` according to the base type
+    /// of `self`.
+    ///
+    /// If `strict`, a leading `-` is only accepted for data types that
+    /// [allow negative values](DataType::allows_negative_values): e.g. `Qty`
+    /// and `Percentage` values must be non-negative. Some exotic products do
+    /// allow negative quantities, hence the lenient mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::DataType;
+    ///
+    /// assert!(DataType::Price.validate(b"-1.5", true));
+    /// assert!(!DataType::Qty.validate(b"-100", true));
+    /// assert!(DataType::Qty.validate(b"-100", false));
+    /// assert!(!DataType::Int.validate(b"1.5", false));
+    /// ```
+    pub fn validate(&self, value: &[u8], strict: bool) -> bool {
+        if value.is_empty() {
+            return false;
+        }
+        let base_type = self.base_type();
+        let is_numeric = base_type == Self::Int || base_type == Self::Float;
+        let digits = match value[0] {
+            b'-' if is_numeric && strict && !self.allows_negative_values() => return false,
+            b'-' => &value[1..],
+            _ => value,
+        };
+        match self {
+            Self::Boolean => value == b"Y" || value == b"N",
+            _ => match base_type {
+                Self::Int => !digits.is_empty() && digits.iter().all(u8::is_ascii_digit),
+                Self::Float => {
+                    digits.iter().any(u8::is_ascii_digit)
+                        && digits.iter().all(|b| b.is_ascii_digit() || *b == b'.')
+                        && digits.iter().filter(|b| **b == b'.').count() <= 1
+                }
+                Self::Char => value.len() == 1,
+                _ => true,
+            },
+        }
+    }
+
+    /// Returns the earliest FIX version in which `self` is a valid datatype,
+    /// following the version history of the official specifications. This
+    /// function never returns [`AppVersion::Fixt11`], and it returns an
+    /// [`AppVersion`] rather than a [`FixVersion`](crate::FixVersion) because
+    /// custom protocols don't introduce data types.
+    ///
+    /// `MultipleValueString`, which was renamed to `MultipleCharValue` in FIX
+    /// 5.0, is parsed as [`DataType::MultipleCharValue`] by this crate, so the
+    /// latter dates back to FIX 4.2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::{AppVersion, DataType};
+    ///
+    /// assert_eq!(DataType::Int.introduced_in(), AppVersion::Fix40);
+    /// assert_eq!(DataType::Qty.introduced_in(), AppVersion::Fix42);
+    /// assert_eq!(DataType::Language.introduced_in(), AppVersion::Fix50SP2);
+    /// ```
+    pub fn introduced_in(&self) -> AppVersion {
+        match self {
+            Self::Char
+            | Self::Int
+            | Self::Float
+            | Self::Data
+            | Self::UtcTimestamp
+            | Self::UtcDateOnly => AppVersion::Fix40,
+            Self::DayOfMonth | Self::MonthYear => AppVersion::Fix41,
+            Self::Boolean
+            | Self::Amt
+            | Self::Price
+            | Self::PriceOffset
+            | Self::Qty
+            | Self::String
+            | Self::MultipleCharValue
+            | Self::Currency
+            | Self::Exchange
+            | Self::LocalMktDate
+            | Self::UtcTimeOnly => AppVersion::Fix42,
+            Self::Length
+            | Self::NumInGroup
+            | Self::SeqNum
+            | Self::TagNum
+            | Self::Percentage
+            | Self::Country => AppVersion::Fix43,
+            Self::MultipleStringValue | Self::TzTimeOnly | Self::TzTimestamp => AppVersion::Fix50,
+            Self::XmlData => AppVersion::Fix50SP1,
+            Self::Language => AppVersion::Fix50SP2,
+        }
+    }
+
+    /// Returns `true` if and only if values of `self` are expressed in UTC,
+    /// i.e. `self` is one of [`DataType::UtcTimestamp`],
+    /// [`DataType::UtcTimeOnly`] and [`DataType::UtcDateOnly`]. `TZ` types
+    /// carry their own offset from UTC, see
+    /// [`DtfTzTimestamp::to_utc`](crate::DtfTzTimestamp::to_utc) and
+    /// [`DtfTzTime::to_utc`](crate::DtfTzTime::to_utc).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::DataType;
+    ///
+    /// assert_eq!(DataType::UtcTimestamp.is_utc(), true);
+    /// assert_eq!(DataType::TzTimestamp.is_utc(), false);
+    /// assert_eq!(DataType::LocalMktDate.is_utc(), false);
+    /// ```
+    pub fn is_utc(&self) -> bool {
+        matches!(
+            self,
+            Self::UtcTimestamp | Self::UtcTimeOnly | Self::UtcDateOnly
+        )
+    }
+
+    /// Returns a numeric identifier of `self` which is guaranteed not to change
+    /// across releases, e.g. for storing [`DataType`]s in binary formats. See
+    /// [`DataType::from_discriminant`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::DataType;
+    ///
+    /// assert_eq!(DataType::Char.discriminant(), 0);
+    /// assert_eq!(DataType::String.discriminant(), 14);
+    /// ```
+    pub fn discriminant(&self) -> u8 {
+        BY_DISCRIMINANT
+            .iter()
+            .position(|dt| dt == self)
+            .expect("Missing discriminant") as u8
+    }
+
+    /// Returns the [`DataType`] with the given
+    /// [`discriminant`](DataType::discriminant), if any.
+    pub fn from_discriminant(discriminant: u8) -> Option<Self> {
+        BY_DISCRIMINANT.get(usize::from(discriminant)).copied()
+    }
+
     /// Returns an [`Iterator`] over all variants of
     /// [`DataType`].
     pub fn iter_all() -> impl Iterator<Item = Self> {
@@ -343,6 +608,26 @@ impl DataType {
     }
 }
 
+/// Physical column types for storing FIX field values in columnar formats. See
+/// [`DataType::storage_kind`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum StorageKind {
+    /// 64-bits signed integers.
+    Int64,
+    /// 64-bits floating point numbers.
+    Float64,
+    /// Booleans.
+    Bool,
+    /// UTF-8 strings.
+    Utf8,
+    /// Arbitrary bytes.
+    Binary,
+    /// Date and time of day.
+    Timestamp,
+    /// Dates without time of day.
+    Date,
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -374,6 +659,16 @@ mod test {
         assert_eq!(as_vec.len(), as_set.len());
     }
 
+    #[test]
+    fn discriminants_are_unique() {
+        let discriminants: HashSet<u8> = DataType::iter_all().map(|dt| dt.discriminant()).collect();
+        assert_eq!(discriminants.len(), DataType::iter_all().count());
+        for dt in DataType::iter_all() {
+            assert_eq!(DataType::from_discriminant(dt.discriminant()), Some(dt));
+        }
+        assert_eq!(DataType::from_discriminant(u8::MAX), None);
+    }
+
     #[test]
     fn base_type_is_itself() {
         for dt in DataType::iter_all() {
@@ -391,4 +686,75 @@ mod test {
             assert!(dt.base_type().is_base_type());
         }
     }
+
+    #[test]
+    fn introduced_in_known_cases() {
+        assert_eq!(DataType::Data.introduced_in(), AppVersion::Fix40);
+        assert_eq!(DataType::MonthYear.introduced_in(), AppVersion::Fix41);
+        assert_eq!(DataType::Currency.introduced_in(), AppVersion::Fix42);
+        assert_eq!(DataType::Percentage.introduced_in(), AppVersion::Fix43);
+        assert_eq!(DataType::XmlData.introduced_in(), AppVersion::Fix50SP1);
+        assert_eq!(DataType::Language.introduced_in(), AppVersion::Fix50SP2);
+    }
+
+    #[test]
+    fn introduced_in_agrees_with_field_tables() {
+        let position = |version| AppVersion::ALL.iter().position(|v| *v == version);
+        for version in AppVersion::ALL {
+            if *version == AppVersion::Fixt11 {
+                continue;
+            }
+            for (tag, _, dt) in crate::tags::all_tags(*version) {
+                assert!(
+                    position(dt.introduced_in()) <= position(*version),
+                    "Tag {} has type {:?} in {:?}",
+                    tag,
+                    dt,
+                    version
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn storage_kind_of_representative_datatypes() {
+        assert_eq!(DataType::SeqNum.storage_kind(), StorageKind::Int64);
+        assert_eq!(DataType::Qty.storage_kind(), StorageKind::Float64);
+        assert_eq!(DataType::Boolean.storage_kind(), StorageKind::Bool);
+        assert_eq!(DataType::Currency.storage_kind(), StorageKind::Utf8);
+        assert_eq!(DataType::XmlData.storage_kind(), StorageKind::Binary);
+        assert_eq!(
+            DataType::UtcTimestamp.storage_kind(),
+            StorageKind::Timestamp
+        );
+        assert_eq!(DataType::LocalMktDate.storage_kind(), StorageKind::Date);
+    }
+
+    #[test]
+    fn negative_values_in_strict_mode() {
+        for dt in &[
+            DataType::Price,
+            DataType::PriceOffset,
+            DataType::Amt,
+            DataType::Float,
+        ] {
+            assert!(dt.validate(b"-0.5", true));
+        }
+        assert!(DataType::Int.validate(b"-723", true));
+        for dt in &[DataType::Qty, DataType::Percentage] {
+            assert!(!dt.validate(b"-0.5", true));
+            assert!(dt.validate(b"-0.5", false));
+            assert!(dt.validate(b"0.5", true));
+        }
+        for dt in &[DataType::SeqNum, DataType::Length, DataType::NumInGroup] {
+            assert!(!dt.validate(b"-1", true));
+        }
+    }
+
+    #[test]
+    fn leading_dash_is_fine_for_non_numeric_types() {
+        assert!(DataType::String.validate(b"-foo", true));
+        assert!(DataType::Char.validate(b"-", true));
+        assert!(!DataType::Boolean.validate(b"-", true));
+    }
 }
@@ -3,8 +3,9 @@ use crate::Buffer;
 
 const LEN_IN_BYTES: usize = 8;
 
-/// Concrete value for [`DataType::LocalMktDate`](crate::DataType::LocalMktDate)
-/// and [`DataType::UTCDateOnly`](crate::DataType::UtcDateOnly) fields.
+/// Concrete value for [`DataType::UTCDateOnly`](crate::DataType::UtcDateOnly)
+/// fields. See [`DtfLocalMktDate`](crate::DtfLocalMktDate) for
+/// [`DataType::LocalMktDate`](crate::DataType::LocalMktDate) fields.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct DtfDate {
     year: u32,
@@ -38,7 +39,7 @@ impl DtfDate {
 
     pub fn to_bytes(&self) -> [u8; LEN_IN_BYTES] {
         fn to_digit(n: u32) -> u8 {
-            (n - (b'0' as u32)) as u8
+            n as u8 + b'0'
         }
         [
             to_digit(self.year() / 1000),
@@ -67,6 +68,22 @@ impl DtfDate {
         self.day
     }
 
+    /// Returns the date `days` days after (or before, if negative) `self`, or
+    /// `None` if it falls outside of the `0000-9999` year range.
+    pub(crate) fn add_days(&self, days: i64) -> Option<Self> {
+        let (year, month, day) =
+            civil_from_days(days_from_civil(self.year, self.month, self.day) + days);
+        if (0..=9999).contains(&year) {
+            Some(Self {
+                year: year as u32,
+                month,
+                day,
+            })
+        } else {
+            None
+        }
+    }
+
     #[cfg(feature = "chrono-time")]
     pub fn to_chrono_utc_date(&self) -> chrono::Date {
         let naive = self.to_chrono_naivedate();
@@ -79,6 +96,33 @@ impl DtfDate {
     }
 }
 
+// Proleptic Gregorian calendar conversions from and to the number of days
+// since 1970-01-01. See http://howardhinnant.github.io/date_algorithms.html.
+
+fn days_from_civil(year: u32, month: u32, day: u32) -> i64 {
+    let year = year as i64 - (month <= 2) as i64;
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+    (year, month, day)
+}
+
 impl SerializeField for DtfDate {
     fn serialize<B>(&self, buffer: &mut B) -> usize
     where
@@ -0,0 +1,223 @@
+use crate::tagvalue::SerializeField;
+use crate::Buffer;
+use std::fmt;
+
+/// The maximum number of digits after the decimal point that a [`DtfDecimal`]
+/// can hold.
+const MAX_SCALE: u8 = 18;
+
+/// Concrete value for [`DataType::Float`](crate::DataType::Float) fields and
+/// all data types derived from it (e.g. `Price`, `Qty`, `Amt`).
+///
+/// Values are stored exactly as they appear on the wire, i.e. as an integer
+/// mantissa and the number of digits after the decimal point, so that no
+/// precision is lost to binary floating point.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DtfDecimal {
+    mantissa: i64,
+    scale: u8,
+}
+
+impl DtfDecimal {
+    /// Parses from a sequence of digits with optional sign character and
+    /// optional decimal point, e.g. `"-23.0500"`.
+    ///
+    /// Returns `None` if `data` is not a valid FIX `float` or if it doesn't
+    /// fit into a [`DtfDecimal`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::DtfDecimal;
+    ///
+    /// let decimal = DtfDecimal::parse(b"-23.050").unwrap();
+    /// assert_eq!(decimal.mantissa(), -23050);
+    /// assert_eq!(decimal.scale(), 3);
+    /// assert!(DtfDecimal::parse(b"1e5").is_none());
+    /// ```
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        let (is_negative, digits) = match data.split_first() {
+            Some((b'-', rest)) => (true, rest),
+            _ => (false, data),
+        };
+        let mut mantissa: i64 = 0;
+        let mut scale: Option<u8> = None;
+        let mut has_digits = false;
+        for byte in digits.iter().copied() {
+            match byte {
+                b'0'..=b'9' => {
+                    let digit = (byte - b'0') as i64;
+                    mantissa = mantissa.checked_mul(10)?.checked_add(digit)?;
+                    has_digits = true;
+                    if let Some(scale) = scale.as_mut() {
+                        *scale += 1;
+                        if *scale > MAX_SCALE {
+                            return None;
+                        }
+                    }
+                }
+                b'.' if scale.is_none() => scale = Some(0),
+                _ => return None,
+            }
+        }
+        if !has_digits {
+            return None;
+        }
+        Some(Self {
+            mantissa: if is_negative { -mantissa } else { mantissa },
+            scale: scale.unwrap_or(0),
+        })
+    }
+
+    /// Returns the integer mantissa of `self`, i.e. the value of `self` with
+    /// the decimal point removed.
+    pub fn mantissa(&self) -> i64 {
+        self.mantissa
+    }
+
+    /// Returns the number of digits after the decimal point.
+    pub fn scale(&self) -> u8 {
+        self.scale
+    }
+
+    /// Converts `self` to the nearest [`f64`].
+    pub fn to_f64(&self) -> f64 {
+        self.mantissa as f64 / 10f64.powi(self.scale as i32)
+    }
+
+    /// Returns `true` if `self` and `other` represent the same number,
+    /// regardless of their scale, e.g. `23.0` and `23.00`.
+    pub fn numeric_eq(&self, other: &Self) -> bool {
+        let scale = self.scale.max(other.scale);
+        self.rescaled_mantissa(scale) == other.rescaled_mantissa(scale)
+    }
+
+    fn rescaled_mantissa(&self, scale: u8) -> i128 {
+        // Both scales are at most `MAX_SCALE`, so this can't overflow.
+        self.mantissa as i128 * 10i128.pow((scale - self.scale) as u32)
+    }
+}
+
+/// Compares two FIX `float` values (e.g. `Price`, `Qty`) numerically, ignoring
+/// non-significant formatting such as leading zeros, trailing zeros, and a
+/// trailing decimal point.
+///
+/// Returns `false` if either `a` or `b` is not a valid [`DtfDecimal`].
+///
+/// # Examples
+///
+/// ```
+/// use fefix::fix_float_eq;
+///
+/// assert!(fix_float_eq(b"00023.23", b"23.23"));
+/// assert!(fix_float_eq(b"23", b"23.00"));
+/// assert!(!fix_float_eq(b"23", b"23.01"));
+/// assert!(!fix_float_eq(b"23", b"foo"));
+/// ```
+pub fn fix_float_eq(a: &[u8], b: &[u8]) -> bool {
+    match (DtfDecimal::parse(a), DtfDecimal::parse(b)) {
+        (Some(a), Some(b)) => a.numeric_eq(&b),
+        _ => false,
+    }
+}
+
+impl fmt::Display for DtfDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        let abs = self.mantissa.unsigned_abs();
+        if self.scale == 0 {
+            return write!(f, "{}{}", sign, abs);
+        }
+        let divisor = 10u64.pow(self.scale as u32);
+        write!(
+            f,
+            "{}{}.{:0width$}",
+            sign,
+            abs / divisor,
+            abs % divisor,
+            width = self.scale as usize
+        )
+    }
+}
+
+impl SerializeField for DtfDecimal {
+    fn serialize<B>(&self, buffer: &mut B) -> usize
+    where
+        B: Buffer,
+    {
+        let s = self.to_string();
+        buffer.extend_from_slice(s.as_bytes());
+        s.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const VALID_DECIMALS: &[&str] = &["0", "-1", "23.0", "23.0000", ".05", "-0.9525", "1000.5"];
+
+    const INVALID_DECIMALS: &[&[u8]] = &[
+        b"",
+        b"-",
+        b".",
+        b"+1",
+        b"1.2.3",
+        b"1e5",
+        b"1,000",
+        b"99999999999999999999",
+        b"0.1234567890123456789",
+    ];
+
+    #[test]
+    fn parse_then_display() {
+        for decimal in VALID_DECIMALS {
+            let dtf = DtfDecimal::parse(decimal.as_bytes()).unwrap();
+            let expected = if decimal.starts_with('.') {
+                format!("0{}", decimal)
+            } else {
+                decimal.to_string()
+            };
+            assert_eq!(dtf.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn detect_errors_in_edge_cases() {
+        for decimal in INVALID_DECIMALS {
+            assert!(DtfDecimal::parse(decimal).is_none());
+        }
+    }
+
+    #[test]
+    fn to_f64_is_close() {
+        let dtf = DtfDecimal::parse(b"-0.9525").unwrap();
+        assert!((dtf.to_f64() + 0.9525).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn float_eq_ignores_formatting() {
+        assert!(fix_float_eq(b"00023.23", b"23.23"));
+        for other in [
+            &b"23.0"[..],
+            b"23.00",
+            b"23.",
+            b"023",
+            b"23.000000000000000",
+        ] {
+            assert!(fix_float_eq(b"23", other));
+            assert!(fix_float_eq(other, b"23"));
+        }
+        assert!(fix_float_eq(b"-0.50", b"-.5"));
+        assert!(fix_float_eq(b"-0", b"0.0"));
+    }
+
+    #[test]
+    fn float_eq_detects_different_values() {
+        assert!(!fix_float_eq(b"23", b"230"));
+        assert!(!fix_float_eq(b"23.23", b"-23.23"));
+        assert!(!fix_float_eq(b"0.000000000000000001", b"0"));
+        assert!(!fix_float_eq(b"", b""));
+        assert!(!fix_float_eq(b"1e5", b"100000"));
+    }
+}
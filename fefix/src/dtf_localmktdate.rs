@@ -0,0 +1,86 @@
+use crate::tagvalue::SerializeField;
+use crate::{Buffer, DtfDate};
+
+/// Concrete value for [`DataType::LocalMktDate`](crate::DataType::LocalMktDate)
+/// fields, e.g. `MaturityDate <541>`.
+///
+/// Unlike [`DtfDate`], which is used for `UTCDateOnly` fields, the date is
+/// relative to the local time zone of the market center and it's never
+/// converted to or from UTC.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::DtfLocalMktDate;
+///
+/// let date = DtfLocalMktDate::parse(b"20211231").unwrap();
+/// assert_eq!((date.year(), date.month(), date.day()), (2021, 12, 31));
+/// assert_eq!(&date.to_bytes(), b"20211231");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DtfLocalMktDate {
+    date: DtfDate,
+}
+
+impl DtfLocalMktDate {
+    /// Parses from a `"YYYYMMDD"` format.
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        DtfDate::parse(data).map(|date| Self { date })
+    }
+
+    pub fn to_bytes(&self) -> [u8; 8] {
+        self.date.to_bytes()
+    }
+
+    /// Returns the `year` of `self`.
+    pub fn year(&self) -> u32 {
+        self.date.year()
+    }
+
+    /// Returns the `month` of `self`.
+    pub fn month(&self) -> u32 {
+        self.date.month()
+    }
+
+    /// Returns the `day` of `self`.
+    pub fn day(&self) -> u32 {
+        self.date.day()
+    }
+
+    #[cfg(feature = "chrono-time")]
+    pub fn to_chrono_naivedate(&self) -> chrono::NaiveDate {
+        self.date.to_chrono_naivedate()
+    }
+}
+
+impl SerializeField for DtfLocalMktDate {
+    fn serialize<B>(&self, buffer: &mut B) -> usize
+    where
+        B: Buffer,
+    {
+        self.date.serialize(buffer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_then_serialize() {
+        for date in [b"20210101", b"00000101", b"99991231", b"20200229"].iter() {
+            let local = DtfLocalMktDate::parse(*date).unwrap();
+            assert_eq!(&local.to_bytes(), *date);
+            let mut buffer = Vec::new();
+            assert_eq!(local.serialize(&mut buffer), 8);
+            assert_eq!(&buffer[..], &date[..]);
+        }
+    }
+
+    #[test]
+    fn invalid_month() {
+        for date in [b"20211301", b"20210001", b"2021a101"].iter() {
+            assert_eq!(DtfLocalMktDate::parse(*date), None);
+        }
+    }
+}
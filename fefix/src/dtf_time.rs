@@ -17,9 +17,10 @@ pub struct DtfTime {
 
 impl DtfTime {
     pub fn parse(data: &[u8]) -> Option<Self> {
-        if data.len() != LEN_IN_BYTES_NO_MILLI || data.len() != LEN_IN_BYTES_WITH_MILLI {
+        if data.len() != LEN_IN_BYTES_NO_MILLI && data.len() != LEN_IN_BYTES_WITH_MILLI {
             return None;
         }
+        let has_milli = data.len() == LEN_IN_BYTES_WITH_MILLI;
         let digits_are_ok = is_digit(data[0])
             && is_digit(data[1])
             && data[2] == b':'
@@ -27,29 +28,51 @@ impl DtfTime {
             && is_digit(data[4])
             && data[5] == b':'
             && is_digit(data[6])
-            && is_digit(data[7]);
-        let hour = from_digit(data[0]) as u32 * 1000
-            + from_digit(data[1]) as u32 * 100
-            + from_digit(data[2]) as u32 * 10
-            + from_digit(data[3]) as u32;
-        let minute = from_digit(data[4]) as u32 * 10 + from_digit(data[5]) as u32;
-        let second = from_digit(data[5]) as u32 * 10 + from_digit(data[6]) as u32;
-        let milli = 0;
-        let dtf = DtfTime {
-            hour,
-            minute,
-            second,
-            milli,
-            has_milli: true,
+            && is_digit(data[7])
+            && (!has_milli
+                || (data[8] == b'.'
+                    && is_digit(data[9])
+                    && is_digit(data[10])
+                    && is_digit(data[11])));
+        if !digits_are_ok {
+            return None;
+        }
+        let hour = from_digit(data[0]) as u32 * 10 + from_digit(data[1]) as u32;
+        let minute = from_digit(data[3]) as u32 * 10 + from_digit(data[4]) as u32;
+        let second = from_digit(data[6]) as u32 * 10 + from_digit(data[7]) as u32;
+        let milli = if has_milli {
+            from_digit(data[9]) as u32 * 100
+                + from_digit(data[10]) as u32 * 10
+                + from_digit(data[11]) as u32
+        } else {
+            0
         };
         // 60 for leap seconds.
-        if digits_are_ok && hour <= 23 && minute <= 59 && second <= 60 {
-            Some(dtf)
+        if hour <= 23 && minute <= 59 && second <= 60 {
+            Some(DtfTime {
+                hour,
+                minute,
+                second,
+                milli,
+                has_milli,
+            })
         } else {
             None
         }
     }
 
+    /// Returns `self` moved forward (or backward, if negative) by `minutes`,
+    /// wrapping around midnight.
+    pub(crate) fn add_minutes(&self, minutes: i64) -> Self {
+        let total = (self.hour * 60 + self.minute) as i64 + minutes;
+        let total = total.rem_euclid(24 * 60) as u32;
+        Self {
+            hour: total / 60,
+            minute: total % 60,
+            ..self.clone()
+        }
+    }
+
     pub const fn to_bytes(&self) -> [u8; LEN_IN_BYTES_NO_MILLI] {
         [
             (self.hour() / 10) as u8 + b'0',
@@ -0,0 +1,290 @@
+use crate::tagvalue::SerializeField;
+use crate::{Buffer, DtfDate};
+
+const LEN_IN_BYTES_NO_MILLI: usize = 17;
+const LEN_IN_BYTES_WITH_MILLI: usize = 21;
+
+/// Concrete value for [`DataType::UtcTimestamp`](crate::DataType::UtcTimestamp)
+/// fields.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DtfTimestamp {
+    date: DtfDate,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    nano: u32,
+    // Number of fractional digits in the original string: 0, 3, 6 or 9.
+    precision: u8,
+}
+
+impl DtfTimestamp {
+    /// Parses from a `"YYYYMMDD-HH:MM:SS"` format, optionally followed by
+    /// milliseconds (`".sss"`), microseconds (`".ssssss"`) or nanoseconds
+    /// (`".sssssssss"`). The original precision is retained, see
+    /// [`DtfTimestamp::precision`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::DtfTimestamp;
+    ///
+    /// let dtf = DtfTimestamp::parse(b"20210101-12:45:00.328").unwrap();
+    /// assert_eq!(dtf.hour(), 12);
+    /// assert_eq!(dtf.milli_opt(), Some(328));
+    /// assert!(DtfTimestamp::parse(b"20210101-24:00:00").is_none());
+    /// ```
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < LEN_IN_BYTES_NO_MILLI || data[8] != b'-' {
+            return None;
+        }
+        let date = DtfDate::parse(&data[..8])?;
+        let time = &data[9..LEN_IN_BYTES_NO_MILLI];
+        if time[2] != b':' || time[5] != b':' {
+            return None;
+        }
+        let hour = parse_digits(&time[0..2])?;
+        let minute = parse_digits(&time[3..5])?;
+        let second = parse_digits(&time[6..8])?;
+        let fraction = &data[LEN_IN_BYTES_NO_MILLI..];
+        let (nano, precision) = match fraction {
+            [] => (0, 0),
+            [b'.', digits @ ..] if matches!(digits.len(), 3 | 6 | 9) => {
+                let n = parse_digits(digits)?;
+                (n * 10u32.pow(9 - digits.len() as u32), digits.len() as u8)
+            }
+            _ => return None,
+        };
+        // 60 for leap seconds.
+        if hour <= 23 && minute <= 59 && second <= 60 {
+            Some(Self {
+                date,
+                hour,
+                minute,
+                second,
+                nano,
+                precision,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Serializes `self` in the canonical `"YYYYMMDD-HH:MM:SS.sss"` form,
+    /// i.e. with `.000` milliseconds if `self` was parsed without them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::DtfTimestamp;
+    ///
+    /// let dtf = DtfTimestamp::parse(b"20210101-12:45:00").unwrap();
+    /// assert_eq!(&dtf.to_canonical_millis(), b"20210101-12:45:00.000");
+    /// ```
+    pub fn to_canonical_millis(&self) -> [u8; LEN_IN_BYTES_WITH_MILLI] {
+        let mut bytes = [0u8; LEN_IN_BYTES_WITH_MILLI];
+        write_digits(&mut bytes[0..4], self.date.year());
+        write_digits(&mut bytes[4..6], self.date.month());
+        write_digits(&mut bytes[6..8], self.date.day());
+        bytes[8] = b'-';
+        write_digits(&mut bytes[9..11], self.hour);
+        bytes[11] = b':';
+        write_digits(&mut bytes[12..14], self.minute);
+        bytes[14] = b':';
+        write_digits(&mut bytes[15..17], self.second);
+        bytes[17] = b'.';
+        write_digits(&mut bytes[18..21], self.milli());
+        bytes
+    }
+
+    /// Serializes `self` with the same precision as the original string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::DtfTimestamp;
+    ///
+    /// let dtf = DtfTimestamp::parse(b"20210101-12:45:00.000001").unwrap();
+    /// assert_eq!(dtf.to_bytes(), b"20210101-12:45:00.000001".to_vec());
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(LEN_IN_BYTES_NO_MILLI + 10);
+        self.serialize(&mut bytes);
+        bytes
+    }
+
+    /// Returns the date of `self`.
+    pub fn date(&self) -> DtfDate {
+        self.date
+    }
+
+    /// Returns the hour of `self`.
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    /// Returns the minute of `self`.
+    pub fn minute(&self) -> u32 {
+        self.minute
+    }
+
+    /// Returns the second of `self`.
+    pub fn second(&self) -> u32 {
+        self.second
+    }
+
+    /// Returns the millisecond of `self`, or zero if it wasn't included in the
+    /// original string.
+    pub fn milli(&self) -> u32 {
+        self.nano / 1_000_000
+    }
+
+    /// Returns the millisecond of `self`, if and only if it was included in the
+    /// original string.
+    pub fn milli_opt(&self) -> Option<u32> {
+        if self.precision > 0 {
+            Some(self.milli())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the nanosecond of `self`, i.e. the whole fractional part of the
+    /// second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::DtfTimestamp;
+    ///
+    /// let dtf = DtfTimestamp::parse(b"20210101-12:45:00.328").unwrap();
+    /// assert_eq!(dtf.nano(), 328_000_000);
+    /// ```
+    pub fn nano(&self) -> u32 {
+        self.nano
+    }
+
+    /// Returns the number of fractional digits in the original string: 0, 3
+    /// (milliseconds), 6 (microseconds) or 9 (nanoseconds).
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// Returns `self` moved forward (or backward, if negative) by `minutes`,
+    /// or `None` if the resulting date falls outside of the `0000-9999` year
+    /// range.
+    pub(crate) fn add_minutes(&self, minutes: i64) -> Option<Self> {
+        let total = (self.hour * 60 + self.minute) as i64 + minutes;
+        let date = self.date.add_days(total.div_euclid(24 * 60))?;
+        let total = total.rem_euclid(24 * 60) as u32;
+        Some(Self {
+            date,
+            hour: total / 60,
+            minute: total % 60,
+            ..*self
+        })
+    }
+}
+
+impl SerializeField for DtfTimestamp {
+    fn serialize<B>(&self, buffer: &mut B) -> usize
+    where
+        B: Buffer,
+    {
+        let canonical = self.to_canonical_millis();
+        buffer.extend_from_slice(&canonical[..LEN_IN_BYTES_NO_MILLI]);
+        if self.precision == 0 {
+            return LEN_IN_BYTES_NO_MILLI;
+        }
+        // The decimal point and up to 9 fractional digits.
+        let mut fraction = [b'.'; 10];
+        let digits = self.precision as usize;
+        write_digits(
+            &mut fraction[1..=digits],
+            self.nano / 10u32.pow(9 - digits as u32),
+        );
+        buffer.extend_from_slice(&fraction[..=digits]);
+        LEN_IN_BYTES_NO_MILLI + 1 + digits
+    }
+}
+
+fn parse_digits(digits: &[u8]) -> Option<u32> {
+    digits.iter().try_fold(0u32, |n, byte| {
+        if byte.is_ascii_digit() {
+            Some(n * 10 + (byte - b'0') as u32)
+        } else {
+            None
+        }
+    })
+}
+
+fn write_digits(buffer: &mut [u8], mut n: u32) {
+    for byte in buffer.iter_mut().rev() {
+        *byte = (n % 10) as u8 + b'0';
+        n /= 10;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn second_and_milli_precision_are_canonicalized() {
+        for (input, canonical) in &[
+            (&b"20210315-09:05:07"[..], b"20210315-09:05:07.000"),
+            (b"20210315-09:05:07.042", b"20210315-09:05:07.042"),
+            (b"20210315-09:05:07.042999", b"20210315-09:05:07.042"),
+            (b"20211231-23:59:60.999999999", b"20211231-23:59:60.999"),
+        ] {
+            let dtf = DtfTimestamp::parse(input).unwrap();
+            assert_eq!(&dtf.to_canonical_millis(), *canonical);
+        }
+    }
+
+    #[test]
+    fn nanosecond_precision_is_retained() {
+        let dtf = DtfTimestamp::parse(b"20231231-23:59:59.123456789").unwrap();
+        assert_eq!(dtf.precision(), 9);
+        assert_eq!(dtf.nano(), 123_456_789);
+        assert_eq!(dtf.milli(), 123);
+        assert_eq!(dtf.to_bytes(), b"20231231-23:59:59.123456789".to_vec());
+        let dtf = DtfTimestamp::parse(b"20231231-23:59:59.000450").unwrap();
+        assert_eq!(dtf.precision(), 6);
+        assert_eq!(dtf.nano(), 450_000);
+        assert_eq!(dtf.to_bytes(), b"20231231-23:59:59.000450".to_vec());
+        let dtf = DtfTimestamp::parse(b"20231231-23:59:59").unwrap();
+        assert_eq!(dtf.to_bytes(), b"20231231-23:59:59".to_vec());
+    }
+
+    #[test]
+    fn serialize_returns_the_length() {
+        for input in &[
+            &b"20231231-23:59:59"[..],
+            b"20231231-23:59:59.120",
+            b"20231231-23:59:59.000450",
+            b"20231231-23:59:59.123456789",
+        ] {
+            let dtf = DtfTimestamp::parse(input).unwrap();
+            let mut buffer = b"52=".to_vec();
+            assert_eq!(dtf.serialize(&mut buffer), input.len());
+            assert_eq!(&buffer[3..], *input);
+        }
+    }
+
+    #[test]
+    fn invalid_timestamps() {
+        for input in &[
+            &b"20210315"[..],
+            b"20210315 09:05:07",
+            b"20210315-09:05:07.",
+            b"20210315-09:05:07.04",
+            b"20210315-09:05:07.0421",
+            b"20210315-09:05:07.0421234567",
+            b"20210315-09:5:07.042",
+            b"20211315-09:05:07",
+            b"20210315-09:60:07",
+        ] {
+            assert!(DtfTimestamp::parse(input).is_none());
+        }
+    }
+}
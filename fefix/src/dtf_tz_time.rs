@@ -0,0 +1,102 @@
+use crate::dtf_tz_timestamp::parse_utc_offset;
+use crate::DtfTime;
+
+/// Concrete value for [`DataType::TzTimeOnly`](crate::DataType::TzTimeOnly)
+/// fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DtfTzTime {
+    local: DtfTime,
+    offset_minutes: i32,
+}
+
+impl DtfTzTime {
+    /// Parses from a `"HH:MM[:SS[.sss]]"` format followed by either `"Z"` or
+    /// an offset from UTC (`"+hh"`, `"-hh"`, `"+hh:mm"` or `"-hh:mm"`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::DtfTzTime;
+    ///
+    /// let dtf = DtfTzTime::parse(b"02:39-05:00").unwrap();
+    /// assert_eq!(dtf.offset_minutes(), -300);
+    /// assert_eq!(dtf.local().hour(), 2);
+    /// assert!(DtfTzTime::parse(b"02:39:00").is_none());
+    /// ```
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        let split = data
+            .iter()
+            .position(|byte| matches!(byte, b'Z' | b'+' | b'-'))?;
+        let (local, offset) = data.split_at(split);
+        let offset_minutes = parse_utc_offset(offset)?;
+        // Seconds are optional.
+        let local = if local.len() == 5 {
+            DtfTime::parse(&[local, b":00"].concat())?
+        } else {
+            DtfTime::parse(local)?
+        };
+        Some(Self {
+            local,
+            offset_minutes,
+        })
+    }
+
+    /// Returns the local time of `self`, i.e. without applying the offset
+    /// from UTC.
+    pub fn local(&self) -> DtfTime {
+        self.local.clone()
+    }
+
+    /// Returns the offset from UTC of `self`, in minutes. `"Z"` has an offset
+    /// of zero.
+    pub fn offset_minutes(&self) -> i32 {
+        self.offset_minutes
+    }
+
+    /// Converts `self` to the equivalent
+    /// [`DataType::UtcTimeOnly`](crate::DataType::UtcTimeOnly) value, wrapping
+    /// around midnight as needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::DtfTzTime;
+    ///
+    /// let dtf = DtfTzTime::parse(b"21:00:00-05:00").unwrap();
+    /// assert_eq!(&dtf.to_utc().to_bytes(), b"02:00:00");
+    /// ```
+    pub fn to_utc(&self) -> DtfTime {
+        self.local.add_minutes(-self.offset_minutes as i64)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn utc(input: &[u8]) -> [u8; 12] {
+        DtfTzTime::parse(input).unwrap().to_utc().to_bytes_wm()
+    }
+
+    #[test]
+    fn conversion_to_utc_wraps_around_midnight() {
+        assert_eq!(&utc(b"13:09:00.125+05:30"), b"07:39:00.125");
+        assert_eq!(&utc(b"01:00:00+05:00"), b"20:00:00.000");
+        assert_eq!(&utc(b"23:59:60-01:00"), b"00:59:60.000");
+        assert_eq!(&utc(b"07:39Z"), b"07:39:00.000");
+    }
+
+    #[test]
+    fn invalid_tz_times() {
+        for input in &[
+            &b"07:39"[..],
+            b"07:39+1",
+            b"7:39Z",
+            b"24:00Z",
+            b"07:39:00.1Z",
+            b"07:39:00.123456Z",
+        ] {
+            assert!(DtfTzTime::parse(input).is_none());
+        }
+    }
+}
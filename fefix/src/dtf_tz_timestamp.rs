@@ -0,0 +1,162 @@
+use crate::DtfTimestamp;
+
+/// Concrete value for [`DataType::TzTimestamp`](crate::DataType::TzTimestamp)
+/// fields.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DtfTzTimestamp {
+    local: DtfTimestamp,
+    offset_minutes: i32,
+    utc: DtfTimestamp,
+}
+
+impl DtfTzTimestamp {
+    /// Parses from a `"YYYYMMDD-HH:MM[:SS[.sss]]"` format followed by either
+    /// `"Z"` or an offset from UTC (`"+hh"`, `"-hh"`, `"+hh:mm"` or
+    /// `"-hh:mm"`). Like [`DtfTimestamp::parse`], microseconds and nanoseconds
+    /// are accepted as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::DtfTzTimestamp;
+    ///
+    /// let dtf = DtfTzTimestamp::parse(b"20060901-13:09+05:30").unwrap();
+    /// assert_eq!(dtf.offset_minutes(), 330);
+    /// assert_eq!(dtf.local().hour(), 13);
+    /// assert!(DtfTzTimestamp::parse(b"20060901-13:09:00").is_none());
+    /// ```
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        // The date is followed by a dash, so we only look for the offset
+        // after it.
+        let split = data
+            .iter()
+            .skip(9)
+            .position(|byte| matches!(byte, b'Z' | b'+' | b'-'))?
+            + 9;
+        let (local, offset) = data.split_at(split);
+        let offset_minutes = parse_utc_offset(offset)?;
+        // Seconds are optional.
+        let local = if local.len() == 14 {
+            DtfTimestamp::parse(&[local, b":00"].concat())?
+        } else {
+            DtfTimestamp::parse(local)?
+        };
+        let utc = local.add_minutes(-offset_minutes as i64)?;
+        Some(Self {
+            local,
+            offset_minutes,
+            utc,
+        })
+    }
+
+    /// Returns the local date and time of `self`, i.e. without applying the
+    /// offset from UTC.
+    pub fn local(&self) -> DtfTimestamp {
+        self.local
+    }
+
+    /// Returns the offset from UTC of `self`, in minutes. `"Z"` has an offset
+    /// of zero.
+    pub fn offset_minutes(&self) -> i32 {
+        self.offset_minutes
+    }
+
+    /// Converts `self` to the equivalent
+    /// [`DataType::UtcTimestamp`](crate::DataType::UtcTimestamp) value, rolling
+    /// over to the previous or next day as needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::DtfTzTimestamp;
+    ///
+    /// let dtf = DtfTzTimestamp::parse(b"20231231-23:59:59-05:00").unwrap();
+    /// assert_eq!(&dtf.to_utc().to_canonical_millis(), b"20240101-04:59:59.000");
+    /// ```
+    pub fn to_utc(&self) -> DtfTimestamp {
+        self.utc
+    }
+}
+
+/// Parses a `"Z"`, `"+hh"`, `"-hh"`, `"+hh:mm"` or `"-hh:mm"` offset from UTC
+/// into minutes.
+pub(crate) fn parse_utc_offset(data: &[u8]) -> Option<i32> {
+    let (sign, digits) = match data {
+        b"Z" => return Some(0),
+        [b'+', digits @ ..] => (1, digits),
+        [b'-', digits @ ..] => (-1, digits),
+        _ => return None,
+    };
+    let (hours, minutes) = match digits {
+        [h1, h2] => (parse_two_digits(*h1, *h2)?, 0),
+        [h1, h2, b':', m1, m2] => (parse_two_digits(*h1, *h2)?, parse_two_digits(*m1, *m2)?),
+        _ => return None,
+    };
+    if hours <= 14 && minutes <= 59 {
+        Some(sign * (hours * 60 + minutes))
+    } else {
+        None
+    }
+}
+
+fn parse_two_digits(tens: u8, units: u8) -> Option<i32> {
+    if tens.is_ascii_digit() && units.is_ascii_digit() {
+        Some((tens - b'0') as i32 * 10 + (units - b'0') as i32)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn utc(input: &[u8]) -> [u8; 21] {
+        DtfTzTimestamp::parse(input)
+            .unwrap()
+            .to_utc()
+            .to_canonical_millis()
+    }
+
+    #[test]
+    fn positive_offsets() {
+        assert_eq!(&utc(b"20231231-23:59:59+05:00"), b"20231231-18:59:59.000");
+        // Rolls back to the previous day, month and year.
+        assert_eq!(
+            &utc(b"20240101-03:30:00.250+05:30"),
+            b"20231231-22:00:00.250"
+        );
+        assert_eq!(&utc(b"20240301-00:15+01"), b"20240229-23:15:00.000");
+    }
+
+    #[test]
+    fn negative_offsets() {
+        // Rolls over to the next day, month and year.
+        assert_eq!(&utc(b"20231231-23:59:59-05:00"), b"20240101-04:59:59.000");
+        assert_eq!(&utc(b"20230228-22:00:00-03:00"), b"20230301-01:00:00.000");
+        assert_eq!(&utc(b"20230615-10:00:00-00:30"), b"20230615-10:30:00.000");
+    }
+
+    #[test]
+    fn zulu() {
+        let dtf = DtfTzTimestamp::parse(b"20060901-07:39Z").unwrap();
+        assert_eq!(dtf.offset_minutes(), 0);
+        assert_eq!(dtf.to_utc(), dtf.local());
+    }
+
+    #[test]
+    fn invalid_tz_timestamps() {
+        for input in &[
+            &b"20231231-23:59:59"[..],
+            b"20231231-23:59:59+5",
+            b"20231231-23:59:59+05:0",
+            b"20231231-23:59:59+0500",
+            b"20231231-23:59:59+15:00",
+            b"20231231-23:59:59Z+01",
+            b"20231231-23+01",
+            b"00000101-00:00:00+01",
+        ] {
+            assert!(DtfTzTimestamp::parse(input).is_none());
+        }
+    }
+}
@@ -0,0 +1,260 @@
+//! Typed values of the most commonly used enumerated fields.
+//!
+//! Unlike [`tags`](crate::tags), which only provides tag mnemonics, the types
+//! in this module give compile-time checked access to field values. Code sets
+//! follow FIX 4.4.
+
+use crate::tagvalue::{DecodeError, FixFieldDecode, SerializeField};
+use crate::Buffer;
+
+macro_rules! define_fix_enum {
+    (
+        $(#[$enum_attr:meta])*
+        $name:ident,
+        $($(#[$attr:meta])* $variant:ident => $value:literal),* $(,)?
+    ) => {
+        $(#[$enum_attr])*
+        #[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$attr])* $variant,)*
+        }
+
+        impl $name {
+            /// All variants, in the order in which they appear in the
+            /// specification.
+            pub const ALL: &'static [Self] = &[$(Self::$variant,)*];
+
+            /// Parses a raw field value. Returns `None` if `data` is not a
+            /// known FIX 4.4 value.
+            pub fn from_fix(data: &[u8]) -> Option<Self> {
+                match data {
+                    $($value => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+
+            /// Returns the raw field value of `self`.
+            pub fn to_fix(&self) -> &'static [u8] {
+                match self {
+                    $(Self::$variant => $value,)*
+                }
+            }
+        }
+
+        impl FixFieldDecode for $name {
+            fn decode_fix_field(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::from_fix(data).ok_or(DecodeError::Invalid)
+            }
+        }
+
+        impl SerializeField for $name {
+            fn serialize<B>(&self, buffer: &mut B) -> usize
+            where
+                B: Buffer,
+            {
+                let value = self.to_fix();
+                buffer.extend_from_slice(value);
+                value.len()
+            }
+        }
+    };
+}
+
+define_fix_enum! {
+    /// Values of `Side <54>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::enums::Side;
+    ///
+    /// assert_eq!(Side::from_fix(b"1"), Some(Side::Buy));
+    /// assert_eq!(Side::SellShort.to_fix(), b"5");
+    /// assert_eq!(Side::from_fix(b"Z"), None);
+    /// ```
+    Side,
+    Buy => b"1",
+    Sell => b"2",
+    BuyMinus => b"3",
+    SellPlus => b"4",
+    SellShort => b"5",
+    SellShortExempt => b"6",
+    Undisclosed => b"7",
+    Cross => b"8",
+    CrossShort => b"9",
+    CrossShortExempt => b"A",
+    AsDefined => b"B",
+    Opposite => b"C",
+    Subscribe => b"D",
+    Redeem => b"E",
+    Lend => b"F",
+    Borrow => b"G",
+}
+
+define_fix_enum! {
+    /// Values of `OrdType <40>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::enums::OrdType;
+    ///
+    /// assert_eq!(OrdType::from_fix(b"2"), Some(OrdType::Limit));
+    /// assert_eq!(OrdType::Pegged.to_fix(), b"P");
+    /// ```
+    OrdType,
+    Market => b"1",
+    Limit => b"2",
+    Stop => b"3",
+    StopLimit => b"4",
+    WithOrWithout => b"6",
+    LimitOrBetter => b"7",
+    LimitWithOrWithout => b"8",
+    OnBasis => b"9",
+    PreviouslyQuoted => b"D",
+    PreviouslyIndicated => b"E",
+    Forex => b"G",
+    Funari => b"I",
+    MarketIfTouched => b"J",
+    MarketWithLeftoverAsLimit => b"K",
+    PreviousFundValuationPoint => b"L",
+    NextFundValuationPoint => b"M",
+    Pegged => b"P",
+}
+
+define_fix_enum! {
+    /// Values of `TimeInForce <59>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::enums::TimeInForce;
+    ///
+    /// assert_eq!(TimeInForce::from_fix(b"3"), Some(TimeInForce::ImmediateOrCancel));
+    /// assert_eq!(TimeInForce::Day.to_fix(), b"0");
+    /// ```
+    TimeInForce,
+    Day => b"0",
+    GoodTillCancel => b"1",
+    AtTheOpening => b"2",
+    ImmediateOrCancel => b"3",
+    FillOrKill => b"4",
+    GoodTillCrossing => b"5",
+    GoodTillDate => b"6",
+    AtTheClose => b"7",
+}
+
+define_fix_enum! {
+    /// Values of `OrdStatus <39>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::enums::OrdStatus;
+    ///
+    /// assert_eq!(OrdStatus::from_fix(b"1"), Some(OrdStatus::PartiallyFilled));
+    /// assert_eq!(OrdStatus::PendingNew.to_fix(), b"A");
+    /// ```
+    OrdStatus,
+    New => b"0",
+    PartiallyFilled => b"1",
+    Filled => b"2",
+    DoneForDay => b"3",
+    Canceled => b"4",
+    PendingCancel => b"6",
+    Stopped => b"7",
+    Rejected => b"8",
+    Suspended => b"9",
+    PendingNew => b"A",
+    Calculated => b"B",
+    Expired => b"C",
+    AcceptedForBidding => b"D",
+    PendingReplace => b"E",
+}
+
+define_fix_enum! {
+    /// Values of `ExecType <150>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::enums::ExecType;
+    ///
+    /// assert_eq!(ExecType::from_fix(b"F"), Some(ExecType::Trade));
+    /// assert_eq!(ExecType::New.to_fix(), b"0");
+    /// // `1` and `2` (partial fill and fill) were removed in FIX 4.3.
+    /// assert_eq!(ExecType::from_fix(b"2"), None);
+    /// ```
+    ExecType,
+    New => b"0",
+    DoneForDay => b"3",
+    Canceled => b"4",
+    Replace => b"5",
+    PendingCancel => b"6",
+    Stopped => b"7",
+    Rejected => b"8",
+    Suspended => b"9",
+    PendingNew => b"A",
+    Calculated => b"B",
+    Expired => b"C",
+    Restated => b"D",
+    PendingReplace => b"E",
+    Trade => b"F",
+    TradeCorrect => b"G",
+    TradeCancel => b"H",
+    OrderStatus => b"I",
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{tags, AppVersion, Dictionary};
+    use std::collections::HashSet;
+
+    fn dictionary_values(tag: u32) -> HashSet<Vec<u8>> {
+        let dict = Dictionary::from_version(AppVersion::Fix44);
+        let field = dict.field_by_tag(tag).unwrap();
+        field
+            .enums()
+            .unwrap()
+            .map(|e| e.value().as_bytes().to_vec())
+            .collect()
+    }
+
+    macro_rules! test_round_trip {
+        ($test_name:ident, $name:ident, $tag:expr) => {
+            #[test]
+            fn $test_name() {
+                for variant in $name::ALL {
+                    assert_eq!($name::from_fix(variant.to_fix()), Some(*variant));
+                    let mut buffer = Vec::new();
+                    assert_eq!(variant.serialize(&mut buffer), 1);
+                    assert_eq!($name::decode_fix_field(&buffer[..]), Ok(*variant));
+                }
+                let values = $name::ALL
+                    .iter()
+                    .map(|variant| variant.to_fix().to_vec())
+                    .collect::<HashSet<_>>();
+                assert_eq!(values.len(), $name::ALL.len());
+                assert_eq!(values, dictionary_values($tag));
+            }
+        };
+    }
+
+    test_round_trip!(side_round_trip, Side, tags::SIDE);
+    test_round_trip!(ord_type_round_trip, OrdType, tags::ORD_TYPE);
+    test_round_trip!(time_in_force_round_trip, TimeInForce, tags::TIME_IN_FORCE);
+    test_round_trip!(ord_status_round_trip, OrdStatus, tags::ORD_STATUS);
+    test_round_trip!(exec_type_round_trip, ExecType, tags::EXEC_TYPE);
+
+    #[test]
+    fn unknown_values() {
+        assert_eq!(Side::from_fix(b""), None);
+        assert_eq!(Side::from_fix(b"11"), None);
+        assert_eq!(OrdType::from_fix(b"5"), None);
+        assert_eq!(
+            TimeInForce::decode_fix_field(b"8"),
+            Err(DecodeError::Invalid)
+        );
+    }
+}
@@ -174,12 +174,67 @@ fn _serialize_bitvec(bits: &BitSlice<Msb0, u8>, output: &mut impl io::Write) ->
     Ok(i)
 }
 
-#[derive(Debug, Clone)]
+/// A FAST presence map (PMAP), i.e. a stop-bit encoded sequence of bits
+/// which tells which fields of a template are present in the stream.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct PresenceMap {
     bits: BitVec,
 }
 
 impl PresenceMap {
+    /// Decodes a [`PresenceMap`] from the start of `data`. Each byte carries
+    /// seven bits, most significant first, and the last byte has its stop bit
+    /// (`0x80`) set. Returns the [`PresenceMap`] together with the number of
+    /// bytes it takes.
+    ///
+    /// # Errors
+    ///
+    /// This function fails with [`io::ErrorKind::UnexpectedEof`] if there's no
+    /// stop bit in `data`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::fast::PresenceMap;
+    ///
+    /// let (pmap, len) = PresenceMap::from_bytes(&[0x40, 0x81, 0xff]).unwrap();
+    /// assert_eq!(len, 2);
+    /// assert_eq!(pmap.len(), 14);
+    /// assert!(pmap.get(0));
+    /// assert!(!pmap.get(1));
+    /// assert!(pmap.get(13));
+    /// // Bits beyond the end of the map are implicitly zero.
+    /// assert!(!pmap.get(100));
+    /// ```
+    pub fn from_bytes(data: &[u8]) -> io::Result<(Self, usize)> {
+        let mut bits = BitVec::new();
+        for (i, byte) in data.iter().enumerate() {
+            for shift in (0..7).rev() {
+                bits.push((byte >> shift) & 1 == 1);
+            }
+            if *byte >= STOP_BYTE {
+                return Ok((Self { bits }, i + 1));
+            }
+        }
+        Err(io::ErrorKind::UnexpectedEof.into())
+    }
+
+    /// Returns the number of bits in `self`, i.e. seven per byte.
+    pub fn len(&self) -> usize {
+        self.bits.len()
+    }
+
+    /// Returns `true` if `self` contains no bits.
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    /// Returns the `i`-th bit of `self`. Bits beyond the end of `self` are
+    /// `false`, as per the FAST specification.
+    pub fn get(&self, i: usize) -> bool {
+        self.bits.get(i).copied().unwrap_or(false)
+    }
+
     pub fn bits(&self) -> impl Iterator<Item = &bool> {
         self.bits.iter()
     }
@@ -191,29 +246,25 @@ impl Codec for PresenceMap {
     }
 
     fn deserialize(&mut self, input: &mut impl io::Read) -> io::Result<usize> {
-        self.bits = BitVec::new();
-        let mut stop_bit = false;
-        while !stop_bit {
-            let mut buffer = [0u8; 1];
-            input.read_exact(&mut buffer[..])?;
-            let byte = buffer[0];
-            stop_bit = byte >= STOP_BYTE;
-            if !stop_bit {
-                self.bits.push(byte >> 7 == 1);
-            }
-            self.bits.push((byte >> 6) & 1 == 1);
-            self.bits.push((byte >> 5) & 1 == 1);
-            self.bits.push((byte >> 4) & 1 == 1);
-            self.bits.push((byte >> 4) & 1 == 1);
-            self.bits.push((byte >> 3) & 1 == 1);
-            self.bits.push((byte >> 2) & 1 == 1);
-            self.bits.push((byte >> 1) & 1 == 1);
-            self.bits.push((byte >> 0) & 1 == 1);
-        }
+        let bytes = read_stop_bit_bytes(input)?;
+        *self = Self::from_bytes(&bytes[..])?.0;
         Ok(self.bits.len())
     }
 }
 
+/// Reads all bytes of a stop-bit encoded entity, stop bit included.
+fn read_stop_bit_bytes(input: &mut impl io::Read) -> io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    loop {
+        let mut byte = [0u8; 1];
+        input.read_exact(&mut byte[..])?;
+        bytes.push(byte[0]);
+        if byte[0] >= STOP_BYTE {
+            return Ok(bytes);
+        }
+    }
+}
+
 //pub fn encode_stop_bit_entity(target: &mut impl io::Write, buffer: &[u8]) -> io::Result<usize> {
 //    let bits = BitVec::from(buffer);
 //    for bit in bits {
@@ -238,26 +289,8 @@ pub fn decode_stop_bit_entity(input: &mut impl io::Read) -> io::Result<Vec<u8>>
 }
 
 pub fn decode_stop_bit_bitvec(input: &mut impl io::Read) -> io::Result<BitVec> {
-    let mut bits = BitVec::new();
-    let mut stop_bit = false;
-    while !stop_bit {
-        let mut buffer = [0u8; 1];
-        input.read_exact(&mut buffer[..])?;
-        let byte = buffer[0];
-        stop_bit = byte >= STOP_BYTE;
-        if !stop_bit {
-            bits.push(byte >> 7 == 1);
-        }
-        bits.push((byte >> 6) & 1 == 1);
-        bits.push((byte >> 5) & 1 == 1);
-        bits.push((byte >> 4) & 1 == 1);
-        bits.push((byte >> 4) & 1 == 1);
-        bits.push((byte >> 3) & 1 == 1);
-        bits.push((byte >> 2) & 1 == 1);
-        bits.push((byte >> 1) & 1 == 1);
-        bits.push((byte >> 0) & 1 == 1);
-    }
-    Ok(bits)
+    let bytes = read_stop_bit_bytes(input)?;
+    Ok(PresenceMap::from_bytes(&bytes[..])?.0.bits)
 }
 
 #[cfg(test)]
@@ -306,6 +339,35 @@ mod test {
         assert_eq!(*value, expected_value);
     }
 
+    #[test]
+    fn presence_map_single_byte() {
+        let (pmap, len) = PresenceMap::from_bytes(&[0xc0]).unwrap();
+        assert_eq!(len, 1);
+        let bits: Vec<bool> = pmap.bits().copied().collect();
+        assert_eq!(bits, vec![true, false, false, false, false, false, false]);
+    }
+
+    #[test]
+    fn presence_map_multiple_bytes() {
+        // 0b0010101, 0b1010100, 0b0000001 (stop bit).
+        let data = [0x15, 0x54, 0x81, 0x00];
+        let (pmap, len) = PresenceMap::from_bytes(&data).unwrap();
+        assert_eq!(len, 3);
+        assert_eq!(pmap.len(), 21);
+        let set: Vec<usize> = (0..pmap.len()).filter(|i| pmap.get(*i)).collect();
+        assert_eq!(set, vec![2, 4, 6, 7, 9, 11, 20]);
+        let mut from_reader = PresenceMap::default();
+        assert_eq!(from_reader.deserialize(&mut &data[..]).unwrap(), 21);
+        assert_eq!(from_reader, pmap);
+    }
+
+    #[test]
+    fn presence_map_without_stop_bit() {
+        let err = PresenceMap::from_bytes(&[0x15, 0x54]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        assert!(PresenceMap::from_bytes(&[]).is_err());
+    }
+
     #[quickcheck]
     fn encode_then_decode_string(expected_value: String) -> bool {
         let mut bytes: Vec<u8> = Vec::new();
@@ -100,6 +100,8 @@ pub static IANA_TO_OPENSSL: phf::Map<&'static str, &'static str> = phf_map! {
     "TLS_PSK_WITH_3DES_EDE_CBC_SHA" => "PSK-3DES-EDE-CBC-SHA",
     "TLS_PSK_WITH_AES_128_CBC_SHA" => "PSK-AES128-CBC-SHA",
     "TLS_PSK_WITH_AES_256_CBC_SHA" => "PSK-AES256-CBC-SHA",
+    "TLS_DHE_PSK_WITH_AES_128_CBC_SHA" => "DHE-PSK-AES128-CBC-SHA",
+    "TLS_DHE_PSK_WITH_AES_256_CBC_SHA" => "DHE-PSK-AES256-CBC-SHA",
     "TLS_RSA_WITH_SEED_CBC_SHA" => "SEED-SHA",
     "TLS_DH_DSS_WITH_SEED_CBC_SHA" => "DH-DSS-SEED-SHA",
     "TLS_DH_RSA_WITH_SEED_CBC_SHA" => "DH-RSA-SEED-SHA",
@@ -110,6 +112,10 @@ pub static IANA_TO_OPENSSL: phf::Map<&'static str, &'static str> = phf_map! {
     "TLS_RSA_WITH_AES_256_GCM_SHA384" => "AES256-GCM-SHA384",
     "TLS_DHE_RSA_WITH_AES_128_GCM_SHA256" => "DHE-RSA-AES128-GCM-SHA256",
     "TLS_DHE_RSA_WITH_AES_256_GCM_SHA384" => "DHE-RSA-AES256-GCM-SHA384",
+    "TLS_DHE_PSK_WITH_AES_128_GCM_SHA256" => "DHE-PSK-AES128-GCM-SHA256",
+    "TLS_DHE_PSK_WITH_AES_256_GCM_SHA384" => "DHE-PSK-AES256-GCM-SHA384",
+    "TLS_DHE_PSK_WITH_AES_128_CBC_SHA256" => "DHE-PSK-AES128-CBC-SHA256",
+    "TLS_DHE_PSK_WITH_AES_256_CBC_SHA384" => "DHE-PSK-AES256-CBC-SHA384",
     "TLS_DH_RSA_WITH_AES_128_GCM_SHA256" => "DH-RSA-AES128-GCM-SHA256",
     "TLS_DH_RSA_WITH_AES_256_GCM_SHA384" => "DH-RSA-AES256-GCM-SHA384",
     "TLS_DHE_DSS_WITH_AES_128_GCM_SHA256" => "DHE-DSS-AES128-GCM-SHA256",
@@ -2,8 +2,59 @@
 
 mod iana2openssl;
 
+use crate::openssl::error::ErrorStack;
 use crate::openssl::ssl::*;
 use iana2openssl::IANA_TO_OPENSSL;
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::{Read, Write};
+use std::os::raw::{c_long, c_void};
+
+/// The TLS session timeout (in seconds) used by
+/// [`Version::recommended_connector_builder_with_session_timeout`] and
+/// [`Version::recommended_acceptor_builder_with_session_timeout`] callers that
+/// don't have more specific requirements. It's long enough to cover a whole
+/// trading day, so that FIX sessions reconnecting within the same day can
+/// resume their TLS session instead of performing a full handshake.
+pub const RECOMMENDED_SESSION_TIMEOUT_SECS: u32 = 24 * 60 * 60;
+
+/// The maximum number of cached TLS sessions used by
+/// [`Version::recommended_connector_builder_with_session_cache`] and
+/// [`Version::recommended_acceptor_builder_with_session_cache`] callers that
+/// don't have more specific requirements. FIX deployments talk to a handful of
+/// counterparties, so this is much smaller than OpenSSL's default of 20480.
+pub const RECOMMENDED_SESSION_CACHE_SIZE: u32 = 1024;
+
+extern "C" {
+    // Not exposed by the `openssl` crate.
+    fn SSL_CTX_set_timeout(ctx: *mut c_void, t: c_long) -> c_long;
+    #[cfg(test)]
+    fn SSL_CTX_get_timeout(ctx: *const c_void) -> c_long;
+}
+
+/// Sets the lifetime of cached TLS sessions, saturating at [`c_long::MAX`].
+// `c_long` is only wider than `u32` on some platforms.
+#[allow(clippy::unnecessary_fallible_conversions)]
+fn set_session_timeout(context: &mut SslContextBuilder, secs: u32) {
+    let secs = c_long::try_from(secs).unwrap_or(c_long::MAX);
+    // SAFETY: the pointer is valid for as long as `context` is borrowed.
+    unsafe {
+        SSL_CTX_set_timeout(context.as_ptr() as *mut c_void, secs);
+    }
+}
+
+/// Returns the lifetime of cached TLS sessions, in seconds.
+#[cfg(test)]
+fn session_timeout(context: &SslContextBuilder) -> c_long {
+    // SAFETY: the pointer is valid for as long as `context` is borrowed.
+    unsafe { SSL_CTX_get_timeout(context.as_ptr() as *const c_void) }
+}
+
+/// Sets the maximum number of cached TLS sessions, saturating at
+/// [`i32::MAX`]. Zero means unbounded.
+fn set_session_cache_size(context: &mut SslContextBuilder, size: u32) {
+    context.set_session_cache_size(i32::try_from(size).unwrap_or(i32::MAX));
+}
 
 /// Which version of FIX-over-TLS (FIXS) to use.
 #[derive(Debug, Copy, Clone)]
@@ -33,6 +84,44 @@ impl Version {
         }
     }
 
+    /// Like [`Version::recommended_cs_iana`], but the ciphersuites are parsed
+    /// into [`CipherSuite`]s.
+    ///
+    /// ```
+    /// use fefix::fixs::Version;
+    ///
+    /// let version = Version::V1Draft;
+    /// let ciphersuites = version.recommended_cs(false);
+    /// assert!(ciphersuites.iter().all(|cs| cs.has_forward_secrecy()));
+    /// ```
+    pub fn recommended_cs(&self, psk: bool) -> Vec<CipherSuite<'static>> {
+        self.recommended_cs_iana(psk)
+            .into_iter()
+            .map(|cs| CipherSuite::from_iana(cs).unwrap())
+            .collect()
+    }
+
+    /// Like [`Version::recommended_cs_iana`], but only returns the ciphersuites
+    /// for which `predicate` returns `true`.
+    ///
+    /// ```
+    /// use fefix::fixs::{SecurityLevel, Version};
+    ///
+    /// let version = Version::V1Draft;
+    /// let ciphersuites_iana =
+    ///     version.recommended_cs_iana_filtered(false, |cs| SecurityLevel::GcmOnly.allows(cs));
+    /// assert!(ciphersuites_iana.iter().all(|cs| cs.contains("_GCM_")));
+    /// ```
+    pub fn recommended_cs_iana_filtered<F>(&self, psk: bool, predicate: F) -> Vec<&'static str>
+    where
+        F: Fn(&str) -> bool,
+    {
+        self.recommended_cs_iana(psk)
+            .into_iter()
+            .filter(|cs| predicate(cs))
+            .collect()
+    }
+
     /// Returns a [`Vec`] of the suggested ciphersuites for TLS,
     /// according to `self` version. The ciphersuites are specified in OpenSSL's
     /// format.
@@ -65,40 +154,161 @@ impl Version {
             .collect()
     }
 
+    /// Audits the colon-separated OpenSSL cipher list `openssl_list` (e.g. from
+    /// a deployment's configuration) against the FIXS recommendations of
+    /// `self`. See [`CipherAudit`].
+    ///
+    /// PSK ciphersuites count as recommended, but they're never reported as
+    /// missing. Cipher list keywords and operators (e.g. `HIGH`, `!aNULL`) are
+    /// not expanded and thus always reported as not recommended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::fixs::Version;
+    ///
+    /// let audit = Version::V1Draft.audit_cipher_list("DHE-RSA-AES128-GCM-SHA256:RC4-SHA");
+    /// assert_eq!(audit.recommended, vec!["DHE-RSA-AES128-GCM-SHA256"]);
+    /// assert_eq!(audit.not_recommended, vec!["RC4-SHA"]);
+    /// assert!(audit.missing.contains(&"ECDHE-RSA-AES256-GCM-SHA384"));
+    /// assert!(!audit.is_compliant());
+    /// ```
+    pub fn audit_cipher_list(&self, openssl_list: &str) -> CipherAudit {
+        let recommended = self.recommended_cs_openssl(true);
+        let configured: Vec<&str> = openssl_list
+            .split(':')
+            .map(str::trim)
+            .filter(|cs| !cs.is_empty())
+            .collect();
+        let mut audit = CipherAudit::default();
+        for cs in configured.iter() {
+            if recommended.contains(cs) {
+                audit.recommended.push(cs.to_string());
+            } else {
+                audit.not_recommended.push(cs.to_string());
+            }
+        }
+        audit.missing = self
+            .recommended_cs_openssl(false)
+            .into_iter()
+            .filter(|cs| !configured.contains(cs))
+            .collect();
+        audit
+    }
+
+    /// Checks whether OpenSSL accepts the colon-separated cipher list
+    /// `openssl_list`, by applying it to a throwaway [`SslContext`]. This is
+    /// the same check that the builder methods of `self` perform, so that a
+    /// user-supplied list can be validated ahead of time.
+    ///
+    /// OpenSSL only rejects lists that don't select any ciphersuite at all:
+    /// unknown ciphersuites are otherwise silently ignored. Use
+    /// [`Version::audit_cipher_list`] for a stricter analysis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::fixs::Version;
+    ///
+    /// let version = Version::V1Draft;
+    /// assert!(version.validate_cipher_list("DHE-RSA-AES128-GCM-SHA256").is_ok());
+    /// assert!(version.validate_cipher_list("NOT-A-CIPHER").is_err());
+    /// ```
+    pub fn validate_cipher_list(&self, openssl_list: &str) -> Result<(), CipherListError> {
+        let mut context = SslContext::builder(SslMethod::tls())?;
+        set_cipher_list(&mut context, openssl_list)
+    }
+
     /// Creates an [`SslConnectorBuilder`] with fhe FIXS recommended settings.
-    pub fn recommended_connector_builder(&self) -> SslConnectorBuilder {
-        let mut context = SslConnector::builder(SslMethod::tls()).unwrap();
+    ///
+    /// # Errors
+    ///
+    /// This function fails if OpenSSL rejects the recommended cipher list, e.g.
+    /// because it was built without support for any of them (see
+    /// [`Version::validate_cipher_list`]), or if it fails to set up the TLS
+    /// context.
+    pub fn recommended_connector_builder(&self) -> Result<SslConnectorBuilder, CipherListError> {
+        let mut context = SslConnector::builder(SslMethod::tls())?;
         match self {
             Version::V1Draft => {
-                context
-                    .set_min_proto_version(Some(SslVersion::TLS1_1))
-                    .unwrap();
-                context
-                    .set_max_proto_version(Some(SslVersion::TLS1_2))
-                    .unwrap();
+                context.set_min_proto_version(Some(SslVersion::TLS1_1))?;
+                context.set_max_proto_version(Some(SslVersion::TLS1_2))?;
                 context.set_options(SslOptions::NO_COMPRESSION);
                 context.set_options(SslOptions::NO_SESSION_RESUMPTION_ON_RENEGOTIATION);
                 context.set_options(SslOptions::NO_TLSV1_3);
             }
         };
         context.set_session_cache_mode(SslSessionCacheMode::SERVER);
-        context
-            .set_cipher_list(self.recommended_cs_openssl(false).join(":").as_str())
-            .unwrap();
-        context
+        set_cipher_list(&mut context, &self.recommended_cs_openssl(false).join(":"))?;
+        Ok(context)
+    }
+
+    /// Like [`Version::recommended_connector_builder`], but client-side
+    /// session caching is enabled as well and cached TLS sessions expire after
+    /// `secs` seconds rather than OpenSSL's default of five minutes. See
+    /// [`RECOMMENDED_SESSION_TIMEOUT_SECS`].
+    pub fn recommended_connector_builder_with_session_timeout(
+        &self,
+        secs: u32,
+    ) -> Result<SslConnectorBuilder, CipherListError> {
+        let mut context = self.recommended_connector_builder()?;
+        context.set_session_cache_mode(SslSessionCacheMode::BOTH);
+        set_session_timeout(&mut context, secs);
+        Ok(context)
+    }
+
+    /// Like [`Version::recommended_connector_builder`], but client-side
+    /// session caching is enabled as well and at most `size` TLS sessions are
+    /// cached (zero means unbounded). See [`RECOMMENDED_SESSION_CACHE_SIZE`].
+    pub fn recommended_connector_builder_with_session_cache(
+        &self,
+        size: u32,
+    ) -> Result<SslConnectorBuilder, CipherListError> {
+        let mut context = self.recommended_connector_builder()?;
+        context.set_session_cache_mode(SslSessionCacheMode::BOTH);
+        set_session_cache_size(&mut context, size);
+        Ok(context)
+    }
+
+    /// Creates an [`SslConnector`] with the FIXS recommended settings. Use
+    /// [`Version::recommended_connector_builder`] if you need further
+    /// customization.
+    pub fn recommended_connector(&self) -> Result<SslConnector, CipherListError> {
+        Ok(self.recommended_connector_builder()?.build())
+    }
+
+    /// Performs a TLS handshake over `stream` with the FIXS recommended
+    /// settings, as a client.
+    ///
+    /// `domain` is used both for Server Name Indication (SNI) and for
+    /// certificate verification: the handshake fails unless the peer presents
+    /// a certificate that chains up to one of the system's trusted root
+    /// certificates *and* is valid for `domain`. Use
+    /// [`Version::recommended_connector_builder`] to customize trusted
+    /// certificates or to relax verification.
+    pub fn connect<S>(&self, stream: S, domain: &str) -> Result<SslStream<S>, HandshakeError<S>>
+    where
+        S: Read + Write,
+    {
+        let connector = self
+            .recommended_connector()
+            .map_err(|err| HandshakeError::SetupFailure(err.into()))?;
+        connector.connect(domain, stream)
     }
 
     /// Creates an [`SslacceptorBuilder`] with fhe FIXS recommended settings.
-    pub fn recommended_acceptor_builder(&self) -> SslAcceptorBuilder {
-        let mut context = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls()).unwrap();
+    ///
+    /// # Errors
+    ///
+    /// This function fails if OpenSSL rejects the recommended cipher list (see
+    /// [`Version::validate_cipher_list`]), or if it fails to set up the TLS
+    /// context.
+    pub fn recommended_acceptor_builder(&self) -> Result<SslAcceptorBuilder, CipherListError> {
+        let mut context = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls())?;
         match self {
             Version::V1Draft => {
-                context
-                    .set_min_proto_version(Some(SslVersion::TLS1_1))
-                    .unwrap();
-                context
-                    .set_max_proto_version(Some(SslVersion::TLS1_2))
-                    .unwrap();
+                context.set_min_proto_version(Some(SslVersion::TLS1_1))?;
+                context.set_max_proto_version(Some(SslVersion::TLS1_2))?;
                 context.set_session_cache_mode(SslSessionCacheMode::SERVER);
                 context.set_options(SslOptions::CIPHER_SERVER_PREFERENCE);
                 context.set_options(SslOptions::NO_COMPRESSION);
@@ -106,10 +316,263 @@ impl Version {
                 context.set_options(SslOptions::NO_TLSV1_3);
             }
         };
-        context
-            .set_cipher_list(self.recommended_cs_openssl(false).join(":").as_str())
-            .unwrap();
-        context
+        set_cipher_list(&mut context, &self.recommended_cs_openssl(false).join(":"))?;
+        Ok(context)
+    }
+
+    /// Like [`Version::recommended_acceptor_builder`], but cached TLS sessions
+    /// expire after `secs` seconds rather than OpenSSL's default of five
+    /// minutes. See [`RECOMMENDED_SESSION_TIMEOUT_SECS`].
+    pub fn recommended_acceptor_builder_with_session_timeout(
+        &self,
+        secs: u32,
+    ) -> Result<SslAcceptorBuilder, CipherListError> {
+        let mut context = self.recommended_acceptor_builder()?;
+        set_session_timeout(&mut context, secs);
+        Ok(context)
+    }
+
+    /// Like [`Version::recommended_acceptor_builder`], but at most `size` TLS
+    /// sessions are cached (zero means unbounded). See
+    /// [`RECOMMENDED_SESSION_CACHE_SIZE`].
+    pub fn recommended_acceptor_builder_with_session_cache(
+        &self,
+        size: u32,
+    ) -> Result<SslAcceptorBuilder, CipherListError> {
+        let mut context = self.recommended_acceptor_builder()?;
+        set_session_cache_size(&mut context, size);
+        Ok(context)
+    }
+
+    /// Creates an [`SslAcceptor`] with the FIXS recommended settings. Use
+    /// [`Version::recommended_acceptor_builder`] if you need further
+    /// customization, e.g. to set the private key and certificate.
+    pub fn recommended_acceptor(&self) -> Result<SslAcceptor, CipherListError> {
+        Ok(self.recommended_acceptor_builder()?.build())
+    }
+}
+
+/// Applies `openssl_list` to `context`, or fails if OpenSSL rejects it.
+fn set_cipher_list(
+    context: &mut SslContextBuilder,
+    openssl_list: &str,
+) -> Result<(), CipherListError> {
+    context
+        .set_cipher_list(openssl_list)
+        .map_err(|source| CipherListError::InvalidList {
+            cipher_list: openssl_list.to_string(),
+            source,
+        })
+}
+
+/// The error returned when OpenSSL rejects a cipher list, or fails to set up
+/// the TLS context it's applied to. See [`Version::validate_cipher_list`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum CipherListError {
+    /// OpenSSL rejected `cipher_list`.
+    InvalidList {
+        /// The rejected cipher list.
+        cipher_list: String,
+        /// The underlying OpenSSL error.
+        source: ErrorStack,
+    },
+    /// OpenSSL failed to create or configure the TLS context.
+    Ssl(ErrorStack),
+}
+
+impl CipherListError {
+    /// Returns the rejected cipher list, if OpenSSL rejected one.
+    pub fn cipher_list(&self) -> Option<&str> {
+        match self {
+            Self::InvalidList { cipher_list, .. } => Some(cipher_list),
+            Self::Ssl(_) => None,
+        }
+    }
+
+    fn error_stack(&self) -> &ErrorStack {
+        match self {
+            Self::InvalidList { source, .. } => source,
+            Self::Ssl(source) => source,
+        }
+    }
+}
+
+impl fmt::Display for CipherListError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidList {
+                cipher_list,
+                source,
+            } => write!(f, "invalid cipher list '{}': {}", cipher_list, source),
+            Self::Ssl(source) => write!(f, "TLS context setup failed: {}", source),
+        }
+    }
+}
+
+impl std::error::Error for CipherListError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.error_stack())
+    }
+}
+
+impl From<ErrorStack> for CipherListError {
+    fn from(err: ErrorStack) -> Self {
+        Self::Ssl(err)
+    }
+}
+
+impl From<CipherListError> for ErrorStack {
+    fn from(err: CipherListError) -> Self {
+        match err {
+            CipherListError::InvalidList { source, .. } => source,
+            CipherListError::Ssl(source) => source,
+        }
+    }
+}
+
+/// The result of [`Version::audit_cipher_list`]. All ciphersuites are in
+/// OpenSSL's format.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CipherAudit {
+    /// Configured ciphersuites that are recommended by FIXS, in configuration
+    /// order.
+    pub recommended: Vec<String>,
+    /// Configured ciphersuites that are *not* recommended by FIXS, in
+    /// configuration order.
+    pub not_recommended: Vec<String>,
+    /// Recommended ciphersuites that are not configured.
+    pub missing: Vec<&'static str>,
+}
+
+impl CipherAudit {
+    /// Returns `true` if and only if all configured ciphersuites are
+    /// recommended by FIXS. Missing ciphersuites don't affect compliance.
+    pub fn is_compliant(&self) -> bool {
+        self.not_recommended.is_empty()
+    }
+}
+
+/// A TLS 1.2 (or earlier) ciphersuite, broken down into its components.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::fixs::CipherSuite;
+///
+/// let cs = CipherSuite::from_iana("TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256").unwrap();
+/// assert_eq!(cs.key_exchange(), "ECDHE");
+/// assert_eq!(cs.authentication(), "RSA");
+/// assert_eq!(cs.cipher(), "AES_128_GCM");
+/// assert_eq!(cs.mac(), "SHA256");
+/// assert!(cs.has_forward_secrecy());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CipherSuite<'a> {
+    iana: &'a str,
+    key_exchange: &'a str,
+    authentication: &'a str,
+    cipher: &'a str,
+    mac: &'a str,
+}
+
+impl<'a> CipherSuite<'a> {
+    /// Parses the IANA name of a ciphersuite, e.g.
+    /// `TLS_DHE_RSA_WITH_AES_256_CBC_SHA256`. Returns `None` if `iana` is not
+    /// of the form `TLS_<key exchange>[_<authentication>]_WITH_<cipher>_<mac>`,
+    /// which rules out TLS 1.3 ciphersuites.
+    ///
+    /// If there's no separate authentication algorithm (e.g.
+    /// `TLS_RSA_WITH_AES_128_CBC_SHA`), it's the same as the key exchange.
+    pub fn from_iana(iana: &'a str) -> Option<Self> {
+        if !iana.starts_with("TLS_") {
+            return None;
+        }
+        let (kx_auth, cipher_mac) = iana["TLS_".len()..].split_once("_WITH_")?;
+        let (key_exchange, authentication) = kx_auth.split_once('_').unwrap_or((kx_auth, kx_auth));
+        let (cipher, mac) = cipher_mac.rsplit_once('_')?;
+        if [key_exchange, authentication, cipher, mac]
+            .iter()
+            .any(|part| part.is_empty())
+        {
+            return None;
+        }
+        Some(Self {
+            iana,
+            key_exchange,
+            authentication,
+            cipher,
+            mac,
+        })
+    }
+
+    /// Returns the IANA name of `self`.
+    pub fn iana(&self) -> &'a str {
+        self.iana
+    }
+
+    /// Returns the key exchange algorithm of `self`, e.g. `ECDHE`.
+    pub fn key_exchange(&self) -> &'a str {
+        self.key_exchange
+    }
+
+    /// Returns the authentication algorithm of `self`, e.g. `RSA` or `PSK`.
+    pub fn authentication(&self) -> &'a str {
+        self.authentication
+    }
+
+    /// Returns the bulk encryption algorithm of `self`, including its key size
+    /// and mode of operation, e.g. `AES_128_GCM`.
+    pub fn cipher(&self) -> &'a str {
+        self.cipher
+    }
+
+    /// Returns the hash algorithm of `self`, e.g. `SHA256`. For AEAD ciphers
+    /// it's only used by the PRF, not for message authentication.
+    pub fn mac(&self) -> &'a str {
+        self.mac
+    }
+
+    /// Returns `true` if and only if the key exchange of `self` is ephemeral
+    /// (`ECDHE` or `DHE`), i.e. it provides forward secrecy.
+    pub fn has_forward_secrecy(&self) -> bool {
+        self.key_exchange == "ECDHE" || self.key_exchange == "DHE"
+    }
+}
+
+impl<'a> fmt::Display for CipherSuite<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.iana)
+    }
+}
+
+/// Restrictions on top of the FIXS recommended ciphersuites, for compliance
+/// policies that are stricter than the FIXS recommendations.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SecurityLevel {
+    /// All recommended ciphersuites are allowed.
+    Recommended,
+    /// Only AEAD ciphersuites in Galois/Counter Mode are allowed, i.e. CBC-mode
+    /// ciphersuites are forbidden.
+    GcmOnly,
+}
+
+impl SecurityLevel {
+    /// Returns `true` if and only if the ciphersuite `iana` (in IANA format) is
+    /// allowed under `self`.
+    ///
+    /// ```
+    /// use fefix::fixs::SecurityLevel;
+    ///
+    /// assert!(SecurityLevel::GcmOnly.allows("TLS_DHE_RSA_WITH_AES_128_GCM_SHA256"));
+    /// assert!(!SecurityLevel::GcmOnly.allows("TLS_DHE_RSA_WITH_AES_128_CBC_SHA256"));
+    /// ```
+    pub fn allows(&self, iana: &str) -> bool {
+        match self {
+            SecurityLevel::Recommended => true,
+            SecurityLevel::GcmOnly => iana.contains("_GCM_"),
+        }
     }
 }
 
@@ -153,11 +616,179 @@ mod test {
 
     #[test]
     fn v1draft_acceptor_is_ok() {
-        Version::V1Draft.recommended_acceptor_builder();
+        Version::V1Draft.recommended_acceptor_builder().unwrap();
     }
 
     #[test]
     fn v1draft_connector_is_ok() {
-        Version::V1Draft.recommended_connector_builder();
+        Version::V1Draft.recommended_connector_builder().unwrap();
+    }
+
+    #[test]
+    fn v1draft_built_acceptor_and_connector_are_ok() {
+        Version::V1Draft.recommended_acceptor().unwrap();
+        Version::V1Draft.recommended_connector().unwrap();
+    }
+
+    #[test]
+    fn v1draft_builders_with_session_timeout() {
+        let acceptor = Version::V1Draft
+            .recommended_acceptor_builder_with_session_timeout(RECOMMENDED_SESSION_TIMEOUT_SECS)
+            .unwrap();
+        assert_eq!(
+            session_timeout(&acceptor),
+            RECOMMENDED_SESSION_TIMEOUT_SECS as c_long
+        );
+        let connector = Version::V1Draft
+            .recommended_connector_builder_with_session_timeout(42)
+            .unwrap();
+        assert_eq!(session_timeout(&connector), 42);
+    }
+
+    #[test]
+    fn v1draft_builders_with_session_cache() {
+        let acceptor = Version::V1Draft
+            .recommended_acceptor_builder_with_session_cache(RECOMMENDED_SESSION_CACHE_SIZE)
+            .unwrap()
+            .build();
+        assert_eq!(
+            acceptor.context().session_cache_size(),
+            i64::from(RECOMMENDED_SESSION_CACHE_SIZE)
+        );
+        let connector = Version::V1Draft
+            .recommended_connector_builder_with_session_cache(42)
+            .unwrap()
+            .build();
+        assert_eq!(connector.context().session_cache_size(), 42);
+        let connector = Version::V1Draft
+            .recommended_connector_builder_with_session_cache(u32::MAX)
+            .unwrap()
+            .build();
+        assert_eq!(
+            connector.context().session_cache_size(),
+            i64::from(i32::MAX)
+        );
+    }
+
+    #[test]
+    fn v1draft_connect_fails_on_closed_stream() {
+        let stream = std::io::Cursor::new(Vec::new());
+        assert!(Version::V1Draft.connect(stream, "example.com").is_err());
+    }
+
+    #[test]
+    fn v1draft_recommended_cipher_lists_are_valid() {
+        for psk in [false, true].iter().copied() {
+            let list = Version::V1Draft.recommended_cs_openssl(psk).join(":");
+            assert!(Version::V1Draft.validate_cipher_list(&list).is_ok());
+        }
+    }
+
+    #[test]
+    fn v1draft_invalid_cipher_list_is_an_error() {
+        for list in ["", "FOO", "!ALL", "FOO:BAR"].iter() {
+            let err = Version::V1Draft.validate_cipher_list(list).unwrap_err();
+            assert_eq!(err.cipher_list(), Some(*list));
+            assert!(std::error::Error::source(&err).is_some());
+        }
+    }
+
+    #[test]
+    fn ssl_error_has_no_cipher_list() {
+        let err = CipherListError::from(ErrorStack::get());
+        assert_eq!(err.cipher_list(), None);
+        assert!(std::error::Error::source(&err).is_some());
+    }
+
+    #[test]
+    fn v1draft_gcm_only_ciphersuites() {
+        for psk in [false, true].iter().copied() {
+            let ciphersuites = Version::V1Draft
+                .recommended_cs_iana_filtered(psk, |cs| SecurityLevel::GcmOnly.allows(cs));
+            assert!(!ciphersuites.is_empty());
+            assert!(ciphersuites.iter().all(|cs| cs.contains("_GCM_")));
+            let gcm_count = Version::V1Draft
+                .recommended_cs_iana(psk)
+                .iter()
+                .filter(|cs| cs.contains("_GCM_"))
+                .count();
+            assert_eq!(ciphersuites.len(), gcm_count);
+        }
+    }
+
+    #[test]
+    fn v1draft_audit_of_recommended_cipher_list() {
+        let list = Version::V1Draft.recommended_cs_openssl(false).join(":");
+        let audit = Version::V1Draft.audit_cipher_list(&list);
+        assert!(audit.is_compliant());
+        assert!(audit.missing.is_empty());
+        assert_eq!(
+            audit.recommended.len(),
+            Version::V1Draft.recommended_cs_openssl(false).len()
+        );
+    }
+
+    #[test]
+    fn v1draft_audit_of_mixed_cipher_list() {
+        let audit = Version::V1Draft
+            .audit_cipher_list(" DHE-PSK-AES128-GCM-SHA256: HIGH ::!aNULL:DES-CBC3-SHA ");
+        assert_eq!(audit.recommended, vec!["DHE-PSK-AES128-GCM-SHA256"]);
+        assert_eq!(
+            audit.not_recommended,
+            vec!["HIGH", "!aNULL", "DES-CBC3-SHA"]
+        );
+        assert_eq!(
+            audit.missing,
+            Version::V1Draft.recommended_cs_openssl(false)
+        );
+    }
+
+    fn components<'a>(cs: &CipherSuite<'a>) -> [&'a str; 4] {
+        [
+            cs.key_exchange(),
+            cs.authentication(),
+            cs.cipher(),
+            cs.mac(),
+        ]
+    }
+
+    #[test]
+    fn parse_recommended_ciphersuites() {
+        let cs = CipherSuite::from_iana("TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA").unwrap();
+        assert_eq!(components(&cs), ["ECDHE", "ECDSA", "AES_256_CBC", "SHA"]);
+        let cs = CipherSuite::from_iana("TLS_DHE_PSK_WITH_AES_256_GCM_SHA384").unwrap();
+        assert_eq!(components(&cs), ["DHE", "PSK", "AES_256_GCM", "SHA384"]);
+        assert_eq!(cs.to_string(), "TLS_DHE_PSK_WITH_AES_256_GCM_SHA384");
+        for psk in [false, true].iter().copied() {
+            let ciphersuites = Version::V1Draft.recommended_cs(psk);
+            let iana: Vec<&str> = ciphersuites.iter().map(|cs| cs.iana()).collect();
+            assert_eq!(iana, Version::V1Draft.recommended_cs_iana(psk));
+            assert!(ciphersuites.iter().all(|cs| cs.has_forward_secrecy()));
+        }
+    }
+
+    #[test]
+    fn parse_other_ciphersuites() {
+        let cs = CipherSuite::from_iana("TLS_RSA_WITH_AES_128_CBC_SHA").unwrap();
+        assert_eq!((cs.key_exchange(), cs.authentication()), ("RSA", "RSA"));
+        assert!(!cs.has_forward_secrecy());
+        for iana in [
+            "TLS_AES_128_GCM_SHA256",
+            "SSL_RSA_WITH_RC4_128_MD5",
+            "TLS__WITH_AES_128_GCM_SHA256",
+            "TLS_RSA_WITH_SHA",
+            "",
+        ]
+        .iter()
+        {
+            assert_eq!(CipherSuite::from_iana(iana), None);
+        }
+    }
+
+    #[test]
+    fn v1draft_recommended_security_level_allows_everything() {
+        let ciphersuites = Version::V1Draft
+            .recommended_cs_iana_filtered(true, |cs| SecurityLevel::Recommended.allows(cs));
+        assert_eq!(ciphersuites, Version::V1Draft.recommended_cs_iana(true));
     }
 }
@@ -65,6 +65,11 @@ impl Config {
     pub fn set_pretty_print(&mut self, pretty_print: bool) {
         self.pretty_print = pretty_print;
     }
+
+    pub fn with_pretty_print(mut self, pretty_print: bool) -> Self {
+        self.pretty_print = pretty_print;
+        self
+    }
 }
 
 impl Default for Config {
@@ -97,5 +102,6 @@ mod test {
         assert_eq!(config.pretty_print(), true);
         config.set_pretty_print(false);
         assert_eq!(config.pretty_print(), false);
+        assert!(Config::new().with_pretty_print(true).pretty_print());
     }
 }
@@ -1,8 +1,9 @@
 use super::{Config, Configure, DecodeError};
+use crate::dictionary::{LayoutItem, LayoutItemKind};
 use crate::tagvalue::FixFieldValue;
 use crate::Dictionary;
 use crate::FixMessage;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::HashMap;
 
 /// A codec for the JSON encoding type.
 #[derive(Debug, Clone)]
@@ -71,10 +72,13 @@ where
             .dictionaries
             .get(begin_string)
             .ok_or(DecodeError::InvalidMsgType)?;
+        // Group entries are laid out according to `MsgType <35>`.
+        let msg_type = header.get("MsgType").and_then(|v| v.as_str()).unwrap_or("");
         let message = &mut self.message;
         message.clear();
         let mut decode_field = |name: &str, value: &serde_json::Value| {
-            decode_field(dictionary, name, value).map(|(tag, field)| message.add_field(tag, field))
+            decode_field(dictionary, msg_type, name, value)
+                .map(|(tag, field)| message.add_field(tag, field))
         };
         for (key, value) in header.iter() {
             decode_field(key, value)?.unwrap();
@@ -91,6 +95,7 @@ where
 
 fn decode_field(
     dictionary: &Dictionary,
+    msg_type: &str,
     key: &str,
     value: &serde_json::Value,
 ) -> Result<(u32, FixFieldValue), DecodeError> {
@@ -101,11 +106,19 @@ fn decode_field(
                 FixFieldValue::string(s.as_bytes()).unwrap(),
             )),
             serde_json::Value::Array(values) => {
+                let tag = field.tag();
+                let order = group_field_order(dictionary, msg_type, tag).unwrap_or_default();
                 let mut group = Vec::new();
                 for item in values {
-                    group.push(decode_component_block(dictionary, item)?);
+                    let mut entry = decode_component_block(dictionary, msg_type, item)?;
+                    // JSON objects are unordered, so entries follow the
+                    // dictionary instead. Unknown fields go last.
+                    entry.sort_by_key(|(tag, _)| {
+                        order.iter().position(|t| t == tag).unwrap_or(order.len())
+                    });
+                    group.push(entry);
                 }
-                Ok((field.tag() as u32, FixFieldValue::Group(group)))
+                Ok((tag, FixFieldValue::Group(group)))
             }
             _ => Err(DecodeError::Schema),
         }
@@ -116,16 +129,74 @@ fn decode_field(
 
 fn decode_component_block(
     dictionary: &Dictionary,
+    msg_type: &str,
     value: &serde_json::Value,
-) -> Result<BTreeMap<i64, FixFieldValue>, DecodeError> {
-    let mut group = BTreeMap::new();
+) -> Result<Vec<(u32, FixFieldValue)>, DecodeError> {
+    let mut group = Vec::new();
     for item in value.as_object().unwrap() {
-        let (tag, field) = decode_field(dictionary, item.0, item.1)?;
-        group.insert(tag as i64, field);
+        group.push(decode_field(dictionary, msg_type, item.0, item.1)?);
     }
     Ok(group)
 }
 
+/// Returns the tags of the fields of each entry of the group `tag`, in layout
+/// order, if `msg_type` (or `StandardHeader`/`StandardTrailer`) has such a group.
+fn group_field_order(dictionary: &Dictionary, msg_type: &str, tag: u32) -> Option<Vec<u32>> {
+    if let Some(order) = dictionary
+        .message_by_msgtype(msg_type)
+        .and_then(|message| find_group_field_order(message.layout(), tag))
+    {
+        return Some(order);
+    }
+    ["StandardHeader", "StandardTrailer"]
+        .iter()
+        .find_map(|name| {
+            dictionary
+                .component_by_name(name)
+                .and_then(|component| find_group_field_order(component.items(), tag))
+        })
+}
+
+fn find_group_field_order<'a, I>(items: I, tag: u32) -> Option<Vec<u32>>
+where
+    I: IntoIterator<Item = LayoutItem<'a>>,
+{
+    for item in items {
+        let order = match item.kind() {
+            LayoutItemKind::Field(_) => None,
+            LayoutItemKind::Component(component) => find_group_field_order(component.items(), tag),
+            LayoutItemKind::Group(len_field, group_items) => {
+                if len_field.tag() == tag {
+                    let mut order = Vec::new();
+                    push_field_order(group_items, &mut order);
+                    Some(order)
+                } else {
+                    find_group_field_order(group_items, tag)
+                }
+            }
+        };
+        if order.is_some() {
+            return order;
+        }
+    }
+    None
+}
+
+/// Appends the tags of `items` to `order`, including those of components.
+/// Nested groups only contribute their `NumInGroup` field.
+fn push_field_order<'a, I>(items: I, order: &mut Vec<u32>)
+where
+    I: IntoIterator<Item = LayoutItem<'a>>,
+{
+    for item in items {
+        match item.kind() {
+            LayoutItemKind::Field(field) => order.push(field.tag()),
+            LayoutItemKind::Component(component) => push_field_order(component.items(), order),
+            LayoutItemKind::Group(len_field, _) => order.push(len_field.tag()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -210,4 +281,32 @@ mod test {
             _ => panic!(),
         };
     }
+
+    #[test]
+    fn group_entries_follow_the_dictionary_layout() {
+        let message = r#"
+{
+    "Header": {
+        "BeginString": "FIX.4.4",
+        "MsgType": "W"
+    },
+    "Body": {
+        "NoMDEntries": [
+            { "MDEntryType": "0", "MDEntryPx": "1.50", "MDEntrySize": "75", "MDEntryTime": "21:14:38.688" }
+        ]
+    },
+    "Trailer": {
+    }
+}
+        "#;
+        let mut decoder = encoder_fix44();
+        let message = decoder.decode(message.as_bytes()).unwrap();
+        match message.field(268) {
+            Some(FixFieldValue::Group(entries)) => {
+                let tags: Vec<u32> = entries[0].iter().map(|(tag, _)| *tag).collect();
+                assert_eq!(tags, vec![269, 270, 271, 273]);
+            }
+            _ => panic!("Not a group"),
+        }
+    }
 }
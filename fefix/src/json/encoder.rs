@@ -62,11 +62,11 @@ where
                     let mut map = serde_json::Map::new();
                     for item in group {
                         let field = dict
-                            .field_by_tag(*item.0 as u32)
+                            .field_by_tag(item.0)
                             .ok_or(DecodeError::InvalidData)
                             .unwrap();
                         let field_name = field.name().to_string();
-                        let field_value = self.translate(dict, item.1);
+                        let field_value = self.translate(dict, &item.1);
                         map.insert(field_name, field_value);
                     }
                     values.push(serde_json::Value::Object(map));
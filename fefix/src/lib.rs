@@ -39,10 +39,16 @@ mod buffer;
 pub mod dictionary;
 mod dt;
 mod dtf_date;
+mod dtf_decimal;
+mod dtf_localmktdate;
 mod dtf_monthyear;
 mod dtf_mulchar;
 mod dtf_mulstr;
 mod dtf_time;
+mod dtf_timestamp;
+mod dtf_tz_time;
+mod dtf_tz_timestamp;
+pub mod enums;
 pub mod fast;
 mod fix_codegen;
 pub mod fixs;
@@ -55,22 +61,42 @@ pub mod sofh;
 mod tagmap;
 pub mod tags;
 pub mod tagvalue;
+#[cfg(feature = "test_util")]
+pub mod test_util;
+#[cfg(any(test, feature = "test_util"))]
+pub mod test_vectors;
+#[cfg(feature = "tokio-codec")]
+pub mod tokio_codec;
 
-pub use app_version::AppVersion;
-pub use buffer::Buffer;
+pub use app_version::{
+    AppVersion, FixVersion, BEGIN_STRING_FIX40, BEGIN_STRING_FIX41, BEGIN_STRING_FIX42,
+    BEGIN_STRING_FIX43, BEGIN_STRING_FIX44, BEGIN_STRING_FIXT11,
+};
+#[cfg(feature = "bytes")]
+pub use buffer::BytesMutBuffer;
+pub use buffer::{ArrayBuffer, Buffer, BufferFull};
 pub use dictionary::Dictionary;
-pub use dt::DataType;
+pub use dt::{DataType, StorageKind};
 pub use dtf_date::DtfDate;
+pub use dtf_decimal::{fix_float_eq, DtfDecimal};
+pub use dtf_localmktdate::DtfLocalMktDate;
 pub use dtf_monthyear::DtfMonthYear;
 pub use dtf_mulchar::DtfMulCharIter;
 pub use dtf_mulstr::DtfMulStrIter;
 pub use dtf_time::DtfTime;
+pub use dtf_timestamp::DtfTimestamp;
+pub use dtf_tz_time::DtfTzTime;
+pub use dtf_tz_timestamp::DtfTzTimestamp;
 pub use fefix_derive::*;
 pub use fix_codegen::{codegen, codegen_tag_mnemonics};
 pub use models::{FieldsIter, FixFieldAccess, FixFieldsIter, FixMessage};
 pub use quickfix_specs::quickfix_spec;
 pub use tagmap::TagMap;
 
+// Allows `::fefix` paths generated by `fefix_derive` within this crate's tests.
+#[cfg(test)]
+extern crate self as fefix;
+
 #[cfg(expose_openssl)]
 pub extern crate openssl;
 
@@ -1,7 +1,9 @@
 use super::{Error, FixFieldAccess, FixFieldsIter};
-use crate::tagvalue::field_value as val;
-use crate::tagvalue::FixFieldValue;
+use crate::tagvalue::field_value::{self as val, read_compact_len, write_compact_len, DataField};
+use crate::tagvalue::{DecodeError, FixFieldValue};
+use crate::DataType;
 use std::collections::HashMap;
+use std::convert::TryFrom;
 
 const DEFAULT_FIELDS_LEN: usize = 64;
 
@@ -90,6 +92,39 @@ impl FixMessage {
         self.add_field(tag, FixFieldValue::from(value)).unwrap()
     }
 
+    /// Sets the `Data` field `data_tag` to `data`, replacing its previous value
+    /// if any. The `Length` field of `data` is written right before it when
+    /// encoding, so any separate field with that tag is removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::field_value::DataField;
+    /// use fefix::{FixFieldAccess, FixMessage};
+    ///
+    /// let message = &mut FixMessage::new();
+    /// message.add_i64(95, 3);
+    /// message.set_data_field(96, DataField::new(95, b"foo".to_vec()));
+    /// assert!(message.field(95).is_none());
+    /// assert_eq!(message.field_data(96), Some(&b"foo"[..]));
+    /// ```
+    pub fn set_data_field(&mut self, data_tag: u32, data: DataField) {
+        self.remove_field(data.length_tag());
+        if self
+            .fields
+            .insert(data_tag, FixFieldValue::Data(data))
+            .is_none()
+        {
+            self.insertion_order.push(data_tag);
+        }
+    }
+
+    fn remove_field(&mut self, tag: u32) {
+        if self.fields.remove(&tag).is_some() {
+            self.insertion_order.retain(|t| *t != tag);
+        }
+    }
+
     /// Returns an immutable reference to the field value of `tag` in `self`, if
     /// present.
     ///
@@ -132,6 +167,135 @@ impl FixMessage {
     }
 
     pub fn end_header(&mut self) {}
+
+    /// Serializes `self` into a compact binary format meant for internal
+    /// storage, e.g. a replay cache. It's not FIX: see
+    /// [`Encoder`](crate::tagvalue::Encoder) for that. Fields are stored in
+    /// insertion order together with the [`DataType::discriminant`] of their
+    /// values, so that [`FixMessage::from_compact`] restores them exactly.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `self` contains
+    /// [`FieldValue::Phantom`](val::FieldValue::Phantom) values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::{FixFieldAccess, FixMessage};
+    ///
+    /// let message = &mut FixMessage::new();
+    /// message.add_str(35, "D");
+    /// message.add_i64(38, 100);
+    /// let compact = message.to_compact();
+    /// let rehydrated = FixMessage::from_compact(&compact).unwrap();
+    /// assert_eq!(&rehydrated, message);
+    /// assert_eq!(rehydrated.field_i64(38), Some(100));
+    /// ```
+    pub fn to_compact(&self) -> Vec<u8> {
+        let mut buffer = vec![COMPACT_FORMAT_VERSION];
+        write_compact_len(&mut buffer, self.insertion_order.len());
+        for (tag, value) in self.iter_fields() {
+            write_compact_field(&mut buffer, tag, value);
+        }
+        buffer
+    }
+
+    /// Deserializes a [`FixMessage`] from the output of
+    /// [`FixMessage::to_compact`]. Fails with [`DecodeError::Invalid`] if
+    /// `data` is malformed or was written by an incompatible version of this
+    /// crate.
+    pub fn from_compact(data: &[u8]) -> std::result::Result<Self, DecodeError> {
+        let mut data = match data.split_first() {
+            Some((&COMPACT_FORMAT_VERSION, rest)) => rest,
+            _ => return Err(DecodeError::Invalid),
+        };
+        let mut message = Self::new();
+        let len = read_compact_len(&mut data).ok_or(DecodeError::Invalid)?;
+        for _ in 0..len {
+            let (tag, value) = read_compact_field(&mut data).ok_or(DecodeError::Invalid)?;
+            message
+                .add_field(tag, value)
+                .map_err(|_| DecodeError::DuplicateTag(tag))?;
+        }
+        if data.is_empty() {
+            Ok(message)
+        } else {
+            Err(DecodeError::Invalid)
+        }
+    }
+}
+
+/// Bumped whenever the output of [`FixMessage::to_compact`] changes.
+const COMPACT_FORMAT_VERSION: u8 = 1;
+
+// Compact representations of the variants of `FixFieldValue`.
+const COMPACT_ATOM: u8 = 0;
+const COMPACT_GROUP: u8 = 1;
+const COMPACT_DATA: u8 = 2;
+
+fn write_compact_field(buffer: &mut Vec<u8>, tag: u32, value: &FixFieldValue) {
+    write_compact_len(buffer, tag as usize);
+    match value {
+        FixFieldValue::Atom(atom) => {
+            let data_type = atom.data_type().expect("Phantom values can't be stored");
+            buffer.extend_from_slice(&[COMPACT_ATOM, data_type.discriminant()]);
+            atom.write_compact(buffer);
+        }
+        FixFieldValue::Group(entries) => {
+            buffer.push(COMPACT_GROUP);
+            write_compact_len(buffer, entries.len());
+            for entry in entries {
+                write_compact_len(buffer, entry.len());
+                for (tag, value) in entry {
+                    write_compact_field(buffer, *tag, value);
+                }
+            }
+        }
+        FixFieldValue::Data(data) => {
+            buffer.push(COMPACT_DATA);
+            write_compact_len(buffer, data.length_tag() as usize);
+            write_compact_len(buffer, data.len());
+            buffer.extend_from_slice(data.value());
+        }
+    }
+}
+
+fn read_compact_field(data: &mut &[u8]) -> Option<(u32, FixFieldValue)> {
+    let tag = u32::try_from(read_compact_len(data)?).ok()?;
+    let (kind, rest) = data.split_first()?;
+    *data = rest;
+    let value = match *kind {
+        COMPACT_ATOM => {
+            let (discriminant, rest) = data.split_first()?;
+            *data = rest;
+            let data_type = DataType::from_discriminant(*discriminant)?;
+            FixFieldValue::Atom(val::FieldValue::read_compact(data_type, data)?)
+        }
+        COMPACT_GROUP => {
+            let mut entries = Vec::new();
+            for _ in 0..read_compact_len(data)? {
+                let mut entry = Vec::new();
+                for _ in 0..read_compact_len(data)? {
+                    entry.push(read_compact_field(data)?);
+                }
+                entries.push(entry);
+            }
+            FixFieldValue::Group(entries)
+        }
+        COMPACT_DATA => {
+            let length_tag = u32::try_from(read_compact_len(data)?).ok()?;
+            let len = read_compact_len(data)?;
+            if data.len() < len {
+                return None;
+            }
+            let (bytes, rest) = data.split_at(len);
+            *data = rest;
+            FixFieldValue::Data(DataField::new(length_tag, bytes.to_vec()))
+        }
+        _ => return None,
+    };
+    Some((tag, value))
 }
 
 impl FixFieldAccess for FixMessage {
@@ -145,6 +309,7 @@ impl FixFieldAccess for FixMessage {
     fn field_data(&self, tag: u32) -> Option<&[u8]> {
         match self.field(tag) {
             Some(FixFieldValue::Atom(val::FieldValue::String(s))) => Some(s.as_str().as_bytes()),
+            Some(FixFieldValue::Data(data)) => Some(data.value()),
             _ => None,
         }
     }
@@ -176,12 +341,13 @@ impl<'a> FixFieldsIter<&'a FixFieldValue> for &'a FixMessage {
     type FieldsIterStdHeader = FieldsIter<'a>;
     type FieldsIterBody = FieldsIter<'a>;
 
-    /// Creates an [`Iterator`] over all FIX fields in `self`.
+    /// Creates an [`Iterator`] over all FIX fields in `self`, in insertion
+    /// order.
     fn iter_fields(&self) -> Self::FieldsIter {
         FieldsIter {
             message: self,
-            i: self.i_first_cell,
-            until: 0,
+            i: 0,
+            until: self.insertion_order.len(),
         }
     }
 
@@ -218,11 +384,62 @@ impl<'a> Iterator for FieldsIter<'a> {
     type Item = (u32, &'a FixFieldValue);
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.i > 0 {
-            let value = self.message.field(self.i as u32).unwrap();
-            Some((self.i as u32, &value))
+        if self.i < self.until {
+            let tag = self.message.insertion_order[self.i];
+            self.i += 1;
+            Some((tag, self.message.field(tag).unwrap()))
         } else {
             None
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tagvalue::field_value::{FieldValue, XmlData};
+
+    #[test]
+    fn compact_round_trip_preserves_fields_and_types() {
+        let mut message = FixMessage::new();
+        let mut add = |tag, value| message.add_field(tag, value).unwrap();
+        add(35, FixFieldValue::from("D".to_string()));
+        add(34, FixFieldValue::Atom(FieldValue::seq_num(42)));
+        add(38, FixFieldValue::from(-100i64));
+        add(54, FixFieldValue::from('1'));
+        add(44, FixFieldValue::from(1.5));
+        add(205, FixFieldValue::Atom(FieldValue::day_of_month(31)));
+        add(43, FixFieldValue::Atom(FieldValue::bool(true)));
+        add(212, FixFieldValue::Atom(FieldValue::length(7)));
+        let xml = FieldValue::XmlData(XmlData::new(b"<a/>"));
+        add(213, FixFieldValue::Atom(xml));
+        let party = |id: &str, role: i64| {
+            vec![
+                (448, FixFieldValue::from(id.to_string())),
+                (452, FixFieldValue::from(role)),
+            ]
+        };
+        let parties = vec![party("A", 1), party("B", 3)];
+        add(453, FixFieldValue::Group(parties));
+        message.set_data_field(96, DataField::new(95, b"foo\x01bar".to_vec()));
+        let compact = message.to_compact();
+        let rehydrated = FixMessage::from_compact(&compact).unwrap();
+        assert_eq!(rehydrated, message);
+        let tags = |msg: &FixMessage| msg.iter_fields().map(|(tag, _)| tag).collect::<Vec<_>>();
+        assert_eq!(tags(&rehydrated), tags(&message));
+    }
+
+    #[test]
+    fn truncated_compact_message_is_invalid() {
+        let mut message = FixMessage::new();
+        message.add_str(35, "D");
+        message.add_i64(38, 100);
+        let compact = message.to_compact();
+        for len in 0..compact.len() {
+            assert_eq!(
+                FixMessage::from_compact(&compact[..len]),
+                Err(DecodeError::Invalid)
+            );
+        }
+    }
+}
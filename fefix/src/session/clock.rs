@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+
+/// A source of wall-clock time for the session layer.
+///
+/// Abstracting over the clock lets tests (and simulations) control the value of
+/// `SendingTime <52>` and other timestamp fields.
+pub trait Clock {
+    /// Returns the current UTC time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// A [`Clock`] backed by the operating system's real-time clock.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Formats `time` as a FIX `UTCTimestamp` with millisecond precision, i.e.
+/// `YYYYMMDD-HH:MM:SS.sss`.
+///
+/// See <https://www.onixs.biz/fix-dictionary/4.4/index.html#UTCTimestamp>.
+pub fn utc_timestamp(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%d-%H:%M:%S%.3f").to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn utc_timestamp_has_millisecond_precision() {
+        let time = Utc.ymd(2021, 3, 7).and_hms_milli(9, 5, 2, 41);
+        assert_eq!(utc_timestamp(time), "20210307-09:05:02.041");
+    }
+}
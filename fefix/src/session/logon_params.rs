@@ -0,0 +1,146 @@
+use crate::session::EncryptMethod;
+use crate::tagvalue::{parse_int, FixMessageRef};
+use crate::{tags, AppVersion};
+use std::convert::TryFrom;
+use std::fmt;
+
+/// Session parameters negotiated by a `Logon <A>` message.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct LogonParams {
+    /// `EncryptMethod <98>`.
+    pub encrypt_method: EncryptMethod,
+    /// `HeartBtInt <108>`, in seconds.
+    pub heart_bt_int: u32,
+    /// `ResetSeqNumFlag <141>`. Defaults to `false` when absent.
+    pub reset_seq_num_flag: bool,
+    /// `DefaultApplVerID <1137>`, only present on FIXT sessions.
+    pub default_appl_ver_id: Option<AppVersion>,
+}
+
+impl LogonParams {
+    /// Extracts [`LogonParams`] from a decoded `Logon <A>` message.
+    /// `EncryptMethod <98>` and `HeartBtInt <108>` are required; all other
+    /// fields are optional.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::session::{EncryptMethod, LogonParams};
+    /// use fefix::tagvalue::{Config, Decoder};
+    /// use fefix::{AppVersion, Dictionary};
+    ///
+    /// let dict = Dictionary::from_version(AppVersion::Fix44);
+    /// let mut decoder = Decoder::with_config(dict, Config::default().with_separator(b'|'));
+    /// let msg = b"8=FIX.4.4|9=53|35=A|49=A|56=B|34=1|52=20210101-00:00:00|98=0|108=30|10=237|";
+    /// let params = LogonParams::from_message(&decoder.decode(msg).unwrap()).unwrap();
+    /// assert_eq!(params.encrypt_method, EncryptMethod::None);
+    /// assert_eq!(params.heart_bt_int, 30);
+    /// assert_eq!(params.reset_seq_num_flag, false);
+    /// assert_eq!(params.default_appl_ver_id, None);
+    /// ```
+    pub fn from_message(msg: &FixMessageRef) -> Result<Self, LogonParamsError> {
+        let encrypt_method = required(msg, tags::ENCRYPT_METHOD)?;
+        let encrypt_method = EncryptMethod::from_bytes(encrypt_method)
+            .ok_or(LogonParamsError::InvalidValue(tags::ENCRYPT_METHOD))?;
+        let heart_bt_int = required(msg, tags::HEART_BT_INT)?;
+        let heart_bt_int = parse_int(heart_bt_int)
+            .ok()
+            .and_then(|secs| u32::try_from(secs).ok())
+            .ok_or(LogonParamsError::InvalidValue(tags::HEART_BT_INT))?;
+        let reset_seq_num_flag = match msg.field_raw(tags::RESET_SEQ_NUM_FLAG) {
+            None | Some(b"N") => false,
+            Some(b"Y") => true,
+            Some(_) => return Err(LogonParamsError::InvalidValue(tags::RESET_SEQ_NUM_FLAG)),
+        };
+        let default_appl_ver_id = match msg.field_raw(DEFAULT_APPL_VER_ID) {
+            None => None,
+            Some(value) => Some(
+                AppVersion::from_appl_ver_id(value)
+                    .ok_or(LogonParamsError::InvalidValue(DEFAULT_APPL_VER_ID))?,
+            ),
+        };
+        Ok(Self {
+            encrypt_method,
+            heart_bt_int,
+            reset_seq_num_flag,
+            default_appl_ver_id,
+        })
+    }
+}
+
+/// The error type returned by [`LogonParams::from_message`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LogonParamsError {
+    /// A required field is missing.
+    MissingField(u32),
+    /// A field has a value that can't be parsed.
+    InvalidValue(u32),
+}
+
+impl fmt::Display for LogonParamsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::MissingField(tag) => write!(f, "Missing required field <{}>", tag),
+            Self::InvalidValue(tag) => write!(f, "Invalid value for field <{}>", tag),
+        }
+    }
+}
+
+impl std::error::Error for LogonParamsError {}
+
+// `DefaultApplVerID <1137>` is not defined in FIX 4.4, whose mnemonics are
+// the ones re-exported by `tags`.
+const DEFAULT_APPL_VER_ID: u32 = tags::fixt11::DEFAULT_APPL_VER_ID;
+
+fn required<'a>(msg: &'a FixMessageRef, tag: u32) -> Result<&'a [u8], LogonParamsError> {
+    msg.field_raw(tag)
+        .ok_or(LogonParamsError::MissingField(tag))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tagvalue::{Config, Decoder};
+    use crate::Dictionary;
+
+    fn decoder() -> Decoder<Config> {
+        let dict = Dictionary::from_version(AppVersion::Fix44);
+        Decoder::with_config(dict, Config::default().with_separator(b'|'))
+    }
+
+    #[test]
+    fn fixt_logon_with_all_params() {
+        let msg = b"8=FIXT.1.1|9=66|35=A|49=A|56=B|34=1|52=20210101-00:00:00|98=0|108=30|141=Y|1137=9|10=165|";
+        let decoder = &mut decoder();
+        let params = LogonParams::from_message(&decoder.decode(msg).unwrap()).unwrap();
+        assert_eq!(
+            params,
+            LogonParams {
+                encrypt_method: EncryptMethod::None,
+                heart_bt_int: 30,
+                reset_seq_num_flag: true,
+                default_appl_ver_id: Some(AppVersion::Fix50SP2),
+            }
+        );
+    }
+
+    #[test]
+    fn negative_heart_bt_int_is_invalid() {
+        let msg = b"8=FIX.4.4|9=53|35=A|49=A|56=B|34=1|52=20210101-00:00:00|98=0|108=-1|10=232|";
+        let decoder = &mut decoder();
+        assert_eq!(
+            LogonParams::from_message(&decoder.decode(msg).unwrap()),
+            Err(LogonParamsError::InvalidValue(tags::HEART_BT_INT))
+        );
+    }
+
+    #[test]
+    fn missing_heart_bt_int() {
+        let msg = b"8=FIX.4.4|9=46|35=A|49=A|56=B|34=1|52=20210101-00:00:00|98=0|10=058|";
+        let decoder = &mut decoder();
+        assert_eq!(
+            LogonParams::from_message(&decoder.decode(msg).unwrap()),
+            Err(LogonParamsError::MissingField(tags::HEART_BT_INT))
+        );
+    }
+}
@@ -0,0 +1,482 @@
+use crate::session::clock::utc_timestamp;
+use crate::session::{Clock, SeqNumbers, SystemClock};
+use crate::tagvalue::{field_value as val, FixFieldValue};
+use crate::{tags, FixMessage};
+use chrono::{DateTime, Utc};
+
+/// Builds outbound [`FixMessage`]s, filling in the session-managed header
+/// fields `MsgSeqNum <34>` and `SendingTime <52>` on
+/// [`MessageBuilder::finish`]. This can be turned off with
+/// [`MessageBuilder::populate_header`], e.g. when a later encoding stage takes
+/// care of the header.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::session::{MessageBuilder, SeqNumbers, SystemClock};
+/// use fefix::tags;
+///
+/// let mut seq_numbers = SeqNumbers::default();
+/// let mut builder = MessageBuilder::new(&mut seq_numbers, SystemClock);
+/// builder.message_mut().add_str(tags::MSG_TYPE, "0");
+/// let msg = builder.finish();
+/// assert_eq!(msg.f_seq_num(), Some(1));
+/// assert_eq!(seq_numbers.next_outbound(), 2);
+/// ```
+#[derive(Debug)]
+pub struct MessageBuilder<'a, C = SystemClock> {
+    seq_numbers: &'a mut SeqNumbers,
+    clock: C,
+    message: FixMessage,
+    resend: Option<Resend>,
+    populate_header: bool,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Resend {
+    seq_num: u64,
+    orig_sending_time: DateTime<Utc>,
+}
+
+impl<'a, C> MessageBuilder<'a, C>
+where
+    C: Clock,
+{
+    /// Creates a new [`MessageBuilder`] that takes outbound seq. numbers from
+    /// `seq_numbers` and timestamps from `clock`.
+    pub fn new(seq_numbers: &'a mut SeqNumbers, clock: C) -> Self {
+        Self {
+            seq_numbers,
+            clock,
+            message: FixMessage::new(),
+            resend: None,
+            populate_header: true,
+        }
+    }
+
+    /// Returns a mutable reference to the message being built.
+    pub fn message_mut(&mut self) -> &mut FixMessage {
+        &mut self.message
+    }
+
+    /// Starts a repeating group with `NumInGroup` field `count_tag`, each of
+    /// whose entries must begin with `delimiter_tag`. The group is added to the
+    /// message, with its count and all entries in order, when the returned
+    /// [`GroupBuilder`] is dropped or [finished](GroupBuilder::finish).
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `count_tag` has already been added to the
+    /// message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::session::{MessageBuilder, SeqNumbers, SystemClock};
+    /// use fefix::tagvalue::FixFieldValue;
+    /// use fefix::tags;
+    ///
+    /// let mut seq_numbers = SeqNumbers::default();
+    /// let mut builder = MessageBuilder::new(&mut seq_numbers, SystemClock);
+    /// builder.message_mut().add_str(tags::MSG_TYPE, "W");
+    /// let mut group = builder.start_group(tags::NO_MD_ENTRIES, tags::MD_ENTRY_TYPE);
+    /// group.add_entry().add_str(tags::MD_ENTRY_TYPE, "0");
+    /// group.add_entry().add_str(tags::MD_ENTRY_TYPE, "1");
+    /// group.finish();
+    /// let msg = builder.finish();
+    /// match msg.field(tags::NO_MD_ENTRIES) {
+    ///     Some(FixFieldValue::Group(entries)) => assert_eq!(entries.len(), 2),
+    ///     _ => panic!(),
+    /// }
+    /// ```
+    pub fn start_group(&mut self, count_tag: u32, delimiter_tag: u32) -> GroupBuilder<'_> {
+        assert!(
+            self.message.field(count_tag).is_none(),
+            "Duplicate NumInGroup field <{}>",
+            count_tag
+        );
+        GroupBuilder::new(
+            GroupSink::Message(&mut self.message),
+            count_tag,
+            delimiter_tag,
+        )
+    }
+
+    /// Marks the message as a retransmission of the message originally sent
+    /// with `seq_num` at `orig_sending_time`. [`MessageBuilder::finish`] will
+    /// then reuse `seq_num` instead of consuming a new outbound seq. number and
+    /// will add `PossDupFlag <43>` and `OrigSendingTime <122>`.
+    pub fn resend(&mut self, seq_num: u64, orig_sending_time: DateTime<Utc>) -> &mut Self {
+        self.resend = Some(Resend {
+            seq_num,
+            orig_sending_time,
+        });
+        self
+    }
+
+    /// Decides whether [`MessageBuilder::finish`] fills in the session-managed
+    /// header fields (the default) or leaves the message as is, with only the
+    /// fields added through [`MessageBuilder::message_mut`].
+    ///
+    /// With `populate == false`, no outbound seq. number is consumed and
+    /// [`MessageBuilder::resend`] has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::session::{MessageBuilder, SeqNumbers, SystemClock};
+    /// use fefix::{tags, FixFieldAccess};
+    ///
+    /// let mut seq_numbers = SeqNumbers::default();
+    /// let mut builder = MessageBuilder::new(&mut seq_numbers, SystemClock);
+    /// builder.populate_header(false);
+    /// builder.message_mut().add_str(tags::MSG_TYPE, "0");
+    /// let msg = builder.finish();
+    /// assert_eq!(msg.f_seq_num(), None);
+    /// assert_eq!(msg.field_str(tags::SENDING_TIME), None);
+    /// assert_eq!(seq_numbers.next_outbound(), 1);
+    /// ```
+    pub fn populate_header(&mut self, populate: bool) -> &mut Self {
+        self.populate_header = populate;
+        self
+    }
+
+    /// Fills in the session-managed header fields (unless disabled via
+    /// [`MessageBuilder::populate_header`]) and returns the finished message.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if any of the session-managed fields has already
+    /// been added manually.
+    pub fn finish(self) -> FixMessage {
+        let mut msg = self.message;
+        if !self.populate_header {
+            return msg;
+        }
+        let seq_num = match self.resend {
+            Some(resend) => resend.seq_num,
+            None => {
+                let seq_num = self.seq_numbers.next_outbound();
+                self.seq_numbers.incr_outbound();
+                seq_num
+            }
+        };
+        msg.add_i64(tags::MSG_SEQ_NUM, seq_num as i64);
+        msg.add_str(tags::SENDING_TIME, utc_timestamp(self.clock.now()));
+        if let Some(resend) = self.resend {
+            msg.add_field(
+                tags::POSS_DUP_FLAG,
+                FixFieldValue::Atom(val::FieldValue::bool(true)),
+            )
+            .unwrap();
+            msg.add_str(
+                tags::ORIG_SENDING_TIME,
+                utc_timestamp(resend.orig_sending_time),
+            );
+        }
+        msg
+    }
+}
+
+/// Collects the entries of a repeating group. See
+/// [`MessageBuilder::start_group`] and [`GroupEntry::start_group`].
+///
+/// Empty entries are ignored. If the group has no entries at all, no field is
+/// added, since `NumInGroup` fields must be positive.
+#[derive(Debug)]
+pub struct GroupBuilder<'a> {
+    sink: GroupSink<'a>,
+    count_tag: u32,
+    delimiter_tag: u32,
+    entries: Vec<GroupEntry>,
+}
+
+#[derive(Debug)]
+enum GroupSink<'a> {
+    Message(&'a mut FixMessage),
+    Entry(&'a mut GroupEntry),
+}
+
+impl<'a> GroupBuilder<'a> {
+    fn new(sink: GroupSink<'a>, count_tag: u32, delimiter_tag: u32) -> Self {
+        Self {
+            sink,
+            count_tag,
+            delimiter_tag,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Appends a new, empty entry to the group and returns a mutable reference
+    /// to it. Its first field must be the delimiter field.
+    pub fn add_entry(&mut self) -> &mut GroupEntry {
+        self.entries.push(GroupEntry {
+            delimiter_tag: Some(self.delimiter_tag),
+            fields: Vec::new(),
+        });
+        self.entries.last_mut().unwrap()
+    }
+
+    /// Returns the number of entries added so far, including empty ones.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if and only if no entries have been added yet.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Adds the group to its parent message or entry. This is the same as
+    /// dropping `self`.
+    pub fn finish(self) {}
+}
+
+impl<'a> Drop for GroupBuilder<'a> {
+    fn drop(&mut self) {
+        let entries: Vec<Vec<(u32, FixFieldValue)>> = self
+            .entries
+            .drain(..)
+            .filter(|entry| !entry.fields.is_empty())
+            .map(|entry| entry.fields)
+            .collect();
+        if entries.is_empty() {
+            return;
+        }
+        let value = FixFieldValue::Group(entries);
+        match &mut self.sink {
+            // `MessageBuilder::start_group` rejects duplicate `NumInGroup`
+            // fields and `self` borrows the message mutably until now, so
+            // this can't fail. Either way, `drop` must never panic.
+            GroupSink::Message(msg) => {
+                let _ = msg.add_field(self.count_tag, value);
+            }
+            GroupSink::Entry(entry) => entry.fields.push((self.count_tag, value)),
+        }
+    }
+}
+
+/// A single entry of a repeating group, with fields in insertion order.
+#[derive(Debug, Clone)]
+pub struct GroupEntry {
+    // `None` once the delimiter has been added.
+    delimiter_tag: Option<u32>,
+    fields: Vec<(u32, FixFieldValue)>,
+}
+
+impl GroupEntry {
+    /// Adds a field to `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `tag` is a duplicate or if `self` is empty and
+    /// `tag` is not the delimiter field of the group.
+    pub fn add_field(&mut self, tag: u32, value: FixFieldValue) -> &mut Self {
+        if let Some(delimiter_tag) = self.delimiter_tag.take() {
+            assert_eq!(
+                tag, delimiter_tag,
+                "Group entries must begin with the delimiter field"
+            );
+        }
+        assert!(
+            self.fields.iter().all(|(t, _)| *t != tag),
+            "Duplicate field <{}> in group entry",
+            tag
+        );
+        self.fields.push((tag, value));
+        self
+    }
+
+    /// Adds a string field to `self`. See [`GroupEntry::add_field`].
+    pub fn add_str<S: Into<String>>(&mut self, tag: u32, value: S) -> &mut Self {
+        self.add_field(tag, FixFieldValue::string(value.into().as_bytes()).unwrap())
+    }
+
+    /// Adds an integer field to `self`. See [`GroupEntry::add_field`].
+    pub fn add_i64(&mut self, tag: u32, value: i64) -> &mut Self {
+        self.add_field(tag, FixFieldValue::from(value))
+    }
+
+    /// Starts a nested repeating group within `self`. See
+    /// [`MessageBuilder::start_group`].
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `count_tag` is a duplicate or if `self` is
+    /// still empty, since entries must begin with the delimiter field.
+    pub fn start_group(&mut self, count_tag: u32, delimiter_tag: u32) -> GroupBuilder<'_> {
+        assert!(
+            self.delimiter_tag.is_none(),
+            "Group entries must begin with the delimiter field"
+        );
+        assert!(
+            self.fields.iter().all(|(t, _)| *t != count_tag),
+            "Duplicate field <{}> in group entry",
+            count_tag
+        );
+        GroupBuilder::new(GroupSink::Entry(self), count_tag, delimiter_tag)
+    }
+
+    /// Returns the fields of `self` in insertion order.
+    pub fn fields(&self) -> &[(u32, FixFieldValue)] {
+        &self.fields[..]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::FixFieldAccess;
+    use chrono::TimeZone;
+
+    #[derive(Debug, Copy, Clone)]
+    struct FixedClock(DateTime<Utc>);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.0
+        }
+    }
+
+    fn clock() -> FixedClock {
+        FixedClock(Utc.ymd(2021, 3, 7).and_hms_milli(9, 5, 2, 41))
+    }
+
+    #[test]
+    fn finish_fills_seq_num_and_sending_time() {
+        let mut seq_numbers = SeqNumbers::default();
+        for expected in 1..=3 {
+            let msg = MessageBuilder::new(&mut seq_numbers, clock()).finish();
+            assert_eq!(msg.f_seq_num(), Some(expected));
+            assert_eq!(
+                msg.field_str(tags::SENDING_TIME),
+                Some("20210307-09:05:02.041")
+            );
+            assert_eq!(msg.field_bool(tags::POSS_DUP_FLAG), None);
+        }
+        assert_eq!(seq_numbers.next_outbound(), 4);
+    }
+
+    #[test]
+    fn resend_reuses_seq_num_and_sets_poss_dup() {
+        let mut seq_numbers = SeqNumbers::default();
+        let orig_sending_time = Utc.ymd(2021, 3, 7).and_hms_milli(9, 0, 0, 0);
+        let mut builder = MessageBuilder::new(&mut seq_numbers, clock());
+        builder.resend(42, orig_sending_time);
+        let msg = builder.finish();
+        assert_eq!(msg.f_seq_num(), Some(42));
+        assert_eq!(msg.field_bool(tags::POSS_DUP_FLAG), Some(true));
+        assert_eq!(
+            msg.field_str(tags::ORIG_SENDING_TIME),
+            Some("20210307-09:00:00.000")
+        );
+        assert_eq!(seq_numbers.next_outbound(), 1);
+    }
+
+    #[test]
+    fn without_header_population_only_app_fields_are_present() {
+        let mut seq_numbers = SeqNumbers::default();
+        let orig_sending_time = Utc.ymd(2021, 3, 7).and_hms_milli(9, 0, 0, 0);
+        let mut builder = MessageBuilder::new(&mut seq_numbers, clock());
+        builder
+            .resend(42, orig_sending_time)
+            .populate_header(false)
+            .message_mut()
+            .add_str(tags::MSG_TYPE, "D");
+        let msg = builder.finish();
+        assert_eq!(msg.field_str(tags::MSG_TYPE), Some("D"));
+        assert_eq!(msg.f_seq_num(), None);
+        assert_eq!(msg.field_str(tags::SENDING_TIME), None);
+        assert_eq!(msg.field_bool(tags::POSS_DUP_FLAG), None);
+        assert_eq!(msg.field_str(tags::ORIG_SENDING_TIME), None);
+        assert_eq!(seq_numbers.next_outbound(), 1);
+    }
+
+    fn group_tags(value: Option<&FixFieldValue>) -> Vec<Vec<u32>> {
+        match value {
+            Some(FixFieldValue::Group(entries)) => entries
+                .iter()
+                .map(|entry| entry.iter().map(|(tag, _)| *tag).collect())
+                .collect(),
+            _ => panic!("Not a group"),
+        }
+    }
+
+    #[test]
+    fn md_entries_group_keeps_count_and_field_order() {
+        let mut seq_numbers = SeqNumbers::default();
+        let mut builder = MessageBuilder::new(&mut seq_numbers, clock());
+        builder.message_mut().add_str(tags::MSG_TYPE, "W");
+        {
+            let mut group = builder.start_group(tags::NO_MD_ENTRIES, tags::MD_ENTRY_TYPE);
+            // `MDEntrySize <271>` comes before `MDEntryPx <270>` to make sure
+            // fields are not sorted by tag.
+            group
+                .add_entry()
+                .add_str(tags::MD_ENTRY_TYPE, "0")
+                .add_i64(tags::MD_ENTRY_SIZE, 100)
+                .add_str(tags::MD_ENTRY_PX, "1.5");
+            group
+                .add_entry()
+                .add_str(tags::MD_ENTRY_TYPE, "1")
+                .add_i64(tags::MD_ENTRY_SIZE, 200)
+                .add_str(tags::MD_ENTRY_PX, "1.6");
+            assert_eq!(group.len(), 2);
+        }
+        let msg = builder.finish();
+        let order = vec![tags::MD_ENTRY_TYPE, tags::MD_ENTRY_SIZE, tags::MD_ENTRY_PX];
+        assert_eq!(
+            group_tags(msg.field(tags::NO_MD_ENTRIES)),
+            vec![order.clone(), order]
+        );
+    }
+
+    #[test]
+    fn nested_groups() {
+        let mut seq_numbers = SeqNumbers::default();
+        let mut builder = MessageBuilder::new(&mut seq_numbers, clock());
+        let mut group = builder.start_group(tags::NO_SIDES, tags::SIDE);
+        let entry = group.add_entry();
+        entry.add_str(tags::SIDE, "1");
+        let mut parties = entry.start_group(tags::NO_PARTY_I_DS, tags::PARTY_ID);
+        parties.add_entry().add_str(tags::PARTY_ID, "A");
+        parties.add_entry().add_str(tags::PARTY_ID, "B");
+        parties.finish();
+        entry.add_str(tags::ACCOUNT, "ACC");
+        group.finish();
+        let msg = builder.finish();
+        let entries = match msg.field(tags::NO_SIDES) {
+            Some(FixFieldValue::Group(entries)) => entries,
+            _ => panic!("Not a group"),
+        };
+        assert_eq!(entries.len(), 1);
+        let tags_of_entry: Vec<u32> = entries[0].iter().map(|(tag, _)| *tag).collect();
+        assert_eq!(
+            tags_of_entry,
+            vec![tags::SIDE, tags::NO_PARTY_I_DS, tags::ACCOUNT]
+        );
+        assert_eq!(
+            group_tags(Some(&entries[0][1].1)),
+            vec![vec![tags::PARTY_ID], vec![tags::PARTY_ID]]
+        );
+    }
+
+    #[test]
+    fn empty_group_is_not_added() {
+        let mut seq_numbers = SeqNumbers::default();
+        let mut builder = MessageBuilder::new(&mut seq_numbers, clock());
+        let mut group = builder.start_group(tags::NO_MD_ENTRIES, tags::MD_ENTRY_TYPE);
+        group.add_entry();
+        group.finish();
+        assert!(builder.finish().field(tags::NO_MD_ENTRIES).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn entry_must_begin_with_delimiter() {
+        let mut seq_numbers = SeqNumbers::default();
+        let mut builder = MessageBuilder::new(&mut seq_numbers, clock());
+        let mut group = builder.start_group(tags::NO_MD_ENTRIES, tags::MD_ENTRY_TYPE);
+        group.add_entry().add_str(tags::MD_ENTRY_PX, "1.5");
+    }
+}
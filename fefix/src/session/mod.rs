@@ -13,17 +13,29 @@
 //! point, namely [`Initiator::feed`] and [`Acceptor::feed`].
 
 //pub mod abstract_connection;
+mod clock;
 mod connection;
 mod errs;
 mod heartbeat_rule;
+mod logon_params;
+mod message_builder;
+mod reject;
 mod resend_request_range;
+mod routing;
 mod seq_numbers;
+mod sequence_reset;
 
 //pub use abstract_connection::AbstractConnection;
+pub use clock::{utc_timestamp, Clock, SystemClock};
 pub use connection::*;
 pub use heartbeat_rule::HeartbeatRule;
+pub use logon_params::{LogonParams, LogonParamsError};
+pub use message_builder::{GroupBuilder, GroupEntry, MessageBuilder};
+pub use reject::{build_reject, Rejectable};
 pub use resend_request_range::ResendRequestRange;
+pub use routing::RoutingHeader;
 pub use seq_numbers::{SeqNumberError, SeqNumbers};
+pub use sequence_reset::build_sequence_reset;
 
 /// An indicator for the kind of environment relative to a FIX Connection.
 #[derive(Debug, Copy, Clone)]
@@ -37,7 +49,8 @@ pub enum Environment {
     Testing,
 }
 
-#[derive(Debug, Clone)]
+/// The possible values of `SessionRejectReason <373>`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum SessionRejectReason {
     InvalidTagNumber,
     RequiredTagMissing,
@@ -87,3 +100,129 @@ impl From<u32> for SessionRejectReason {
         }
     }
 }
+
+impl SessionRejectReason {
+    /// Returns the numeric code of `self`, i.e. the raw value of
+    /// `SessionRejectReason <373>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::session::SessionRejectReason;
+    ///
+    /// assert_eq!(SessionRejectReason::RequiredTagMissing.code(), 1);
+    /// assert_eq!(SessionRejectReason::Other.code(), 99);
+    /// ```
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::InvalidTagNumber => 0,
+            Self::RequiredTagMissing => 1,
+            Self::TagNotDefinedForThisMessageType => 2,
+            Self::UndefinedTag => 3,
+            Self::TagSpecifiedWithoutAValue => 4,
+            Self::ValueIsIncorrect => 5,
+            Self::IncorrectDataFormatForValue => 6,
+            Self::DecryptionProblem => 7,
+            Self::SignatureProblem => 8,
+            Self::CompIDProblem => 9,
+            Self::SendingTimeAccuracyProblem => 10,
+            Self::InvalidMsgType => 11,
+            Self::XMLValidationError => 12,
+            Self::TagAppearsMoreThanOnce => 13,
+            Self::TagSpecifiedOutOfRequiredOrder => 14,
+            Self::RepeatingGroupFieldsOutOfOrder => 15,
+            Self::IncorrectNumInGroupCountForRepeatingGroup => 16,
+            Self::FieldDelimiterInFieldValue => 17,
+            Self::InvalidUnsupportedAppVersion => 18,
+            Self::Other => 99,
+        }
+    }
+}
+
+/// Method of encryption, as specified in `EncryptMethod <98>`.
+///
+/// FerrumFIX doesn't implement any of these methods, but messages with
+/// encrypted segments (`SecureData <91>`) can still be decoded.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EncryptMethod {
+    /// No encryption (`0`).
+    None,
+    /// PKCS (`1`).
+    Pkcs,
+    /// DES, ECB mode (`2`).
+    Des,
+    /// PKCS/DES (`3`).
+    PkcsDes,
+    /// PGP/DES (`4`).
+    PgpDes,
+    /// PGP/DES-MD5 (`5`).
+    PgpDesMd5,
+    /// PEM/DES-MD5 (`6`).
+    PemDesMd5,
+}
+
+impl EncryptMethod {
+    /// Parses an [`EncryptMethod`] from the raw value of `EncryptMethod <98>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::session::EncryptMethod;
+    ///
+    /// assert_eq!(EncryptMethod::from_bytes(b"0"), Some(EncryptMethod::None));
+    /// assert_eq!(EncryptMethod::from_bytes(b"7"), None);
+    /// ```
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        match data {
+            b"0" => Some(Self::None),
+            b"1" => Some(Self::Pkcs),
+            b"2" => Some(Self::Des),
+            b"3" => Some(Self::PkcsDes),
+            b"4" => Some(Self::PgpDes),
+            b"5" => Some(Self::PgpDesMd5),
+            b"6" => Some(Self::PemDesMd5),
+            _ => None,
+        }
+    }
+
+    /// Returns the raw value of `EncryptMethod <98>` for `self`.
+    pub fn to_bytes(&self) -> &'static [u8] {
+        match self {
+            Self::None => b"0",
+            Self::Pkcs => b"1",
+            Self::Des => b"2",
+            Self::PkcsDes => b"3",
+            Self::PgpDes => b"4",
+            Self::PgpDesMd5 => b"5",
+            Self::PemDesMd5 => b"6",
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn session_reject_reason_code_roundtrip() {
+        for code in (0..=18).chain(std::iter::once(99)) {
+            assert_eq!(SessionRejectReason::from(code).code(), code);
+        }
+    }
+
+    #[test]
+    fn encrypt_method_roundtrip() {
+        let methods = [
+            EncryptMethod::None,
+            EncryptMethod::Pkcs,
+            EncryptMethod::Des,
+            EncryptMethod::PkcsDes,
+            EncryptMethod::PgpDes,
+            EncryptMethod::PgpDesMd5,
+            EncryptMethod::PemDesMd5,
+        ];
+        for method in methods.iter() {
+            assert_eq!(EncryptMethod::from_bytes(method.to_bytes()), Some(*method));
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use crate::session::SessionRejectReason;
+use crate::tagvalue::DecodeError;
+use crate::{tags, FixMessage};
+
+/// Errors that can be reported to the counterparty with a `Reject <3>`
+/// message.
+pub trait Rejectable {
+    /// The value of `SessionRejectReason <373>` that best describes `self`.
+    fn session_reject_reason(&self) -> SessionRejectReason;
+
+    /// The tag number of the offending field, if any, i.e. the value of
+    /// `RefTagID <371>`.
+    fn ref_tag_id(&self) -> Option<u32> {
+        None
+    }
+
+    /// Builds the `Reject <3>` message in response to the inbound message with
+    /// `MsgSeqNum <34>` equal to `ref_seq_num`. See [`build_reject`].
+    fn to_reject(&self, ref_seq_num: u64) -> FixMessage {
+        build_reject(ref_seq_num, self.ref_tag_id(), self.session_reject_reason())
+    }
+}
+
+impl Rejectable for DecodeError {
+    fn session_reject_reason(&self) -> SessionRejectReason {
+        match self {
+            Self::FieldPresence | Self::MissingField(_) | Self::MissingLengthPrefix { .. } => {
+                SessionRejectReason::RequiredTagMissing
+            }
+            Self::UnknownTag(_) => SessionRejectReason::UndefinedTag,
+            Self::InvalidValue(_) | Self::IntOverflow => {
+                SessionRejectReason::IncorrectDataFormatForValue
+            }
+            Self::DuplicateTag(_) => SessionRejectReason::TagAppearsMoreThanOnce,
+            Self::TagOutOfOrder(_) => SessionRejectReason::TagSpecifiedOutOfRequiredOrder,
+            Self::GroupCount { .. } => {
+                SessionRejectReason::IncorrectNumInGroupCountForRepeatingGroup
+            }
+            Self::InvalidXml(_) => SessionRejectReason::XMLValidationError,
+            Self::Invalid
+            | Self::CheckSum
+            | Self::CheckSumFormat
+            | Self::MessageTooLarge { .. } => SessionRejectReason::Other,
+        }
+    }
+
+    fn ref_tag_id(&self) -> Option<u32> {
+        match self {
+            Self::UnknownTag(tag)
+            | Self::InvalidValue(tag)
+            | Self::DuplicateTag(tag)
+            | Self::MissingField(tag)
+            | Self::TagOutOfOrder(tag)
+            | Self::InvalidXml(tag)
+            | Self::MissingLengthPrefix { data_tag: tag }
+            | Self::GroupCount { tag, .. } => Some(*tag),
+            _ => None,
+        }
+    }
+}
+
+/// Builds a `Reject <3>` message with `RefSeqNum <45>`, `RefTagID <371>` (if
+/// any) and `SessionRejectReason <373>`.
+///
+/// Session-managed header fields are *not* included: use
+/// [`MessageBuilder`](crate::session::MessageBuilder) or add them manually
+/// before encoding.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::session::{build_reject, SessionRejectReason};
+/// use fefix::{tags, FixFieldAccess};
+///
+/// let msg = build_reject(42, Some(54), SessionRejectReason::ValueIsIncorrect);
+/// assert_eq!(msg.field_str(tags::MSG_TYPE), Some("3"));
+/// assert_eq!(msg.field_i64(tags::REF_TAG_ID), Some(54));
+/// assert_eq!(msg.field_i64(tags::SESSION_REJECT_REASON), Some(5));
+/// ```
+pub fn build_reject(
+    ref_seq_num: u64,
+    ref_tag_id: Option<u32>,
+    reason: SessionRejectReason,
+) -> FixMessage {
+    let mut msg = FixMessage::new();
+    msg.add_str(tags::MSG_TYPE, "3");
+    msg.add_i64(tags::REF_SEQ_NUM, ref_seq_num as i64);
+    if let Some(tag) = ref_tag_id {
+        msg.add_i64(tags::REF_TAG_ID, tag as i64);
+    }
+    msg.add_i64(tags::SESSION_REJECT_REASON, reason.code() as i64);
+    msg
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::FixFieldAccess;
+
+    #[test]
+    fn reject_without_ref_tag_id() {
+        let msg = build_reject(7, None, SessionRejectReason::CompIDProblem);
+        assert_eq!(msg.field_i64(tags::REF_SEQ_NUM), Some(7));
+        assert_eq!(msg.field_i64(tags::REF_TAG_ID), None);
+        assert_eq!(msg.field_i64(tags::SESSION_REJECT_REASON), Some(9));
+    }
+
+    #[test]
+    fn decode_errors_are_rejectable() {
+        let msg = DecodeError::DuplicateTag(55).to_reject(3);
+        assert_eq!(msg.field_i64(tags::REF_SEQ_NUM), Some(3));
+        assert_eq!(msg.field_i64(tags::REF_TAG_ID), Some(55));
+        assert_eq!(msg.field_i64(tags::SESSION_REJECT_REASON), Some(13));
+        let msg = DecodeError::FieldPresence.to_reject(3);
+        assert_eq!(msg.field_i64(tags::REF_TAG_ID), None);
+        assert_eq!(msg.field_i64(tags::SESSION_REJECT_REASON), Some(1));
+    }
+}
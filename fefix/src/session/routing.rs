@@ -0,0 +1,152 @@
+use crate::tagvalue::FixMessageRef;
+use crate::{tags, FixMessage};
+
+/// The standard header fields used for third-party routing of messages through
+/// a hub, e.g. a service bureau.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RoutingHeader {
+    /// `SenderCompID <49>`.
+    pub sender: String,
+    /// `TargetCompID <56>`.
+    pub target: String,
+    /// `OnBehalfOfCompID <115>`, i.e. the firm that originally sent the
+    /// message, if it was forwarded by a hub.
+    pub on_behalf_of: Option<String>,
+    /// `DeliverToCompID <128>`, i.e. the firm the hub must forward the
+    /// message to.
+    pub deliver_to: Option<String>,
+}
+
+impl RoutingHeader {
+    /// Extracts a [`RoutingHeader`] from a decoded message. Returns `None` if
+    /// either `SenderCompID <49>` or `TargetCompID <56>` are missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::session::RoutingHeader;
+    /// use fefix::tagvalue::{Config, Decoder};
+    /// use fefix::{AppVersion, Dictionary};
+    ///
+    /// let dict = Dictionary::from_version(AppVersion::Fix44);
+    /// let mut decoder = Decoder::with_config(dict, Config::default().with_separator(b'|'));
+    /// let msg = b"8=FIX.4.4|9=49|35=0|49=A|56=HUB|34=2|52=20210101-00:00:00|128=C|10=007|";
+    /// let routing = RoutingHeader::from_message(&decoder.decode(msg).unwrap()).unwrap();
+    /// assert_eq!(routing.sender, "A");
+    /// assert_eq!(routing.target, "HUB");
+    /// assert_eq!(routing.on_behalf_of, None);
+    /// assert_eq!(routing.deliver_to.as_deref(), Some("C"));
+    /// ```
+    pub fn from_message(msg: &FixMessageRef) -> Option<Self> {
+        let field = |tag| msg.field_as_str(tag).map(str::to_string);
+        Some(Self {
+            sender: field(tags::SENDER_COMP_ID)?,
+            target: field(tags::TARGET_COMP_ID)?,
+            on_behalf_of: field(tags::ON_BEHALF_OF_COMP_ID),
+            deliver_to: field(tags::DELIVER_TO_COMP_ID),
+        })
+    }
+
+    /// Returns the [`RoutingHeader`] that the hub `hub_comp_id` must use to
+    /// forward a message with routing header `self`, or `None` if `self` has
+    /// no `DeliverToCompID <128>`.
+    ///
+    /// The original sender becomes `OnBehalfOfCompID <115>` and
+    /// `DeliverToCompID <128>` becomes `TargetCompID <56>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::session::RoutingHeader;
+    ///
+    /// let routing = RoutingHeader {
+    ///     sender: "A".to_string(),
+    ///     target: "HUB".to_string(),
+    ///     on_behalf_of: None,
+    ///     deliver_to: Some("C".to_string()),
+    /// };
+    /// let forwarded = routing.forwarded_by("HUB").unwrap();
+    /// assert_eq!(forwarded.sender, "HUB");
+    /// assert_eq!(forwarded.target, "C");
+    /// assert_eq!(forwarded.on_behalf_of.as_deref(), Some("A"));
+    /// assert_eq!(forwarded.deliver_to, None);
+    /// ```
+    pub fn forwarded_by(&self, hub_comp_id: &str) -> Option<Self> {
+        Some(Self {
+            sender: hub_comp_id.to_string(),
+            target: self.deliver_to.clone()?,
+            on_behalf_of: Some(self.sender.clone()),
+            deliver_to: None,
+        })
+    }
+
+    /// Adds all fields of `self` to `msg`. Absent optional fields are
+    /// skipped.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `msg` already contains any of the fields.
+    pub fn add_to(&self, msg: &mut FixMessage) {
+        msg.add_str(tags::SENDER_COMP_ID, self.sender.as_str());
+        msg.add_str(tags::TARGET_COMP_ID, self.target.as_str());
+        if let Some(on_behalf_of) = &self.on_behalf_of {
+            msg.add_str(tags::ON_BEHALF_OF_COMP_ID, on_behalf_of.as_str());
+        }
+        if let Some(deliver_to) = &self.deliver_to {
+            msg.add_str(tags::DELIVER_TO_COMP_ID, deliver_to.as_str());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tagvalue::{Config, Decoder};
+    use crate::{AppVersion, Dictionary, FixFieldAccess};
+
+    fn decoder() -> Decoder<Config> {
+        let dict = Dictionary::from_version(AppVersion::Fix44);
+        Decoder::with_config(dict, Config::default().with_separator(b'|'))
+    }
+
+    #[test]
+    fn extract_on_behalf_of() {
+        let msg = b"8=FIX.4.4|9=49|35=0|49=HUB|56=A|34=3|52=20210101-00:00:00|115=C|10=004|";
+        let decoder = &mut decoder();
+        let routing = RoutingHeader::from_message(&decoder.decode(msg).unwrap()).unwrap();
+        assert_eq!(
+            routing,
+            RoutingHeader {
+                sender: "HUB".to_string(),
+                target: "A".to_string(),
+                on_behalf_of: Some("C".to_string()),
+                deliver_to: None,
+            }
+        );
+        assert_eq!(routing.forwarded_by("HUB"), None);
+    }
+
+    #[test]
+    fn missing_target_comp_id() {
+        let msg = b"8=FIX.4.4|9=36|35=0|49=A|34=2|52=20210101-00:00:00|10=105|";
+        let decoder = &mut decoder();
+        assert_eq!(
+            RoutingHeader::from_message(&decoder.decode(msg).unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn forward_and_add_to_message() {
+        let msg = b"8=FIX.4.4|9=49|35=0|49=A|56=HUB|34=2|52=20210101-00:00:00|128=C|10=007|";
+        let decoder = &mut decoder();
+        let routing = RoutingHeader::from_message(&decoder.decode(msg).unwrap()).unwrap();
+        let forwarded = routing.forwarded_by("HUB").unwrap();
+        let mut msg = FixMessage::new();
+        forwarded.add_to(&mut msg);
+        assert_eq!(msg.field_str(tags::SENDER_COMP_ID), Some("HUB"));
+        assert_eq!(msg.field_str(tags::TARGET_COMP_ID), Some("C"));
+        assert_eq!(msg.field_str(tags::ON_BEHALF_OF_COMP_ID), Some("A"));
+        assert_eq!(msg.field_str(tags::DELIVER_TO_COMP_ID), None);
+    }
+}
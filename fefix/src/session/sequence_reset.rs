@@ -0,0 +1,100 @@
+use crate::tagvalue::{field_value as val, FixFieldValue};
+use crate::{tags, FixMessage};
+
+/// Builds a `SequenceReset <4>` message with `MsgSeqNum <34>` equal to
+/// `msg_seq_num` and `NewSeqNo <36>` equal to `new_seq_no`.
+///
+/// - In gap-fill mode (`gap_fill == true`), the message replaces
+///   administrative or otherwise skipped messages during a resend, so it carries
+///   `GapFillFlag <123>` and `PossDupFlag <43>`, both set to `Y`. `msg_seq_num`
+///   must be the seq. number of the first skipped message.
+/// - In reset mode (`gap_fill == false`), neither flag is present and
+///   `msg_seq_num` is ignored by the counterparty, so any value is fine.
+///
+/// `SendingTime <52>` and, in gap-fill mode, `OrigSendingTime <122>` are *not*
+/// included: add them before encoding.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::session::build_sequence_reset;
+/// use fefix::{tags, FixFieldAccess};
+///
+/// let msg = build_sequence_reset(10, true, 5);
+/// assert_eq!(msg.field_str(tags::MSG_TYPE), Some("4"));
+/// assert_eq!(msg.f_seq_num(), Some(5));
+/// assert_eq!(msg.field_i64(tags::NEW_SEQ_NO), Some(10));
+/// assert_eq!(msg.field_bool(tags::GAP_FILL_FLAG), Some(true));
+/// assert_eq!(msg.field_bool(tags::POSS_DUP_FLAG), Some(true));
+/// ```
+pub fn build_sequence_reset(new_seq_no: u64, gap_fill: bool, msg_seq_num: u64) -> FixMessage {
+    let mut msg = FixMessage::new();
+    msg.add_str(tags::MSG_TYPE, "4");
+    msg.add_i64(tags::MSG_SEQ_NUM, msg_seq_num as i64);
+    if gap_fill {
+        add_flag(&mut msg, tags::POSS_DUP_FLAG);
+        add_flag(&mut msg, tags::GAP_FILL_FLAG);
+    }
+    msg.add_i64(tags::NEW_SEQ_NO, new_seq_no as i64);
+    msg
+}
+
+fn add_flag(msg: &mut FixMessage, tag: u32) {
+    msg.add_field(tag, FixFieldValue::Atom(val::FieldValue::bool(true)))
+        .unwrap();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::FixFieldAccess;
+
+    const TAGS: &[u32] = &[
+        tags::MSG_TYPE,
+        tags::MSG_SEQ_NUM,
+        tags::POSS_DUP_FLAG,
+        tags::GAP_FILL_FLAG,
+        tags::NEW_SEQ_NO,
+        tags::SENDING_TIME,
+        tags::ORIG_SENDING_TIME,
+    ];
+
+    fn present_tags(msg: &FixMessage) -> Vec<u32> {
+        TAGS.iter()
+            .copied()
+            .filter(|tag| msg.field(*tag).is_some())
+            .collect()
+    }
+
+    #[test]
+    fn gap_fill_has_poss_dup_and_gap_fill_flags() {
+        let msg = build_sequence_reset(12, true, 7);
+        assert_eq!(
+            present_tags(&msg),
+            vec![
+                tags::MSG_TYPE,
+                tags::MSG_SEQ_NUM,
+                tags::POSS_DUP_FLAG,
+                tags::GAP_FILL_FLAG,
+                tags::NEW_SEQ_NO
+            ]
+        );
+        assert_eq!(msg.field_str(tags::MSG_TYPE), Some("4"));
+        assert_eq!(msg.field_i64(tags::MSG_SEQ_NUM), Some(7));
+        assert_eq!(msg.field_bool(tags::POSS_DUP_FLAG), Some(true));
+        assert_eq!(msg.field_bool(tags::GAP_FILL_FLAG), Some(true));
+        assert_eq!(msg.field_i64(tags::NEW_SEQ_NO), Some(12));
+    }
+
+    #[test]
+    fn reset_has_no_flags() {
+        let msg = build_sequence_reset(100, false, 3);
+        assert_eq!(
+            present_tags(&msg),
+            vec![tags::MSG_TYPE, tags::MSG_SEQ_NUM, tags::NEW_SEQ_NO]
+        );
+        assert_eq!(msg.field_bool(tags::POSS_DUP_FLAG), None);
+        assert_eq!(msg.field_bool(tags::GAP_FILL_FLAG), None);
+        assert_eq!(msg.field_i64(tags::NEW_SEQ_NO), Some(100));
+    }
+}
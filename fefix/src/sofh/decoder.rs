@@ -1,8 +1,10 @@
 use crate::buffer::Buffer;
+use std::convert::TryInto;
 use std::io;
 
-use super::err::Error;
+use super::err::SofhError;
 use super::frame::Frame;
+use super::{EncodingType, Endianness, SofhHeader};
 
 /// A parser for SOFH-enclosed messages.
 ///
@@ -17,6 +19,11 @@ where
 {
     buffer: T,
     buffer_actual_len: usize,
+    // Length of the last frame returned by `Frames::next`, which must be
+    // discarded before reading the next one.
+    frame_len: usize,
+    endianness: Endianness,
+    max_frame_len: usize,
 }
 
 impl<T> Decoder<T>
@@ -29,9 +36,64 @@ where
         Self {
             buffer,
             buffer_actual_len: 0,
+            frame_len: 0,
+            endianness: Endianness::default(),
+            max_frame_len: u32::MAX as usize,
         }
     }
 
+    /// Returns the byte order used for the `Message_Length` field. It's
+    /// [`Endianness::Big`] by default, as mandated by the SOFH specification.
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+
+    /// Sets the byte order used for the `Message_Length` field.
+    ///
+    /// Please note that anything other than [`Endianness::Big`] (the default)
+    /// violates the SOFH specification. Only use this setting to interoperate
+    /// with non-conformant counterparties.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::sofh::{Decoder, Endianness};
+    ///
+    /// let mut decoder = Decoder::from_buffer(Vec::new());
+    /// decoder.set_endianness(Endianness::Little);
+    /// assert_eq!(decoder.endianness(), Endianness::Little);
+    /// ```
+    pub fn set_endianness(&mut self, endianness: Endianness) {
+        self.endianness = endianness;
+    }
+
+    /// Returns the maximum `Message_Length` (header included) of acceptable
+    /// frames. No limit other than `u32::MAX` is enforced by default.
+    pub fn max_frame_len(&self) -> usize {
+        self.max_frame_len
+    }
+
+    /// Sets the maximum `Message_Length` (header included) of acceptable
+    /// frames. Larger frames are rejected with [`SofhError::FrameTooLarge`]
+    /// as soon as their header is decoded, i.e. before buffering them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::sofh::{Decoder, SofhError};
+    ///
+    /// let mut decoder = Decoder::from_buffer(Vec::new());
+    /// decoder.set_max_frame_len(1024);
+    /// let mut frames = decoder.read_frames(&[0, 0, 0x10, 0, 0xF0, 0x00][..]);
+    /// assert!(matches!(
+    ///     frames.next(),
+    ///     Some(Err(SofhError::FrameTooLarge { message_length: 4096 }))
+    /// ));
+    /// ```
+    pub fn set_max_frame_len(&mut self, max_frame_len: usize) {
+        self.max_frame_len = max_frame_len;
+    }
+
     /// Returns the current buffer capacity of this [`Decoder`]. This value is
     /// subject to change after every incoming message.
     ///
@@ -55,32 +117,137 @@ where
     /// Provides a buffer that must be filled before re-attempting to deserialize
     /// the next [`Frame`].
     pub fn supply_buffer(&mut self) -> &mut [u8] {
-        let decode_result = Frame::decode(self.buffer.as_slice());
+        let decode_result = self.decode(self.buffer.as_slice());
         match decode_result {
             Ok(_) => &mut [],
-            Err(Error::Io(_)) => panic!("Impossible IO error"),
-            Err(Error::Incomplete { needed }) => {
+            Err(SofhError::Incomplete { needed }) => {
                 self.buffer.resize(self.buffer.as_slice().len() + needed, 0);
                 &mut self.buffer.as_mut_slice()[self.buffer_actual_len..]
             }
-            Err(Error::InvalidMessageLength) => panic!("Invalid stream"),
+            Err(SofhError::Io(_)) => panic!("Impossible IO error"),
+            Err(_) => panic!("Invalid stream"),
         }
     }
 
     /// Attempts decoding. Returns `Ok(())` if a [`Frame`] is ready, otherwise an `Err`.
-    pub fn attempt_decoding(&mut self) -> Result<(), Error> {
+    pub fn attempt_decoding(&mut self) -> Result<(), SofhError> {
         let slice = &self.buffer.as_slice()[..self.buffer_actual_len];
-        let decode_result = Frame::decode(slice);
+        let decode_result = self.decode(slice);
         decode_result.map(|_| ())
     }
 
     ///
     pub fn current_frame(&self) -> Frame {
         let slice = &self.buffer.as_slice()[..self.buffer_actual_len];
-        let decode_result = Frame::decode(slice);
+        let decode_result = self.decode(slice);
         decode_result.unwrap()
     }
 
+    /// Like [`Frame::decode_with_endianness`], but also enforces
+    /// [`Decoder::max_frame_len`].
+    fn decode<'a>(&self, data: &'a [u8]) -> Result<Frame<'a>, SofhError> {
+        decode_with_limit(data, self.endianness, self.max_frame_len)
+    }
+
+    /// Returns an [`Iterator`] over the frames in `buf`, which must contain
+    /// zero or more concatenated SOFH-enclosed messages. Payloads are borrowed
+    /// from `buf`, i.e. no copying is involved.
+    ///
+    /// Iteration stops at the first trailing partial frame, if any, or right
+    /// after the first error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::sofh::{Decoder, EncodingType};
+    ///
+    /// let decoder = Decoder::from_buffer(Vec::new());
+    /// let buf = &[0, 0, 0, 7, 0xF0, 0x00, 42, 0, 0, 0, 8, 0xF5, 0x00, b'{'];
+    /// let mut frames = decoder.frames_in(buf);
+    /// assert_eq!(frames.next().unwrap().unwrap(), (EncodingType::TagValue, &[42][..]));
+    /// assert!(frames.next().is_none());
+    /// ```
+    pub fn frames_in<'a>(
+        &self,
+        buf: &'a [u8],
+    ) -> impl Iterator<Item = Result<(EncodingType, &'a [u8]), SofhError>> + 'a {
+        let (endianness, max_frame_len) = (self.endianness, self.max_frame_len);
+        let mut rest = buf;
+        std::iter::from_fn(move || {
+            if rest.is_empty() {
+                return None;
+            }
+            match decode_with_limit(rest, endianness, max_frame_len) {
+                Ok(frame) => {
+                    let encoding_type = EncodingType::from(frame.encoding_type());
+                    let (frame, tail) = rest.split_at(frame.header().message_length as usize);
+                    rest = tail;
+                    Some(Ok((encoding_type, &frame[SofhHeader::LEN..])))
+                }
+                Err(SofhError::Incomplete { .. }) => None,
+                Err(err) => {
+                    rest = &[];
+                    Some(Err(err))
+                }
+            }
+        })
+    }
+
+    /// Splits the next complete frame off the front of `buf`, without copying
+    /// its payload. Returns `Ok(None)` and leaves `buf` untouched (except for
+    /// reserving space for the missing bytes) if the frame is still
+    /// incomplete. Only available with the `bytes` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytes::BytesMut;
+    /// use fefix::sofh::{Decoder, EncodingType};
+    ///
+    /// let decoder = Decoder::from_buffer(Vec::new());
+    /// let mut buf = BytesMut::from(&[0, 0, 0, 8, 0xF0, 0x00, 4][..]);
+    /// assert!(decoder.decode_frame(&mut buf).unwrap().is_none());
+    /// buf.extend_from_slice(&[2, 0, 0, 0]);
+    /// let (encoding_type, payload) = decoder.decode_frame(&mut buf).unwrap().unwrap();
+    /// assert_eq!(encoding_type, EncodingType::TagValue);
+    /// assert_eq!(&payload[..], &[4, 2]);
+    /// assert_eq!(&buf[..], &[0, 0, 0]);
+    /// ```
+    #[cfg(feature = "bytes")]
+    pub fn decode_frame(
+        &self,
+        buf: &mut bytes::BytesMut,
+    ) -> Result<Option<(EncodingType, bytes::Bytes)>, SofhError> {
+        let (encoding_type, message_len) = match self.decode(&buf[..]) {
+            Ok(frame) => (
+                frame.encoding_type(),
+                frame.header().message_length as usize,
+            ),
+            Err(SofhError::Incomplete { needed }) => {
+                buf.reserve(needed);
+                return Ok(None);
+            }
+            Err(err) => return Err(err),
+        };
+        let mut frame = buf.split_to(message_len);
+        let payload = frame.split_off(SofhHeader::LEN).freeze();
+        Ok(Some((EncodingType::from(encoding_type), payload)))
+    }
+
+    /// Returns a [`Frames`] that reads and decodes frames from `reader`, one
+    /// after the other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::sofh::Decoder;
+    ///
+    /// let stream = &[0, 0, 0, 7, 0xF0, 0x00, 42, 0, 0, 0, 6, 0xF0, 0x00][..];
+    /// let mut frames = Decoder::from_buffer(Vec::new()).read_frames(stream);
+    /// assert_eq!(frames.next().unwrap().unwrap().message(), &[42]);
+    /// assert!(frames.next().unwrap().unwrap().message().is_empty());
+    /// assert!(frames.next().is_none());
+    /// ```
     pub fn read_frames<R>(self, reader: R) -> Frames<T, R>
     where
         R: io::Read,
@@ -92,6 +259,30 @@ where
     }
 }
 
+fn decode_with_limit(
+    data: &[u8],
+    endianness: Endianness,
+    max_frame_len: usize,
+) -> Result<Frame<'_>, SofhError> {
+    if data.len() >= SofhHeader::LEN {
+        let header = SofhHeader::parse_with_endianness(
+            data[..SofhHeader::LEN].try_into().unwrap(),
+            endianness,
+        );
+        let message_length = header.message_length as usize;
+        if message_length > max_frame_len {
+            return Err(SofhError::FrameTooLarge { message_length });
+        }
+    }
+    Frame::decode_with_endianness(data, endianness)
+}
+
+/// Reads SOFH-enclosed messages from a [`std::io::Read`] implementor. See
+/// [`Decoder::read_frames`].
+///
+/// [`Frames`] is not an [`Iterator`] because each [`Frame`] borrows from the
+/// internal buffer, but [`Frames::next`] has the same semantics as
+/// [`Iterator::next`].
 #[derive(Debug)]
 pub struct Frames<T, R>
 where
@@ -106,22 +297,41 @@ where
     T: Buffer,
     R: std::io::Read,
 {
-    pub fn next(&mut self) -> Result<Option<Frame>, Error> {
+    /// Reads the next [`Frame`]. Returns `None` if the reader reached EOF
+    /// cleanly, i.e. at a frame boundary, and
+    /// [`SofhError::Incomplete`] if it did so in the middle of a frame.
+    pub fn next(&mut self) -> Option<Result<Frame, SofhError>> {
+        let decoder = &mut self.decoder;
+        // Discard the previous frame, if any.
+        if decoder.frame_len > 0 {
+            let (frame_len, actual_len) = (decoder.frame_len, decoder.buffer_actual_len);
+            decoder
+                .buffer
+                .as_mut_slice()
+                .copy_within(frame_len..actual_len, 0);
+            decoder.buffer_actual_len -= frame_len;
+            decoder.frame_len = 0;
+        }
         loop {
-            let buffer = &mut self.decoder.supply_buffer();
-            match self.reader.read(buffer) {
-                Err(e) => {
-                    return Err(Error::Io(e));
+            let actual_len = decoder.buffer_actual_len;
+            let needed = match decoder.decode(&decoder.buffer.as_slice()[..actual_len]) {
+                Ok(frame) => {
+                    decoder.frame_len = frame.header().message_length as usize;
+                    return Some(Ok(decoder.current_frame()));
                 }
-                Ok(_) => {}
+                Err(SofhError::Incomplete { needed }) => needed,
+                Err(err) => return Some(Err(err)),
+            };
+            if decoder.buffer.len() < actual_len + needed {
+                decoder.buffer.resize(actual_len + needed, 0);
             }
-            match self.decoder.attempt_decoding() {
-                Ok(()) => {
-                    return Ok(Some(self.decoder.current_frame()));
-                }
-                Err(e) => {
-                    return Err(e);
-                }
+            let buffer = &mut decoder.buffer.as_mut_slice()[actual_len..actual_len + needed];
+            match self.reader.read(buffer) {
+                Ok(0) if actual_len == 0 => return None,
+                Ok(0) => return Some(Err(SofhError::Incomplete { needed })),
+                Ok(n) => decoder.buffer_actual_len += n,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Some(Err(SofhError::Io(err))),
             }
         }
     }
@@ -129,7 +339,158 @@ where
 
 #[cfg(test)]
 mod test {
-    //use super::*;
+    use super::*;
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn decode_frame_across_split_reads() {
+        use bytes::BytesMut;
+
+        let mut stream = Vec::new();
+        for payload in [&b"8=FIX.4.4|"[..], b"", b"35=0|"].iter() {
+            let header = SofhHeader {
+                message_length: (SofhHeader::LEN + payload.len()) as u32,
+                encoding_type: EncodingType::TagValue.into(),
+            };
+            stream.extend_from_slice(&header.to_bytes());
+            stream.extend_from_slice(payload);
+        }
+        let decoder = Decoder::from_buffer(Vec::new());
+        let mut buf = BytesMut::new();
+        let mut payloads = Vec::new();
+        // Feed three bytes at a time, so that both headers and payloads are
+        // split across reads.
+        for chunk in stream.chunks(3) {
+            buf.extend_from_slice(chunk);
+            while let Some((encoding_type, payload)) = decoder.decode_frame(&mut buf).unwrap() {
+                assert_eq!(encoding_type, EncodingType::TagValue);
+                payloads.push(payload);
+            }
+        }
+        assert!(buf.is_empty());
+        assert_eq!(payloads, vec![&b"8=FIX.4.4|"[..], b"", b"35=0|"]);
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn decode_frame_with_invalid_length() {
+        let decoder = Decoder::from_buffer(Vec::new());
+        let mut buf = bytes::BytesMut::from(&[0, 0, 0, 5, 0xF0, 0x00][..]);
+        assert!(matches!(
+            decoder.decode_frame(&mut buf),
+            Err(SofhError::InvalidLength)
+        ));
+        assert_eq!(buf.len(), 6);
+    }
+
+    #[test]
+    fn frames_in_stops_at_truncated_frame() {
+        let mut buf = Vec::new();
+        for (encoding_type, payload) in [
+            (EncodingType::TagValue, &b"8=FIX.4.4|35=0|"[..]),
+            (EncodingType::Json, b"{}"),
+            (EncodingType::TagValue, b""),
+        ]
+        .iter()
+        {
+            Frame::new((*encoding_type).into(), payload)
+                .encode(&mut buf)
+                .unwrap();
+        }
+        Frame::new(EncodingType::TagValue.into(), b"8=FIX.4.4|")
+            .encode(&mut buf)
+            .unwrap();
+        // Truncate the fourth frame.
+        buf.pop();
+        let decoder = Decoder::from_buffer(Vec::new());
+        let frames = decoder
+            .frames_in(&buf[..])
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            frames,
+            vec![
+                (EncodingType::TagValue, &b"8=FIX.4.4|35=0|"[..]),
+                (EncodingType::Json, b"{}"),
+                (EncodingType::TagValue, b""),
+            ]
+        );
+    }
+
+    #[test]
+    fn frames_in_stops_after_error() {
+        let buf = [
+            0, 0, 0, 7, 0xF0, 0x00, 1, 0, 0, 0, 3, 0xF0, 0x00, 0, 0, 0, 6, 0xF0, 0x00,
+        ];
+        let decoder = Decoder::from_buffer(Vec::new());
+        let mut frames = decoder.frames_in(&buf[..]);
+        assert_eq!(frames.next().unwrap().unwrap().1, &[1]);
+        assert!(matches!(frames.next(), Some(Err(SofhError::InvalidLength))));
+        assert!(frames.next().is_none());
+    }
+
+    /// Returns at most one byte per `read` call.
+    struct SlowReader<'a>(&'a [u8]);
+
+    impl<'a> io::Read for SlowReader<'a> {
+        fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+            match (self.0.split_first(), buffer.first_mut()) {
+                (Some((byte, rest)), Some(first)) => {
+                    *first = *byte;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn read_frames_one_byte_at_a_time() {
+        let stream = [
+            0, 0, 0, 8, 0xF0, 0x00, 1, 2, 0, 0, 0, 6, 0xF5, 0x00, 0, 0, 0, 7, 0, 0, 3,
+        ];
+        let decoder = Decoder::from_buffer(Vec::new());
+        let mut frames = decoder.read_frames(SlowReader(&stream[..]));
+        let mut messages = Vec::new();
+        while let Some(frame) = frames.next() {
+            messages.push(frame.unwrap().message().to_vec());
+        }
+        assert_eq!(messages, vec![vec![1, 2], vec![], vec![3]]);
+    }
+
+    #[test]
+    fn read_frames_with_truncated_frame() {
+        let stream = [0, 0, 0, 7, 0xF0, 0x00, 1, 0, 0, 0, 9, 0xF0, 0x00, 1];
+        let decoder = Decoder::from_buffer(Vec::new());
+        let mut frames = decoder.read_frames(&stream[..]);
+        assert_eq!(frames.next().unwrap().unwrap().message(), &[1]);
+        assert!(matches!(
+            frames.next(),
+            Some(Err(SofhError::Incomplete { needed: 2 }))
+        ));
+    }
+
+    #[test]
+    fn read_frames_with_invalid_length() {
+        let decoder = Decoder::from_buffer(Vec::new());
+        let mut frames = decoder.read_frames(&[0, 0, 0, 2, 0xF0, 0x00][..]);
+        assert!(matches!(frames.next(), Some(Err(SofhError::InvalidLength))));
+    }
+
+    #[test]
+    fn frame_too_large_is_rejected_before_buffering() {
+        let mut decoder = Decoder::from_buffer(Vec::new());
+        decoder.set_max_frame_len(8);
+        let mut frames = decoder.read_frames(&[0xFF, 0xFF, 0xFF, 0xFF, 0xF0, 0x00][..]);
+        assert!(matches!(
+            frames.next(),
+            Some(Err(SofhError::FrameTooLarge {
+                message_length: 0xFFFF_FFFF
+            }))
+        ));
+        assert!(frames.decoder.capacity() < 64);
+    }
 
     //fn _frames_with_increasing_length() -> impl Iterator<Item = Vec<u8>> {
     //    std::iter::once(()).enumerate().map(|(i, ())| {
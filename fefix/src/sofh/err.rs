@@ -2,39 +2,50 @@ use std::fmt;
 use std::io;
 
 /// The type returned in the event of an error when decoding SOFH-enclosed
-/// messages.
+/// messages. Framing errors are kept separate from the errors of the
+/// enclosed payload's encoding, e.g.
+/// [`tagvalue::DecodeError`](crate::tagvalue::DecodeError).
 #[derive(Debug)]
-pub enum Error {
-    /// The provided message length is outside the legal range.
-    InvalidMessageLength,
+pub enum SofhError {
     /// The given message is incomplete.
     Incomplete { needed: usize },
+    /// The provided message length is less than the length of the header
+    /// itself.
+    InvalidLength,
+    /// The provided message length exceeds the maximum allowed by the
+    /// [`Decoder`](super::Decoder).
+    FrameTooLarge { message_length: usize },
     /// I/O-related error.
     Io(io::Error),
 }
 
-impl fmt::Display for Error {
+impl fmt::Display for SofhError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Error::Io(err) => {
+            SofhError::Io(err) => {
                 writeln!(f, "I/O error while reading the message.")?;
                 (*err).fmt(f)
             }
-            Error::Incomplete { needed } => {
+            SofhError::Incomplete { needed } => {
                 writeln!(
                     f,
                     "The message is incomplete. {} more bytes are needed.",
                     needed
                 )
             }
-            Error::InvalidMessageLength => {
+            SofhError::InvalidLength => {
                 writeln!(f, "Message length must be greater than or equal to 6.",)
             }
+            SofhError::FrameTooLarge { message_length } => {
+                writeln!(f, "Message length {} is too large.", message_length)
+            }
         }
     }
 }
 
-impl From<io::Error> for Error {
+impl std::error::Error for SofhError {}
+
+impl From<io::Error> for SofhError {
     fn from(err: io::Error) -> Self {
         Self::Io(err)
     }
@@ -1,8 +1,8 @@
-use super::Error;
+use super::{EncodingType, Endianness, SofhError, SofhHeader};
 use std::convert::TryInto;
 use std::io;
 
-const HEADER_SIZE_IN_BYTES: usize = 6;
+const HEADER_SIZE_IN_BYTES: usize = SofhHeader::LEN;
 const MAX_MESSAGE_SIZE_IN_BYTES: usize = u32::MAX as usize - HEADER_SIZE_IN_BYTES;
 
 /// An immutable view into a SOFH-enclosed message, complete with its
@@ -14,6 +14,7 @@ const MAX_MESSAGE_SIZE_IN_BYTES: usize = u32::MAX as usize - HEADER_SIZE_IN_BYTE
 pub struct Frame<'a> {
     encoding_type: u16,
     message: &'a [u8],
+    header_bytes: [u8; HEADER_SIZE_IN_BYTES],
 }
 
 impl<'a> Frame<'a> {
@@ -34,9 +35,14 @@ impl<'a> Frame<'a> {
     /// ```
     pub fn new(encoding_type: u16, message: &[u8]) -> Frame {
         assert!(message.len() <= MAX_MESSAGE_SIZE_IN_BYTES);
+        let header = SofhHeader {
+            message_length: (message.len() + HEADER_SIZE_IN_BYTES) as u32,
+            encoding_type,
+        };
         Frame {
             encoding_type,
             message,
+            header_bytes: header.to_bytes(),
         }
     }
 
@@ -72,6 +78,48 @@ impl<'a> Frame<'a> {
         self.message
     }
 
+    /// Returns the [`SofhHeader`] that precedes `self` on the wire.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::sofh::Frame;
+    ///
+    /// let frame = Frame::new(0xF500, b"{}");
+    /// let header = frame.header();
+    /// assert_eq!(header.message_length, 8);
+    /// assert_eq!(header.encoding_type, 0xF500);
+    /// ```
+    pub fn header(&self) -> SofhHeader {
+        SofhHeader {
+            message_length: (self.message.len() + HEADER_SIZE_IN_BYTES) as u32,
+            encoding_type: self.encoding_type,
+        }
+    }
+
+    /// Returns the six bytes of the SOFH header of `self`, exactly as they were
+    /// read by [`Frame::decode`] (or [`Frame::decode_with_endianness`]). For
+    /// frames created with [`Frame::new`], these are the bytes that
+    /// [`Frame::encode`] writes.
+    ///
+    /// Unlike [`Frame::header`], this is useful for diagnostics on the wire
+    /// representation itself, e.g. a `Message_Length` with the wrong byte
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::sofh::{Endianness, Frame};
+    ///
+    /// let data = &[7, 0, 0, 0, 0xF0, 0x00, 42];
+    /// let frame = Frame::decode_with_endianness(data, Endianness::Little).unwrap();
+    /// assert_eq!(frame.header_bytes(), [7, 0, 0, 0, 0xF0, 0x00]);
+    /// assert_eq!(frame.header().to_bytes(), [0, 0, 0, 7, 0xF0, 0x00]);
+    /// ```
+    pub fn header_bytes(&self) -> [u8; HEADER_SIZE_IN_BYTES] {
+        self.header_bytes
+    }
+
     /// Deserializes a [`Frame`] from `data`. Returns an `Err` if invalid. Zero-copy.
     ///
     /// This function ignores trailing bytes that are not part of the message.
@@ -87,29 +135,56 @@ impl<'a> Frame<'a> {
     /// let frame = Frame::decode(&[0, 0, 0, 7, 0x0, 0x0, 42]).unwrap();
     /// assert_eq!(frame.message(), &[42]);
     /// ```
-    pub fn decode(data: &[u8]) -> Result<Frame, Error> {
+    pub fn decode(data: &[u8]) -> Result<Frame, SofhError> {
+        Self::decode_with_endianness(data, Endianness::Big)
+    }
+
+    /// Like [`Frame::decode`], but `Message_Length` is read with the given
+    /// `endianness`.
+    ///
+    /// Please note that [`Endianness::Little`] violates the SOFH
+    /// specification.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::sofh::{Endianness, Frame};
+    ///
+    /// let frame = Frame::decode_with_endianness(&[7, 0, 0, 0, 0x0, 0x0, 42], Endianness::Little).unwrap();
+    /// assert_eq!(frame.message(), &[42]);
+    /// ```
+    pub fn decode_with_endianness(
+        data: &[u8],
+        endianness: Endianness,
+    ) -> Result<Frame<'_>, SofhError> {
         // The buffer doesn't contain enough data to even meaningfully reason
         // about it, let alone decode it.
         if data.len() < HEADER_SIZE_IN_BYTES {
-            return Err(Error::Incomplete {
+            return Err(SofhError::Incomplete {
                 needed: HEADER_SIZE_IN_BYTES - data.len(),
             });
         }
-        let message_len = field_message_length(data) as usize;
+        let header = SofhHeader::parse_with_endianness(
+            data[..HEADER_SIZE_IN_BYTES].try_into().unwrap(),
+            endianness,
+        );
+        let message_len = header.message_length as usize;
         if message_len < HEADER_SIZE_IN_BYTES {
             // We have enough data to decode the header, but the Message_Length
             // field is invalid.
-            Err(Error::InvalidMessageLength)
+            Err(SofhError::InvalidLength)
         } else if data.len() < message_len {
             // The header is fine, we just need to wait for the whole message.
-            Err(Error::Incomplete {
+            Err(SofhError::Incomplete {
                 needed: message_len - data.len(),
             })
         } else {
-            Ok(Self::new(
-                field_encoding_type(data),
-                &data[HEADER_SIZE_IN_BYTES..],
-            ))
+            let mut frame = Self::new(
+                header.encoding_type,
+                &data[HEADER_SIZE_IN_BYTES..message_len],
+            );
+            frame.header_bytes = data[..HEADER_SIZE_IN_BYTES].try_into().unwrap();
+            Ok(frame)
         }
     }
 
@@ -136,19 +211,47 @@ impl<'a> Frame<'a> {
         W: io::Write,
     {
         let len = self.message().len();
-        writer.write_all(&((len + HEADER_SIZE_IN_BYTES) as u32).to_be_bytes())?;
-        writer.write_all(&self.encoding_type().to_be_bytes())?;
+        writer.write_all(&self.header().to_bytes())?;
         writer.write_all(self.message())?;
         Ok(HEADER_SIZE_IN_BYTES + len)
     }
 }
 
-fn field_message_length(data: &[u8]) -> u32 {
-    u32::from_be_bytes(data[0..4].try_into().unwrap())
-}
-
-fn field_encoding_type(data: &[u8]) -> u16 {
-    u16::from_be_bytes(data[4..6].try_into().unwrap())
+/// Strips the SOFH header from `data` if it looks like a SOFH-enclosed message,
+/// otherwise returns `data` unchanged together with
+/// `EncodingType::Unknown(0)`.
+///
+/// This is a heuristic: `data` is considered SOFH-enclosed if and only if its
+/// first six bytes are a SOFH header with a known encoding type and a message
+/// length that fits inside `data`. Trailing bytes are ignored, like in
+/// [`Frame::decode`].
+///
+/// # Examples
+///
+/// ```
+/// use fefix::sofh::{maybe_unframe, EncodingType};
+///
+/// let (encoding_type, payload) = maybe_unframe(&[0, 0, 0, 8, 0xF5, 0x00, b'{', b'}']);
+/// assert_eq!(encoding_type, EncodingType::Json);
+/// assert_eq!(payload, b"{}");
+///
+/// let (encoding_type, payload) = maybe_unframe(b"8=FIX.4.4|9=5|35=0|10=000|");
+/// assert_eq!(encoding_type, EncodingType::Unknown(0));
+/// assert_eq!(payload, b"8=FIX.4.4|9=5|35=0|10=000|");
+/// ```
+pub fn maybe_unframe(data: &[u8]) -> (EncodingType, &[u8]) {
+    let unframed = (EncodingType::Unknown(0), data);
+    if data.len() < HEADER_SIZE_IN_BYTES {
+        return unframed;
+    }
+    let header = SofhHeader::parse(data[..HEADER_SIZE_IN_BYTES].try_into().unwrap());
+    let message_len = header.message_length as usize;
+    let encoding_type = EncodingType::from(header.encoding_type);
+    match encoding_type {
+        EncodingType::Unknown(_) => unframed,
+        _ if message_len < HEADER_SIZE_IN_BYTES || message_len > data.len() => unframed,
+        _ => (encoding_type, &data[HEADER_SIZE_IN_BYTES..message_len]),
+    }
 }
 
 #[cfg(test)]
@@ -199,15 +302,15 @@ mod test {
     fn decode_incomplete_header() {
         assert!(matches!(
             Frame::decode(&[]),
-            Err(Error::Incomplete { needed: 6 })
+            Err(SofhError::Incomplete { needed: 6 })
         ));
         assert!(matches!(
             Frame::decode(&[0, 0, 0]),
-            Err(Error::Incomplete { needed: 3 })
+            Err(SofhError::Incomplete { needed: 3 })
         ));
         assert!(matches!(
             Frame::decode(&[0, 0, 0, 0, 0]),
-            Err(Error::Incomplete { needed: 1 })
+            Err(SofhError::Incomplete { needed: 1 })
         ));
     }
 
@@ -218,6 +321,67 @@ mod test {
         assert_eq!(frame.message(), [] as [u8; 0]);
     }
 
+    #[test]
+    fn decode_ignores_trailing_bytes() {
+        let frame = Frame::decode(&[0, 0, 0, 7, 0, 0, 42, 43, 44]).unwrap();
+        assert_eq!(frame.message(), &[42]);
+    }
+
+    #[test]
+    fn decode_same_frame_with_both_endiannesses() {
+        let data = [7, 0, 0, 0, 0xF0, 0x00, 42];
+        let frame = Frame::decode_with_endianness(&data[..], Endianness::Little).unwrap();
+        assert_eq!(frame.message(), &[42]);
+        assert_eq!(frame.encoding_type(), 0xF000);
+        // Big-endian `Message_Length` is huge, so the frame looks incomplete.
+        match Frame::decode_with_endianness(&data[..], Endianness::Big) {
+            Err(SofhError::Incomplete { needed }) => assert_eq!(needed, 0x0700_0000 - 7),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn header_bytes_are_the_ones_read() {
+        let data = [0, 0, 0, 8, 0xF5, 0x00, b'{', b'}', 0xFF];
+        let frame = Frame::decode(&data[..]).unwrap();
+        assert_eq!(frame.header_bytes(), [0, 0, 0, 8, 0xF5, 0x00]);
+        assert_eq!(frame.header_bytes(), frame.header().to_bytes());
+        let data = [8, 0, 0, 0, 0xF5, 0x00, b'{', b'}'];
+        let frame = Frame::decode_with_endianness(&data[..], Endianness::Little).unwrap();
+        assert_eq!(frame.header_bytes(), [8, 0, 0, 0, 0xF5, 0x00]);
+        assert_ne!(frame.header_bytes(), frame.header().to_bytes());
+    }
+
+    #[test]
+    fn maybe_unframe_tagvalue_message() {
+        let message = b"8=FIX.4.4\x019=5\x0135=0\x0110=000\x01";
+        let mut data = Vec::new();
+        Frame::new(0xF000, &message[..]).encode(&mut data).unwrap();
+        assert_eq!(
+            maybe_unframe(&data[..]),
+            (EncodingType::TagValue, &message[..])
+        );
+        assert_eq!(
+            maybe_unframe(&message[..]),
+            (EncodingType::Unknown(0), &message[..])
+        );
+    }
+
+    #[test]
+    fn maybe_unframe_rejects_implausible_headers() {
+        // Message length is too short.
+        let data = [0, 0, 0, 5, 0xF0, 0x00, 0];
+        assert_eq!(maybe_unframe(&data[..]).1, &data[..]);
+        // Message length exceeds the buffer.
+        let data = [0, 0, 0, 9, 0xF0, 0x00, 0];
+        assert_eq!(maybe_unframe(&data[..]).1, &data[..]);
+        // Unknown encoding type.
+        let data = [0, 0, 0, 7, 0x12, 0x34, 0];
+        assert_eq!(maybe_unframe(&data[..]).1, &data[..]);
+        // Not even a header.
+        assert_eq!(maybe_unframe(&[0, 0]).1, &[0, 0]);
+    }
+
     #[test]
     fn encode_then_decode_should_have_no_effect() {
         fn prop(encoding_type: u16, data: Vec<u8>) -> bool {
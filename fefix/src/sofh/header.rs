@@ -0,0 +1,171 @@
+use std::convert::TryInto;
+
+/// The byte order of the `Message_Length` field of a [`SofhHeader`].
+///
+/// The SOFH specification mandates [`Endianness::Big`]; anything else violates
+/// the specification and is only meant as an escape hatch to interoperate with
+/// non-conformant counterparties.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    /// Network byte order, as mandated by the SOFH specification.
+    Big,
+    /// Little-endian byte order. **Not** SOFH-compliant.
+    Little,
+}
+
+// `#[default]` on enum variants requires Rust 1.62.
+#[allow(clippy::derivable_impls)]
+impl Default for Endianness {
+    fn default() -> Self {
+        Self::Big
+    }
+}
+
+/// The fixed-size header that precedes every SOFH-enclosed message.
+///
+/// Both fields are encoded in network byte order (big-endian), as mandated by
+/// the SOFH specification.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SofhHeader {
+    /// Length of the whole message in bytes, *including* the header itself.
+    pub message_length: u32,
+    /// 16-bits encoding type of the payload. You may want to convert this value
+    /// to an [`EncodingType`](super::EncodingType).
+    pub encoding_type: u16,
+}
+
+impl SofhHeader {
+    /// The size of a SOFH header in bytes.
+    pub const LEN: usize = 6;
+
+    /// Deserializes a [`SofhHeader`] from its six-bytes wire representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::sofh::SofhHeader;
+    ///
+    /// let header = SofhHeader::parse(&[0, 0, 0, 42, 0xF0, 0x00]);
+    /// assert_eq!(header.message_length, 42);
+    /// assert_eq!(header.encoding_type, 0xF000);
+    /// ```
+    pub fn parse(data: &[u8; Self::LEN]) -> Self {
+        Self::parse_with_endianness(data, Endianness::Big)
+    }
+
+    /// Like [`SofhHeader::parse`], but `Message_Length` is read with the
+    /// given `endianness`. `Encoding_Type` is always big-endian.
+    ///
+    /// Please note that [`Endianness::Little`] violates the SOFH
+    /// specification.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::sofh::{Endianness, SofhHeader};
+    ///
+    /// let header = SofhHeader::parse_with_endianness(&[42, 0, 0, 0, 0xF0, 0x00], Endianness::Little);
+    /// assert_eq!(header.message_length, 42);
+    /// assert_eq!(header.encoding_type, 0xF000);
+    /// ```
+    pub fn parse_with_endianness(data: &[u8; Self::LEN], endianness: Endianness) -> Self {
+        let message_length = data[0..4].try_into().unwrap();
+        Self {
+            message_length: match endianness {
+                Endianness::Big => u32::from_be_bytes(message_length),
+                Endianness::Little => u32::from_le_bytes(message_length),
+            },
+            encoding_type: u16::from_be_bytes(data[4..6].try_into().unwrap()),
+        }
+    }
+
+    /// Serializes `self` to its six-bytes wire representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::sofh::SofhHeader;
+    ///
+    /// let header = SofhHeader {
+    ///     message_length: 42,
+    ///     encoding_type: 0xF000,
+    /// };
+    /// assert_eq!(header.to_bytes(), [0, 0, 0, 42, 0xF0, 0x00]);
+    /// ```
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut bytes = [0; Self::LEN];
+        bytes[0..4].copy_from_slice(&self.message_length.to_be_bytes());
+        bytes[4..6].copy_from_slice(&self.encoding_type.to_be_bytes());
+        bytes
+    }
+
+    /// Returns the length of the payload that follows the header, or `None` if
+    /// `self.message_length` is too small to even contain the header.
+    pub fn payload_length(&self) -> Option<usize> {
+        (self.message_length as usize).checked_sub(Self::LEN)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::sofh::EncodingType;
+    use quickcheck::QuickCheck;
+
+    #[test]
+    fn sbe_big_endian_header() {
+        // A 72-bytes SBE 1.0 (big-endian) message.
+        let bytes = [0x00, 0x00, 0x00, 0x48, 0x5B, 0xE0];
+        let header = SofhHeader::parse(&bytes);
+        assert_eq!(header.message_length, 72);
+        assert_eq!(
+            EncodingType::from(header.encoding_type),
+            EncodingType::SimpleBinaryEncodingV10BE
+        );
+        assert_eq!(header.payload_length(), Some(66));
+        assert_eq!(header.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn tagvalue_header_with_multi_byte_length() {
+        let bytes = [0x00, 0x01, 0x02, 0x03, 0xF0, 0x00];
+        let header = SofhHeader::parse(&bytes);
+        assert_eq!(header.message_length, 0x0001_0203);
+        assert_eq!(
+            EncodingType::from(header.encoding_type),
+            EncodingType::TagValue
+        );
+        assert_eq!(header.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn same_header_with_both_endiannesses() {
+        let bytes = [0x00, 0x00, 0x00, 0x48, 0x5B, 0xE0];
+        let big = SofhHeader::parse_with_endianness(&bytes, Endianness::Big);
+        let little = SofhHeader::parse_with_endianness(&bytes, Endianness::Little);
+        assert_eq!(big, SofhHeader::parse(&bytes));
+        assert_eq!(big.message_length, 0x48);
+        assert_eq!(little.message_length, 0x4800_0000);
+        assert_eq!(big.encoding_type, little.encoding_type);
+    }
+
+    #[test]
+    fn payload_length_of_invalid_header() {
+        let header = SofhHeader::parse(&[0, 0, 0, 5, 0xF5, 0x00]);
+        assert_eq!(header.payload_length(), None);
+    }
+
+    #[test]
+    fn to_bytes_then_parse_should_have_no_effect() {
+        fn prop(message_length: u32, encoding_type: u16) -> bool {
+            let header = SofhHeader {
+                message_length,
+                encoding_type,
+            };
+            SofhHeader::parse(&header.to_bytes()) == header
+        }
+        QuickCheck::new()
+            .tests(1000)
+            .quickcheck(prop as fn(u32, u16) -> bool)
+    }
+}
@@ -13,8 +13,10 @@ mod decoder;
 mod encoding_type;
 mod err;
 mod frame;
+mod header;
 
 pub use decoder::{Decoder, Frames};
 pub use encoding_type::EncodingType;
-pub use err::Error;
-pub use frame::Frame;
+pub use err::SofhError;
+pub use frame::{maybe_unframe, Frame};
+pub use header::{Endianness, SofhHeader};
@@ -2,6 +2,8 @@
 
 #![allow(dead_code)]
 
+use crate::DataType;
+
 pub const ACCOUNT: u32 = 1;
 pub const ADV_ID: u32 = 2;
 pub const ADV_REF_ID: u32 = 3;
@@ -208,3 +210,213 @@ pub const NOTIFY_BROKER_OF_CREDIT: u32 = 208;
 pub const ALLOC_HANDL_INST: u32 = 209;
 pub const MAX_SHOW: u32 = 210;
 pub const PEG_DIFFERENCE: u32 = 211;
+
+/// All fields, as `(tag, name, data type)` triplets.
+pub const FIELDS: &[(u32, &str, DataType)] = &[
+    (1, "Account", DataType::Char),
+    (2, "AdvId", DataType::Char),
+    (3, "AdvRefID", DataType::Char),
+    (4, "AdvSide", DataType::Char),
+    (5, "AdvTransType", DataType::Char),
+    (6, "AvgPx", DataType::Float),
+    (7, "BeginSeqNo", DataType::Int),
+    (8, "BeginString", DataType::Char),
+    (9, "BodyLength", DataType::Int),
+    (10, "CheckSum", DataType::Char),
+    (11, "ClOrdID", DataType::Char),
+    (12, "Commission", DataType::Float),
+    (13, "CommType", DataType::Char),
+    (14, "CumQty", DataType::Int),
+    (15, "Currency", DataType::Char),
+    (16, "EndSeqNo", DataType::Int),
+    (17, "ExecID", DataType::Char),
+    (18, "ExecInst", DataType::Char),
+    (19, "ExecRefID", DataType::Char),
+    (20, "ExecTransType", DataType::Char),
+    (21, "HandlInst", DataType::Char),
+    (22, "IDSource", DataType::Char),
+    (23, "IOIid", DataType::Char),
+    (24, "IOIOthSvc", DataType::Char),
+    (25, "IOIQltyInd", DataType::Char),
+    (26, "IOIRefID", DataType::Char),
+    (27, "IOIShares", DataType::Char),
+    (28, "IOITransType", DataType::Char),
+    (29, "LastCapacity", DataType::Char),
+    (30, "LastMkt", DataType::Char),
+    (31, "LastPx", DataType::Float),
+    (32, "LastShares", DataType::Int),
+    (33, "LinesOfText", DataType::Int),
+    (34, "MsgSeqNum", DataType::Int),
+    (35, "MsgType", DataType::Char),
+    (36, "NewSeqNo", DataType::Int),
+    (37, "OrderID", DataType::Char),
+    (38, "OrderQty", DataType::Int),
+    (39, "OrdStatus", DataType::Char),
+    (40, "OrdType", DataType::Char),
+    (41, "OrigClOrdID", DataType::Char),
+    (42, "OrigTime", DataType::UtcTimestamp),
+    (43, "PossDupFlag", DataType::Char),
+    (44, "Price", DataType::Float),
+    (45, "RefSeqNum", DataType::Int),
+    (46, "RelatdSym", DataType::Char),
+    (47, "Rule80A", DataType::Char),
+    (48, "SecurityID", DataType::Char),
+    (49, "SenderCompID", DataType::Char),
+    (50, "SenderSubID", DataType::Char),
+    (52, "SendingTime", DataType::UtcTimestamp),
+    (53, "Shares", DataType::Int),
+    (54, "Side", DataType::Char),
+    (55, "Symbol", DataType::Char),
+    (56, "TargetCompID", DataType::Char),
+    (57, "TargetSubID", DataType::Char),
+    (58, "Text", DataType::Char),
+    (59, "TimeInForce", DataType::Char),
+    (60, "TransactTime", DataType::UtcTimestamp),
+    (61, "Urgency", DataType::Char),
+    (62, "ValidUntilTime", DataType::UtcTimestamp),
+    (63, "SettlmntTyp", DataType::Char),
+    (64, "FutSettDate", DataType::UtcDateOnly),
+    (65, "SymbolSfx", DataType::Char),
+    (66, "ListID", DataType::Char),
+    (67, "ListSeqNo", DataType::Int),
+    (68, "ListNoOrds", DataType::Int),
+    (69, "ListExecInst", DataType::Char),
+    (70, "AllocID", DataType::Char),
+    (71, "AllocTransType", DataType::Char),
+    (72, "RefAllocID", DataType::Char),
+    (73, "NoOrders", DataType::Int),
+    (74, "AvgPrxPrecision", DataType::Int),
+    (75, "TradeDate", DataType::UtcDateOnly),
+    (76, "ExecBroker", DataType::Char),
+    (77, "OpenClose", DataType::Char),
+    (78, "NoAllocs", DataType::Int),
+    (79, "AllocAccount", DataType::Char),
+    (80, "AllocShares", DataType::Int),
+    (81, "ProcessCode", DataType::Char),
+    (82, "NoRpts", DataType::Int),
+    (83, "RptSeq", DataType::Int),
+    (84, "CxlQty", DataType::Int),
+    (87, "AllocStatus", DataType::Int),
+    (88, "AllocRejCode", DataType::Int),
+    (89, "Signature", DataType::Data),
+    (90, "SecureDataLen", DataType::Int),
+    (91, "SecureData", DataType::Data),
+    (92, "BrokerOfCredit", DataType::Char),
+    (93, "SignatureLength", DataType::Int),
+    (94, "EmailType", DataType::Char),
+    (95, "RawDataLength", DataType::Int),
+    (96, "RawData", DataType::Data),
+    (97, "PossResend", DataType::Char),
+    (98, "EncryptMethod", DataType::Int),
+    (99, "StopPx", DataType::Float),
+    (100, "ExDestination", DataType::Char),
+    (102, "CxlRejReason", DataType::Int),
+    (103, "OrdRejReason", DataType::Int),
+    (104, "IOIQualifier", DataType::Char),
+    (105, "WaveNo", DataType::Char),
+    (106, "Issuer", DataType::Char),
+    (107, "SecurityDesc", DataType::Char),
+    (108, "HeartBtInt", DataType::Int),
+    (109, "ClientID", DataType::Char),
+    (110, "MinQty", DataType::Int),
+    (111, "MaxFloor", DataType::Int),
+    (112, "TestReqID", DataType::Char),
+    (113, "ReportToExch", DataType::Char),
+    (114, "LocateReqd", DataType::Char),
+    (115, "OnBehalfOfCompID", DataType::Char),
+    (116, "OnBehalfOfSubID", DataType::Char),
+    (117, "QuoteID", DataType::Char),
+    (118, "NetMoney", DataType::Float),
+    (119, "SettlCurrAmt", DataType::Float),
+    (120, "SettlCurrency", DataType::Char),
+    (121, "ForexReq", DataType::Char),
+    (122, "OrigSendingTime", DataType::UtcTimestamp),
+    (123, "GapFillFlag", DataType::Char),
+    (124, "NoExecs", DataType::Int),
+    (126, "ExpireTime", DataType::UtcTimestamp),
+    (127, "DKReason", DataType::Char),
+    (128, "DeliverToCompID", DataType::Char),
+    (129, "DeliverToSubID", DataType::Char),
+    (130, "IOINaturalFlag", DataType::Char),
+    (131, "QuoteReqID", DataType::Char),
+    (132, "BidPx", DataType::Float),
+    (133, "OfferPx", DataType::Float),
+    (134, "BidSize", DataType::Int),
+    (135, "OfferSize", DataType::Int),
+    (136, "NoMiscFees", DataType::Int),
+    (137, "MiscFeeAmt", DataType::Float),
+    (138, "MiscFeeCurr", DataType::Char),
+    (139, "MiscFeeType", DataType::Char),
+    (140, "PrevClosePx", DataType::Float),
+    (141, "ResetSeqNumFlag", DataType::Char),
+    (142, "SenderLocationID", DataType::Char),
+    (143, "TargetLocationID", DataType::Char),
+    (144, "OnBehalfOfLocationID", DataType::Char),
+    (145, "DeliverToLocationID", DataType::Char),
+    (146, "NoRelatedSym", DataType::Int),
+    (147, "Subject", DataType::Char),
+    (148, "Headline", DataType::Char),
+    (149, "URLLink", DataType::Char),
+    (150, "ExecType", DataType::Char),
+    (151, "LeavesQty", DataType::Int),
+    (152, "CashOrderQty", DataType::Float),
+    (153, "AllocAvgPx", DataType::Float),
+    (154, "AllocNetMoney", DataType::Float),
+    (155, "SettlCurrFxRate", DataType::Float),
+    (156, "SettlCurrFxRateCalc", DataType::Char),
+    (157, "NumDaysInterest", DataType::Int),
+    (158, "AccruedInterestRate", DataType::Float),
+    (159, "AccruedInterestAmt", DataType::Float),
+    (160, "SettlInstMode", DataType::Char),
+    (161, "AllocText", DataType::Char),
+    (162, "SettlInstID", DataType::Char),
+    (163, "SettlInstTransType", DataType::Char),
+    (164, "EmailThreadID", DataType::Char),
+    (165, "SettlInstSource", DataType::Char),
+    (166, "SettlLocation", DataType::Char),
+    (167, "SecurityType", DataType::Char),
+    (168, "EffectiveTime", DataType::UtcTimestamp),
+    (169, "StandInstDbType", DataType::Int),
+    (170, "StandInstDbName", DataType::Char),
+    (171, "StandInstDbID", DataType::Char),
+    (172, "SettlDeliveryType", DataType::Int),
+    (173, "SettlDepositoryCode", DataType::Char),
+    (174, "SettlBrkrCode", DataType::Char),
+    (175, "SettlInstCode", DataType::Char),
+    (176, "SecuritySettlAgentName", DataType::Char),
+    (177, "SecuritySettlAgentCode", DataType::Char),
+    (178, "SecuritySettlAgentAcctNum", DataType::Char),
+    (179, "SecuritySettlAgentAcctName", DataType::Char),
+    (180, "SecuritySettlAgentContactName", DataType::Char),
+    (181, "SecuritySettlAgentContactPhone", DataType::Char),
+    (182, "CashSettlAgentName", DataType::Char),
+    (183, "CashSettlAgentCode", DataType::Char),
+    (184, "CashSettlAgentAcctNum", DataType::Char),
+    (185, "CashSettlAgentAcctName", DataType::Char),
+    (186, "CashSettlAgentContactName", DataType::Char),
+    (187, "CashSettlAgentContactPhone", DataType::Char),
+    (188, "BidSpotRate", DataType::Float),
+    (189, "BidForwardPoints", DataType::Float),
+    (190, "OfferSpotRate", DataType::Float),
+    (191, "OfferForwardPoints", DataType::Float),
+    (192, "OrderQty2", DataType::Float),
+    (193, "FutSettDate2", DataType::UtcDateOnly),
+    (194, "LastSpotRate", DataType::Float),
+    (195, "LastForwardPoints", DataType::Float),
+    (196, "AllocLinkID", DataType::Char),
+    (197, "AllocLinkType", DataType::Int),
+    (198, "SecondaryOrderID", DataType::Char),
+    (199, "NoIOIQualifiers", DataType::Int),
+    (200, "MaturityMonthYear", DataType::MonthYear),
+    (201, "PutOrCall", DataType::Int),
+    (202, "StrikePrice", DataType::Float),
+    (203, "CoveredOrUncovered", DataType::Int),
+    (204, "CustomerOrFirm", DataType::Int),
+    (205, "MaturityDay", DataType::DayOfMonth),
+    (206, "OptAttribute", DataType::Char),
+    (207, "SecurityExchange", DataType::Char),
+    (208, "NotifyBrokerOfCredit", DataType::Char),
+    (209, "AllocHandlInst", DataType::Int),
+    (210, "MaxShow", DataType::Int),
+    (211, "PegDifference", DataType::Float),
+];
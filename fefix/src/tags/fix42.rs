@@ -2,6 +2,8 @@
 
 #![allow(dead_code)]
 
+use crate::DataType;
+
 pub const ACCOUNT: u32 = 1;
 pub const ADV_ID: u32 = 2;
 pub const ADV_REF_ID: u32 = 3;
@@ -407,3 +409,412 @@ pub const STRIKE_TIME: u32 = 443;
 pub const LIST_STATUS_TEXT: u32 = 444;
 pub const ENCODED_LIST_STATUS_TEXT_LEN: u32 = 445;
 pub const ENCODED_LIST_STATUS_TEXT: u32 = 446;
+
+/// All fields, as `(tag, name, data type)` triplets.
+pub const FIELDS: &[(u32, &str, DataType)] = &[
+    (1, "Account", DataType::String),
+    (2, "AdvId", DataType::String),
+    (3, "AdvRefID", DataType::String),
+    (4, "AdvSide", DataType::Char),
+    (5, "AdvTransType", DataType::String),
+    (6, "AvgPx", DataType::Price),
+    (7, "BeginSeqNo", DataType::Int),
+    (8, "BeginString", DataType::String),
+    (9, "BodyLength", DataType::Int),
+    (10, "CheckSum", DataType::String),
+    (11, "ClOrdID", DataType::String),
+    (12, "Commission", DataType::Amt),
+    (13, "CommType", DataType::Char),
+    (14, "CumQty", DataType::Qty),
+    (15, "Currency", DataType::Currency),
+    (16, "EndSeqNo", DataType::Int),
+    (17, "ExecID", DataType::String),
+    (18, "ExecInst", DataType::MultipleCharValue),
+    (19, "ExecRefID", DataType::String),
+    (20, "ExecTransType", DataType::Char),
+    (21, "HandlInst", DataType::Char),
+    (22, "IDSource", DataType::String),
+    (23, "IOIid", DataType::String),
+    (24, "IOIOthSvc", DataType::Char),
+    (25, "IOIQltyInd", DataType::Char),
+    (26, "IOIRefID", DataType::String),
+    (27, "IOIShares", DataType::String),
+    (28, "IOITransType", DataType::Char),
+    (29, "LastCapacity", DataType::Char),
+    (30, "LastMkt", DataType::Exchange),
+    (31, "LastPx", DataType::Price),
+    (32, "LastShares", DataType::Qty),
+    (33, "LinesOfText", DataType::Int),
+    (34, "MsgSeqNum", DataType::Int),
+    (35, "MsgType", DataType::String),
+    (36, "NewSeqNo", DataType::Int),
+    (37, "OrderID", DataType::String),
+    (38, "OrderQty", DataType::Qty),
+    (39, "OrdStatus", DataType::Char),
+    (40, "OrdType", DataType::Char),
+    (41, "OrigClOrdID", DataType::String),
+    (42, "OrigTime", DataType::UtcTimestamp),
+    (43, "PossDupFlag", DataType::Boolean),
+    (44, "Price", DataType::Price),
+    (45, "RefSeqNum", DataType::Int),
+    (46, "RelatdSym", DataType::String),
+    (47, "Rule80A", DataType::Char),
+    (48, "SecurityID", DataType::String),
+    (49, "SenderCompID", DataType::String),
+    (50, "SenderSubID", DataType::String),
+    (51, "SendingDate", DataType::LocalMktDate),
+    (52, "SendingTime", DataType::UtcTimestamp),
+    (53, "Shares", DataType::Qty),
+    (54, "Side", DataType::Char),
+    (55, "Symbol", DataType::String),
+    (56, "TargetCompID", DataType::String),
+    (57, "TargetSubID", DataType::String),
+    (58, "Text", DataType::String),
+    (59, "TimeInForce", DataType::Char),
+    (60, "TransactTime", DataType::UtcTimestamp),
+    (61, "Urgency", DataType::Char),
+    (62, "ValidUntilTime", DataType::UtcTimestamp),
+    (63, "SettlmntTyp", DataType::Char),
+    (64, "FutSettDate", DataType::LocalMktDate),
+    (65, "SymbolSfx", DataType::String),
+    (66, "ListID", DataType::String),
+    (67, "ListSeqNo", DataType::Int),
+    (68, "TotNoOrders", DataType::Int),
+    (69, "ListExecInst", DataType::String),
+    (70, "AllocID", DataType::String),
+    (71, "AllocTransType", DataType::Char),
+    (72, "RefAllocID", DataType::String),
+    (73, "NoOrders", DataType::Int),
+    (74, "AvgPrxPrecision", DataType::Int),
+    (75, "TradeDate", DataType::LocalMktDate),
+    (76, "ExecBroker", DataType::String),
+    (77, "OpenClose", DataType::Char),
+    (78, "NoAllocs", DataType::Int),
+    (79, "AllocAccount", DataType::String),
+    (80, "AllocShares", DataType::Qty),
+    (81, "ProcessCode", DataType::Char),
+    (82, "NoRpts", DataType::Int),
+    (83, "RptSeq", DataType::Int),
+    (84, "CxlQty", DataType::Qty),
+    (85, "NoDlvyInst", DataType::Int),
+    (86, "DlvyInst", DataType::String),
+    (87, "AllocStatus", DataType::Int),
+    (88, "AllocRejCode", DataType::Int),
+    (89, "Signature", DataType::Data),
+    (90, "SecureDataLen", DataType::Int),
+    (91, "SecureData", DataType::Data),
+    (92, "BrokerOfCredit", DataType::String),
+    (93, "SignatureLength", DataType::Int),
+    (94, "EmailType", DataType::Char),
+    (95, "RawDataLength", DataType::Int),
+    (96, "RawData", DataType::Data),
+    (97, "PossResend", DataType::Boolean),
+    (98, "EncryptMethod", DataType::Int),
+    (99, "StopPx", DataType::Price),
+    (100, "ExDestination", DataType::Exchange),
+    (102, "CxlRejReason", DataType::Int),
+    (103, "OrdRejReason", DataType::Int),
+    (104, "IOIQualifier", DataType::Char),
+    (105, "WaveNo", DataType::String),
+    (106, "Issuer", DataType::String),
+    (107, "SecurityDesc", DataType::String),
+    (108, "HeartBtInt", DataType::Int),
+    (109, "ClientID", DataType::String),
+    (110, "MinQty", DataType::Qty),
+    (111, "MaxFloor", DataType::Qty),
+    (112, "TestReqID", DataType::String),
+    (113, "ReportToExch", DataType::Boolean),
+    (114, "LocateReqd", DataType::Boolean),
+    (115, "OnBehalfOfCompID", DataType::String),
+    (116, "OnBehalfOfSubID", DataType::String),
+    (117, "QuoteID", DataType::String),
+    (118, "NetMoney", DataType::Amt),
+    (119, "SettlCurrAmt", DataType::Amt),
+    (120, "SettlCurrency", DataType::Currency),
+    (121, "ForexReq", DataType::Boolean),
+    (122, "OrigSendingTime", DataType::UtcTimestamp),
+    (123, "GapFillFlag", DataType::Boolean),
+    (124, "NoExecs", DataType::Int),
+    (125, "CxlType", DataType::Char),
+    (126, "ExpireTime", DataType::UtcTimestamp),
+    (127, "DKReason", DataType::Char),
+    (128, "DeliverToCompID", DataType::String),
+    (129, "DeliverToSubID", DataType::String),
+    (130, "IOINaturalFlag", DataType::Boolean),
+    (131, "QuoteReqID", DataType::String),
+    (132, "BidPx", DataType::Price),
+    (133, "OfferPx", DataType::Price),
+    (134, "BidSize", DataType::Qty),
+    (135, "OfferSize", DataType::Qty),
+    (136, "NoMiscFees", DataType::Int),
+    (137, "MiscFeeAmt", DataType::Amt),
+    (138, "MiscFeeCurr", DataType::Currency),
+    (139, "MiscFeeType", DataType::Char),
+    (140, "PrevClosePx", DataType::Price),
+    (141, "ResetSeqNumFlag", DataType::Boolean),
+    (142, "SenderLocationID", DataType::String),
+    (143, "TargetLocationID", DataType::String),
+    (144, "OnBehalfOfLocationID", DataType::String),
+    (145, "DeliverToLocationID", DataType::String),
+    (146, "NoRelatedSym", DataType::Int),
+    (147, "Subject", DataType::String),
+    (148, "Headline", DataType::String),
+    (149, "URLLink", DataType::String),
+    (150, "ExecType", DataType::Char),
+    (151, "LeavesQty", DataType::Qty),
+    (152, "CashOrderQty", DataType::Qty),
+    (153, "AllocAvgPx", DataType::Price),
+    (154, "AllocNetMoney", DataType::Amt),
+    (155, "SettlCurrFxRate", DataType::Float),
+    (156, "SettlCurrFxRateCalc", DataType::Char),
+    (157, "NumDaysInterest", DataType::Int),
+    (158, "AccruedInterestRate", DataType::Float),
+    (159, "AccruedInterestAmt", DataType::Amt),
+    (160, "SettlInstMode", DataType::Char),
+    (161, "AllocText", DataType::String),
+    (162, "SettlInstID", DataType::String),
+    (163, "SettlInstTransType", DataType::Char),
+    (164, "EmailThreadID", DataType::String),
+    (165, "SettlInstSource", DataType::Char),
+    (166, "SettlLocation", DataType::String),
+    (167, "SecurityType", DataType::String),
+    (168, "EffectiveTime", DataType::UtcTimestamp),
+    (169, "StandInstDbType", DataType::Int),
+    (170, "StandInstDbName", DataType::String),
+    (171, "StandInstDbID", DataType::String),
+    (172, "SettlDeliveryType", DataType::Int),
+    (173, "SettlDepositoryCode", DataType::String),
+    (174, "SettlBrkrCode", DataType::String),
+    (175, "SettlInstCode", DataType::String),
+    (176, "SecuritySettlAgentName", DataType::String),
+    (177, "SecuritySettlAgentCode", DataType::String),
+    (178, "SecuritySettlAgentAcctNum", DataType::String),
+    (179, "SecuritySettlAgentAcctName", DataType::String),
+    (180, "SecuritySettlAgentContactName", DataType::String),
+    (181, "SecuritySettlAgentContactPhone", DataType::String),
+    (182, "CashSettlAgentName", DataType::String),
+    (183, "CashSettlAgentCode", DataType::String),
+    (184, "CashSettlAgentAcctNum", DataType::String),
+    (185, "CashSettlAgentAcctName", DataType::String),
+    (186, "CashSettlAgentContactName", DataType::String),
+    (187, "CashSettlAgentContactPhone", DataType::String),
+    (188, "BidSpotRate", DataType::Price),
+    (189, "BidForwardPoints", DataType::PriceOffset),
+    (190, "OfferSpotRate", DataType::Price),
+    (191, "OfferForwardPoints", DataType::PriceOffset),
+    (192, "OrderQty2", DataType::Qty),
+    (193, "FutSettDate2", DataType::LocalMktDate),
+    (194, "LastSpotRate", DataType::Price),
+    (195, "LastForwardPoints", DataType::PriceOffset),
+    (196, "AllocLinkID", DataType::String),
+    (197, "AllocLinkType", DataType::Int),
+    (198, "SecondaryOrderID", DataType::String),
+    (199, "NoIOIQualifiers", DataType::Int),
+    (200, "MaturityMonthYear", DataType::MonthYear),
+    (201, "PutOrCall", DataType::Int),
+    (202, "StrikePrice", DataType::Price),
+    (203, "CoveredOrUncovered", DataType::Int),
+    (204, "CustomerOrFirm", DataType::Int),
+    (205, "MaturityDay", DataType::DayOfMonth),
+    (206, "OptAttribute", DataType::Char),
+    (207, "SecurityExchange", DataType::Exchange),
+    (208, "NotifyBrokerOfCredit", DataType::Boolean),
+    (209, "AllocHandlInst", DataType::Int),
+    (210, "MaxShow", DataType::Qty),
+    (211, "PegDifference", DataType::PriceOffset),
+    (212, "XmlDataLen", DataType::Int),
+    (213, "XmlData", DataType::Data),
+    (214, "SettlInstRefID", DataType::String),
+    (215, "NoRoutingIDs", DataType::Int),
+    (216, "RoutingType", DataType::Int),
+    (217, "RoutingID", DataType::String),
+    (218, "SpreadToBenchmark", DataType::PriceOffset),
+    (219, "Benchmark", DataType::Char),
+    (223, "CouponRate", DataType::Float),
+    (231, "ContractMultiplier", DataType::Float),
+    (262, "MDReqID", DataType::String),
+    (263, "SubscriptionRequestType", DataType::Char),
+    (264, "MarketDepth", DataType::Int),
+    (265, "MDUpdateType", DataType::Int),
+    (266, "AggregatedBook", DataType::Boolean),
+    (267, "NoMDEntryTypes", DataType::Int),
+    (268, "NoMDEntries", DataType::Int),
+    (269, "MDEntryType", DataType::Char),
+    (270, "MDEntryPx", DataType::Price),
+    (271, "MDEntrySize", DataType::Qty),
+    (272, "MDEntryDate", DataType::UtcDateOnly),
+    (273, "MDEntryTime", DataType::UtcTimeOnly),
+    (274, "TickDirection", DataType::Char),
+    (275, "MDMkt", DataType::Exchange),
+    (276, "QuoteCondition", DataType::MultipleCharValue),
+    (277, "TradeCondition", DataType::MultipleCharValue),
+    (278, "MDEntryID", DataType::String),
+    (279, "MDUpdateAction", DataType::Char),
+    (280, "MDEntryRefID", DataType::String),
+    (281, "MDReqRejReason", DataType::Char),
+    (282, "MDEntryOriginator", DataType::String),
+    (283, "LocationID", DataType::String),
+    (284, "DeskID", DataType::String),
+    (285, "DeleteReason", DataType::Char),
+    (286, "OpenCloseSettleFlag", DataType::Char),
+    (287, "SellerDays", DataType::Int),
+    (288, "MDEntryBuyer", DataType::String),
+    (289, "MDEntrySeller", DataType::String),
+    (290, "MDEntryPositionNo", DataType::Int),
+    (291, "FinancialStatus", DataType::Char),
+    (292, "CorporateAction", DataType::Char),
+    (293, "DefBidSize", DataType::Qty),
+    (294, "DefOfferSize", DataType::Qty),
+    (295, "NoQuoteEntries", DataType::Int),
+    (296, "NoQuoteSets", DataType::Int),
+    (297, "QuoteAckStatus", DataType::Int),
+    (298, "QuoteCancelType", DataType::Int),
+    (299, "QuoteEntryID", DataType::String),
+    (300, "QuoteRejectReason", DataType::Int),
+    (301, "QuoteResponseLevel", DataType::Int),
+    (302, "QuoteSetID", DataType::String),
+    (303, "QuoteRequestType", DataType::Int),
+    (304, "TotQuoteEntries", DataType::Int),
+    (305, "UnderlyingIDSource", DataType::String),
+    (306, "UnderlyingIssuer", DataType::String),
+    (307, "UnderlyingSecurityDesc", DataType::String),
+    (308, "UnderlyingSecurityExchange", DataType::Exchange),
+    (309, "UnderlyingSecurityID", DataType::String),
+    (310, "UnderlyingSecurityType", DataType::String),
+    (311, "UnderlyingSymbol", DataType::String),
+    (312, "UnderlyingSymbolSfx", DataType::String),
+    (313, "UnderlyingMaturityMonthYear", DataType::MonthYear),
+    (314, "UnderlyingMaturityDay", DataType::DayOfMonth),
+    (315, "UnderlyingPutOrCall", DataType::Int),
+    (316, "UnderlyingStrikePrice", DataType::Price),
+    (317, "UnderlyingOptAttribute", DataType::Char),
+    (318, "UnderlyingCurrency", DataType::Currency),
+    (319, "RatioQty", DataType::Qty),
+    (320, "SecurityReqID", DataType::String),
+    (321, "SecurityRequestType", DataType::Int),
+    (322, "SecurityResponseID", DataType::String),
+    (323, "SecurityResponseType", DataType::Int),
+    (324, "SecurityStatusReqID", DataType::String),
+    (325, "UnsolicitedIndicator", DataType::Boolean),
+    (326, "SecurityTradingStatus", DataType::Int),
+    (327, "HaltReasonChar", DataType::Char),
+    (328, "InViewOfCommon", DataType::Boolean),
+    (329, "DueToRelated", DataType::Boolean),
+    (330, "BuyVolume", DataType::Qty),
+    (331, "SellVolume", DataType::Qty),
+    (332, "HighPx", DataType::Price),
+    (333, "LowPx", DataType::Price),
+    (334, "Adjustment", DataType::Int),
+    (335, "TradSesReqID", DataType::String),
+    (336, "TradingSessionID", DataType::String),
+    (337, "ContraTrader", DataType::String),
+    (338, "TradSesMethod", DataType::Int),
+    (339, "TradSesMode", DataType::Int),
+    (340, "TradSesStatus", DataType::Int),
+    (341, "TradSesStartTime", DataType::UtcTimestamp),
+    (342, "TradSesOpenTime", DataType::UtcTimestamp),
+    (343, "TradSesPreCloseTime", DataType::UtcTimestamp),
+    (344, "TradSesCloseTime", DataType::UtcTimestamp),
+    (345, "TradSesEndTime", DataType::UtcTimestamp),
+    (346, "NumberOfOrders", DataType::Int),
+    (347, "MessageEncoding", DataType::String),
+    (348, "EncodedIssuerLen", DataType::Int),
+    (349, "EncodedIssuer", DataType::Data),
+    (350, "EncodedSecurityDescLen", DataType::Int),
+    (351, "EncodedSecurityDesc", DataType::Data),
+    (352, "EncodedListExecInstLen", DataType::Int),
+    (353, "EncodedListExecInst", DataType::Data),
+    (354, "EncodedTextLen", DataType::Int),
+    (355, "EncodedText", DataType::Data),
+    (356, "EncodedSubjectLen", DataType::Int),
+    (357, "EncodedSubject", DataType::Data),
+    (358, "EncodedHeadlineLen", DataType::Int),
+    (359, "EncodedHeadline", DataType::Data),
+    (360, "EncodedAllocTextLen", DataType::Int),
+    (361, "EncodedAllocText", DataType::Data),
+    (362, "EncodedUnderlyingIssuerLen", DataType::Int),
+    (363, "EncodedUnderlyingIssuer", DataType::Data),
+    (364, "EncodedUnderlyingSecurityDescLen", DataType::Int),
+    (365, "EncodedUnderlyingSecurityDesc", DataType::Data),
+    (366, "AllocPrice", DataType::Price),
+    (367, "QuoteSetValidUntilTime", DataType::UtcTimestamp),
+    (368, "QuoteEntryRejectReason", DataType::Int),
+    (369, "LastMsgSeqNumProcessed", DataType::Int),
+    (370, "OnBehalfOfSendingTime", DataType::UtcTimestamp),
+    (371, "RefTagID", DataType::Int),
+    (372, "RefMsgType", DataType::String),
+    (373, "SessionRejectReason", DataType::Int),
+    (374, "BidRequestTransType", DataType::Char),
+    (375, "ContraBroker", DataType::String),
+    (376, "ComplianceID", DataType::String),
+    (377, "SolicitedFlag", DataType::Boolean),
+    (378, "ExecRestatementReason", DataType::Int),
+    (379, "BusinessRejectRefID", DataType::String),
+    (380, "BusinessRejectReason", DataType::Int),
+    (381, "GrossTradeAmt", DataType::Amt),
+    (382, "NoContraBrokers", DataType::Int),
+    (383, "MaxMessageSize", DataType::Int),
+    (384, "NoMsgTypes", DataType::Int),
+    (385, "MsgDirection", DataType::Char),
+    (386, "NoTradingSessions", DataType::Int),
+    (387, "TotalVolumeTraded", DataType::Qty),
+    (388, "DiscretionInst", DataType::Char),
+    (389, "DiscretionOffset", DataType::PriceOffset),
+    (390, "BidID", DataType::String),
+    (391, "ClientBidID", DataType::String),
+    (392, "ListName", DataType::String),
+    (393, "TotalNumSecurities", DataType::Int),
+    (394, "BidType", DataType::Int),
+    (395, "NumTickets", DataType::Int),
+    (396, "SideValue1", DataType::Amt),
+    (397, "SideValue2", DataType::Amt),
+    (398, "NoBidDescriptors", DataType::Int),
+    (399, "BidDescriptorType", DataType::Int),
+    (400, "BidDescriptor", DataType::String),
+    (401, "SideValueInd", DataType::Int),
+    (402, "LiquidityPctLow", DataType::Float),
+    (403, "LiquidityPctHigh", DataType::Float),
+    (404, "LiquidityValue", DataType::Amt),
+    (405, "EFPTrackingError", DataType::Float),
+    (406, "FairValue", DataType::Amt),
+    (407, "OutsideIndexPct", DataType::Float),
+    (408, "ValueOfFutures", DataType::Amt),
+    (409, "LiquidityIndType", DataType::Int),
+    (410, "WtAverageLiquidity", DataType::Float),
+    (411, "ExchangeForPhysical", DataType::Boolean),
+    (412, "OutMainCntryUIndex", DataType::Amt),
+    (413, "CrossPercent", DataType::Float),
+    (414, "ProgRptReqs", DataType::Int),
+    (415, "ProgPeriodInterval", DataType::Int),
+    (416, "IncTaxInd", DataType::Int),
+    (417, "NumBidders", DataType::Int),
+    (418, "TradeType", DataType::Char),
+    (419, "BasisPxType", DataType::Char),
+    (420, "NoBidComponents", DataType::Int),
+    (421, "Country", DataType::String),
+    (422, "TotNoStrikes", DataType::Int),
+    (423, "PriceType", DataType::Int),
+    (424, "DayOrderQty", DataType::Qty),
+    (425, "DayCumQty", DataType::Qty),
+    (426, "DayAvgPx", DataType::Price),
+    (427, "GTBookingInst", DataType::Int),
+    (428, "NoStrikes", DataType::Int),
+    (429, "ListStatusType", DataType::Int),
+    (430, "NetGrossInd", DataType::Int),
+    (431, "ListOrderStatus", DataType::Int),
+    (432, "ExpireDate", DataType::LocalMktDate),
+    (433, "ListExecInstType", DataType::Char),
+    (434, "CxlRejResponseTo", DataType::Char),
+    (435, "UnderlyingCouponRate", DataType::Float),
+    (436, "UnderlyingContractMultiplier", DataType::Float),
+    (437, "ContraTradeQty", DataType::Qty),
+    (438, "ContraTradeTime", DataType::UtcTimestamp),
+    (439, "ClearingFirm", DataType::String),
+    (440, "ClearingAccount", DataType::String),
+    (441, "LiquidityNumSecurities", DataType::Int),
+    (442, "MultiLegReportingType", DataType::Char),
+    (443, "StrikeTime", DataType::UtcTimestamp),
+    (444, "ListStatusText", DataType::String),
+    (445, "EncodedListStatusTextLen", DataType::Int),
+    (446, "EncodedListStatusText", DataType::Data),
+];
@@ -2,6 +2,8 @@
 
 #![allow(dead_code)]
 
+use crate::DataType;
+
 pub const ACCOUNT: u32 = 1;
 pub const ADV_ID: u32 = 2;
 pub const ADV_REF_ID: u32 = 3;
@@ -914,3 +916,923 @@ pub const NESTED_3_PARTY_SUB_ID: u32 = 953;
 pub const NESTED_3_PARTY_SUB_ID_TYPE: u32 = 954;
 pub const LEG_CONTRACT_SETTL_MONTH: u32 = 955;
 pub const LEG_INTEREST_ACCRUAL_DATE: u32 = 956;
+
+/// All fields, as `(tag, name, data type)` triplets.
+pub const FIELDS: &[(u32, &str, DataType)] = &[
+    (1, "Account", DataType::String),
+    (2, "AdvId", DataType::String),
+    (3, "AdvRefID", DataType::String),
+    (4, "AdvSide", DataType::Char),
+    (5, "AdvTransType", DataType::String),
+    (6, "AvgPx", DataType::Price),
+    (7, "BeginSeqNo", DataType::Int),
+    (8, "BeginString", DataType::String),
+    (9, "BodyLength", DataType::Int),
+    (10, "CheckSum", DataType::String),
+    (11, "ClOrdID", DataType::String),
+    (12, "Commission", DataType::Amt),
+    (13, "CommType", DataType::Char),
+    (14, "CumQty", DataType::Qty),
+    (15, "Currency", DataType::Currency),
+    (16, "EndSeqNo", DataType::Int),
+    (17, "ExecID", DataType::String),
+    (18, "ExecInst", DataType::MultipleCharValue),
+    (19, "ExecRefID", DataType::String),
+    (21, "HandlInst", DataType::Char),
+    (22, "SecurityIDSource", DataType::String),
+    (23, "IOIID", DataType::String),
+    (25, "IOIQltyInd", DataType::Char),
+    (26, "IOIRefID", DataType::String),
+    (27, "IOIQty", DataType::String),
+    (28, "IOITransType", DataType::Char),
+    (29, "LastCapacity", DataType::Char),
+    (30, "LastMkt", DataType::Exchange),
+    (31, "LastPx", DataType::Price),
+    (32, "LastQty", DataType::Qty),
+    (33, "NoLinesOfText", DataType::NumInGroup),
+    (34, "MsgSeqNum", DataType::Int),
+    (35, "MsgType", DataType::String),
+    (36, "NewSeqNo", DataType::Int),
+    (37, "OrderID", DataType::String),
+    (38, "OrderQty", DataType::Qty),
+    (39, "OrdStatus", DataType::Char),
+    (40, "OrdType", DataType::Char),
+    (41, "OrigClOrdID", DataType::String),
+    (42, "OrigTime", DataType::UtcTimestamp),
+    (43, "PossDupFlag", DataType::Boolean),
+    (44, "Price", DataType::Price),
+    (45, "RefSeqNum", DataType::Int),
+    (48, "SecurityID", DataType::String),
+    (49, "SenderCompID", DataType::String),
+    (50, "SenderSubID", DataType::String),
+    (52, "SendingTime", DataType::UtcTimestamp),
+    (53, "Quantity", DataType::Qty),
+    (54, "Side", DataType::Char),
+    (55, "Symbol", DataType::String),
+    (56, "TargetCompID", DataType::String),
+    (57, "TargetSubID", DataType::String),
+    (58, "Text", DataType::String),
+    (59, "TimeInForce", DataType::Char),
+    (60, "TransactTime", DataType::UtcTimestamp),
+    (61, "Urgency", DataType::Char),
+    (62, "ValidUntilTime", DataType::UtcTimestamp),
+    (63, "SettlType", DataType::Char),
+    (64, "SettlDate", DataType::LocalMktDate),
+    (65, "SymbolSfx", DataType::String),
+    (66, "ListID", DataType::String),
+    (67, "ListSeqNo", DataType::Int),
+    (68, "TotNoOrders", DataType::Int),
+    (69, "ListExecInst", DataType::String),
+    (70, "AllocID", DataType::String),
+    (71, "AllocTransType", DataType::Char),
+    (72, "RefAllocID", DataType::String),
+    (73, "NoOrders", DataType::NumInGroup),
+    (74, "AvgPxPrecision", DataType::Int),
+    (75, "TradeDate", DataType::LocalMktDate),
+    (77, "PositionEffect", DataType::Char),
+    (78, "NoAllocs", DataType::NumInGroup),
+    (79, "AllocAccount", DataType::String),
+    (80, "AllocQty", DataType::Qty),
+    (81, "ProcessCode", DataType::Char),
+    (82, "NoRpts", DataType::Int),
+    (83, "RptSeq", DataType::Int),
+    (84, "CxlQty", DataType::Qty),
+    (85, "NoDlvyInst", DataType::NumInGroup),
+    (87, "AllocStatus", DataType::Int),
+    (88, "AllocRejCode", DataType::Int),
+    (89, "Signature", DataType::Data),
+    (90, "SecureDataLen", DataType::Int),
+    (91, "SecureData", DataType::Data),
+    (93, "SignatureLength", DataType::Int),
+    (94, "EmailType", DataType::Char),
+    (95, "RawDataLength", DataType::Int),
+    (96, "RawData", DataType::Data),
+    (97, "PossResend", DataType::Boolean),
+    (98, "EncryptMethod", DataType::Int),
+    (99, "StopPx", DataType::Price),
+    (100, "ExDestination", DataType::Exchange),
+    (102, "CxlRejReason", DataType::Int),
+    (103, "OrdRejReason", DataType::Int),
+    (104, "IOIQualifier", DataType::Char),
+    (106, "Issuer", DataType::String),
+    (107, "SecurityDesc", DataType::String),
+    (108, "HeartBtInt", DataType::Int),
+    (110, "MinQty", DataType::Qty),
+    (111, "MaxFloor", DataType::Qty),
+    (112, "TestReqID", DataType::String),
+    (113, "ReportToExch", DataType::Boolean),
+    (114, "LocateReqd", DataType::Boolean),
+    (115, "OnBehalfOfCompID", DataType::String),
+    (116, "OnBehalfOfSubID", DataType::String),
+    (117, "QuoteID", DataType::String),
+    (118, "NetMoney", DataType::Amt),
+    (119, "SettlCurrAmt", DataType::Amt),
+    (120, "SettlCurrency", DataType::Currency),
+    (121, "ForexReq", DataType::Boolean),
+    (122, "OrigSendingTime", DataType::UtcTimestamp),
+    (123, "GapFillFlag", DataType::Boolean),
+    (124, "NoExecs", DataType::NumInGroup),
+    (126, "ExpireTime", DataType::UtcTimestamp),
+    (127, "DKReason", DataType::Char),
+    (128, "DeliverToCompID", DataType::String),
+    (129, "DeliverToSubID", DataType::String),
+    (130, "IOINaturalFlag", DataType::Boolean),
+    (131, "QuoteReqID", DataType::String),
+    (132, "BidPx", DataType::Price),
+    (133, "OfferPx", DataType::Price),
+    (134, "BidSize", DataType::Qty),
+    (135, "OfferSize", DataType::Qty),
+    (136, "NoMiscFees", DataType::NumInGroup),
+    (137, "MiscFeeAmt", DataType::Amt),
+    (138, "MiscFeeCurr", DataType::Currency),
+    (139, "MiscFeeType", DataType::Char),
+    (140, "PrevClosePx", DataType::Price),
+    (141, "ResetSeqNumFlag", DataType::Boolean),
+    (142, "SenderLocationID", DataType::String),
+    (143, "TargetLocationID", DataType::String),
+    (144, "OnBehalfOfLocationID", DataType::String),
+    (145, "DeliverToLocationID", DataType::String),
+    (146, "NoRelatedSym", DataType::NumInGroup),
+    (147, "Subject", DataType::String),
+    (148, "Headline", DataType::String),
+    (149, "URLLink", DataType::String),
+    (150, "ExecType", DataType::Char),
+    (151, "LeavesQty", DataType::Qty),
+    (152, "CashOrderQty", DataType::Qty),
+    (153, "AllocAvgPx", DataType::Price),
+    (154, "AllocNetMoney", DataType::Amt),
+    (155, "SettlCurrFxRate", DataType::Float),
+    (156, "SettlCurrFxRateCalc", DataType::Char),
+    (157, "NumDaysInterest", DataType::Int),
+    (158, "AccruedInterestRate", DataType::Percentage),
+    (159, "AccruedInterestAmt", DataType::Amt),
+    (160, "SettlInstMode", DataType::Char),
+    (161, "AllocText", DataType::String),
+    (162, "SettlInstID", DataType::String),
+    (163, "SettlInstTransType", DataType::Char),
+    (164, "EmailThreadID", DataType::String),
+    (165, "SettlInstSource", DataType::Char),
+    (167, "SecurityType", DataType::String),
+    (168, "EffectiveTime", DataType::UtcTimestamp),
+    (169, "StandInstDbType", DataType::Int),
+    (170, "StandInstDbName", DataType::String),
+    (171, "StandInstDbID", DataType::String),
+    (172, "SettlDeliveryType", DataType::Int),
+    (188, "BidSpotRate", DataType::Price),
+    (189, "BidForwardPoints", DataType::PriceOffset),
+    (190, "OfferSpotRate", DataType::Price),
+    (191, "OfferForwardPoints", DataType::PriceOffset),
+    (192, "OrderQty2", DataType::Qty),
+    (193, "SettlDate2", DataType::LocalMktDate),
+    (194, "LastSpotRate", DataType::Price),
+    (195, "LastForwardPoints", DataType::PriceOffset),
+    (196, "AllocLinkID", DataType::String),
+    (197, "AllocLinkType", DataType::Int),
+    (198, "SecondaryOrderID", DataType::String),
+    (199, "NoIOIQualifiers", DataType::NumInGroup),
+    (200, "MaturityMonthYear", DataType::MonthYear),
+    (201, "PutOrCall", DataType::Int),
+    (202, "StrikePrice", DataType::Price),
+    (203, "CoveredOrUncovered", DataType::Int),
+    (206, "OptAttribute", DataType::Char),
+    (207, "SecurityExchange", DataType::Exchange),
+    (208, "NotifyBrokerOfCredit", DataType::Boolean),
+    (209, "AllocHandlInst", DataType::Int),
+    (210, "MaxShow", DataType::Qty),
+    (211, "PegOffsetValue", DataType::Float),
+    (212, "XmlDataLen", DataType::Int),
+    (213, "XmlData", DataType::Data),
+    (214, "SettlInstRefID", DataType::String),
+    (215, "NoRoutingIDs", DataType::NumInGroup),
+    (216, "RoutingType", DataType::Int),
+    (217, "RoutingID", DataType::String),
+    (218, "Spread", DataType::PriceOffset),
+    (220, "BenchmarkCurveCurrency", DataType::Currency),
+    (221, "BenchmarkCurveName", DataType::String),
+    (222, "BenchmarkCurvePoint", DataType::String),
+    (223, "CouponRate", DataType::Percentage),
+    (224, "CouponPaymentDate", DataType::LocalMktDate),
+    (225, "IssueDate", DataType::LocalMktDate),
+    (226, "RepurchaseTerm", DataType::Int),
+    (227, "RepurchaseRate", DataType::Percentage),
+    (228, "Factor", DataType::Float),
+    (229, "TradeOriginationDate", DataType::LocalMktDate),
+    (230, "ExDate", DataType::LocalMktDate),
+    (231, "ContractMultiplier", DataType::Float),
+    (232, "NoStipulations", DataType::NumInGroup),
+    (233, "StipulationType", DataType::String),
+    (234, "StipulationValue", DataType::String),
+    (235, "YieldType", DataType::String),
+    (236, "Yield", DataType::Percentage),
+    (237, "TotalTakedown", DataType::Amt),
+    (238, "Concession", DataType::Amt),
+    (239, "RepoCollateralSecurityType", DataType::String),
+    (240, "RedemptionDate", DataType::LocalMktDate),
+    (241, "UnderlyingCouponPaymentDate", DataType::LocalMktDate),
+    (242, "UnderlyingIssueDate", DataType::LocalMktDate),
+    (
+        243,
+        "UnderlyingRepoCollateralSecurityType",
+        DataType::String,
+    ),
+    (244, "UnderlyingRepurchaseTerm", DataType::Int),
+    (245, "UnderlyingRepurchaseRate", DataType::Percentage),
+    (246, "UnderlyingFactor", DataType::Float),
+    (247, "UnderlyingRedemptionDate", DataType::LocalMktDate),
+    (248, "LegCouponPaymentDate", DataType::LocalMktDate),
+    (249, "LegIssueDate", DataType::LocalMktDate),
+    (250, "LegRepoCollateralSecurityType", DataType::String),
+    (251, "LegRepurchaseTerm", DataType::Int),
+    (252, "LegRepurchaseRate", DataType::Percentage),
+    (253, "LegFactor", DataType::Float),
+    (254, "LegRedemptionDate", DataType::LocalMktDate),
+    (255, "CreditRating", DataType::String),
+    (256, "UnderlyingCreditRating", DataType::String),
+    (257, "LegCreditRating", DataType::String),
+    (258, "TradedFlatSwitch", DataType::Boolean),
+    (259, "BasisFeatureDate", DataType::LocalMktDate),
+    (260, "BasisFeaturePrice", DataType::Price),
+    (262, "MDReqID", DataType::String),
+    (263, "SubscriptionRequestType", DataType::Char),
+    (264, "MarketDepth", DataType::Int),
+    (265, "MDUpdateType", DataType::Int),
+    (266, "AggregatedBook", DataType::Boolean),
+    (267, "NoMDEntryTypes", DataType::NumInGroup),
+    (268, "NoMDEntries", DataType::NumInGroup),
+    (269, "MDEntryType", DataType::Char),
+    (270, "MDEntryPx", DataType::Price),
+    (271, "MDEntrySize", DataType::Qty),
+    (272, "MDEntryDate", DataType::UtcDateOnly),
+    (273, "MDEntryTime", DataType::UtcTimeOnly),
+    (274, "TickDirection", DataType::Char),
+    (275, "MDMkt", DataType::Exchange),
+    (276, "QuoteCondition", DataType::MultipleCharValue),
+    (277, "TradeCondition", DataType::MultipleCharValue),
+    (278, "MDEntryID", DataType::String),
+    (279, "MDUpdateAction", DataType::Char),
+    (280, "MDEntryRefID", DataType::String),
+    (281, "MDReqRejReason", DataType::Char),
+    (282, "MDEntryOriginator", DataType::String),
+    (283, "LocationID", DataType::String),
+    (284, "DeskID", DataType::String),
+    (285, "DeleteReason", DataType::Char),
+    (286, "OpenCloseSettlFlag", DataType::MultipleCharValue),
+    (287, "SellerDays", DataType::Int),
+    (288, "MDEntryBuyer", DataType::String),
+    (289, "MDEntrySeller", DataType::String),
+    (290, "MDEntryPositionNo", DataType::Int),
+    (291, "FinancialStatus", DataType::MultipleCharValue),
+    (292, "CorporateAction", DataType::MultipleCharValue),
+    (293, "DefBidSize", DataType::Qty),
+    (294, "DefOfferSize", DataType::Qty),
+    (295, "NoQuoteEntries", DataType::NumInGroup),
+    (296, "NoQuoteSets", DataType::NumInGroup),
+    (297, "QuoteStatus", DataType::Int),
+    (298, "QuoteCancelType", DataType::Int),
+    (299, "QuoteEntryID", DataType::String),
+    (300, "QuoteRejectReason", DataType::Int),
+    (301, "QuoteResponseLevel", DataType::Int),
+    (302, "QuoteSetID", DataType::String),
+    (303, "QuoteRequestType", DataType::Int),
+    (304, "TotNoQuoteEntries", DataType::Int),
+    (305, "UnderlyingSecurityIDSource", DataType::String),
+    (306, "UnderlyingIssuer", DataType::String),
+    (307, "UnderlyingSecurityDesc", DataType::String),
+    (308, "UnderlyingSecurityExchange", DataType::Exchange),
+    (309, "UnderlyingSecurityID", DataType::String),
+    (310, "UnderlyingSecurityType", DataType::String),
+    (311, "UnderlyingSymbol", DataType::String),
+    (312, "UnderlyingSymbolSfx", DataType::String),
+    (313, "UnderlyingMaturityMonthYear", DataType::MonthYear),
+    (315, "UnderlyingPutOrCall", DataType::Int),
+    (316, "UnderlyingStrikePrice", DataType::Price),
+    (317, "UnderlyingOptAttribute", DataType::Char),
+    (318, "UnderlyingCurrency", DataType::Currency),
+    (320, "SecurityReqID", DataType::String),
+    (321, "SecurityRequestType", DataType::Int),
+    (322, "SecurityResponseID", DataType::String),
+    (323, "SecurityResponseType", DataType::Int),
+    (324, "SecurityStatusReqID", DataType::String),
+    (325, "UnsolicitedIndicator", DataType::Boolean),
+    (326, "SecurityTradingStatus", DataType::Int),
+    (327, "HaltReasonChar", DataType::Char),
+    (328, "InViewOfCommon", DataType::Boolean),
+    (329, "DueToRelated", DataType::Boolean),
+    (330, "BuyVolume", DataType::Qty),
+    (331, "SellVolume", DataType::Qty),
+    (332, "HighPx", DataType::Price),
+    (333, "LowPx", DataType::Price),
+    (334, "Adjustment", DataType::Int),
+    (335, "TradSesReqID", DataType::String),
+    (336, "TradingSessionID", DataType::String),
+    (337, "ContraTrader", DataType::String),
+    (338, "TradSesMethod", DataType::Int),
+    (339, "TradSesMode", DataType::Int),
+    (340, "TradSesStatus", DataType::Int),
+    (341, "TradSesStartTime", DataType::UtcTimestamp),
+    (342, "TradSesOpenTime", DataType::UtcTimestamp),
+    (343, "TradSesPreCloseTime", DataType::UtcTimestamp),
+    (344, "TradSesCloseTime", DataType::UtcTimestamp),
+    (345, "TradSesEndTime", DataType::UtcTimestamp),
+    (346, "NumberOfOrders", DataType::Int),
+    (347, "MessageEncoding", DataType::String),
+    (348, "EncodedIssuerLen", DataType::Int),
+    (349, "EncodedIssuer", DataType::Data),
+    (350, "EncodedSecurityDescLen", DataType::Int),
+    (351, "EncodedSecurityDesc", DataType::Data),
+    (352, "EncodedListExecInstLen", DataType::Int),
+    (353, "EncodedListExecInst", DataType::Data),
+    (354, "EncodedTextLen", DataType::Int),
+    (355, "EncodedText", DataType::Data),
+    (356, "EncodedSubjectLen", DataType::Int),
+    (357, "EncodedSubject", DataType::Data),
+    (358, "EncodedHeadlineLen", DataType::Int),
+    (359, "EncodedHeadline", DataType::Data),
+    (360, "EncodedAllocTextLen", DataType::Int),
+    (361, "EncodedAllocText", DataType::Data),
+    (362, "EncodedUnderlyingIssuerLen", DataType::Int),
+    (363, "EncodedUnderlyingIssuer", DataType::Data),
+    (364, "EncodedUnderlyingSecurityDescLen", DataType::Int),
+    (365, "EncodedUnderlyingSecurityDesc", DataType::Data),
+    (366, "AllocPrice", DataType::Price),
+    (367, "QuoteSetValidUntilTime", DataType::UtcTimestamp),
+    (368, "QuoteEntryRejectReason", DataType::Int),
+    (369, "LastMsgSeqNumProcessed", DataType::Int),
+    (371, "RefTagID", DataType::Int),
+    (372, "RefMsgType", DataType::String),
+    (373, "SessionRejectReason", DataType::Int),
+    (374, "BidRequestTransType", DataType::Char),
+    (375, "ContraBroker", DataType::String),
+    (376, "ComplianceID", DataType::String),
+    (377, "SolicitedFlag", DataType::Boolean),
+    (378, "ExecRestatementReason", DataType::Int),
+    (379, "BusinessRejectRefID", DataType::String),
+    (380, "BusinessRejectReason", DataType::Int),
+    (381, "GrossTradeAmt", DataType::Amt),
+    (382, "NoContraBrokers", DataType::NumInGroup),
+    (383, "MaxMessageSize", DataType::Int),
+    (384, "NoMsgTypes", DataType::NumInGroup),
+    (385, "MsgDirection", DataType::Char),
+    (386, "NoTradingSessions", DataType::NumInGroup),
+    (387, "TotalVolumeTraded", DataType::Qty),
+    (388, "DiscretionInst", DataType::Char),
+    (389, "DiscretionOffsetValue", DataType::Float),
+    (390, "BidID", DataType::String),
+    (391, "ClientBidID", DataType::String),
+    (392, "ListName", DataType::String),
+    (393, "TotNoRelatedSym", DataType::Int),
+    (394, "BidType", DataType::Int),
+    (395, "NumTickets", DataType::Int),
+    (396, "SideValue1", DataType::Amt),
+    (397, "SideValue2", DataType::Amt),
+    (398, "NoBidDescriptors", DataType::NumInGroup),
+    (399, "BidDescriptorType", DataType::Int),
+    (400, "BidDescriptor", DataType::String),
+    (401, "SideValueInd", DataType::Int),
+    (402, "LiquidityPctLow", DataType::Percentage),
+    (403, "LiquidityPctHigh", DataType::Percentage),
+    (404, "LiquidityValue", DataType::Amt),
+    (405, "EFPTrackingError", DataType::Percentage),
+    (406, "FairValue", DataType::Amt),
+    (407, "OutsideIndexPct", DataType::Percentage),
+    (408, "ValueOfFutures", DataType::Amt),
+    (409, "LiquidityIndType", DataType::Int),
+    (410, "WtAverageLiquidity", DataType::Percentage),
+    (411, "ExchangeForPhysical", DataType::Boolean),
+    (412, "OutMainCntryUIndex", DataType::Amt),
+    (413, "CrossPercent", DataType::Percentage),
+    (414, "ProgRptReqs", DataType::Int),
+    (415, "ProgPeriodInterval", DataType::Int),
+    (416, "IncTaxInd", DataType::Int),
+    (417, "NumBidders", DataType::Int),
+    (418, "BidTradeType", DataType::Char),
+    (419, "BasisPxType", DataType::Char),
+    (420, "NoBidComponents", DataType::NumInGroup),
+    (421, "Country", DataType::Country),
+    (422, "TotNoStrikes", DataType::Int),
+    (423, "PriceType", DataType::Int),
+    (424, "DayOrderQty", DataType::Qty),
+    (425, "DayCumQty", DataType::Qty),
+    (426, "DayAvgPx", DataType::Price),
+    (427, "GTBookingInst", DataType::Int),
+    (428, "NoStrikes", DataType::NumInGroup),
+    (429, "ListStatusType", DataType::Int),
+    (430, "NetGrossInd", DataType::Int),
+    (431, "ListOrderStatus", DataType::Int),
+    (432, "ExpireDate", DataType::LocalMktDate),
+    (433, "ListExecInstType", DataType::Char),
+    (434, "CxlRejResponseTo", DataType::Char),
+    (435, "UnderlyingCouponRate", DataType::Percentage),
+    (436, "UnderlyingContractMultiplier", DataType::Float),
+    (437, "ContraTradeQty", DataType::Qty),
+    (438, "ContraTradeTime", DataType::UtcTimestamp),
+    (441, "LiquidityNumSecurities", DataType::Int),
+    (442, "MultiLegReportingType", DataType::Char),
+    (443, "StrikeTime", DataType::UtcTimestamp),
+    (444, "ListStatusText", DataType::String),
+    (445, "EncodedListStatusTextLen", DataType::Int),
+    (446, "EncodedListStatusText", DataType::Data),
+    (447, "PartyIDSource", DataType::Char),
+    (448, "PartyID", DataType::String),
+    (451, "NetChgPrevDay", DataType::PriceOffset),
+    (452, "PartyRole", DataType::Int),
+    (453, "NoPartyIDs", DataType::NumInGroup),
+    (454, "NoSecurityAltID", DataType::NumInGroup),
+    (455, "SecurityAltID", DataType::String),
+    (456, "SecurityAltIDSource", DataType::String),
+    (457, "NoUnderlyingSecurityAltID", DataType::NumInGroup),
+    (458, "UnderlyingSecurityAltID", DataType::String),
+    (459, "UnderlyingSecurityAltIDSource", DataType::String),
+    (460, "Product", DataType::Int),
+    (461, "CFICode", DataType::String),
+    (462, "UnderlyingProduct", DataType::Int),
+    (463, "UnderlyingCFICode", DataType::String),
+    (464, "TestMessageIndicator", DataType::Boolean),
+    (466, "BookingRefID", DataType::String),
+    (467, "IndividualAllocID", DataType::String),
+    (468, "RoundingDirection", DataType::Char),
+    (469, "RoundingModulus", DataType::Float),
+    (470, "CountryOfIssue", DataType::Country),
+    (471, "StateOrProvinceOfIssue", DataType::String),
+    (472, "LocaleOfIssue", DataType::String),
+    (473, "NoRegistDtls", DataType::NumInGroup),
+    (474, "MailingDtls", DataType::String),
+    (475, "InvestorCountryOfResidence", DataType::Country),
+    (476, "PaymentRef", DataType::String),
+    (477, "DistribPaymentMethod", DataType::Int),
+    (478, "CashDistribCurr", DataType::Currency),
+    (479, "CommCurrency", DataType::Currency),
+    (480, "CancellationRights", DataType::Char),
+    (481, "MoneyLaunderingStatus", DataType::Char),
+    (482, "MailingInst", DataType::String),
+    (483, "TransBkdTime", DataType::UtcTimestamp),
+    (484, "ExecPriceType", DataType::Char),
+    (485, "ExecPriceAdjustment", DataType::Float),
+    (486, "DateOfBirth", DataType::LocalMktDate),
+    (487, "TradeReportTransType", DataType::Int),
+    (488, "CardHolderName", DataType::String),
+    (489, "CardNumber", DataType::String),
+    (490, "CardExpDate", DataType::LocalMktDate),
+    (491, "CardIssNum", DataType::String),
+    (492, "PaymentMethod", DataType::Int),
+    (493, "RegistAcctType", DataType::String),
+    (494, "Designation", DataType::String),
+    (495, "TaxAdvantageType", DataType::Int),
+    (496, "RegistRejReasonText", DataType::String),
+    (497, "FundRenewWaiv", DataType::Char),
+    (498, "CashDistribAgentName", DataType::String),
+    (499, "CashDistribAgentCode", DataType::String),
+    (500, "CashDistribAgentAcctNumber", DataType::String),
+    (501, "CashDistribPayRef", DataType::String),
+    (502, "CashDistribAgentAcctName", DataType::String),
+    (503, "CardStartDate", DataType::LocalMktDate),
+    (504, "PaymentDate", DataType::LocalMktDate),
+    (505, "PaymentRemitterID", DataType::String),
+    (506, "RegistStatus", DataType::Char),
+    (507, "RegistRejReasonCode", DataType::Int),
+    (508, "RegistRefID", DataType::String),
+    (509, "RegistDtls", DataType::String),
+    (510, "NoDistribInsts", DataType::NumInGroup),
+    (511, "RegistEmail", DataType::String),
+    (512, "DistribPercentage", DataType::Percentage),
+    (513, "RegistID", DataType::String),
+    (514, "RegistTransType", DataType::Char),
+    (515, "ExecValuationPoint", DataType::UtcTimestamp),
+    (516, "OrderPercent", DataType::Percentage),
+    (517, "OwnershipType", DataType::Char),
+    (518, "NoContAmts", DataType::NumInGroup),
+    (519, "ContAmtType", DataType::Int),
+    (520, "ContAmtValue", DataType::Float),
+    (521, "ContAmtCurr", DataType::Currency),
+    (522, "OwnerType", DataType::Int),
+    (523, "PartySubID", DataType::String),
+    (524, "NestedPartyID", DataType::String),
+    (525, "NestedPartyIDSource", DataType::Char),
+    (526, "SecondaryClOrdID", DataType::String),
+    (527, "SecondaryExecID", DataType::String),
+    (528, "OrderCapacity", DataType::Char),
+    (529, "OrderRestrictions", DataType::MultipleCharValue),
+    (530, "MassCancelRequestType", DataType::Char),
+    (531, "MassCancelResponse", DataType::Char),
+    (532, "MassCancelRejectReason", DataType::Char),
+    (533, "TotalAffectedOrders", DataType::Int),
+    (534, "NoAffectedOrders", DataType::NumInGroup),
+    (535, "AffectedOrderID", DataType::String),
+    (536, "AffectedSecondaryOrderID", DataType::String),
+    (537, "QuoteType", DataType::Int),
+    (538, "NestedPartyRole", DataType::Int),
+    (539, "NoNestedPartyIDs", DataType::NumInGroup),
+    (540, "TotalAccruedInterestAmt", DataType::Amt),
+    (541, "MaturityDate", DataType::LocalMktDate),
+    (542, "UnderlyingMaturityDate", DataType::LocalMktDate),
+    (543, "InstrRegistry", DataType::String),
+    (544, "CashMargin", DataType::Char),
+    (545, "NestedPartySubID", DataType::String),
+    (546, "Scope", DataType::MultipleCharValue),
+    (547, "MDImplicitDelete", DataType::Boolean),
+    (548, "CrossID", DataType::String),
+    (549, "CrossType", DataType::Int),
+    (550, "CrossPrioritization", DataType::Int),
+    (551, "OrigCrossID", DataType::String),
+    (552, "NoSides", DataType::NumInGroup),
+    (553, "Username", DataType::String),
+    (554, "Password", DataType::String),
+    (555, "NoLegs", DataType::NumInGroup),
+    (556, "LegCurrency", DataType::Currency),
+    (557, "TotNoSecurityTypes", DataType::Int),
+    (558, "NoSecurityTypes", DataType::NumInGroup),
+    (559, "SecurityListRequestType", DataType::Int),
+    (560, "SecurityRequestResult", DataType::Int),
+    (561, "RoundLot", DataType::Qty),
+    (562, "MinTradeVol", DataType::Qty),
+    (563, "MultiLegRptTypeReq", DataType::Int),
+    (564, "LegPositionEffect", DataType::Char),
+    (565, "LegCoveredOrUncovered", DataType::Int),
+    (566, "LegPrice", DataType::Price),
+    (567, "TradSesStatusRejReason", DataType::Int),
+    (568, "TradeRequestID", DataType::String),
+    (569, "TradeRequestType", DataType::Int),
+    (570, "PreviouslyReported", DataType::Boolean),
+    (571, "TradeReportID", DataType::String),
+    (572, "TradeReportRefID", DataType::String),
+    (573, "MatchStatus", DataType::Char),
+    (574, "MatchType", DataType::String),
+    (575, "OddLot", DataType::Boolean),
+    (576, "NoClearingInstructions", DataType::NumInGroup),
+    (577, "ClearingInstruction", DataType::Int),
+    (578, "TradeInputSource", DataType::String),
+    (579, "TradeInputDevice", DataType::String),
+    (580, "NoDates", DataType::NumInGroup),
+    (581, "AccountType", DataType::Int),
+    (582, "CustOrderCapacity", DataType::Int),
+    (583, "ClOrdLinkID", DataType::String),
+    (584, "MassStatusReqID", DataType::String),
+    (585, "MassStatusReqType", DataType::Int),
+    (586, "OrigOrdModTime", DataType::UtcTimestamp),
+    (587, "LegSettlType", DataType::Char),
+    (588, "LegSettlDate", DataType::LocalMktDate),
+    (589, "DayBookingInst", DataType::Char),
+    (590, "BookingUnit", DataType::Char),
+    (591, "PreallocMethod", DataType::Char),
+    (592, "UnderlyingCountryOfIssue", DataType::Country),
+    (593, "UnderlyingStateOrProvinceOfIssue", DataType::String),
+    (594, "UnderlyingLocaleOfIssue", DataType::String),
+    (595, "UnderlyingInstrRegistry", DataType::String),
+    (596, "LegCountryOfIssue", DataType::Country),
+    (597, "LegStateOrProvinceOfIssue", DataType::String),
+    (598, "LegLocaleOfIssue", DataType::String),
+    (599, "LegInstrRegistry", DataType::String),
+    (600, "LegSymbol", DataType::String),
+    (601, "LegSymbolSfx", DataType::String),
+    (602, "LegSecurityID", DataType::String),
+    (603, "LegSecurityIDSource", DataType::String),
+    (604, "NoLegSecurityAltID", DataType::NumInGroup),
+    (605, "LegSecurityAltID", DataType::String),
+    (606, "LegSecurityAltIDSource", DataType::String),
+    (607, "LegProduct", DataType::Int),
+    (608, "LegCFICode", DataType::String),
+    (609, "LegSecurityType", DataType::String),
+    (610, "LegMaturityMonthYear", DataType::MonthYear),
+    (611, "LegMaturityDate", DataType::LocalMktDate),
+    (612, "LegStrikePrice", DataType::Price),
+    (613, "LegOptAttribute", DataType::Char),
+    (614, "LegContractMultiplier", DataType::Float),
+    (615, "LegCouponRate", DataType::Percentage),
+    (616, "LegSecurityExchange", DataType::Exchange),
+    (617, "LegIssuer", DataType::String),
+    (618, "EncodedLegIssuerLen", DataType::Int),
+    (619, "EncodedLegIssuer", DataType::Data),
+    (620, "LegSecurityDesc", DataType::String),
+    (621, "EncodedLegSecurityDescLen", DataType::Int),
+    (622, "EncodedLegSecurityDesc", DataType::Data),
+    (623, "LegRatioQty", DataType::Float),
+    (624, "LegSide", DataType::Char),
+    (625, "TradingSessionSubID", DataType::String),
+    (626, "AllocType", DataType::Int),
+    (627, "NoHops", DataType::NumInGroup),
+    (628, "HopCompID", DataType::String),
+    (629, "HopSendingTime", DataType::UtcTimestamp),
+    (630, "HopRefID", DataType::Int),
+    (631, "MidPx", DataType::Price),
+    (632, "BidYield", DataType::Percentage),
+    (633, "MidYield", DataType::Percentage),
+    (634, "OfferYield", DataType::Percentage),
+    (635, "ClearingFeeIndicator", DataType::String),
+    (636, "WorkingIndicator", DataType::Boolean),
+    (637, "LegLastPx", DataType::Price),
+    (638, "PriorityIndicator", DataType::Int),
+    (639, "PriceImprovement", DataType::PriceOffset),
+    (640, "Price2", DataType::Price),
+    (641, "LastForwardPoints2", DataType::PriceOffset),
+    (642, "BidForwardPoints2", DataType::PriceOffset),
+    (643, "OfferForwardPoints2", DataType::PriceOffset),
+    (644, "RFQReqID", DataType::String),
+    (645, "MktBidPx", DataType::Price),
+    (646, "MktOfferPx", DataType::Price),
+    (647, "MinBidSize", DataType::Qty),
+    (648, "MinOfferSize", DataType::Qty),
+    (649, "QuoteStatusReqID", DataType::String),
+    (650, "LegalConfirm", DataType::Boolean),
+    (651, "UnderlyingLastPx", DataType::Price),
+    (652, "UnderlyingLastQty", DataType::Qty),
+    (654, "LegRefID", DataType::String),
+    (655, "ContraLegRefID", DataType::String),
+    (656, "SettlCurrBidFxRate", DataType::Float),
+    (657, "SettlCurrOfferFxRate", DataType::Float),
+    (658, "QuoteRequestRejectReason", DataType::Int),
+    (659, "SideComplianceID", DataType::String),
+    (660, "AcctIDSource", DataType::Int),
+    (661, "AllocAcctIDSource", DataType::Int),
+    (662, "BenchmarkPrice", DataType::Price),
+    (663, "BenchmarkPriceType", DataType::Int),
+    (664, "ConfirmID", DataType::String),
+    (665, "ConfirmStatus", DataType::Int),
+    (666, "ConfirmTransType", DataType::Int),
+    (667, "ContractSettlMonth", DataType::MonthYear),
+    (668, "DeliveryForm", DataType::Int),
+    (669, "LastParPx", DataType::Price),
+    (670, "NoLegAllocs", DataType::NumInGroup),
+    (671, "LegAllocAccount", DataType::String),
+    (672, "LegIndividualAllocID", DataType::String),
+    (673, "LegAllocQty", DataType::Qty),
+    (674, "LegAllocAcctIDSource", DataType::String),
+    (675, "LegSettlCurrency", DataType::Currency),
+    (676, "LegBenchmarkCurveCurrency", DataType::Currency),
+    (677, "LegBenchmarkCurveName", DataType::String),
+    (678, "LegBenchmarkCurvePoint", DataType::String),
+    (679, "LegBenchmarkPrice", DataType::Price),
+    (680, "LegBenchmarkPriceType", DataType::Int),
+    (681, "LegBidPx", DataType::Price),
+    (682, "LegIOIQty", DataType::String),
+    (683, "NoLegStipulations", DataType::NumInGroup),
+    (684, "LegOfferPx", DataType::Price),
+    (686, "LegPriceType", DataType::Int),
+    (687, "LegQty", DataType::Qty),
+    (688, "LegStipulationType", DataType::String),
+    (689, "LegStipulationValue", DataType::String),
+    (690, "LegSwapType", DataType::Int),
+    (691, "Pool", DataType::String),
+    (692, "QuotePriceType", DataType::Int),
+    (693, "QuoteRespID", DataType::String),
+    (694, "QuoteRespType", DataType::Int),
+    (695, "QuoteQualifier", DataType::Char),
+    (696, "YieldRedemptionDate", DataType::LocalMktDate),
+    (697, "YieldRedemptionPrice", DataType::Price),
+    (698, "YieldRedemptionPriceType", DataType::Int),
+    (699, "BenchmarkSecurityID", DataType::String),
+    (700, "ReversalIndicator", DataType::Boolean),
+    (701, "YieldCalcDate", DataType::LocalMktDate),
+    (702, "NoPositions", DataType::NumInGroup),
+    (703, "PosType", DataType::String),
+    (704, "LongQty", DataType::Qty),
+    (705, "ShortQty", DataType::Qty),
+    (706, "PosQtyStatus", DataType::Int),
+    (707, "PosAmtType", DataType::String),
+    (708, "PosAmt", DataType::Amt),
+    (709, "PosTransType", DataType::Int),
+    (710, "PosReqID", DataType::String),
+    (711, "NoUnderlyings", DataType::NumInGroup),
+    (712, "PosMaintAction", DataType::Int),
+    (713, "OrigPosReqRefID", DataType::String),
+    (714, "PosMaintRptRefID", DataType::String),
+    (715, "ClearingBusinessDate", DataType::LocalMktDate),
+    (716, "SettlSessID", DataType::String),
+    (717, "SettlSessSubID", DataType::String),
+    (718, "AdjustmentType", DataType::Int),
+    (719, "ContraryInstructionIndicator", DataType::Boolean),
+    (720, "PriorSpreadIndicator", DataType::Boolean),
+    (721, "PosMaintRptID", DataType::String),
+    (722, "PosMaintStatus", DataType::Int),
+    (723, "PosMaintResult", DataType::Int),
+    (724, "PosReqType", DataType::Int),
+    (725, "ResponseTransportType", DataType::Int),
+    (726, "ResponseDestination", DataType::String),
+    (727, "TotalNumPosReports", DataType::Int),
+    (728, "PosReqResult", DataType::Int),
+    (729, "PosReqStatus", DataType::Int),
+    (730, "SettlPrice", DataType::Price),
+    (731, "SettlPriceType", DataType::Int),
+    (732, "UnderlyingSettlPrice", DataType::Price),
+    (733, "UnderlyingSettlPriceType", DataType::Int),
+    (734, "PriorSettlPrice", DataType::Price),
+    (735, "NoQuoteQualifiers", DataType::NumInGroup),
+    (736, "AllocSettlCurrency", DataType::Currency),
+    (737, "AllocSettlCurrAmt", DataType::Amt),
+    (738, "InterestAtMaturity", DataType::Amt),
+    (739, "LegDatedDate", DataType::LocalMktDate),
+    (740, "LegPool", DataType::String),
+    (741, "AllocInterestAtMaturity", DataType::Amt),
+    (742, "AllocAccruedInterestAmt", DataType::Amt),
+    (743, "DeliveryDate", DataType::LocalMktDate),
+    (744, "AssignmentMethod", DataType::Char),
+    (745, "AssignmentUnit", DataType::Qty),
+    (746, "OpenInterest", DataType::Amt),
+    (747, "ExerciseMethod", DataType::Char),
+    (748, "TotNumTradeReports", DataType::Int),
+    (749, "TradeRequestResult", DataType::Int),
+    (750, "TradeRequestStatus", DataType::Int),
+    (751, "TradeReportRejectReason", DataType::Int),
+    (752, "SideMultiLegReportingType", DataType::Int),
+    (753, "NoPosAmt", DataType::NumInGroup),
+    (754, "AutoAcceptIndicator", DataType::Boolean),
+    (755, "AllocReportID", DataType::String),
+    (756, "NoNested2PartyIDs", DataType::NumInGroup),
+    (757, "Nested2PartyID", DataType::String),
+    (758, "Nested2PartyIDSource", DataType::Char),
+    (759, "Nested2PartyRole", DataType::Int),
+    (760, "Nested2PartySubID", DataType::String),
+    (761, "BenchmarkSecurityIDSource", DataType::String),
+    (762, "SecuritySubType", DataType::String),
+    (763, "UnderlyingSecuritySubType", DataType::String),
+    (764, "LegSecuritySubType", DataType::String),
+    (765, "AllowableOneSidednessPct", DataType::Percentage),
+    (766, "AllowableOneSidednessValue", DataType::Amt),
+    (767, "AllowableOneSidednessCurr", DataType::Currency),
+    (768, "NoTrdRegTimestamps", DataType::NumInGroup),
+    (769, "TrdRegTimestamp", DataType::UtcTimestamp),
+    (770, "TrdRegTimestampType", DataType::Int),
+    (771, "TrdRegTimestampOrigin", DataType::String),
+    (772, "ConfirmRefID", DataType::String),
+    (773, "ConfirmType", DataType::Int),
+    (774, "ConfirmRejReason", DataType::Int),
+    (775, "BookingType", DataType::Int),
+    (776, "IndividualAllocRejCode", DataType::Int),
+    (777, "SettlInstMsgID", DataType::String),
+    (778, "NoSettlInst", DataType::NumInGroup),
+    (779, "LastUpdateTime", DataType::UtcTimestamp),
+    (780, "AllocSettlInstType", DataType::Int),
+    (781, "NoSettlPartyIDs", DataType::NumInGroup),
+    (782, "SettlPartyID", DataType::String),
+    (783, "SettlPartyIDSource", DataType::Char),
+    (784, "SettlPartyRole", DataType::Int),
+    (785, "SettlPartySubID", DataType::String),
+    (786, "SettlPartySubIDType", DataType::Int),
+    (787, "DlvyInstType", DataType::Char),
+    (788, "TerminationType", DataType::Int),
+    (789, "NextExpectedMsgSeqNum", DataType::Int),
+    (790, "OrdStatusReqID", DataType::String),
+    (791, "SettlInstReqID", DataType::String),
+    (792, "SettlInstReqRejCode", DataType::Int),
+    (793, "SecondaryAllocID", DataType::String),
+    (794, "AllocReportType", DataType::Int),
+    (795, "AllocReportRefID", DataType::String),
+    (796, "AllocCancReplaceReason", DataType::Int),
+    (797, "CopyMsgIndicator", DataType::Boolean),
+    (798, "AllocAccountType", DataType::Int),
+    (799, "OrderAvgPx", DataType::Price),
+    (800, "OrderBookingQty", DataType::Qty),
+    (801, "NoSettlPartySubIDs", DataType::NumInGroup),
+    (802, "NoPartySubIDs", DataType::NumInGroup),
+    (803, "PartySubIDType", DataType::Int),
+    (804, "NoNestedPartySubIDs", DataType::NumInGroup),
+    (805, "NestedPartySubIDType", DataType::Int),
+    (806, "NoNested2PartySubIDs", DataType::NumInGroup),
+    (807, "Nested2PartySubIDType", DataType::Int),
+    (808, "AllocIntermedReqType", DataType::Int),
+    (810, "UnderlyingPx", DataType::Price),
+    (811, "PriceDelta", DataType::Float),
+    (812, "ApplQueueMax", DataType::Int),
+    (813, "ApplQueueDepth", DataType::Int),
+    (814, "ApplQueueResolution", DataType::Int),
+    (815, "ApplQueueAction", DataType::Int),
+    (816, "NoAltMDSource", DataType::NumInGroup),
+    (817, "AltMDSourceID", DataType::String),
+    (818, "SecondaryTradeReportID", DataType::String),
+    (819, "AvgPxIndicator", DataType::Int),
+    (820, "TradeLinkID", DataType::String),
+    (821, "OrderInputDevice", DataType::String),
+    (822, "UnderlyingTradingSessionID", DataType::String),
+    (823, "UnderlyingTradingSessionSubID", DataType::String),
+    (824, "TradeLegRefID", DataType::String),
+    (825, "ExchangeRule", DataType::String),
+    (826, "TradeAllocIndicator", DataType::Int),
+    (827, "ExpirationCycle", DataType::Int),
+    (828, "TrdType", DataType::Int),
+    (829, "TrdSubType", DataType::Int),
+    (830, "TransferReason", DataType::String),
+    (832, "TotNumAssignmentReports", DataType::Int),
+    (833, "AsgnRptID", DataType::String),
+    (834, "ThresholdAmount", DataType::PriceOffset),
+    (835, "PegMoveType", DataType::Int),
+    (836, "PegOffsetType", DataType::Int),
+    (837, "PegLimitType", DataType::Int),
+    (838, "PegRoundDirection", DataType::Int),
+    (839, "PeggedPrice", DataType::Price),
+    (840, "PegScope", DataType::Int),
+    (841, "DiscretionMoveType", DataType::Int),
+    (842, "DiscretionOffsetType", DataType::Int),
+    (843, "DiscretionLimitType", DataType::Int),
+    (844, "DiscretionRoundDirection", DataType::Int),
+    (845, "DiscretionPrice", DataType::Price),
+    (846, "DiscretionScope", DataType::Int),
+    (847, "TargetStrategy", DataType::Int),
+    (848, "TargetStrategyParameters", DataType::String),
+    (849, "ParticipationRate", DataType::Percentage),
+    (850, "TargetStrategyPerformance", DataType::Float),
+    (851, "LastLiquidityInd", DataType::Int),
+    (852, "PublishTrdIndicator", DataType::Boolean),
+    (853, "ShortSaleReason", DataType::Int),
+    (854, "QtyType", DataType::Int),
+    (855, "SecondaryTrdType", DataType::Int),
+    (856, "TradeReportType", DataType::Int),
+    (857, "AllocNoOrdersType", DataType::Int),
+    (858, "SharedCommission", DataType::Amt),
+    (859, "ConfirmReqID", DataType::String),
+    (860, "AvgParPx", DataType::Price),
+    (861, "ReportedPx", DataType::Price),
+    (862, "NoCapacities", DataType::NumInGroup),
+    (863, "OrderCapacityQty", DataType::Qty),
+    (864, "NoEvents", DataType::NumInGroup),
+    (865, "EventType", DataType::Int),
+    (866, "EventDate", DataType::LocalMktDate),
+    (867, "EventPx", DataType::Price),
+    (868, "EventText", DataType::String),
+    (869, "PctAtRisk", DataType::Percentage),
+    (870, "NoInstrAttrib", DataType::NumInGroup),
+    (871, "InstrAttribType", DataType::Int),
+    (872, "InstrAttribValue", DataType::String),
+    (873, "DatedDate", DataType::LocalMktDate),
+    (874, "InterestAccrualDate", DataType::LocalMktDate),
+    (875, "CPProgram", DataType::Int),
+    (876, "CPRegType", DataType::String),
+    (877, "UnderlyingCPProgram", DataType::String),
+    (878, "UnderlyingCPRegType", DataType::String),
+    (879, "UnderlyingQty", DataType::Qty),
+    (880, "TrdMatchID", DataType::String),
+    (881, "SecondaryTradeReportRefID", DataType::String),
+    (882, "UnderlyingDirtyPrice", DataType::Price),
+    (883, "UnderlyingEndPrice", DataType::Price),
+    (884, "UnderlyingStartValue", DataType::Amt),
+    (885, "UnderlyingCurrentValue", DataType::Amt),
+    (886, "UnderlyingEndValue", DataType::Amt),
+    (887, "NoUnderlyingStips", DataType::NumInGroup),
+    (888, "UnderlyingStipType", DataType::String),
+    (889, "UnderlyingStipValue", DataType::String),
+    (890, "MaturityNetMoney", DataType::Amt),
+    (891, "MiscFeeBasis", DataType::Int),
+    (892, "TotNoAllocs", DataType::Int),
+    (893, "LastFragment", DataType::Boolean),
+    (894, "CollReqID", DataType::String),
+    (895, "CollAsgnReason", DataType::Int),
+    (896, "CollInquiryQualifier", DataType::Int),
+    (897, "NoTrades", DataType::NumInGroup),
+    (898, "MarginRatio", DataType::Percentage),
+    (899, "MarginExcess", DataType::Amt),
+    (900, "TotalNetValue", DataType::Amt),
+    (901, "CashOutstanding", DataType::Amt),
+    (902, "CollAsgnID", DataType::String),
+    (903, "CollAsgnTransType", DataType::Int),
+    (904, "CollRespID", DataType::String),
+    (905, "CollAsgnRespType", DataType::Int),
+    (906, "CollAsgnRejectReason", DataType::Int),
+    (907, "CollAsgnRefID", DataType::String),
+    (908, "CollRptID", DataType::String),
+    (909, "CollInquiryID", DataType::String),
+    (910, "CollStatus", DataType::Int),
+    (911, "TotNumReports", DataType::Int),
+    (912, "LastRptRequested", DataType::Boolean),
+    (913, "AgreementDesc", DataType::String),
+    (914, "AgreementID", DataType::String),
+    (915, "AgreementDate", DataType::LocalMktDate),
+    (916, "StartDate", DataType::LocalMktDate),
+    (917, "EndDate", DataType::LocalMktDate),
+    (918, "AgreementCurrency", DataType::Currency),
+    (919, "DeliveryType", DataType::Int),
+    (920, "EndAccruedInterestAmt", DataType::Amt),
+    (921, "StartCash", DataType::Amt),
+    (922, "EndCash", DataType::Amt),
+    (923, "UserRequestID", DataType::String),
+    (924, "UserRequestType", DataType::Int),
+    (925, "NewPassword", DataType::String),
+    (926, "UserStatus", DataType::Int),
+    (927, "UserStatusText", DataType::String),
+    (928, "StatusValue", DataType::Int),
+    (929, "StatusText", DataType::String),
+    (930, "RefCompID", DataType::String),
+    (931, "RefSubID", DataType::String),
+    (932, "NetworkResponseID", DataType::String),
+    (933, "NetworkRequestID", DataType::String),
+    (934, "LastNetworkResponseID", DataType::String),
+    (935, "NetworkRequestType", DataType::Int),
+    (936, "NoCompIDs", DataType::NumInGroup),
+    (937, "NetworkStatusResponseType", DataType::Int),
+    (938, "NoCollInquiryQualifier", DataType::NumInGroup),
+    (939, "TrdRptStatus", DataType::Int),
+    (940, "AffirmStatus", DataType::Int),
+    (941, "UnderlyingStrikeCurrency", DataType::Currency),
+    (942, "LegStrikeCurrency", DataType::Currency),
+    (943, "TimeBracket", DataType::String),
+    (944, "CollAction", DataType::Int),
+    (945, "CollInquiryStatus", DataType::Int),
+    (946, "CollInquiryResult", DataType::Int),
+    (947, "StrikeCurrency", DataType::Currency),
+    (948, "NoNested3PartyIDs", DataType::NumInGroup),
+    (949, "Nested3PartyID", DataType::String),
+    (950, "Nested3PartyIDSource", DataType::Char),
+    (951, "Nested3PartyRole", DataType::Int),
+    (952, "NoNested3PartySubIDs", DataType::NumInGroup),
+    (953, "Nested3PartySubID", DataType::String),
+    (954, "Nested3PartySubIDType", DataType::Int),
+    (955, "LegContractSettlMonth", DataType::MonthYear),
+    (956, "LegInterestAccrualDate", DataType::LocalMktDate),
+];
@@ -2,6 +2,8 @@
 
 #![allow(dead_code)]
 
+use crate::DataType;
+
 pub const BEGIN_SEQ_NO: u32 = 7;
 pub const BEGIN_STRING: u32 = 8;
 pub const BODY_LENGTH: u32 = 9;
@@ -64,3 +66,69 @@ pub const CSTM_APPL_VER_ID: u32 = 1129;
 pub const REF_APPL_VER_ID: u32 = 1130;
 pub const REF_CSTM_APPL_VER_ID: u32 = 1131;
 pub const DEFAULT_APPL_VER_ID: u32 = 1137;
+
+/// All fields, as `(tag, name, data type)` triplets.
+pub const FIELDS: &[(u32, &str, DataType)] = &[
+    (7, "BeginSeqNo", DataType::Int),
+    (8, "BeginString", DataType::String),
+    (9, "BodyLength", DataType::Int),
+    (10, "CheckSum", DataType::String),
+    (16, "EndSeqNo", DataType::Int),
+    (34, "MsgSeqNum", DataType::Int),
+    (35, "MsgType", DataType::String),
+    (36, "NewSeqNo", DataType::Int),
+    (43, "PossDupFlag", DataType::Boolean),
+    (45, "RefSeqNum", DataType::Int),
+    (49, "SenderCompID", DataType::String),
+    (50, "SenderSubID", DataType::String),
+    (52, "SendingTime", DataType::UtcTimestamp),
+    (56, "TargetCompID", DataType::String),
+    (57, "TargetSubID", DataType::String),
+    (58, "Text", DataType::String),
+    (89, "Signature", DataType::Data),
+    (90, "SecureDataLen", DataType::Int),
+    (91, "SecureData", DataType::Data),
+    (93, "SignatureLength", DataType::Int),
+    (95, "RawDataLength", DataType::Int),
+    (96, "RawData", DataType::Data),
+    (97, "PossResend", DataType::Boolean),
+    (98, "EncryptMethod", DataType::Int),
+    (108, "HeartBtInt", DataType::Int),
+    (112, "TestReqID", DataType::String),
+    (115, "OnBehalfOfCompID", DataType::String),
+    (116, "OnBehalfOfSubID", DataType::String),
+    (122, "OrigSendingTime", DataType::UtcTimestamp),
+    (123, "GapFillFlag", DataType::Boolean),
+    (128, "DeliverToCompID", DataType::String),
+    (129, "DeliverToSubID", DataType::String),
+    (141, "ResetSeqNumFlag", DataType::Boolean),
+    (142, "SenderLocationID", DataType::String),
+    (143, "TargetLocationID", DataType::String),
+    (144, "OnBehalfOfLocationID", DataType::String),
+    (145, "DeliverToLocationID", DataType::String),
+    (212, "XmlDataLen", DataType::Int),
+    (213, "XmlData", DataType::Data),
+    (347, "MessageEncoding", DataType::String),
+    (354, "EncodedTextLen", DataType::Int),
+    (355, "EncodedText", DataType::Data),
+    (369, "LastMsgSeqNumProcessed", DataType::Int),
+    (371, "RefTagID", DataType::Int),
+    (372, "RefMsgType", DataType::String),
+    (373, "SessionRejectReason", DataType::Int),
+    (383, "MaxMessageSize", DataType::Int),
+    (384, "NoMsgTypes", DataType::NumInGroup),
+    (385, "MsgDirection", DataType::Char),
+    (464, "TestMessageIndicator", DataType::Boolean),
+    (553, "Username", DataType::String),
+    (554, "Password", DataType::String),
+    (627, "NoHops", DataType::NumInGroup),
+    (628, "HopCompID", DataType::String),
+    (629, "HopSendingTime", DataType::UtcTimestamp),
+    (630, "HopRefID", DataType::Int),
+    (789, "NextExpectedMsgSeqNum", DataType::Int),
+    (1128, "ApplVerID", DataType::String),
+    (1129, "CstmApplVerID", DataType::String),
+    (1130, "RefApplVerID", DataType::String),
+    (1131, "RefCstmApplVerID", DataType::String),
+    (1137, "DefaultApplVerID", DataType::String),
+];
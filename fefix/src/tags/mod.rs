@@ -3,6 +3,8 @@
 //! This module contains tag mnemonics for "FIX Latest". Tag mnemonics for
 //! specific protocol versions are available in sub-module.
 
+use crate::{AppVersion, DataType, FixVersion};
+
 pub mod fix40;
 pub mod fix41;
 pub mod fix42;
@@ -14,3 +16,116 @@ pub mod fix50sp2;
 pub mod fixt11;
 
 pub use fix44::*;
+
+/// Returns an [`Iterator`] over all fields defined in `version`, as `(tag,
+/// name, data type)` triplets in ascending tag order. This is the runtime
+/// counterpart of the constants in the per-version sub-modules.
+///
+/// `version` is either an [`AppVersion`] or a [`FixVersion`]. No fields are
+/// known for [`FixVersion::Custom`].
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tags::all_tags;
+/// use fefix::{AppVersion, DataType, FixVersion};
+///
+/// let mut fields = all_tags(AppVersion::Fix42);
+/// assert_eq!(fields.next(), Some((1, "Account", DataType::String)));
+/// assert_eq!(all_tags(FixVersion::from_begin_string(b"MYPROTO.1.0")).count(), 0);
+/// ```
+pub fn all_tags<'a, V>(version: V) -> impl Iterator<Item = (u32, &'static str, DataType)>
+where
+    V: Into<FixVersion<'a>>,
+{
+    let version = match version.into() {
+        FixVersion::Standard(version) => version,
+        FixVersion::Custom(_) => return [].iter().copied(),
+    };
+    let fields = match version {
+        AppVersion::Fix40 => fix40::FIELDS,
+        AppVersion::Fix41 => fix41::FIELDS,
+        AppVersion::Fix42 => fix42::FIELDS,
+        AppVersion::Fix43 => fix43::FIELDS,
+        AppVersion::Fix44 => fix44::FIELDS,
+        AppVersion::Fix50 => fix50::FIELDS,
+        AppVersion::Fix50SP1 => fix50sp1::FIELDS,
+        AppVersion::Fix50SP2 => fix50sp2::FIELDS,
+        AppVersion::Fixt11 => fixt11::FIELDS,
+    };
+    fields.iter().copied()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{codegen_tag_mnemonics, Dictionary};
+    use std::path::Path;
+
+    /// Generates the source code of the sub-module for `version`. Tuples that
+    /// don't fit within `rustfmt`'s default width are split over several
+    /// lines, so the output is already formatted.
+    fn codegen_module(version: AppVersion) -> String {
+        let dict = Dictionary::from_version(version);
+        let mnemonics = codegen_tag_mnemonics(&dict);
+        let consts = mnemonics.strip_prefix("#![allow(dead_code)]\n\n").unwrap();
+        let mut code = format!(
+            "//! Tag mnemonics for {}.\n\n#![allow(dead_code)]\n\nuse crate::DataType;\n\n{}",
+            version.name().replace('-', " "),
+            consts
+        );
+        code.push_str("\n/// All fields, as `(tag, name, data type)` triplets.\n");
+        code.push_str("pub const FIELDS: &[(u32, &str, DataType)] = &[\n");
+        for field in dict.iter_fields() {
+            let name = format!("\"{}\"", field.name());
+            let data_type = format!("DataType::{:?}", field.basetype());
+            let items = format!("{}, {}, {}", field.tag(), name, data_type);
+            if items.len() <= 60 {
+                code.push_str(&format!("    ({}),\n", items));
+            } else {
+                code.push_str(&format!(
+                    "    (\n        {},\n        {},\n        {},\n    ),\n",
+                    field.tag(),
+                    name,
+                    data_type
+                ));
+            }
+        }
+        code.push_str("];\n");
+        code
+    }
+
+    /// The sub-modules are generated by [`codegen_module`]. Run this test with
+    /// `FEFIX_REGENERATE_TAGS=1` to overwrite them after dictionary changes.
+    #[test]
+    fn sub_modules_are_generated_from_dictionaries() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/tags");
+        for version in AppVersion::ALL.iter().copied() {
+            let file_name = version.name().to_lowercase().replace(&['-', '.'][..], "");
+            let path = dir.join(file_name).with_extension("rs");
+            let code = codegen_module(version);
+            if std::env::var_os("FEFIX_REGENERATE_TAGS").is_some() {
+                std::fs::write(&path, &code).unwrap();
+            }
+            assert_eq!(std::fs::read_to_string(&path).unwrap(), code);
+        }
+    }
+
+    #[test]
+    fn fix44_has_plausible_tag_count() {
+        assert!(all_tags(AppVersion::Fix44).count() > 900);
+    }
+
+    #[test]
+    fn all_tags_agree_with_dictionary() {
+        for version in AppVersion::ALL {
+            let dict = Dictionary::from_version(*version);
+            assert_eq!(all_tags(*version).count(), dict.iter_fields().count());
+            for (tag, name, data_type) in all_tags(*version) {
+                let field = dict.field_by_tag(tag).unwrap();
+                assert_eq!(field.name(), name);
+                assert_eq!(field.basetype(), data_type);
+            }
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use super::DecodeError;
+use crate::{DataType, DtfDecimal};
+use std::convert::TryFrom;
+
+/// A borrowed field value, represented according to the base type of its
+/// [`DataType`]. See [`parse_value`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BaseValue<'a> {
+    /// Any [`DataType`] derived from [`DataType::Int`].
+    Int(i64),
+    /// Any [`DataType`] derived from [`DataType::Float`].
+    Float(DtfDecimal),
+    /// [`DataType::Boolean`].
+    Bool(bool),
+    /// [`DataType::Char`].
+    Char(u8),
+    /// Any [`DataType`] derived from [`DataType::String`], including binary
+    /// data. The bytes are left untouched.
+    Str(&'a [u8]),
+}
+
+/// Parses the raw field value `data` according to the base type of `data_type`.
+///
+/// This is the single dispatch point between [`DataType`]s and concrete Rust
+/// values: typed message layers should call it once per field.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::{parse_value, BaseValue};
+/// use fefix::DataType;
+///
+/// assert_eq!(parse_value(DataType::SeqNum, b"42"), Ok(BaseValue::Int(42)));
+/// assert_eq!(parse_value(DataType::Boolean, b"Y"), Ok(BaseValue::Bool(true)));
+/// assert!(parse_value(DataType::Qty, b"abc").is_err());
+/// ```
+pub fn parse_value(data_type: DataType, data: &[u8]) -> Result<BaseValue<'_>, DecodeError> {
+    let value = match data_type {
+        DataType::Boolean => match data {
+            b"Y" => Some(BaseValue::Bool(true)),
+            b"N" => Some(BaseValue::Bool(false)),
+            _ => None,
+        },
+        DataType::Data | DataType::XmlData => Some(BaseValue::Str(data)),
+        dt => match dt.base_type() {
+            DataType::Int => return parse_int(data).map(BaseValue::Int),
+            DataType::Float => DtfDecimal::parse(data).map(BaseValue::Float),
+            DataType::Char => match data {
+                [c] => Some(BaseValue::Char(*c)),
+                _ => None,
+            },
+            _ => Some(BaseValue::Str(data)),
+        },
+    };
+    value.ok_or(DecodeError::Invalid)
+}
+
+/// Parses a FIX `Int` value, i.e. a sequence of digits with an optional
+/// leading `-` sign character. Leading zeros are allowed, e.g. `"00023"` and
+/// `"-00023"` are valid.
+///
+/// Unlike [`str::parse`], values outside of the [`i64`] range are explicitly
+/// reported as [`DecodeError::IntOverflow`] rather than [`DecodeError::Invalid`].
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::{parse_int, DecodeError};
+///
+/// assert_eq!(parse_int(b"00023"), Ok(23));
+/// assert_eq!(parse_int(b"9223372036854775808"), Err(DecodeError::IntOverflow));
+/// assert_eq!(parse_int(b"+1"), Err(DecodeError::Invalid));
+/// ```
+pub fn parse_int(data: &[u8]) -> Result<i64, DecodeError> {
+    i64::try_from(parse_int_i128(data)?).map_err(|_| DecodeError::IntOverflow)
+}
+
+/// Parses a `Percentage` value as a fraction, following the FIX convention
+/// that e.g. `0.05` represents 5% and `0.9525` represents 95.25%. The result
+/// is never scaled by 100.
+///
+/// Negative values are accepted, as they are by
+/// [`DataType::validate`](crate::DataType::validate) in lenient mode.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::{parse_percentage, DecodeError};
+///
+/// assert_eq!(parse_percentage(b"0.05"), Ok(0.05));
+/// assert_eq!(parse_percentage(b".9525"), Ok(0.9525));
+/// assert_eq!(parse_percentage(b"5%"), Err(DecodeError::Invalid));
+/// ```
+pub fn parse_percentage(data: &[u8]) -> Result<f64, DecodeError> {
+    if !DataType::Percentage.validate(data, false) {
+        return Err(DecodeError::Invalid);
+    }
+    std::str::from_utf8(data)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .ok_or(DecodeError::Invalid)
+}
+
+/// Like [`parse_int`], but for values up to the [`i128`] range.
+pub fn parse_int_i128(data: &[u8]) -> Result<i128, DecodeError> {
+    let (is_negative, digits) = match data.split_first() {
+        Some((b'-', rest)) => (true, rest),
+        _ => (false, data),
+    };
+    if digits.is_empty() {
+        return Err(DecodeError::Invalid);
+    }
+    // Accumulate as a negative number so that `i128::MIN` doesn't overflow.
+    let mut n: i128 = 0;
+    for byte in digits.iter().copied() {
+        if !byte.is_ascii_digit() {
+            return Err(DecodeError::Invalid);
+        }
+        n = n
+            .checked_mul(10)
+            .and_then(|n| n.checked_sub((byte - b'0') as i128))
+            .ok_or(DecodeError::IntOverflow)?;
+    }
+    if is_negative {
+        Ok(n)
+    } else {
+        n.checked_neg().ok_or(DecodeError::IntOverflow)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn dispatch_on_base_type() {
+        assert_eq!(parse_value(DataType::Length, b"-3"), Ok(BaseValue::Int(-3)));
+        let price = parse_value(DataType::Price, b"1.50").unwrap();
+        assert_eq!(price, BaseValue::Float(DtfDecimal::parse(b"1.50").unwrap()));
+        assert_eq!(
+            parse_value(DataType::Boolean, b"N"),
+            Ok(BaseValue::Bool(false))
+        );
+        assert_eq!(parse_value(DataType::Char, b"1"), Ok(BaseValue::Char(b'1')));
+        assert_eq!(
+            parse_value(DataType::Currency, b"USD"),
+            Ok(BaseValue::Str(b"USD"))
+        );
+    }
+
+    #[test]
+    fn parse_int_edge_cases() {
+        assert_eq!(parse_int(b"0"), Ok(0));
+        assert_eq!(parse_int(b"-00023"), Ok(-23));
+        assert_eq!(parse_int(b"9223372036854775807"), Ok(i64::MAX));
+        assert_eq!(parse_int(b"-9223372036854775808"), Ok(i64::MIN));
+        assert_eq!(
+            parse_int(b"9223372036854775808"),
+            Err(DecodeError::IntOverflow)
+        );
+        assert_eq!(
+            parse_int(b"-9223372036854775809"),
+            Err(DecodeError::IntOverflow)
+        );
+        assert_eq!(parse_int(b""), Err(DecodeError::Invalid));
+        assert_eq!(parse_int(b"-"), Err(DecodeError::Invalid));
+        assert_eq!(parse_int(b"1-"), Err(DecodeError::Invalid));
+    }
+
+    #[test]
+    fn parse_int_i128_beyond_i64() {
+        assert_eq!(
+            parse_int_i128(b"9223372036854775808"),
+            Ok(i64::MAX as i128 + 1)
+        );
+        assert_eq!(
+            parse_int_i128(b"-170141183460469231731687303715884105728"),
+            Ok(i128::MIN)
+        );
+        assert_eq!(
+            parse_int_i128(b"170141183460469231731687303715884105728"),
+            Err(DecodeError::IntOverflow)
+        );
+    }
+
+    #[test]
+    fn invalid_values() {
+        assert!(parse_value(DataType::Int, b"1.5").is_err());
+        assert!(parse_value(DataType::Boolean, b"y").is_err());
+        assert!(parse_value(DataType::Char, b"").is_err());
+        assert!(parse_value(DataType::Char, b"ab").is_err());
+    }
+}
@@ -0,0 +1,253 @@
+use super::{
+    encode_field, length_tag_for, recompute_trailer, scan_fields, Configure, DecodeError,
+    RawDecoder,
+};
+use crate::dictionary::{LayoutItem, LayoutItemKind};
+use crate::{tags, Dictionary};
+use std::collections::{HashMap, HashSet};
+
+/// Where a field belongs within a message, in canonical order.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Section {
+    Header,
+    Body,
+    Trailer,
+}
+
+/// The canonical positions of the fields of a section, and the members of its
+/// repeating groups.
+#[derive(Debug, Default)]
+struct SectionLayout {
+    ranks: HashMap<u32, usize>,
+    group_members: HashMap<u32, HashSet<u32>>,
+}
+
+impl SectionLayout {
+    fn new(dictionary: &Dictionary, component_name: &str) -> Self {
+        let mut layout = Self::default();
+        if let Some(component) = dictionary.component_by_name(component_name) {
+            for item in component.items() {
+                layout.add_item(item);
+            }
+        }
+        layout
+    }
+
+    fn body(dictionary: &Dictionary, msg_type: &[u8]) -> Self {
+        let mut layout = Self::default();
+        let message = std::str::from_utf8(msg_type)
+            .ok()
+            .and_then(|msg_type| dictionary.message_by_msgtype(msg_type));
+        if let Some(message) = message {
+            for item in message.layout() {
+                layout.add_item(item);
+            }
+        }
+        layout
+    }
+
+    fn add_item(&mut self, item: LayoutItem) {
+        match item.kind() {
+            LayoutItemKind::Field(field) => self.add_tag(field.tag()),
+            LayoutItemKind::Group(len_field, items) => {
+                self.add_tag(len_field.tag());
+                let mut members = HashSet::new();
+                collect_tags(&items, &mut members);
+                self.group_members.insert(len_field.tag(), members);
+            }
+            LayoutItemKind::Component(component) => {
+                for item in component.items() {
+                    self.add_item(item);
+                }
+            }
+        }
+    }
+
+    fn add_tag(&mut self, tag: u32) {
+        let rank = self.ranks.len();
+        self.ranks.entry(tag).or_insert(rank);
+    }
+}
+
+/// Either a single field or a whole repeating group, together with its
+/// section and rank within the section.
+type Unit<'a> = (Section, usize, Vec<(u32, &'a [u8])>);
+
+/// Collects the tags of all fields in `items`, recursively.
+fn collect_tags(items: &[LayoutItem], tags: &mut HashSet<u32>) {
+    for item in items {
+        match item.kind() {
+            LayoutItemKind::Field(field) => {
+                tags.insert(field.tag());
+            }
+            LayoutItemKind::Group(len_field, items) => {
+                tags.insert(len_field.tag());
+                collect_tags(&items, tags);
+            }
+            LayoutItemKind::Component(component) => {
+                let items: Vec<LayoutItem> = component.items().collect();
+                collect_tags(&items, tags);
+            }
+        }
+    }
+}
+
+/// Re-encodes `message` with its fields in canonical order, i.e.:
+///
+/// 1. `BeginString <8>`, `BodyLength <9>` and `MsgType <35>`, followed by all
+///    other standard header fields in the order given by `dictionary`.
+/// 2. Body fields in the order given by the layout of `MsgType <35>` in
+///    `dictionary`, followed by unknown fields in their original order.
+/// 3. Standard trailer fields in the order given by `dictionary`, with
+///    `CheckSum <10>` last.
+///
+/// Repeating groups are moved together with all their entries, whose fields
+/// keep their original order. The values of standard data fields (see
+/// [`length_tag_for`]) may contain the separator, and they always stay right
+/// after their length field. `BodyLength <9>` and `CheckSum <10>` are
+/// recomputed.
+///
+/// Two messages which only differ in field order have the same canonical form,
+/// so they can be compared byte by byte.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::{canonicalize, Config};
+/// use fefix::{AppVersion, Dictionary};
+///
+/// let dict = Dictionary::from_version(AppVersion::Fix44);
+/// let config = Config::default().with_separator(b'|');
+/// let msg = b"8=FIX.4.4|9=28|55=AAPL|35=D|56=B|49=A|54=1|10=190|";
+/// assert_eq!(
+///     canonicalize(msg, &dict, &config).unwrap(),
+///     b"8=FIX.4.4|9=28|35=D|49=A|56=B|55=AAPL|54=1|10=190|".to_vec()
+/// );
+/// ```
+pub fn canonicalize<C>(
+    message: &[u8],
+    dictionary: &Dictionary,
+    config: &C,
+) -> Result<Vec<u8>, DecodeError>
+where
+    C: Configure,
+{
+    let separator = config.separator();
+    let frame = RawDecoder::with_config(config.clone()).decode(message)?;
+    let header = SectionLayout::new(dictionary, "StandardHeader");
+    let trailer = SectionLayout::new(dictionary, "StandardTrailer");
+    let fields = scan_fields(frame.payload(), separator)
+        .with_data_fields()
+        .collect::<Result<Vec<_>, _>>()?;
+    let msg_type = fields
+        .iter()
+        .find(|(tag, _)| *tag == tags::MSG_TYPE)
+        .map_or(&b""[..], |(_, value)| value);
+    let body = SectionLayout::body(dictionary, msg_type);
+    let mut units: Vec<Unit> = Vec::new();
+    let mut i = 0;
+    while i < fields.len() {
+        let tag = fields[i].0;
+        let (section, layout) = if tag == tags::MSG_TYPE || header.ranks.contains_key(&tag) {
+            (Section::Header, &header)
+        } else if trailer.ranks.contains_key(&tag) {
+            (Section::Trailer, &trailer)
+        } else {
+            (Section::Body, &body)
+        };
+        let rank = match layout.ranks.get(&tag) {
+            Some(rank) if section == Section::Header => rank + 1,
+            Some(rank) => *rank,
+            // `MsgType <35>` comes first; unknown body fields come last.
+            None if section == Section::Header => 0,
+            None => usize::MAX,
+        };
+        let mut end = i + 1;
+        if let Some(members) = layout.group_members.get(&tag) {
+            while end < fields.len() && members.contains(&fields[end].0) {
+                end += 1;
+            }
+        }
+        if end < fields.len() && length_tag_for(fields[end].0) == Some(fields[end - 1].0) {
+            end += 1;
+        }
+        units.push((section, rank, fields[i..end].to_vec()));
+        i = end;
+    }
+    // Stable, so that fields with the same rank keep their original order.
+    units.sort_by_key(|(section, rank, _)| (*section, *rank));
+    let mut buffer = Vec::new();
+    buffer.extend_from_slice(b"8=");
+    buffer.extend_from_slice(frame.begin_string());
+    buffer.extend_from_slice(&[separator, b'9', b'=', b'0', separator]);
+    for (tag, value) in units.iter().flat_map(|(_, _, fields)| fields) {
+        encode_field(*tag, value, &mut buffer, separator);
+    }
+    buffer.extend_from_slice(&[b'1', b'0', b'=', b'0', b'0', b'0', separator]);
+    recompute_trailer(&mut buffer)?;
+    Ok(buffer)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tagvalue::Config;
+    use crate::AppVersion;
+
+    fn config() -> Config {
+        Config::default().with_separator(b'|')
+    }
+
+    fn dict() -> Dictionary {
+        Dictionary::from_version(AppVersion::Fix44)
+    }
+
+    #[test]
+    fn canonical_message_is_unchanged() {
+        let msg = b"8=FIX.4.4|9=28|35=D|49=A|56=B|55=AAPL|54=1|10=190|";
+        assert_eq!(canonicalize(msg, &dict(), &config()).unwrap(), msg.to_vec());
+    }
+
+    #[test]
+    fn header_group_is_moved_with_its_entries() {
+        let msg = b"8=FIX.4.4|9=53|35=D|55=AAPL|627=2|628=X|630=1|628=Y|630=2|49=A|54=1|10=074|";
+        assert_eq!(
+            canonicalize(msg, &dict(), &config()).unwrap(),
+            b"8=FIX.4.4|9=53|35=D|49=A|627=2|628=X|630=1|628=Y|630=2|55=AAPL|54=1|10=074|".to_vec()
+        );
+    }
+
+    #[test]
+    fn body_group_entries_stay_together() {
+        let msg = b"8=FIX.4.4|9=49|262=1|268=2|269=0|270=1|269=1|270=2|35=W|55=AAPL|10=223|";
+        assert_eq!(
+            canonicalize(msg, &dict(), &config()).unwrap(),
+            b"8=FIX.4.4|9=49|35=W|262=1|55=AAPL|268=2|269=0|270=1|269=1|270=2|10=223|".to_vec()
+        );
+    }
+
+    #[test]
+    fn body_fields_follow_the_dictionary() {
+        let a = b"8=FIX.4.4|9=30|35=D|54=1|55=AAPL|11=X|9999=Y|10=083|";
+        let b = b"8=FIX.4.4|9=30|35=D|11=X|55=AAPL|54=1|9999=Y|10=083|";
+        assert_eq!(canonicalize(a, &dict(), &config()).unwrap(), b.to_vec());
+        assert_eq!(canonicalize(b, &dict(), &config()).unwrap(), b.to_vec());
+    }
+
+    #[test]
+    fn data_fields_stay_after_their_length() {
+        let msg = b"8=FIX.4.4|9=38|55=AAPL|90=6|91=a|35=X|35=D|49=A|54=1|10=174|";
+        assert_eq!(
+            canonicalize(msg, &dict(), &config()).unwrap(),
+            b"8=FIX.4.4|9=38|35=D|49=A|90=6|91=a|35=X|55=AAPL|54=1|10=174|".to_vec()
+        );
+    }
+
+    #[test]
+    fn trailer_fields_go_last() {
+        let a = b"8=FIX.4.4|9=21|35=0|93=2|89=XY|49=A|10=219|";
+        let b = b"8=FIX.4.4|9=21|35=0|49=A|93=2|89=XY|10=219|";
+        assert_eq!(canonicalize(a, &dict(), &config()).unwrap(), b.to_vec());
+        assert_eq!(canonicalize(b, &dict(), &config()).unwrap(), b.to_vec());
+    }
+}
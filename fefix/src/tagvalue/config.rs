@@ -1,6 +1,10 @@
-use crate::tagvalue::{TagLookup, TagLookupSingleAppVersion};
+use crate::tagvalue::{Interner, TagLookup, TagLookupSingleAppVersion};
+use crate::DataType;
+use std::collections::HashMap;
+use std::sync::Arc;
 
 const SOH: u8 = 0x1;
+const DEFAULT_MAX_MESSAGE_SIZE: usize = 65536;
 
 /// Collection of configuration options related to FIX encoding and decoding.
 ///
@@ -24,24 +28,241 @@ pub trait Configure: Clone + Default {
     }
 
     /// The maximum allowed size for any single FIX message. No restrictions are
-    /// imposed when it is `None`.
+    /// imposed when it is `None`. Larger messages fail to decode with
+    /// [`DecodeError::MessageTooLarge`](super::DecodeError::MessageTooLarge),
+    /// and streaming decoders stop reading as soon as `BodyLength <9>` reveals
+    /// the size of the message.
+    ///
+    /// The default limit is 64 KiB. It used to be ignored by decoders, so
+    /// applications that receive larger messages must now raise it or return
+    /// `None`.
     fn max_message_size(&self) -> Option<usize> {
-        Some(65536)
+        Some(DEFAULT_MAX_MESSAGE_SIZE)
+    }
+
+    /// Determines how `CheckSum(10)` should be verified.
+    ///
+    /// This setting has no effect when encoding FIX messages.
+    fn checksum_policy(&self) -> ChecksumPolicy {
+        ChecksumPolicy::Enforce
+    }
+
+    /// Determines how `BodyLength(9)` should be verified.
+    ///
+    /// This setting has no effect when encoding FIX messages.
+    fn body_length_policy(&self) -> BodyLengthPolicy {
+        BodyLengthPolicy::Enforce
+    }
+
+    /// If `true`, `CheckSum(10)` is computed as if the separator were SOH
+    /// (`0x1`), regardless of [`Configure::separator`]. This allows to verify
+    /// messages that have been logged with e.g. `|` against their original
+    /// checksums.
+    ///
+    /// This setting has no effect when encoding FIX messages.
+    fn checksum_assumes_soh(&self) -> bool {
+        false
+    }
+
+    /// If `true`, the value of `CheckSum(10)` must be exactly three ASCII
+    /// digits, as mandated by the specification. Otherwise, e.g. `10=5` and
+    /// `10=0012` fail to decode with
+    /// [`DecodeError::CheckSumFormat`](super::DecodeError::CheckSumFormat).
+    /// This check is performed regardless of [`Configure::checksum_policy`].
+    ///
+    /// This setting has no effect when encoding FIX messages.
+    fn checksum_format_strict(&self) -> bool {
+        false
+    }
+
+    /// Determines what to do with a separator that appears inside a field value
+    /// (other than `Data` and `XmlData` fields, which are length-prefixed and
+    /// may contain it).
+    ///
+    /// This setting has no effect when encoding FIX messages.
+    fn on_embedded_separator(&self) -> EmbeddedSeparatorPolicy {
+        EmbeddedSeparatorPolicy::Error
     }
 
-    /// Determines wheather or not `CheckSum(10)` should be verified.
+    /// If `true`, `NumInGroup` fields with a value of zero are accepted.
+    /// Otherwise they fail to decode with
+    /// [`DecodeError::GroupCount`](super::DecodeError::GroupCount), since the
+    /// specification requires `NumInGroup` values to be positive. Many
+    /// counterparties send empty groups anyway, so they're accepted by
+    /// default.
     ///
     /// This setting has no effect when encoding FIX messages.
-    fn verify_checksum(&self) -> bool {
+    fn empty_groups_allowed(&self) -> bool {
         true
     }
+
+    /// If `true`, negative values are accepted for all numeric data types.
+    /// Otherwise, they're only accepted for data types that
+    /// [allow them](DataType::allows_negative_values), e.g. `Price` but not
+    /// `Qty`. See [`DataType::validate`].
+    ///
+    /// Field values are only validated by
+    /// [`Decoder::decode_collecting_errors`](super::Decoder::decode_collecting_errors).
+    /// This setting has no effect when encoding FIX messages.
+    fn negative_values_allowed(&self) -> bool {
+        false
+    }
+
+    /// If `true`, `XmlData <213>` fields that are not immediately preceded by
+    /// `XmlDataLen <212>` are accepted (with a warning in debug builds) and
+    /// their value ends at the next separator, which breaks if the XML contains
+    /// the separator. Otherwise they fail to decode with
+    /// [`DecodeError::MissingLengthPrefix`](super::DecodeError::MissingLengthPrefix).
+    ///
+    /// This is a workaround for non-conformant counterparties. This setting has
+    /// no effect when encoding FIX messages.
+    fn unprefixed_xml_data_allowed(&self) -> bool {
+        false
+    }
+
+    /// If `true`, values of `Boolean` and enumerated `Char` fields are matched
+    /// case-insensitively and normalized to their canonical case, e.g. `43=y`
+    /// reads as `PossDupFlag <43>` set to `Y`. Values that match more than one
+    /// enumerated value are left untouched. `String` and `Data` fields are
+    /// never affected, and neither are byte offsets as returned by
+    /// [`FixMessageRef::field_ranges`](super::FixMessageRef::field_ranges).
+    ///
+    /// FIX is case-sensitive, so this is a workaround for non-conformant
+    /// counterparties. This setting has no effect when encoding FIX messages.
+    fn normalize_case(&self) -> bool {
+        false
+    }
+
+    /// If `true`, `MsgType <35>` must be the third field of every message,
+    /// right after `BeginString <8>` and `BodyLength <9>`, or else decoding
+    /// fails with
+    /// [`DecodeError::TagOutOfOrder`](super::DecodeError::TagOutOfOrder).
+    /// Otherwise `MsgType <35>` is accepted anywhere (see
+    /// [`RawFrame::msg_type`](super::RawFrame::msg_type)).
+    ///
+    /// This setting has no effect when encoding FIX messages.
+    fn verify_field_order(&self) -> bool {
+        false
+    }
+
+    /// If `false`, [`RawDecoder::decode`](super::RawDecoder::decode) also
+    /// accepts messages that end with the last digit of `CheckSum <10>`, i.e.
+    /// without the final separator. Some logs and captures drop it. Otherwise
+    /// such messages fail to decode with
+    /// [`DecodeError::Invalid`](super::DecodeError::Invalid), as per the
+    /// specification.
+    ///
+    /// Streaming decoders always need the final separator to find the end of
+    /// the message. This setting has no effect when encoding FIX messages.
+    fn require_trailing_separator(&self) -> bool {
+        true
+    }
+
+    /// Returns the [`DataType`] of `tag` if it's been overridden, thus taking
+    /// precedence over the dictionary. This is typically used for custom tags
+    /// agreed upon with counterparties.
+    ///
+    /// This setting has no effect when encoding FIX messages.
+    fn tag_type(&self, _tag: u32) -> Option<DataType> {
+        None
+    }
+
+    /// Returns the [`DataType`] of `tag` as defined by the extension pack
+    /// `appl_ext_id`, if it's known. The decoder only consults this for
+    /// messages that carry `ApplExtID <1156>`, and overrides set via
+    /// [`Configure::tag_type`] still take precedence.
+    ///
+    /// This setting has no effect when encoding FIX messages.
+    fn extension_tag_type(&self, _appl_ext_id: u32, _tag: u32) -> Option<DataType> {
+        None
+    }
+
+    /// Returns the name of the enumerated `value` of `tag` if it's been
+    /// registered, thus taking precedence over the dictionary. This is
+    /// typically used for proprietary values agreed upon with counterparties.
+    /// See [`Decoder::resolve_enum`](super::Decoder::resolve_enum).
+    ///
+    /// This setting has no effect when encoding FIX messages.
+    fn enum_name(&self, _tag: u32, _value: &[u8]) -> Option<&str> {
+        None
+    }
+
+    /// The [`Interner`] used to map well-known field values to
+    /// `&'static str`s while decoding, if any.
+    ///
+    /// This setting has no effect when encoding FIX messages.
+    fn interner(&self) -> Option<&Interner> {
+        None
+    }
+}
+
+/// The action to take when decoding a message with an invalid `CheckSum(10)`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ChecksumPolicy {
+    /// Messages with an invalid checksum fail to decode with
+    /// [`DecodeError::CheckSum`](super::DecodeError::CheckSum).
+    Enforce,
+    /// Messages with an invalid checksum are decoded anyway, but they are
+    /// flagged as such (see [`RawFrame::checksum_ok`](super::RawFrame::checksum_ok)).
+    /// This is mostly useful for monitoring and diagnostic tools.
+    Warn,
+    /// `CheckSum(10)` is not verified at all.
+    Ignore,
+}
+
+/// The action to take when decoding a message with an invalid `BodyLength(9)`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BodyLengthPolicy {
+    /// Messages with an invalid `BodyLength(9)` fail to decode with
+    /// [`DecodeError::Invalid`](super::DecodeError::Invalid).
+    Enforce,
+    /// The end of the body is located by looking for `CheckSum(10)` rather than
+    /// trusting `BodyLength(9)`, and messages with an invalid `BodyLength(9)`
+    /// are flagged as such (see
+    /// [`RawFrame::body_length_ok`](super::RawFrame::body_length_ok)).
+    Warn,
+    /// The end of the body is located by looking for `CheckSum(10)`, and
+    /// `BodyLength(9)` is not verified at all.
+    Ignore,
+}
+
+/// The action to take when a field value contains the separator, i.e. when a
+/// separator is *not* followed by another `tag=` (or by the end of the
+/// message). Strictly speaking this is never valid, but buggy counterparties
+/// produce such messages nonetheless.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EmbeddedSeparatorPolicy {
+    /// Messages with embedded separators fail to decode with
+    /// [`DecodeError::InvalidValue`](super::DecodeError::InvalidValue).
+    Error,
+    /// The field value ends at the first separator, and everything up to the
+    /// next field is discarded.
+    Truncate,
+    /// The field value extends until the next field, embedded separators
+    /// included.
+    Lenient,
 }
 
 /// The canonical implementor of [`Configure`]. Every setting can be changed.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub struct Config {
     separator: u8,
-    verify_checksum: bool,
+    max_message_size: Option<usize>,
+    checksum_policy: ChecksumPolicy,
+    body_length_policy: BodyLengthPolicy,
+    checksum_assumes_soh: bool,
+    checksum_format_strict: bool,
+    embedded_separator_policy: EmbeddedSeparatorPolicy,
+    empty_groups_allowed: bool,
+    negative_values_allowed: bool,
+    unprefixed_xml_data_allowed: bool,
+    normalize_case: bool,
+    verify_field_order: bool,
+    require_trailing_separator: bool,
+    tag_types: HashMap<u32, DataType>,
+    extension_packs: HashMap<u32, HashMap<u32, DataType>>,
+    enum_values: HashMap<u32, HashMap<Vec<u8>, String>>,
+    interner: Option<Arc<Interner>>,
 }
 
 impl Config {
@@ -66,7 +287,162 @@ impl Config {
         self
     }
 
-    /// Turns on or off `ChekSum(10)` verification. On by default.
+    /// Changes the maximum allowed size for any single FIX message. It is 64
+    /// KiB by default; `None` means no limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Configure};
+    ///
+    /// let config = &mut Config::default();
+    /// assert_eq!(config.max_message_size(), Some(65536));
+    /// config.set_max_message_size(None);
+    /// assert_eq!(config.max_message_size(), None);
+    /// ```
+    pub fn set_max_message_size(&mut self, max_message_size: Option<usize>) {
+        self.max_message_size = max_message_size;
+    }
+
+    pub fn with_max_message_size(mut self, max_message_size: Option<usize>) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+
+    /// Changes the [`ChecksumPolicy`] used for `CheckSum(10)` verification. It
+    /// is [`ChecksumPolicy::Enforce`] by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{ChecksumPolicy, Config, Configure};
+    ///
+    /// let config = &mut Config::default();
+    /// assert_eq!(config.checksum_policy(), ChecksumPolicy::Enforce);
+    /// config.set_checksum_policy(ChecksumPolicy::Warn);
+    /// assert_eq!(config.checksum_policy(), ChecksumPolicy::Warn);
+    /// ```
+    pub fn set_checksum_policy(&mut self, policy: ChecksumPolicy) {
+        self.checksum_policy = policy;
+    }
+
+    pub fn with_checksum_policy(mut self, policy: ChecksumPolicy) -> Self {
+        self.checksum_policy = policy;
+        self
+    }
+
+    /// Changes the [`BodyLengthPolicy`] used for `BodyLength(9)` verification.
+    /// It is [`BodyLengthPolicy::Enforce`] by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{BodyLengthPolicy, Config, Configure};
+    ///
+    /// let config = &mut Config::default();
+    /// assert_eq!(config.body_length_policy(), BodyLengthPolicy::Enforce);
+    /// config.set_body_length_policy(BodyLengthPolicy::Warn);
+    /// assert_eq!(config.body_length_policy(), BodyLengthPolicy::Warn);
+    /// ```
+    pub fn set_body_length_policy(&mut self, policy: BodyLengthPolicy) {
+        self.body_length_policy = policy;
+    }
+
+    pub fn with_body_length_policy(mut self, policy: BodyLengthPolicy) -> Self {
+        self.body_length_policy = policy;
+        self
+    }
+
+    /// Changes whether `CheckSum(10)` is computed as if the separator were SOH.
+    /// It is `false` by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Configure};
+    ///
+    /// let config = &mut Config::default();
+    /// assert_eq!(config.checksum_assumes_soh(), false);
+    /// config.set_checksum_assumes_soh(true);
+    /// assert_eq!(config.checksum_assumes_soh(), true);
+    /// ```
+    pub fn set_checksum_assumes_soh(&mut self, assumes_soh: bool) {
+        self.checksum_assumes_soh = assumes_soh;
+    }
+
+    pub fn with_checksum_assumes_soh(mut self, assumes_soh: bool) -> Self {
+        self.checksum_assumes_soh = assumes_soh;
+        self
+    }
+
+    /// Changes whether the value of `CheckSum(10)` must be exactly three
+    /// digits. It is `false` by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Configure};
+    ///
+    /// let config = &mut Config::default();
+    /// assert_eq!(config.checksum_format_strict(), false);
+    /// config.set_checksum_format_strict(true);
+    /// assert_eq!(config.checksum_format_strict(), true);
+    /// ```
+    pub fn set_checksum_format_strict(&mut self, strict: bool) {
+        self.checksum_format_strict = strict;
+    }
+
+    pub fn with_checksum_format_strict(mut self, strict: bool) -> Self {
+        self.checksum_format_strict = strict;
+        self
+    }
+
+    /// Changes the [`EmbeddedSeparatorPolicy`]. It is
+    /// [`EmbeddedSeparatorPolicy::Error`] by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Configure, EmbeddedSeparatorPolicy};
+    ///
+    /// let config = &mut Config::default();
+    /// assert_eq!(config.on_embedded_separator(), EmbeddedSeparatorPolicy::Error);
+    /// config.set_embedded_separator_policy(EmbeddedSeparatorPolicy::Truncate);
+    /// assert_eq!(config.on_embedded_separator(), EmbeddedSeparatorPolicy::Truncate);
+    /// ```
+    pub fn set_embedded_separator_policy(&mut self, policy: EmbeddedSeparatorPolicy) {
+        self.embedded_separator_policy = policy;
+    }
+
+    pub fn with_embedded_separator_policy(mut self, policy: EmbeddedSeparatorPolicy) -> Self {
+        self.embedded_separator_policy = policy;
+        self
+    }
+
+    /// Decides whether `NumInGroup` fields with a value of zero are accepted.
+    /// They're accepted by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Configure};
+    ///
+    /// let config = &mut Config::default();
+    /// assert_eq!(config.empty_groups_allowed(), true);
+    /// config.set_empty_groups_allowed(false);
+    /// assert_eq!(config.empty_groups_allowed(), false);
+    /// ```
+    pub fn set_empty_groups_allowed(&mut self, allowed: bool) {
+        self.empty_groups_allowed = allowed;
+    }
+
+    pub fn with_empty_groups_allowed(mut self, allowed: bool) -> Self {
+        self.set_empty_groups_allowed(allowed);
+        self
+    }
+
+    /// Decides whether negative values are accepted for data types that are
+    /// documented as non-negative, e.g. `Qty`. They're rejected by default.
     ///
     /// # Examples
     ///
@@ -74,16 +450,200 @@ impl Config {
     /// use fefix::tagvalue::{Config, Configure};
     ///
     /// let config = &mut Config::default();
-    /// assert_eq!(config.verify_checksum(), true);
-    /// config.set_verify_checksum(false);
-    /// assert_eq!(config.verify_checksum(), false);
+    /// assert_eq!(config.negative_values_allowed(), false);
+    /// config.set_negative_values_allowed(true);
+    /// assert_eq!(config.negative_values_allowed(), true);
     /// ```
-    pub fn set_verify_checksum(&mut self, verify: bool) {
-        self.verify_checksum = verify;
+    pub fn set_negative_values_allowed(&mut self, allowed: bool) {
+        self.negative_values_allowed = allowed;
     }
 
-    pub fn with_checksum_verification(mut self, verify: bool) -> Self {
-        self.verify_checksum = verify;
+    pub fn with_negative_values_allowed(mut self, allowed: bool) -> Self {
+        self.set_negative_values_allowed(allowed);
+        self
+    }
+
+    /// Decides whether `XmlData <213>` fields without a preceding
+    /// `XmlDataLen <212>` are accepted. They're rejected by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Configure};
+    ///
+    /// let config = &mut Config::default();
+    /// assert_eq!(config.unprefixed_xml_data_allowed(), false);
+    /// config.set_unprefixed_xml_data_allowed(true);
+    /// assert_eq!(config.unprefixed_xml_data_allowed(), true);
+    /// ```
+    pub fn set_unprefixed_xml_data_allowed(&mut self, allowed: bool) {
+        self.unprefixed_xml_data_allowed = allowed;
+    }
+
+    pub fn with_unprefixed_xml_data_allowed(mut self, allowed: bool) -> Self {
+        self.set_unprefixed_xml_data_allowed(allowed);
+        self
+    }
+
+    /// Decides whether enumerated values are matched case-insensitively and
+    /// normalized while decoding. It is `false` by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Configure};
+    ///
+    /// let config = &mut Config::default();
+    /// assert_eq!(config.normalize_case(), false);
+    /// config.set_normalize_case(true);
+    /// assert_eq!(config.normalize_case(), true);
+    /// ```
+    pub fn set_normalize_case(&mut self, normalize: bool) {
+        self.normalize_case = normalize;
+    }
+
+    pub fn with_normalize_case(mut self, normalize: bool) -> Self {
+        self.set_normalize_case(normalize);
+        self
+    }
+
+    /// Decides whether `MsgType <35>` must be the third field of every
+    /// message. It is `false` by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Configure};
+    ///
+    /// let config = &mut Config::default();
+    /// assert_eq!(config.verify_field_order(), false);
+    /// config.set_verify_field_order(true);
+    /// assert_eq!(config.verify_field_order(), true);
+    /// ```
+    pub fn set_verify_field_order(&mut self, verify: bool) {
+        self.verify_field_order = verify;
+    }
+
+    pub fn with_verify_field_order(mut self, verify: bool) -> Self {
+        self.set_verify_field_order(verify);
+        self
+    }
+
+    /// Decides whether messages must end with a separator after
+    /// `CheckSum <10>`. It is `true` by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Configure};
+    ///
+    /// let config = &mut Config::default();
+    /// assert_eq!(config.require_trailing_separator(), true);
+    /// config.set_require_trailing_separator(false);
+    /// assert_eq!(config.require_trailing_separator(), false);
+    /// ```
+    pub fn set_require_trailing_separator(&mut self, require: bool) {
+        self.require_trailing_separator = require;
+    }
+
+    pub fn with_require_trailing_separator(mut self, require: bool) -> Self {
+        self.set_require_trailing_separator(require);
+        self
+    }
+
+    /// Overrides the [`DataType`] of `tag`, regardless of what the dictionary
+    /// says about it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Configure};
+    /// use fefix::DataType;
+    ///
+    /// let config = &mut Config::default();
+    /// assert_eq!(config.tag_type(6000), None);
+    /// config.set_tag_type(6000, DataType::Data);
+    /// assert_eq!(config.tag_type(6000), Some(DataType::Data));
+    /// ```
+    pub fn set_tag_type(&mut self, tag: u32, data_type: DataType) {
+        self.tag_types.insert(tag, data_type);
+    }
+
+    pub fn with_tag_type(mut self, tag: u32, data_type: DataType) -> Self {
+        self.tag_types.insert(tag, data_type);
+        self
+    }
+
+    /// Registers the `(tag, data type)` pairs that the extension pack
+    /// `appl_ext_id` adds to the dictionary. See
+    /// [`Configure::extension_tag_type`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Configure};
+    /// use fefix::DataType;
+    ///
+    /// let config = &mut Config::default();
+    /// config.set_extension_pack(254, &[(20001, DataType::Int)]);
+    /// assert_eq!(config.extension_tag_type(254, 20001), Some(DataType::Int));
+    /// assert_eq!(config.extension_tag_type(253, 20001), None);
+    /// ```
+    pub fn set_extension_pack(&mut self, appl_ext_id: u32, fields: &[(u32, DataType)]) {
+        self.extension_packs
+            .entry(appl_ext_id)
+            .or_default()
+            .extend(fields.iter().copied());
+    }
+
+    pub fn with_extension_pack(mut self, appl_ext_id: u32, fields: &[(u32, DataType)]) -> Self {
+        self.set_extension_pack(appl_ext_id, fields);
+        self
+    }
+
+    /// Registers custom `(value, name)` pairs for the enumerated field `tag`,
+    /// in addition to (or instead of) what the dictionary says about it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Configure};
+    ///
+    /// let config = &mut Config::default();
+    /// assert_eq!(config.enum_name(40, b"Z"), None);
+    /// config.set_enum_values(40, &[("Z", "VWAP_LIMIT")]);
+    /// assert_eq!(config.enum_name(40, b"Z"), Some("VWAP_LIMIT"));
+    /// ```
+    pub fn set_enum_values(&mut self, tag: u32, values: &[(&str, &str)]) {
+        let names = self.enum_values.entry(tag).or_default();
+        for (value, name) in values {
+            names.insert(value.as_bytes().to_vec(), name.to_string());
+        }
+    }
+
+    pub fn with_enum_values(mut self, tag: u32, values: &[(&str, &str)]) -> Self {
+        self.set_enum_values(tag, values);
+        self
+    }
+
+    /// Sets the [`Interner`] to use while decoding. There's none by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Configure, Interner};
+    ///
+    /// let config = &mut Config::default();
+    /// assert!(config.interner().is_none());
+    /// config.set_interner(Some(Interner::common()));
+    /// assert_eq!(config.interner().unwrap().get(15, b"USD"), Some("USD"));
+    /// ```
+    pub fn set_interner(&mut self, interner: Option<Interner>) {
+        self.interner = interner.map(Arc::new);
+    }
+
+    pub fn with_interner(mut self, interner: Interner) -> Self {
+        self.set_interner(Some(interner));
         self
     }
 }
@@ -95,8 +655,68 @@ impl Configure for Config {
         self.separator
     }
 
-    fn verify_checksum(&self) -> bool {
-        self.verify_checksum
+    fn max_message_size(&self) -> Option<usize> {
+        self.max_message_size
+    }
+
+    fn checksum_policy(&self) -> ChecksumPolicy {
+        self.checksum_policy
+    }
+
+    fn body_length_policy(&self) -> BodyLengthPolicy {
+        self.body_length_policy
+    }
+
+    fn checksum_assumes_soh(&self) -> bool {
+        self.checksum_assumes_soh
+    }
+
+    fn checksum_format_strict(&self) -> bool {
+        self.checksum_format_strict
+    }
+
+    fn on_embedded_separator(&self) -> EmbeddedSeparatorPolicy {
+        self.embedded_separator_policy
+    }
+
+    fn empty_groups_allowed(&self) -> bool {
+        self.empty_groups_allowed
+    }
+
+    fn negative_values_allowed(&self) -> bool {
+        self.negative_values_allowed
+    }
+
+    fn unprefixed_xml_data_allowed(&self) -> bool {
+        self.unprefixed_xml_data_allowed
+    }
+
+    fn normalize_case(&self) -> bool {
+        self.normalize_case
+    }
+
+    fn verify_field_order(&self) -> bool {
+        self.verify_field_order
+    }
+
+    fn require_trailing_separator(&self) -> bool {
+        self.require_trailing_separator
+    }
+
+    fn tag_type(&self, tag: u32) -> Option<DataType> {
+        self.tag_types.get(&tag).copied()
+    }
+
+    fn extension_tag_type(&self, appl_ext_id: u32, tag: u32) -> Option<DataType> {
+        self.extension_packs.get(&appl_ext_id)?.get(&tag).copied()
+    }
+
+    fn enum_name(&self, tag: u32, value: &[u8]) -> Option<&str> {
+        self.enum_values.get(&tag)?.get(value).map(String::as_str)
+    }
+
+    fn interner(&self) -> Option<&Interner> {
+        self.interner.as_deref()
     }
 }
 
@@ -104,7 +724,22 @@ impl Default for Config {
     fn default() -> Self {
         Self {
             separator: SOH,
-            verify_checksum: true,
+            max_message_size: Some(DEFAULT_MAX_MESSAGE_SIZE),
+            checksum_policy: ChecksumPolicy::Enforce,
+            body_length_policy: BodyLengthPolicy::Enforce,
+            checksum_assumes_soh: false,
+            checksum_format_strict: false,
+            embedded_separator_policy: EmbeddedSeparatorPolicy::Error,
+            empty_groups_allowed: true,
+            negative_values_allowed: false,
+            unprefixed_xml_data_allowed: false,
+            normalize_case: false,
+            verify_field_order: false,
+            require_trailing_separator: true,
+            tag_types: HashMap::new(),
+            extension_packs: HashMap::new(),
+            enum_values: HashMap::new(),
+            interner: None,
         }
     }
 }
@@ -130,16 +765,57 @@ mod test {
     }
 
     #[test]
-    fn config_verifies_checksum_by_default() {
-        assert_eq!(Config::default().verify_checksum(), true);
+    fn config_enforces_checksum_by_default() {
+        assert_eq!(Config::default().checksum_policy(), ChecksumPolicy::Enforce);
     }
 
     #[test]
-    fn config_checksum_verification_can_be_changed() {
+    fn config_checksum_policy_can_be_changed() {
         let config = &mut Config::default();
-        config.set_verify_checksum(false);
-        assert_eq!(config.verify_checksum(), false);
-        config.set_verify_checksum(true);
-        assert_eq!(config.verify_checksum(), true);
+        config.set_checksum_policy(ChecksumPolicy::Ignore);
+        assert_eq!(config.checksum_policy(), ChecksumPolicy::Ignore);
+        config.set_checksum_policy(ChecksumPolicy::Warn);
+        assert_eq!(config.checksum_policy(), ChecksumPolicy::Warn);
+        config.set_checksum_policy(ChecksumPolicy::Enforce);
+        assert_eq!(config.checksum_policy(), ChecksumPolicy::Enforce);
+    }
+
+    #[test]
+    fn config_rejects_embedded_separators_by_default() {
+        assert_eq!(
+            Config::default().on_embedded_separator(),
+            EmbeddedSeparatorPolicy::Error
+        );
+    }
+
+    #[test]
+    fn config_has_no_tag_type_overrides_by_default() {
+        let config = Config::default();
+        assert_eq!(config.tag_type(6000), None);
+        assert_eq!(config.tag_type(35), None);
+    }
+
+    #[test]
+    fn config_tag_type_overrides_can_be_changed() {
+        let config = Config::default()
+            .with_tag_type(6000, DataType::Data)
+            .with_tag_type(5999, DataType::Length);
+        assert_eq!(config.tag_type(6000), Some(DataType::Data));
+        assert_eq!(config.tag_type(5999), Some(DataType::Length));
+        let config = config.with_tag_type(6000, DataType::String);
+        assert_eq!(config.tag_type(6000), Some(DataType::String));
+    }
+
+    #[test]
+    fn config_settings_can_be_chained() {
+        let config = Config::default()
+            .with_separator(b'|')
+            .with_max_message_size(None)
+            .with_checksum_policy(ChecksumPolicy::Warn)
+            .with_tag_type(6000, DataType::Data);
+        assert_eq!(config.separator(), b'|');
+        assert_eq!(config.max_message_size(), None);
+        assert_eq!(config.checksum_policy(), ChecksumPolicy::Warn);
+        assert_eq!(config.tag_type(6000), Some(DataType::Data));
     }
 }
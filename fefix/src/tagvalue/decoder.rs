@@ -1,10 +1,70 @@
+use super::raw_decoder::start_of_begin_string;
 use super::{RawDecoder, RawDecoderBuffered, RawFrame};
-use crate::tagvalue::{Config, Configure, DecodeError, FixMessageRef, FixMessageRefBuilder};
-use crate::{tags, Dictionary};
+#[cfg(feature = "metrics")]
+use crate::tagvalue::DecoderStats;
+use crate::tagvalue::{
+    Config, Configure, DecodeError, EmbeddedSeparatorPolicy, FixMessageRef, FixMessageRefBuilder,
+    GroupDelimiter, GroupLayout,
+};
+use crate::{tags, DataType, Dictionary};
 use std::fmt::Debug;
+use std::ops::Range;
+use std::sync::Arc;
 
 const BEGIN_STRING_OFFSET: usize = 2;
 
+/// Standard length-prefixed fields, as (length tag, data tag, data type)
+/// triplets. They are always recognized, regardless of the dictionary.
+const LENGTH_PREFIXED_FIELDS: &[(u32, u32, DataType)] = &[
+    // `SecureDataLen <90>`, `SecureData <91>`
+    (tags::SECURE_DATA_LEN, tags::SECURE_DATA, DataType::Data),
+    // `RawDataLength <95>`, `RawData <96>`
+    (tags::RAW_DATA_LENGTH, tags::RAW_DATA, DataType::Data),
+    // `XmlDataLen <212>`, `XmlData <213>`
+    (tags::XML_DATA_LEN, tags::XML_DATA, DataType::XmlData),
+    // `EncodedTextLen <354>`, `EncodedText <355>`
+    (tags::ENCODED_TEXT_LEN, tags::ENCODED_TEXT, DataType::Data),
+    // `EncodedSubjectLen <356>`, `EncodedSubject <357>`
+    (
+        tags::ENCODED_SUBJECT_LEN,
+        tags::ENCODED_SUBJECT,
+        DataType::Data,
+    ),
+    // `EncodedHeadlineLen <358>`, `EncodedHeadline <359>`
+    (
+        tags::ENCODED_HEADLINE_LEN,
+        tags::ENCODED_HEADLINE,
+        DataType::Data,
+    ),
+];
+
+/// Returns the tag of the length field that must immediately precede the
+/// standard `Data` field `data_tag`, if known.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::length_tag_for;
+///
+/// assert_eq!(length_tag_for(96), Some(95));
+/// assert_eq!(length_tag_for(55), None);
+/// ```
+pub fn length_tag_for(data_tag: u32) -> Option<u32> {
+    LENGTH_PREFIXED_FIELDS
+        .iter()
+        .find(|(_, tag, _)| *tag == data_tag)
+        .map(|(length_tag, _, _)| *length_tag)
+}
+
+/// Returns the tag of the standard `Data` field that must immediately follow
+/// the length field `length_tag`, if known.
+pub(crate) fn data_tag_for(length_tag: u32) -> Option<u32> {
+    LENGTH_PREFIXED_FIELDS
+        .iter()
+        .find(|(tag, _, _)| *tag == length_tag)
+        .map(|(_, data_tag, _)| *data_tag)
+}
+
 /// FIX message decoder.
 #[derive(Debug)]
 pub struct Decoder<C = Config>
@@ -12,8 +72,11 @@ where
     C: Configure,
 {
     dict: Dictionary,
+    groups: GroupDelimiter,
     builder: FixMessageRefBuilder,
     raw_decoder: RawDecoder<C>,
+    #[cfg(feature = "metrics")]
+    stats: DecoderStats,
 }
 
 impl<C> Decoder<C>
@@ -28,12 +91,28 @@ where
 
     pub fn with_config(dict: Dictionary, config: C) -> Self {
         Self {
+            groups: GroupDelimiter::new(dict.clone()),
             dict,
             builder: FixMessageRefBuilder::new(),
             raw_decoder: RawDecoder::with_config(config),
+            #[cfg(feature = "metrics")]
+            stats: DecoderStats::default(),
         }
     }
 
+    /// Returns an immutable reference to the [`DecoderStats`] of `self`.
+    #[cfg(feature = "metrics")]
+    pub fn stats(&self) -> &DecoderStats {
+        &self.stats
+    }
+
+    /// Returns a mutable reference to the [`DecoderStats`] of `self`, e.g. to
+    /// [`DecoderStats::reset`] them.
+    #[cfg(feature = "metrics")]
+    pub fn stats_mut(&mut self) -> &mut DecoderStats {
+        &mut self.stats
+    }
+
     /// Returns an immutable reference to the [`Configure`] used by `self`.
     ///
     /// # Examples
@@ -96,17 +175,203 @@ where
     /// );
     /// ```
     pub fn decode<'a>(&'a mut self, bytes: &'a [u8]) -> Result<FixMessageRef<'a>, DecodeError> {
-        let frame = self.raw_decoder.decode(bytes)?;
+        let frame = self.decode_frame(bytes)?;
         self.from_frame(frame)
     }
 
+    /// Like [`Decoder::decode`], but ignores any bytes before the first
+    /// occurrence of `8=`, e.g. a UTF-8 BOM. Returns the number of skipped
+    /// bytes alongside the message. See [`RawDecoder::decode_lenient`].
+    pub fn decode_lenient<'a>(
+        &'a mut self,
+        bytes: &'a [u8],
+    ) -> Result<(usize, FixMessageRef<'a>), DecodeError> {
+        let skipped = start_of_begin_string(bytes).ok_or(DecodeError::Invalid)?;
+        let frame = self.decode_frame(&bytes[skipped..])?;
+        Ok((skipped, self.from_frame(frame)?))
+    }
+
+    fn decode_frame<'a>(&mut self, bytes: &'a [u8]) -> Result<RawFrame<'a>, DecodeError> {
+        let frame = self.raw_decoder.decode(bytes);
+        #[cfg(feature = "metrics")]
+        {
+            self.stats
+                .record_frame(frame.as_ref(), bytes, self.raw_decoder.config());
+            if frame.is_err() {
+                self.stats.record_outcome(false);
+            }
+        }
+        frame
+    }
+
+    /// Returns the canonical case of `value` if it's the value of a `Boolean`
+    /// or enumerated `Char` field and it only differs from its canonical form
+    /// in case. See [`Configure::normalize_case`].
+    fn normalized_case(
+        &self,
+        tag: u32,
+        data_type: Option<DataType>,
+        value: &[u8],
+    ) -> Option<Vec<u8>> {
+        if !self.config().normalize_case() {
+            return None;
+        }
+        match data_type? {
+            DataType::Boolean => match value {
+                b"y" => Some(b"Y".to_vec()),
+                b"n" => Some(b"N".to_vec()),
+                _ => None,
+            },
+            DataType::Char => {
+                let field = self.dict.field_by_tag(tag)?;
+                let mut matches = field
+                    .enums()?
+                    .filter(|variant| variant.value().as_bytes().eq_ignore_ascii_case(value));
+                let canonical = matches.next()?;
+                // Ambiguous or already canonical values are left untouched.
+                if matches.next().is_some() || canonical.value().as_bytes() == value {
+                    None
+                } else {
+                    Some(canonical.value().as_bytes().to_vec())
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the enumerated `value` of `tag`, e.g. `BUY` for
+    /// `Side <54>` and `1`. Values registered via [`Configure::enum_name`]
+    /// take precedence over the dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Decoder};
+    /// use fefix::{AppVersion, Dictionary};
+    ///
+    /// let dict = Dictionary::from_version(AppVersion::Fix44);
+    /// let config = Config::default().with_enum_values(54, &[("Z", "BUY_BLOCK")]);
+    /// let decoder = Decoder::with_config(dict, config);
+    /// assert_eq!(decoder.resolve_enum(54, b"Z"), Some("BUY_BLOCK"));
+    /// assert_eq!(decoder.resolve_enum(54, b"1"), Some("BUY"));
+    /// assert_eq!(decoder.resolve_enum(54, b"Y"), None);
+    /// ```
+    pub fn resolve_enum(&self, tag: u32, value: &[u8]) -> Option<&str> {
+        if let Some(name) = self.config().enum_name(tag, value) {
+            return Some(name);
+        }
+        self.dict
+            .field_by_tag(tag)?
+            .enums()?
+            .find(|variant| variant.value().as_bytes() == value)
+            .map(|variant| variant.description())
+    }
+
+    /// Returns the [`DataType`] of `tag`. Overrides set via
+    /// [`Configure::tag_type`] take precedence over the extension pack
+    /// `appl_ext_id` (see [`Configure::extension_tag_type`]), then standard
+    /// length-prefixed fields, and finally the dictionary.
+    fn data_type_of(&self, tag: u32, appl_ext_id: Option<u32>) -> Option<DataType> {
+        self.config()
+            .tag_type(tag)
+            .or_else(|| {
+                appl_ext_id.and_then(|ext_id| self.config().extension_tag_type(ext_id, tag))
+            })
+            .or_else(|| {
+                LENGTH_PREFIXED_FIELDS
+                    .iter()
+                    .find(|(_, data_tag, _)| *data_tag == tag)
+                    .map(|(_, _, data_type)| *data_type)
+            })
+            .or_else(|| self.dict.field_by_tag(tag).map(|field| field.basetype()))
+    }
+
+    /// Decodes `bytes` like [`Decoder::decode`], but doesn't give up on the
+    /// first problem. Field-level errors (unknown tags, invalid values,
+    /// duplicate tags, a bad `CheckSum <10>` under
+    /// [`ChecksumPolicy::Warn`](super::ChecksumPolicy::Warn)) are accumulated
+    /// and decoding goes on; structural errors stop decoding altogether.
+    ///
+    /// The returned message contains all fields decoded until then, and it's
+    /// only `None` if not even the message framing (`BeginString <8>`,
+    /// `BodyLength <9>`, `CheckSum <10>`) could be recognized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, DecodeError, Decoder};
+    /// use fefix::{AppVersion, Dictionary};
+    ///
+    /// let dict = Dictionary::from_version(AppVersion::Fix44);
+    /// let config = Config::default().with_separator(b'|');
+    /// let decoder = &mut Decoder::with_config(dict, config);
+    /// let data = b"8=FIX.4.4|9=19|35=0|34=X|9999=foo|10=162|";
+    /// let (message, errors) = decoder.decode_collecting_errors(data);
+    /// assert!(message.is_some());
+    /// assert_eq!(
+    ///     errors,
+    ///     vec![DecodeError::InvalidValue(34), DecodeError::UnknownTag(9999)]
+    /// );
+    /// ```
+    pub fn decode_collecting_errors<'a>(
+        &'a mut self,
+        bytes: &'a [u8],
+    ) -> (Option<FixMessageRef<'a>>, Vec<DecodeError>) {
+        let mut errors = Vec::new();
+        match self.decode_frame(bytes) {
+            Ok(frame) => {
+                if !frame.checksum_ok() {
+                    errors.push(DecodeError::CheckSum);
+                }
+                if let Err(err) = self.read_fields(&frame, Some(&mut errors)) {
+                    errors.push(err);
+                }
+                #[cfg(feature = "metrics")]
+                self.stats.record_outcome(errors.is_empty());
+                (Some(self.builder.build(bytes)), errors)
+            }
+            Err(err) => {
+                errors.push(err);
+                (None, errors)
+            }
+        }
+    }
+
     fn from_frame<'a>(&'a mut self, frame: RawFrame<'a>) -> Result<FixMessageRef<'a>, DecodeError> {
+        let result = self.read_fields(&frame, None);
+        #[cfg(feature = "metrics")]
+        self.stats.record_outcome(result.is_ok());
+        result?;
+        Ok(self.builder.build(frame.as_bytes()))
+    }
+
+    /// Fills `self.builder` with the fields of `frame`. Field-level errors are
+    /// pushed to `errors` if present, otherwise they cause an early return.
+    fn read_fields(
+        &mut self,
+        frame: &RawFrame,
+        mut errors: Option<&mut Vec<DecodeError>>,
+    ) -> Result<(), DecodeError> {
         self.builder.clear();
-        let bytes = frame.as_bytes();
+        let payload = frame.payload();
+        let separator = self.config().separator();
+        let embedded_separator_policy = self.config().on_embedded_separator();
         let mut tag_num = 0u32;
+        let mut previous_tag_num = 0u32;
+        let mut data_type = None;
         let mut state_is_tag = true;
-        let mut i_sep;
         let mut i_equal_sign = 0usize;
+        // `Data` fields are always immediately preceded by their length, so we
+        // keep track of the last field if it's a `Length` field, together
+        // with its value.
+        let mut data_field_length = None;
+        // Extension packs only apply to the fields after `ApplExtID <1156>`,
+        // which is part of the header.
+        let mut appl_ext_id = None;
+        // Repeating groups are delimited according to the layouts of
+        // `MsgType <35>`, so they can only start after it.
+        let mut msg_type = "";
+        let mut open_groups: Vec<OpenGroup> = Vec::new();
         self.builder
             .add_field(
                 tags::BEGIN_STRING,
@@ -114,27 +379,282 @@ where
                 frame.begin_string().len(),
             )
             .unwrap();
-        for i in 0..frame.payload().len() {
-            let byte = frame.payload()[i];
-            if byte == b'=' {
+        let mut i = 0;
+        while i < payload.len() {
+            let byte = payload[i];
+            if state_is_tag && byte == b'=' {
                 i_equal_sign = i;
                 state_is_tag = false;
-            } else if byte == self.config().separator() {
-                i_sep = i;
+                data_type = self.data_type_of(tag_num, appl_ext_id);
+                let mut is_data = data_type
+                    .and_then(|dt| dt.requires_preceding_length_tag())
+                    .is_some();
+                // Custom data fields have no known length tag, so we can only
+                // trust the previous field.
+                match length_tag_for(tag_num) {
+                    Some(length_tag) if is_data && previous_tag_num != length_tag => {
+                        if data_type == Some(DataType::XmlData)
+                            && self.config().unprefixed_xml_data_allowed()
+                        {
+                            dbglog!("XmlData <{}> has no length prefix.", tag_num);
+                            is_data = false;
+                        } else {
+                            return Err(DecodeError::MissingLengthPrefix { data_tag: tag_num });
+                        }
+                    }
+                    _ => {}
+                }
+                match data_field_length.take() {
+                    Some((length_tag, len))
+                        if is_data
+                            && length_tag_for(tag_num).unwrap_or(length_tag) == length_tag =>
+                    {
+                        // `Data` fields may contain the separator, so we must
+                        // jump over them.
+                        i = i
+                            .checked_add(len)
+                            .filter(|end| *end < payload.len())
+                            .ok_or(DecodeError::Invalid)?;
+                        if payload.get(i + 1) != Some(&separator) {
+                            return Err(DecodeError::Invalid);
+                        }
+                    }
+                    _ => {}
+                }
+            } else if !state_is_tag && byte == separator {
+                let value_end = i;
+                if !is_field_start(&payload[i + 1..]) {
+                    match embedded_separator_policy {
+                        EmbeddedSeparatorPolicy::Error => {
+                            return Err(DecodeError::InvalidValue(tag_num));
+                        }
+                        EmbeddedSeparatorPolicy::Truncate => {
+                            i = next_field_boundary(payload, i, separator);
+                        }
+                        EmbeddedSeparatorPolicy::Lenient => {
+                            i += 1;
+                            continue;
+                        }
+                    }
+                }
                 state_is_tag = true;
-                self.builder
-                    .add_field(
-                        tag_num,
-                        frame.payload_offset() + i_equal_sign + 1,
-                        i_sep - i_equal_sign - 1,
-                    )
-                    .unwrap();
+                let value = &payload[i_equal_sign + 1..value_end];
+                data_field_length = if is_length_tag(tag_num, data_type) {
+                    std::str::from_utf8(value)
+                        .ok()
+                        .and_then(|s| s.parse::<usize>().ok())
+                        .map(|len| (tag_num, len))
+                } else {
+                    None
+                };
+                let value_len = value.len();
+                if tag_num == tags::fix50sp2::APPL_EXT_ID {
+                    appl_ext_id = std::str::from_utf8(value).ok().and_then(|s| s.parse().ok());
+                }
+                let normalized = self.normalized_case(tag_num, data_type, value);
+                let value = normalized.as_deref().unwrap_or(value);
+                #[cfg(feature = "metrics")]
+                {
+                    if data_type.is_none() {
+                        self.stats.record_unknown_tag();
+                    }
+                }
+                if let Some(errors) = errors.as_deref_mut() {
+                    let strict = !self.config().negative_values_allowed();
+                    match data_type {
+                        None => errors.push(DecodeError::UnknownTag(tag_num)),
+                        Some(dt) if !dt.validate(value, strict) => {
+                            errors.push(DecodeError::InvalidValue(tag_num))
+                        }
+                        Some(_) => {}
+                    }
+                }
+                // Every group entry starts with the delimiter, and the group
+                // ends at the first field that doesn't belong to it.
+                while let Some(group) = open_groups.last_mut() {
+                    if tag_num == group.layout.delimiter() {
+                        group.entries += 1;
+                        self.builder.add_entry();
+                        break;
+                    } else if group.entries > 0 && group.layout.contains(tag_num) {
+                        break;
+                    }
+                    let group = open_groups.pop().unwrap();
+                    self.builder.exit_group();
+                    if let Err(err) = group.check(payload) {
+                        match errors.as_deref_mut() {
+                            Some(errors) => errors.push(err),
+                            None => return Err(err),
+                        }
+                    }
+                }
+                #[cfg(feature = "validate-xml")]
+                {
+                    if data_type == Some(DataType::XmlData) && !xml_is_well_formed(value) {
+                        match errors.as_deref_mut() {
+                            Some(errors) => errors.push(DecodeError::InvalidXml(tag_num)),
+                            None => return Err(DecodeError::InvalidXml(tag_num)),
+                        }
+                    }
+                }
+                if tag_num == tags::MSG_TYPE && open_groups.is_empty() {
+                    let raw_value = &payload[i_equal_sign + 1..value_end];
+                    msg_type = std::str::from_utf8(raw_value).unwrap_or("");
+                }
+                let offset = frame.payload_offset() + i_equal_sign + 1;
+                if self.builder.add_field(tag_num, offset, value_len).is_err() {
+                    match errors.as_deref_mut() {
+                        Some(errors) => errors.push(DecodeError::DuplicateTag(tag_num)),
+                        None => return Err(DecodeError::DuplicateTag(tag_num)),
+                    }
+                } else {
+                    if let Some(normalized) = &normalized {
+                        self.builder.set_normalized(tag_num, normalized);
+                    }
+                    if let Some(interned) = self
+                        .config()
+                        .interner()
+                        .and_then(|interner| interner.get(tag_num, value))
+                    {
+                        self.builder.set_interned(tag_num, interned);
+                    }
+                }
+                if data_type == Some(DataType::NumInGroup) {
+                    let empty_groups_allowed = self.config().empty_groups_allowed();
+                    let value_range = i_equal_sign + 1..value_end;
+                    match parse_group_count(tag_num, value, empty_groups_allowed) {
+                        Ok(0) => {}
+                        // Groups with an unknown layout can't be delimited,
+                        // so only their count is checked.
+                        Ok(count) => {
+                            if let Some(layout) = self.groups.layout(msg_type, tag_num) {
+                                open_groups.push(OpenGroup {
+                                    tag: tag_num,
+                                    layout: layout.clone(),
+                                    count,
+                                    entries: 0,
+                                    value_range,
+                                });
+                                self.builder.enter_group(tag_num);
+                            }
+                        }
+                        Err(err) => match errors.as_deref_mut() {
+                            Some(errors) => errors.push(err),
+                            None => return Err(err),
+                        },
+                    }
+                }
+                previous_tag_num = tag_num;
                 tag_num = 0;
             } else if state_is_tag {
-                tag_num = tag_num * 10 + (byte - b'0') as u32;
+                tag_num = tag_num * 10 + byte.wrapping_sub(b'0') as u32;
+            }
+            i += 1;
+        }
+        while let Some(group) = open_groups.pop() {
+            self.builder.exit_group();
+            if let Err(err) = group.check(payload) {
+                match errors.as_deref_mut() {
+                    Some(errors) => errors.push(err),
+                    None => return Err(err),
+                }
+            }
+        }
+        self.builder.set_checksum_ok(frame.checksum_ok());
+        Ok(())
+    }
+}
+
+/// A repeating group that is being decoded.
+struct OpenGroup {
+    tag: u32,
+    layout: Arc<GroupLayout>,
+    count: usize,
+    entries: usize,
+    // The value of the `NumInGroup` field, within the payload.
+    value_range: Range<usize>,
+}
+
+impl OpenGroup {
+    /// Checks that the group has as many entries as announced.
+    fn check(&self, payload: &[u8]) -> Result<(), DecodeError> {
+        let value = &payload[self.value_range.clone()];
+        if self.entries == self.count {
+            Ok(())
+        } else {
+            Err(group_count_error(self.tag, value))
+        }
+    }
+}
+
+/// Returns `true` if `tag` may hold the length of the `Data` field that
+/// follows it, i.e. if it's either a standard length tag (see
+/// [`length_tag_for`]) or a `Length` field.
+fn is_length_tag(tag: u32, data_type: Option<DataType>) -> bool {
+    data_type == Some(DataType::Length)
+        || LENGTH_PREFIXED_FIELDS
+            .iter()
+            .any(|(length_tag, _, _)| *length_tag == tag)
+}
+
+/// Returns `true` if `data` is either empty or starts with `tag=`.
+fn is_field_start(data: &[u8]) -> bool {
+    let digits = data.iter().take_while(|byte| byte.is_ascii_digit()).count();
+    data.is_empty() || (digits > 0 && data.get(digits) == Some(&b'='))
+}
+
+/// Returns the index of the first separator after `i` that is followed by
+/// another field (or by nothing at all).
+fn next_field_boundary(payload: &[u8], i: usize, separator: u8) -> usize {
+    (i + 1..payload.len())
+        .find(|j| payload[*j] == separator && is_field_start(&payload[j + 1..]))
+        .unwrap_or(payload.len() - 1)
+}
+
+fn group_count_error(tag: u32, value: &[u8]) -> DecodeError {
+    DecodeError::GroupCount {
+        tag,
+        value: String::from_utf8_lossy(value).into_owned(),
+    }
+}
+
+/// Parses the value of the `NumInGroup` field `tag`, i.e. the number of
+/// entries of its repeating group.
+pub(super) fn parse_group_count(
+    tag: u32,
+    value: &[u8],
+    empty_groups_allowed: bool,
+) -> Result<usize, DecodeError> {
+    if value.is_empty() || !value.iter().all(u8::is_ascii_digit) {
+        return Err(group_count_error(tag, value));
+    }
+    match std::str::from_utf8(value).ok().and_then(|s| s.parse().ok()) {
+        Some(0) if !empty_groups_allowed => Err(group_count_error(tag, value)),
+        Some(count) => Ok(count),
+        None => Err(group_count_error(tag, value)),
+    }
+}
+
+#[cfg(feature = "validate-xml")]
+fn xml_is_well_formed(data: &[u8]) -> bool {
+    use quick_xml::events::Event;
+    let mut reader = quick_xml::Reader::from_reader(data);
+    let mut buffer = Vec::new();
+    let mut depth = 0usize;
+    let mut elements = 0usize;
+    loop {
+        match reader.read_event(&mut buffer) {
+            Ok(Event::Start(_)) => {
+                depth += 1;
+                elements += 1;
             }
+            Ok(Event::Empty(_)) => elements += 1,
+            Ok(Event::End(_)) => depth -= 1,
+            Ok(Event::Eof) => return depth == 0 && elements > 0,
+            Ok(_) => {}
+            Err(_) => return false,
         }
-        Ok(self.builder.build(bytes))
+        buffer.clear();
     }
 }
 
@@ -199,7 +719,23 @@ where
     }
 
     pub fn current_message(&mut self) -> Result<Option<FixMessageRef>, DecodeError> {
-        match self.raw_decoder.current_frame() {
+        let frame = self.raw_decoder.current_frame();
+        #[cfg(feature = "metrics")]
+        {
+            let config = self.raw_decoder.config();
+            let stats = &mut self.decoder.stats;
+            match &frame {
+                Ok(Some(frame)) => stats.record_frame(Ok(frame), frame.as_bytes(), config),
+                Ok(None) => {}
+                // The buffer isn't available in case of errors, so
+                // `BodyLength <9>` mismatches can't be told apart.
+                Err(err) => {
+                    stats.record_frame(Err(err), &[], config);
+                    stats.record_outcome(false);
+                }
+            }
+        }
+        match frame {
             Ok(Some(frame)) => self.decoder.from_frame(frame).map(|msg| Some(msg)),
             Ok(None) => Ok(None),
             Err(e) => Err(e),
@@ -210,7 +746,8 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{tagvalue::Config, AppVersion};
+    use crate::tagvalue::{recompute_trailer, ChecksumPolicy, Config};
+    use crate::{AppVersion, FixVersion};
 
     // Use http://www.validfix.com/fix-analyzer.html for testing.
 
@@ -241,11 +778,38 @@ mod test {
         "8=FIX.4.2|9=196|35=X|49=A|56=B|34=12|52=20100318-03:21:11.364|262=A|268=2|279=0|269=0|278=BID|55=EUR/USD|270=1.37215|15=EUR|271=2500000|346=1|279=0|269=1|278=OFFER|55=EUR/USD|270=1.37224|15=EUR|271=2503200|346=1|10=171|",
     ];
 
+    #[test]
+    fn hot_header_fields_match_field_lookups() {
+        let decoder = &mut decoder();
+        decoder.config_mut().set_separator(0x1);
+        for msg_with_vertical_bar in RANDOM_MESSAGES {
+            let msg = with_soh(msg_with_vertical_bar);
+            let message = decoder.decode(msg.as_bytes()).unwrap();
+            assert_eq!(Some(message.msg_type()), message.field_raw(tags::MSG_TYPE));
+            assert_eq!(
+                message.seq_num(),
+                message.field_as_i64(tags::MSG_SEQ_NUM).map(|n| n as u64)
+            );
+            assert_eq!(message.sender(), message.field_raw(tags::SENDER_COMP_ID));
+            assert_eq!(message.target(), message.field_raw(tags::TARGET_COMP_ID));
+            assert!(message.target().is_some());
+        }
+        // Nothing is left over from previous messages.
+        let msg = with_soh("8=FIX.4.4|9=5|35=0|10=163|");
+        let message = decoder.decode(msg.as_bytes()).unwrap();
+        assert_eq!(message.msg_type(), b"0");
+        assert_eq!(message.seq_num(), None);
+        assert_eq!(message.sender(), None);
+        assert_eq!(message.target(), None);
+    }
+
     #[test]
     fn skip_checksum_verification() {
         let message = "8=FIX.FOOBAR|9=5|35=0|10=000|";
         let decoder = &mut decoder();
-        decoder.config_mut().set_verify_checksum(false);
+        decoder
+            .config_mut()
+            .set_checksum_policy(ChecksumPolicy::Ignore);
         let result = decoder.decode(message.as_bytes());
         assert!(result.is_ok());
     }
@@ -255,11 +819,295 @@ mod test {
         let message = "8=FIX.FOOBAR|9=5|35=0|10=000|";
         let mut codec = Decoder::<Config>::new(Dictionary::from_version(AppVersion::Fix44));
         codec.config_mut().set_separator(b'|');
-        codec.config_mut().set_verify_checksum(true);
+        codec
+            .config_mut()
+            .set_checksum_policy(ChecksumPolicy::Enforce);
         let result = codec.decode(message.as_bytes());
         assert!(result.is_err());
     }
 
+    #[test]
+    fn warn_on_bad_checksum() {
+        let message = "8=FIX.FOOBAR|9=5|35=0|10=000|";
+        let decoder = &mut decoder();
+        decoder
+            .config_mut()
+            .set_checksum_policy(ChecksumPolicy::Warn);
+        let message = decoder.decode(message.as_bytes()).unwrap();
+        assert!(!message.checksum_ok());
+        assert_eq!(message.field_raw(35), Some(b"0" as &[u8]));
+    }
+
+    #[test]
+    fn custom_data_field_may_contain_separator() {
+        let message = "8=FIX.4.4|9=25|35=0|5999=7|6000=foo|bar|10=138|";
+        let config = Config::default()
+            .with_separator(b'|')
+            .with_tag_type(5999, DataType::Length)
+            .with_tag_type(6000, DataType::Data);
+        let decoder =
+            &mut Decoder::with_config(Dictionary::from_version(AppVersion::Fix44), config);
+        let message = decoder.decode(message.as_bytes()).unwrap();
+        assert_eq!(message.field_raw(6000), Some(b"foo|bar" as &[u8]));
+        assert_eq!(message.field_raw(35), Some(b"0" as &[u8]));
+    }
+
+    #[test]
+    fn custom_data_field_length_comes_from_length_field_only() {
+        let message = "8=FIX.4.4|9=22|35=0|34=3|6000=abcdef|10=106|";
+        let config = Config::default()
+            .with_separator(b'|')
+            .with_tag_type(6000, DataType::Data);
+        let dict = Dictionary::from_version(AppVersion::Fix44);
+        let decoder = &mut Decoder::with_config(dict, config);
+        let message = decoder.decode(message.as_bytes()).unwrap();
+        assert_eq!(message.field_raw(6000), Some(b"abcdef" as &[u8]));
+    }
+
+    #[test]
+    fn huge_data_field_length_is_invalid() {
+        let message = "8=FIX.4.4|9=34|35=0|95=18446744073709551615|96=x|10=036|";
+        let decoder = &mut decoder();
+        assert_eq!(
+            decoder.decode(message.as_bytes()).err(),
+            Some(DecodeError::Invalid)
+        );
+    }
+
+    #[test]
+    fn embedded_separator_is_an_error_by_default() {
+        let message = "8=FIX.4.4|9=19|35=D|55=AA|PL|54=1|10=109|";
+        let decoder = &mut decoder();
+        assert_eq!(
+            decoder.decode(message.as_bytes()).err(),
+            Some(DecodeError::InvalidValue(55))
+        );
+    }
+
+    #[test]
+    fn embedded_separator_truncates_value() {
+        let message = "8=FIX.4.4|9=19|35=D|55=AA|PL|54=1|10=109|";
+        let decoder = &mut decoder();
+        decoder
+            .config_mut()
+            .set_embedded_separator_policy(EmbeddedSeparatorPolicy::Truncate);
+        let message = decoder.decode(message.as_bytes()).unwrap();
+        assert_eq!(message.field_raw(55), Some(b"AA" as &[u8]));
+        assert_eq!(message.field_raw(54), Some(b"1" as &[u8]));
+    }
+
+    #[test]
+    fn embedded_separator_is_kept_by_lenient_policy() {
+        let message = "8=FIX.4.4|9=19|35=D|55=AA|PL|54=1|10=109|";
+        let decoder = &mut decoder();
+        decoder
+            .config_mut()
+            .set_embedded_separator_policy(EmbeddedSeparatorPolicy::Lenient);
+        let message = decoder.decode(message.as_bytes()).unwrap();
+        assert_eq!(message.field_raw(55), Some(b"AA|PL" as &[u8]));
+        assert_eq!(message.field_raw(54), Some(b"1" as &[u8]));
+    }
+
+    #[test]
+    fn standard_data_field_may_contain_separator() {
+        let message = "8=FIX.4.4|9=19|35=0|95=5|96=a|b|c|10=235|";
+        let decoder = &mut decoder();
+        let message = decoder.decode(message.as_bytes()).unwrap();
+        assert_eq!(message.field_raw(96), Some(b"a|b|c" as &[u8]));
+    }
+
+    #[test]
+    fn data_field_without_length_prefix() {
+        let message = "8=FIX.4.4|9=14|35=0|96=a|b|c|10=138|";
+        let decoder = &mut decoder();
+        assert_eq!(
+            decoder.decode(message.as_bytes()).err(),
+            Some(DecodeError::MissingLengthPrefix { data_tag: 96 })
+        );
+    }
+
+    #[test]
+    fn secure_data_is_length_prefixed_without_dictionary() {
+        let message = "8=FIX.4.4|9=20|35=A|90=6|91=\x01a|b\x01c|10=113|";
+        let decoder =
+            &mut Decoder::with_config(Dictionary::empty(), Config::default().with_separator(b'|'));
+        let message = decoder.decode(message.as_bytes()).unwrap();
+        assert_eq!(message.field_raw(91), Some(b"\x01a|b\x01c" as &[u8]));
+    }
+
+    #[test]
+    fn patch_price_in_place() {
+        let message = "8=FIX.4.2|9=27|35=D|44=1.5|49=A|56=B|59=0|10=000|";
+        let decoder = &mut decoder();
+        decoder
+            .config_mut()
+            .set_checksum_policy(ChecksumPolicy::Ignore);
+        let range = {
+            let message = decoder.decode(message.as_bytes()).unwrap();
+            let ranges = message.field_ranges();
+            assert_eq!(ranges[0], (8, 2..9));
+            ranges.into_iter().find(|(tag, _)| *tag == 44).unwrap().1
+        };
+        let mut buffer = message.as_bytes().to_vec();
+        buffer.splice(range, b"101.25".iter().copied());
+        recompute_trailer(&mut buffer).unwrap();
+        assert_eq!(
+            &buffer[..],
+            b"8=FIX.4.2|9=30|35=D|44=101.25|49=A|56=B|59=0|10=192|" as &[u8]
+        );
+        decoder
+            .config_mut()
+            .set_checksum_policy(ChecksumPolicy::Enforce);
+        let message = decoder.decode(&buffer[..]).unwrap();
+        assert_eq!(message.field_raw(44), Some(b"101.25" as &[u8]));
+    }
+
+    const XML_MESSAGE: &str = "8=FIX.4.4|9=38|35=n|212=21|213=<a><b>x\x01y</b><c/></a>|10=082|";
+
+    #[test]
+    fn xml_data_may_contain_soh() {
+        let decoder = &mut decoder();
+        let message = decoder.decode(XML_MESSAGE.as_bytes()).unwrap();
+        assert_eq!(
+            message.field_raw(213),
+            Some(b"<a><b>x\x01y</b><c/></a>" as &[u8])
+        );
+    }
+
+    #[test]
+    fn unprefixed_xml_data_is_only_accepted_in_lenient_mode() {
+        let message = "8=FIX.4.4|9=34|35=n|213=<a><b>x</b><c/></a>|49=A|10=138|";
+        let decoder = &mut decoder();
+        assert_eq!(
+            decoder.decode(message.as_bytes()).err(),
+            Some(DecodeError::MissingLengthPrefix { data_tag: 213 })
+        );
+        decoder.config_mut().set_unprefixed_xml_data_allowed(true);
+        let decoded = decoder.decode(message.as_bytes()).unwrap();
+        assert_eq!(
+            decoded.field_raw(213),
+            Some(b"<a><b>x</b><c/></a>" as &[u8])
+        );
+        assert_eq!(decoded.field_raw(49), Some(b"A" as &[u8]));
+        // Length-prefixed `XmlData <213>` is still decoded as usual.
+        let decoded = decoder.decode(XML_MESSAGE.as_bytes()).unwrap();
+        assert_eq!(
+            decoded.field_raw(213),
+            Some(b"<a><b>x\x01y</b><c/></a>" as &[u8])
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "validate-xml")]
+    fn malformed_xml_data_is_invalid() {
+        let message = "8=FIX.4.4|9=32|35=n|212=15|213=<a><b>x</a></b>|10=201|";
+        let decoder = &mut decoder();
+        assert_eq!(
+            decoder.decode(message.as_bytes()),
+            Err(DecodeError::InvalidXml(213))
+        );
+        assert!(decoder.decode(XML_MESSAGE.as_bytes()).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "metrics")]
+    fn stats_count_body_length_failures_under_each_policy() {
+        use crate::tagvalue::BodyLengthPolicy;
+        // `BodyLength <9>` is off by 5.
+        let message = b"8=FIX.4.2|9=37|35=0|49=A|56=B|34=12|52=20100304-07:59:30|10=026|";
+        let decoder = &mut decoder();
+        assert!(decoder.decode(message).is_err());
+        decoder
+            .config_mut()
+            .set_body_length_policy(BodyLengthPolicy::Warn);
+        assert!(decoder.decode(message).is_ok());
+        decoder
+            .config_mut()
+            .set_body_length_policy(BodyLengthPolicy::Ignore);
+        assert!(decoder.decode(message).is_ok());
+        assert_eq!(decoder.stats().body_length_failures(), 2);
+        assert_eq!(decoder.stats().messages_decoded(), 2);
+        assert_eq!(decoder.stats().decode_errors(), 1);
+        assert_eq!(decoder.stats().checksum_failures(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "metrics")]
+    fn stats_count_errors_while_collecting() {
+        let message = "8=FIX.4.4|9=29|35=0|34=X|9999=foo|43=?|34=2|10=000|";
+        let decoder = &mut decoder();
+        decoder
+            .config_mut()
+            .set_checksum_policy(ChecksumPolicy::Warn);
+        let (_, errors) = decoder.decode_collecting_errors(message.as_bytes());
+        assert!(!errors.is_empty());
+        let stats = *decoder.stats();
+        assert_eq!(stats.checksum_failures(), 1);
+        assert_eq!(stats.unknown_tags(), 1);
+        assert_eq!(stats.decode_errors(), 1);
+        assert_eq!(stats.messages_decoded(), 0);
+        decoder.stats_mut().reset();
+        assert_eq!(decoder.stats().unknown_tags(), 0);
+    }
+
+    #[test]
+    fn negative_qty_is_invalid_unless_allowed() {
+        let message = "8=FIX.4.4|9=21|35=D|38=-100|44=-1.5|10=026|";
+        let decoder = &mut decoder();
+        let (_, errors) = decoder.decode_collecting_errors(message.as_bytes());
+        assert_eq!(errors, vec![DecodeError::InvalidValue(38)]);
+        decoder.config_mut().set_negative_values_allowed(true);
+        let (message, errors) = decoder.decode_collecting_errors(message.as_bytes());
+        assert!(errors.is_empty());
+        assert_eq!(message.unwrap().field_raw(38), Some(b"-100" as &[u8]));
+    }
+
+    #[test]
+    fn custom_begin_string() {
+        let message = "8=MYPROTO.1.0|9=10|35=0|49=A|10=243|";
+        let decoder = &mut decoder();
+        let message = decoder.decode(message.as_bytes()).unwrap();
+        assert_eq!(message.field_raw(8), Some(b"MYPROTO.1.0" as &[u8]));
+        assert_eq!(message.field_raw(49), Some(b"A" as &[u8]));
+    }
+
+    #[test]
+    fn collecting_errors_goes_past_field_errors() {
+        let message = "8=FIX.4.4|9=29|35=0|34=X|9999=foo|43=?|34=2|10=084|";
+        let decoder = &mut decoder();
+        let (message, errors) = decoder.decode_collecting_errors(message.as_bytes());
+        assert_eq!(
+            errors,
+            vec![
+                DecodeError::InvalidValue(34),
+                DecodeError::UnknownTag(9999),
+                DecodeError::InvalidValue(43),
+                DecodeError::DuplicateTag(34),
+            ]
+        );
+        let message = message.unwrap();
+        assert_eq!(message.field_raw(35), Some(b"0" as &[u8]));
+        assert_eq!(message.field_raw(9999), Some(b"foo" as &[u8]));
+    }
+
+    #[test]
+    fn collecting_errors_stops_at_structural_errors() {
+        let message = "8=FIX.4.4|9=37|35=0|34=X|10=104|";
+        let decoder = &mut decoder();
+        let (message, errors) = decoder.decode_collecting_errors(message.as_bytes());
+        assert!(message.is_none());
+        assert_eq!(errors, vec![DecodeError::Invalid]);
+    }
+
+    #[test]
+    fn collecting_errors_on_valid_message() {
+        let message = "8=FIX.4.2|9=40|35=D|49=AFUNDMGR|56=ABROKER|15=USD|59=0|10=091|";
+        let decoder = &mut decoder();
+        let (message, errors) = decoder.decode_collecting_errors(message.as_bytes());
+        assert!(message.is_some());
+        assert!(errors.is_empty());
+    }
+
     #[test]
     fn assortment_of_random_messages_is_ok() {
         for msg_with_vertical_bar in RANDOM_MESSAGES {
@@ -274,12 +1122,149 @@ mod test {
     #[test]
     fn heartbeat_message_fields_are_ok() {
         let mut codec = decoder();
-        codec.config_mut().set_verify_checksum(false);
+        codec
+            .config_mut()
+            .set_checksum_policy(ChecksumPolicy::Ignore);
         let message = codec.decode(&mut RANDOM_MESSAGES[0].as_bytes()).unwrap();
         assert_eq!(message.field_raw(8), Some(b"FIX.4.2" as &[u8]));
         assert_eq!(message.field_raw(35), Some(b"0" as &[u8]),);
     }
 
+    #[test]
+    fn utf8_encoded_text_with_separator() {
+        let msg = "8=FIX.4.4|9=43|35=B|347=UTF-8|354=11|355=Grüße|€|58=x|10=057|";
+        let mut codec = decoder();
+        let message = codec.decode(msg.as_bytes()).unwrap();
+        let text = message.field_as_encoded(tags::ENCODED_TEXT).unwrap();
+        assert_eq!(text.encoding(), Some("UTF-8"));
+        assert_eq!(std::str::from_utf8(text.value()), Ok("Grüße|€"));
+        assert_eq!(message.field_raw(tags::TEXT), Some(b"x" as &[u8]));
+        assert_eq!(message.field_as_encoded(tags::TEXT), None);
+    }
+
+    #[test]
+    fn encoded_text_without_length() {
+        let msg = "8=FIX.4.4|9=18|35=B|355=foo|58=x|10=146|";
+        let mut codec = decoder();
+        assert_eq!(
+            codec.decode(msg.as_bytes()).err(),
+            Some(DecodeError::MissingLengthPrefix {
+                data_tag: tags::ENCODED_TEXT
+            })
+        );
+    }
+
+    #[test]
+    fn extension_pack_tags_resolve_after_appl_ext_id() {
+        let dict = Dictionary::from_version(AppVersion::Fix50SP2);
+        let config = Config::default().with_separator(b'|');
+        let decoder = &mut Decoder::with_config(dict.clone(), config.clone());
+        let msg = b"8=FIXT.1.1|9=23|35=0|1156=254|20001=42|10=197|";
+        let (_, errors) = decoder.decode_collecting_errors(msg);
+        assert_eq!(errors, vec![DecodeError::UnknownTag(20001)]);
+
+        let config = config.with_extension_pack(254, &[(20001, DataType::Int)]);
+        let decoder = &mut Decoder::with_config(dict, config);
+        let (message, errors) = decoder.decode_collecting_errors(msg);
+        assert_eq!(errors, vec![]);
+        assert_eq!(message.unwrap().field_as_i64(20001), Some(42));
+        // Other extension packs and messages without `ApplExtID <1156>` are
+        // unaffected.
+        for msg in [
+            &b"8=FIXT.1.1|9=21|35=0|1156=7|20001=42|10=095|"[..],
+            b"8=FIXT.1.1|9=14|35=0|20001=42|10=164|",
+        ]
+        .iter()
+        {
+            let (_, errors) = decoder.decode_collecting_errors(msg);
+            assert_eq!(errors, vec![DecodeError::UnknownTag(20001)]);
+        }
+    }
+
+    #[test]
+    fn decode_lenient_skips_bom() {
+        let decoder = &mut decoder();
+        let msg = b"\xEF\xBB\xBF8=FIX.4.4|9=41|35=0|49=A|56=B|34=1|52=20210101-00:00:00|10=202|";
+        assert!(decoder.decode(msg).is_err());
+        let (skipped, message) = decoder.decode_lenient(msg).unwrap();
+        assert_eq!(skipped, 3);
+        assert_eq!(message.field_as_str(tags::SENDER_COMP_ID), Some("A"));
+        let (skipped, _) = decoder.decode_lenient(&msg[3..]).unwrap();
+        assert_eq!(skipped, 0);
+        assert_eq!(
+            decoder.decode_lenient(b"\xEF\xBB\xBF").unwrap_err(),
+            DecodeError::Invalid
+        );
+    }
+
+    #[test]
+    fn equal_signs_within_values() {
+        let msg = b"8=FIX.4.4|9=50|35=0|49=A|56=B|34=1|52=20210101-00:00:00|58=a=b=c|10=144|";
+        let decoder = &mut decoder();
+        let message = decoder.decode(msg).unwrap();
+        assert_eq!(message.field_raw(tags::TEXT), Some(&b"a=b=c"[..]));
+        assert_eq!(message.field_raw(tags::SENDER_COMP_ID), Some(&b"A"[..]));
+    }
+
+    #[test]
+    fn all_values_across_group_entries() {
+        let msg = b"8=FIX.4.4|9=101|35=W|49=A|56=B|34=1|52=20210101-00:00:00|55=EUR/USD|268=3|269=0|270=1.1|269=1|270=1.2|269=2|270=1.15|10=081|";
+        let decoder = &mut decoder();
+        let message = decoder.decode(msg).unwrap();
+        let prices: Vec<&[u8]> = message.all_values(270).collect();
+        assert_eq!(prices, vec![&b"1.1"[..], b"1.2", b"1.15"]);
+        assert_eq!(message.all_values(55).count(), 1);
+        assert_eq!(message.all_values(271).count(), 0);
+    }
+
+    #[test]
+    fn all_values_skips_data_fields() {
+        let msg = b"8=FIX.4.4|9=81|35=W|95=7|96=x|270=9|55=EUR/USD|268=3|269=0|270=1.1|269=1|270=1.2|269=2|270=1.15|10=043|";
+        let decoder = &mut decoder();
+        let message = decoder.decode(msg).unwrap();
+        let prices: Vec<&[u8]> = message.all_values(270).collect();
+        assert_eq!(prices, vec![&b"1.1"[..], b"1.2", b"1.15"]);
+        assert_eq!(message.all_values(96).collect::<Vec<_>>(), vec![b"x|270=9"]);
+    }
+
+    #[test]
+    fn fixt11_message_reports_appl_ver_id() {
+        let dict = Dictionary::from_version(AppVersion::Fix50SP2);
+        let config = Config::default().with_separator(b'|');
+        let fixt_decoder = &mut Decoder::with_config(dict, config);
+        let msg = b"8=FIXT.1.1|9=48|35=0|49=A|56=B|34=1|52=20210101-00:00:00|1128=9|10=221|";
+        let message = fixt_decoder.decode(msg).unwrap();
+        assert_eq!(
+            message.resolved_version(),
+            FixVersion::Standard(AppVersion::Fixt11)
+        );
+        assert_eq!(message.appl_ver_id(), Some(AppVersion::Fix50SP2));
+
+        let decoder = &mut decoder();
+        let msg = b"8=FIX.4.4|9=41|35=0|49=A|56=B|34=1|52=20210101-00:00:00|10=202|";
+        let message = decoder.decode(msg).unwrap();
+        assert_eq!(
+            message.resolved_version(),
+            FixVersion::Standard(AppVersion::Fix44)
+        );
+        assert_eq!(message.appl_ver_id(), None);
+    }
+
+    #[test]
+    fn custom_enum_values_take_precedence() {
+        let config = Config::default()
+            .with_separator(b'|')
+            .with_enum_values(tags::ORD_TYPE, &[("Z", "VWAP"), ("1", "AT_MARKET")]);
+        let decoder = Decoder::with_config(Dictionary::from_version(AppVersion::Fix44), config);
+        assert_eq!(decoder.resolve_enum(tags::ORD_TYPE, b"Z"), Some("VWAP"));
+        assert_eq!(
+            decoder.resolve_enum(tags::ORD_TYPE, b"1"),
+            Some("AT_MARKET")
+        );
+        assert_eq!(decoder.resolve_enum(tags::ORD_TYPE, b"2"), Some("LIMIT"));
+        assert_eq!(decoder.resolve_enum(tags::SIDE, b"Z"), None);
+    }
+
     #[test]
     fn message_without_final_separator() {
         let message = "8=FIX.4.4|9=122|35=D|34=215|49=CLIENT12|52=20100225-19:41:57.316|56=B|1=Marcel|11=13346|21=1|40=2|44=5|54=1|59=0|60=20100225-19:39:52.020|10=072";
@@ -321,4 +1306,131 @@ mod test {
         let result = codec.decode(&mut msg.as_bytes());
         assert_eq!(result, Err(DecodeError::Invalid));
     }
+
+    #[test]
+    fn two_char_msg_type() {
+        use crate::dictionary::MsgType;
+
+        let msg = "8=FIX.4.4|9=40|35=AE|571=TR-1|55=AAPL|32=100|31=150.25|10=043|";
+        let mut codec = decoder();
+        let message = codec.decode(msg.as_bytes()).unwrap();
+        assert_eq!(message.f_msg_type(), Some("AE"));
+        let msg_type = MsgType::from_bytes(message.field_raw(35).unwrap()).unwrap();
+        assert_eq!(msg_type, MsgType::TradeCaptureReport);
+        assert!(!msg_type.is_admin());
+    }
+
+    #[test]
+    fn interned_field_values() {
+        use crate::tagvalue::Interner;
+
+        let dict = Dictionary::from_version(AppVersion::Fix44);
+        let config = Config::default()
+            .with_separator(b'|')
+            .with_interner(Interner::common());
+        let mut codec = Decoder::with_config(dict, config);
+        let msg = "8=FIX.4.4|9=29|35=D|15=EUR|55=AAPL|207=XNAS|10=054|";
+        let interned = {
+            let message = codec.decode(msg.as_bytes()).unwrap();
+            assert_eq!(message.field_interned(tags::SYMBOL), None);
+            assert_eq!(message.field_interned(tags::MSG_TYPE), Some("D"));
+            (
+                message.field_interned(tags::CURRENCY),
+                message.field_interned(tags::SECURITY_EXCHANGE),
+            )
+        };
+        assert_eq!(interned, (Some("EUR"), Some("XNAS")));
+    }
+
+    #[test]
+    fn group_count_larger_than_entries() {
+        let msg = "8=FIX.4.4|9=55|35=V|262=1|263=0|264=0|267=5|269=0|269=1|146=1|55=AAPL|10=100|";
+        let mut codec = decoder();
+        let result = codec.decode(msg.as_bytes());
+        assert_eq!(
+            result,
+            Err(DecodeError::GroupCount {
+                tag: 267,
+                value: "5".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn negative_group_count() {
+        let msg = "8=FIX.4.4|9=50|35=V|262=1|263=0|264=0|267=-1|269=0|146=1|55=AAPL|10=253|";
+        let mut codec = decoder();
+        let result = codec.decode(msg.as_bytes());
+        assert_eq!(
+            result,
+            Err(DecodeError::GroupCount {
+                tag: 267,
+                value: "-1".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn empty_groups_are_configurable() {
+        let msg = "8=FIX.4.4|9=43|35=V|262=1|263=0|264=0|267=0|146=1|55=AAPL|10=071|";
+        let mut codec = decoder();
+        let message = codec.decode(msg.as_bytes()).unwrap();
+        assert_eq!(message.field_as_i64(267), Some(0));
+        codec.config_mut().set_empty_groups_allowed(false);
+        assert!(matches!(
+            codec.decode(msg.as_bytes()),
+            Err(DecodeError::GroupCount { tag: 267, .. })
+        ));
+    }
+
+    #[test]
+    fn group_with_two_entries() {
+        let msg = b"8=FIX.4.4|9=56|35=W|55=EUR/USD|268=2|269=0|270=1.1|271=5|269=1|270=1.2|10=136|";
+        let mut codec = decoder();
+        let message = codec.decode(msg).unwrap();
+        assert_eq!(message.field_raw(tags::SYMBOL), Some(&b"EUR/USD"[..]));
+        assert_eq!(message.field_raw(270), None);
+        let entries = message.group(268).unwrap();
+        assert_eq!(entries.len(), 2);
+        let prices: Vec<_> = entries.iter().map(|e| e.field_raw(270).unwrap()).collect();
+        assert_eq!(prices, vec![&b"1.1"[..], b"1.2"]);
+        assert_eq!(entries.get(0).unwrap().field_raw(271), Some(&b"5"[..]));
+        assert_eq!(entries.get(1).unwrap().field_raw(271), None);
+    }
+
+    #[test]
+    fn group_count_smaller_than_entries() {
+        let msg = "8=FIX.4.4|9=50|35=W|55=EUR/USD|268=1|269=0|270=1.1|269=1|270=1.2|10=249|";
+        let mut codec = decoder();
+        let result = codec.decode(msg.as_bytes());
+        assert_eq!(
+            result,
+            Err(DecodeError::GroupCount {
+                tag: 268,
+                value: "1".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn case_normalization_of_enumerated_values() {
+        let msg = "8=FIX.4.4|9=20|35=D|43=y|40=d|58=y|10=014|";
+        let mut codec = decoder();
+        let message = codec.decode(msg.as_bytes()).unwrap();
+        assert_eq!(message.field_as_bool(tags::POSS_DUP_FLAG), Some(false));
+        assert_eq!(message.field_raw(tags::ORD_TYPE), Some(&b"d"[..]));
+        codec.config_mut().set_normalize_case(true);
+        let message = codec.decode(msg.as_bytes()).unwrap();
+        assert_eq!(message.field_as_bool(tags::POSS_DUP_FLAG), Some(true));
+        assert_eq!(message.field_raw(tags::ORD_TYPE), Some(&b"D"[..]));
+        // `Text <58>` is a `String` field.
+        assert_eq!(message.field_raw(tags::TEXT), Some(&b"y"[..]));
+        // Byte offsets still refer to the original message.
+        let (_, range) = message
+            .field_ranges()
+            .into_iter()
+            .find(|(tag, _)| *tag == tags::POSS_DUP_FLAG)
+            .unwrap();
+        assert_eq!(&msg.as_bytes()[range], b"y");
+    }
 }
@@ -0,0 +1,191 @@
+use super::{scan_fields, Configure, DecodeError, RawDecoder};
+use crate::Dictionary;
+use std::collections::HashMap;
+use std::fmt;
+
+/// A field-level difference between two messages. See [`diff`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldDiff {
+    /// The field is only present in the second message.
+    Added { tag: u32, value: Vec<u8> },
+    /// The field is only present in the first message.
+    Removed { tag: u32, value: Vec<u8> },
+    /// The field is present in both messages, but with different values.
+    Changed {
+        tag: u32,
+        left: Vec<u8>,
+        right: Vec<u8>,
+    },
+}
+
+impl FieldDiff {
+    /// Returns the tag of the field that differs.
+    pub fn tag(&self) -> u32 {
+        match self {
+            Self::Added { tag, .. } | Self::Removed { tag, .. } | Self::Changed { tag, .. } => *tag,
+        }
+    }
+
+    /// Renders `self` in a human-readable form, using the field names in
+    /// `dictionary` (if any) alongside tags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::FieldDiff;
+    /// use fefix::{AppVersion, Dictionary};
+    ///
+    /// let diff = FieldDiff::Changed {
+    ///     tag: 55,
+    ///     left: b"AAPL".to_vec(),
+    ///     right: b"MSFT".to_vec(),
+    /// };
+    /// let dict = Dictionary::from_version(AppVersion::Fix44);
+    /// assert_eq!(diff.render(Some(&dict)), "~ Symbol <55>: AAPL -> MSFT");
+    /// assert_eq!(diff.render(None), "~ <55>: AAPL -> MSFT");
+    /// ```
+    pub fn render(&self, dictionary: Option<&Dictionary>) -> String {
+        let tag = self.tag();
+        let name = dictionary
+            .and_then(|dict| dict.field_by_tag(tag).map(|field| field.name().to_string()))
+            .map(|name| format!("{} <{}>", name, tag))
+            .unwrap_or_else(|| format!("<{}>", tag));
+        match self {
+            Self::Added { value, .. } => {
+                format!("+ {}: {}", name, String::from_utf8_lossy(value))
+            }
+            Self::Removed { value, .. } => {
+                format!("- {}: {}", name, String::from_utf8_lossy(value))
+            }
+            Self::Changed { left, right, .. } => format!(
+                "~ {}: {} -> {}",
+                name,
+                String::from_utf8_lossy(left),
+                String::from_utf8_lossy(right)
+            ),
+        }
+    }
+}
+
+impl fmt::Display for FieldDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.render(None))
+    }
+}
+
+/// Compares the messages `a` and `b` field by field and returns all
+/// differences, in order of appearance (first in `a`, then in `b`).
+///
+/// Repeating groups are not interpreted: the Nth occurrence of a tag in `a` is
+/// compared to the Nth occurrence of the same tag in `b`. Standard header and
+/// trailer fields are compared like all others, so any other difference will
+/// usually show up in `BodyLength <9>` and `CheckSum <10>` as well.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::{diff, Config, FieldDiff};
+///
+/// let config = Config::default().with_separator(b'|');
+/// let a = b"8=FIX.4.4|9=18|35=D|55=AAPL|54=1|10=240|";
+/// let b = b"8=FIX.4.4|9=18|35=D|55=MSFT|38=5|10=018|";
+/// let diffs = diff(a, b, &config).unwrap();
+/// assert_eq!(diffs[0], FieldDiff::Changed {
+///     tag: 55,
+///     left: b"AAPL".to_vec(),
+///     right: b"MSFT".to_vec(),
+/// });
+/// ```
+pub fn diff<C>(a: &[u8], b: &[u8], config: &C) -> Result<Vec<FieldDiff>, DecodeError>
+where
+    C: Configure,
+{
+    let fields_a = occurrences(a, config)?;
+    let fields_b = occurrences(b, config)?;
+    let index_a: HashMap<(u32, usize), &[u8]> = fields_a.iter().map(|(k, v)| (*k, *v)).collect();
+    let index_b: HashMap<(u32, usize), &[u8]> = fields_b.iter().map(|(k, v)| (*k, *v)).collect();
+    let mut diffs = Vec::new();
+    for (key, left) in fields_a.iter() {
+        match index_b.get(key) {
+            Some(right) if right == left => {}
+            Some(right) => diffs.push(FieldDiff::Changed {
+                tag: key.0,
+                left: left.to_vec(),
+                right: right.to_vec(),
+            }),
+            None => diffs.push(FieldDiff::Removed {
+                tag: key.0,
+                value: left.to_vec(),
+            }),
+        }
+    }
+    for (key, right) in fields_b.iter() {
+        if !index_a.contains_key(key) {
+            diffs.push(FieldDiff::Added {
+                tag: key.0,
+                value: right.to_vec(),
+            });
+        }
+    }
+    Ok(diffs)
+}
+
+/// A field keyed by its tag and the occurrence index of that tag.
+type Occurrence<'a> = ((u32, usize), &'a [u8]);
+
+/// Returns all fields in `message`, keyed by tag and occurrence index of that
+/// tag.
+fn occurrences<'a, C>(message: &'a [u8], config: &C) -> Result<Vec<Occurrence<'a>>, DecodeError>
+where
+    C: Configure,
+{
+    let frame = RawDecoder::with_config(config.clone()).decode(message)?;
+    let mut counts: HashMap<u32, usize> = HashMap::new();
+    scan_fields(frame.as_bytes(), config.separator())
+        .map(|field| {
+            let (tag, value) = field?;
+            let count = counts.entry(tag).or_insert(0);
+            *count += 1;
+            Ok(((tag, *count - 1), value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tagvalue::Config;
+
+    fn config() -> Config {
+        Config::default().with_separator(b'|')
+    }
+
+    #[test]
+    fn identical_messages_have_no_diffs() {
+        let msg = b"8=FIX.4.4|9=18|35=D|55=AAPL|54=1|10=240|";
+        assert_eq!(diff(msg, msg, &config()), Ok(vec![]));
+    }
+
+    #[test]
+    fn added_removed_and_changed_fields() {
+        let a = b"8=FIX.4.4|9=18|35=D|55=AAPL|54=1|10=240|";
+        let b = b"8=FIX.4.4|9=18|35=D|55=MSFT|38=5|10=018|";
+        let diffs = diff(a, b, &config()).unwrap();
+        let rendered: Vec<String> = diffs.iter().map(|d| d.to_string()).collect();
+        assert_eq!(
+            rendered,
+            vec![
+                "~ <55>: AAPL -> MSFT",
+                "- <54>: 1",
+                "~ <10>: 240 -> 018",
+                "+ <38>: 5"
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_message() {
+        let a = b"8=FIX.4.4|9=18|35=D|55=AAPL|54=1|10=240|";
+        assert!(diff(a, b"foobar", &config()).is_err());
+    }
+}
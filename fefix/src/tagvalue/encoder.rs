@@ -1,7 +1,10 @@
-use super::{MessageAccumulator, SerializeField};
+use super::{write_int, write_uint, MessageAccumulator, SerializeField};
 use crate::buffer::Buffer;
-use crate::tagvalue::{field_value::TagNum, utils, Config, Configure, EncodeError, FixFieldValue};
-use crate::{AppVersion, Dictionary, FixFieldsIter, FixMessage};
+use crate::tagvalue::field_value::{FieldValue, TagNum};
+use crate::tagvalue::{utils, Config, Configure, EncodeError, FixFieldValue};
+use crate::{tags, AppVersion, Dictionary, FixFieldsIter, FixMessage};
+use std::cell::Cell;
+use std::convert::TryFrom;
 use std::fmt::Debug;
 
 /// FIX message encoder and decoder.
@@ -58,43 +61,94 @@ where
         &mut self.config
     }
 
+    /// Encodes `message` to `buffer` and returns the number of bytes written.
+    /// `BodyLength <9>` and `CheckSum <10>` are always computed from scratch,
+    /// so any existing values in `message` are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`EncodeError::MissingField`] if `message` doesn't have a
+    /// `BeginString <8>` field, or with [`EncodeError::InvalidValue`] if it's
+    /// not a string. Tags beyond 65535 fail with [`EncodeError::InvalidTag`].
     pub fn encode<B>(&mut self, buffer: &mut B, message: &FixMessage) -> Result<usize, EncodeError>
     where
         B: Buffer,
     {
+        // `encode_raw` doesn't let `body_writer` fail, so the first error is
+        // stashed here instead.
+        let error = Cell::new(None);
         let body_writer = |buffer: &mut B| {
-            let start_i = buffer.as_slice().len();
-            // Skips `BeginString`.
-            for (tag, value) in message.iter_fields().skip(1) {
-                encode_field(
-                    TagNum::from(tag as u16),
-                    value,
-                    buffer,
-                    self.config.separator(),
-                );
+            let start = buffer.mark();
+            // `BeginString`, `BodyLength` and `CheckSum` are written by
+            // `encode_raw`.
+            let fields = message.iter_fields().filter(|(tag, _)| {
+                ![tags::BEGIN_STRING, tags::BODY_LENGTH, tags::CHECK_SUM].contains(tag)
+            });
+            for (tag, value) in fields {
+                if let Err(err) = encode_field(tag, value, buffer, self.config.separator()) {
+                    error.set(Some(err));
+                    break;
+                }
             }
-            buffer.as_slice().len() - start_i
+            buffer.slice_from(start).len()
         };
         let begin_string = message
-            .iter_fields_in_std_header()
-            .next()
-            .unwrap()
-            .1
+            .field(tags::BEGIN_STRING)
+            .ok_or(EncodeError::MissingField(tags::BEGIN_STRING))?
             .as_str()
-            .unwrap()
+            .ok_or(EncodeError::InvalidValue)?
             .as_bytes();
-        utils::encode_raw(begin_string, body_writer, buffer, self.config.separator())
+        let len = utils::encode_raw(begin_string, body_writer, buffer, self.config.separator())?;
+        match error.into_inner() {
+            Some(err) => Err(err),
+            None => Ok(len),
+        }
     }
 }
 
-fn encode_field(tag: TagNum, value: &FixFieldValue, write: &mut impl Buffer, separator: u8) {
-    write.extend_from_slice(tag.to_string().as_bytes());
+fn encode_field(
+    tag: u32,
+    value: &FixFieldValue,
+    write: &mut impl Buffer,
+    separator: u8,
+) -> Result<(), EncodeError> {
+    let tag = u16::try_from(tag)
+        .map(TagNum::from)
+        .map_err(|_| EncodeError::InvalidTag(tag))?;
+    if let FixFieldValue::Data(data) = value {
+        // The `Length` field, with a value that always matches the data.
+        write_uint(write, data.length_tag() as u64);
+        write.extend_from_slice(b"=");
+        write_uint(write, data.len() as u64);
+        write.extend_from_slice(&[separator]);
+    }
+    write_uint(write, u16::from(tag) as u64);
     write.extend_from_slice(&[b'=']);
     match &value {
-        FixFieldValue::Group(_) => panic!("Can't encode a group!"),
-        FixFieldValue::Atom(field) => write.extend_from_slice(field.to_string().as_bytes()),
+        FixFieldValue::Group(entries) => {
+            // The `NumInGroup` field, followed by all fields of each entry.
+            write_uint(write, entries.len() as u64);
+            write.extend_from_slice(&[separator]);
+            for entry in entries {
+                for (tag, value) in entry {
+                    encode_field(*tag, value, write, separator)?;
+                }
+            }
+        }
+        FixFieldValue::Atom(FieldValue::Int(int)) => {
+            write_int(write, int.0 as i64);
+            write.extend_from_slice(&[separator]);
+        }
+        FixFieldValue::Atom(field) => {
+            write.extend_from_slice(field.to_string().as_bytes());
+            write.extend_from_slice(&[separator]);
+        }
+        FixFieldValue::Data(data) => {
+            write.extend_from_slice(data.value());
+            write.extend_from_slice(&[separator]);
+        }
     };
-    write.extend_from_slice(&[separator]);
+    Ok(())
 }
 
 /// FIX message encoder and decoder.
@@ -133,3 +187,75 @@ where
 
     fn wrap_std_trailer(&mut self) {}
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn encode_md_entries_group() {
+        let entry = |entry_type: &str, px: &str| {
+            vec![
+                (
+                    tags::MD_ENTRY_TYPE,
+                    FixFieldValue::string(entry_type.as_bytes()).unwrap(),
+                ),
+                (tags::MD_ENTRY_SIZE, FixFieldValue::from(100i64)),
+                (
+                    tags::MD_ENTRY_PX,
+                    FixFieldValue::string(px.as_bytes()).unwrap(),
+                ),
+            ]
+        };
+        let group = FixFieldValue::Group(vec![entry("0", "1.5"), entry("1", "1.75")]);
+        let buffer = &mut Vec::new();
+        encode_field(tags::NO_MD_ENTRIES, &group, buffer, b'|').unwrap();
+        assert_eq!(
+            &buffer[..],
+            &b"268=2|269=0|271=100|270=1.5|269=1|271=100|270=1.75|"[..]
+        );
+    }
+
+    #[test]
+    fn mutated_data_field_is_encoded_with_new_length() {
+        use crate::tagvalue::Decoder;
+        let dict = Dictionary::from_version(AppVersion::Fix44);
+        let config = Config::default().with_separator(b'|');
+        let mut decoder = Decoder::with_config(dict, config.clone());
+        let msg = b"8=FIX.4.4|9=19|35=0|95=5|96=a|b|c|10=235|";
+        let mut data = decoder.decode(msg).unwrap().field_as_data(96).unwrap();
+        data.value_mut().extend_from_slice(b"|d");
+        let mut message = FixMessage::new();
+        message.add_str(tags::BEGIN_STRING, "FIX.4.4");
+        message.add_str(tags::MSG_TYPE, "0");
+        message.set_data_field(tags::RAW_DATA, data);
+        let buffer = &mut Vec::new();
+        Encoder::new(config).encode(buffer, &message).unwrap();
+        assert!(buffer.starts_with(b"8=FIX.4.4|9=000021|35=0|95=7|96=a|b|c|d|10="));
+        let message = decoder.decode(&buffer[..]).unwrap();
+        assert_eq!(message.field_raw(tags::RAW_DATA), Some(&b"a|b|c|d"[..]));
+    }
+
+    #[test]
+    fn tags_beyond_u16_are_an_error() {
+        let entry = vec![(70000, FixFieldValue::from(1i64))];
+        let mut message = FixMessage::new();
+        message.add_str(tags::BEGIN_STRING, "FIX.4.4");
+        message.add_str(tags::MSG_TYPE, "0");
+        message
+            .add_field(tags::NO_MD_ENTRIES, FixFieldValue::Group(vec![entry]))
+            .unwrap();
+        let buffer = &mut Vec::new();
+        let result = Encoder::new(Config::default()).encode(buffer, &message);
+        assert_eq!(result, Err(EncodeError::InvalidTag(70000)));
+    }
+
+    #[test]
+    fn message_without_begin_string_is_an_error() {
+        let mut message = FixMessage::new();
+        message.add_str(tags::MSG_TYPE, "0");
+        let buffer = &mut Vec::new();
+        let result = Encoder::new(Config::default()).encode(buffer, &message);
+        assert_eq!(result, Err(EncodeError::MissingField(tags::BEGIN_STRING)));
+    }
+}
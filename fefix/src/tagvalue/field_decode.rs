@@ -0,0 +1,499 @@
+use super::decoder::data_tag_for;
+use super::{parse_int, Configure, DecodeError, RawDecoder};
+use crate::{DataType, DtfDate, DtfDecimal, DtfLocalMktDate};
+use std::convert::TryFrom;
+use std::ops::ControlFlow;
+
+/// A trait for types that can be decoded from a single raw field value.
+///
+/// This is the building block used by `#[derive(FixDecode)]`.
+pub trait FixFieldDecode: Sized {
+    /// The [`DataType`] of field values, which `validate()` checks with
+    /// [`DataType::validate`] instead of decoding them. Defaults to
+    /// [`DataType::String`], i.e. any non-empty value.
+    const DATA_TYPE: DataType = DataType::String;
+
+    /// Decodes `data`, i.e. the raw bytes between `=` and the field separator.
+    fn decode_fix_field(data: &[u8]) -> Result<Self, DecodeError>;
+}
+
+macro_rules! impl_fix_field_decode_for_int {
+    ($($t:ty => $data_type:ident),*) => {
+        $(
+            impl FixFieldDecode for $t {
+                const DATA_TYPE: DataType = DataType::$data_type;
+
+                fn decode_fix_field(data: &[u8]) -> Result<Self, DecodeError> {
+                    <$t>::try_from(parse_int(data)?).map_err(|_| DecodeError::IntOverflow)
+                }
+            }
+        )*
+    };
+}
+
+// `Length` is the non-negative flavour of `Int`.
+impl_fix_field_decode_for_int!(
+    i8 => Int,
+    i16 => Int,
+    i32 => Int,
+    u8 => Length,
+    u16 => Length,
+    u32 => Length,
+    u64 => Length,
+    usize => Length
+);
+
+impl FixFieldDecode for i64 {
+    const DATA_TYPE: DataType = DataType::Int;
+
+    fn decode_fix_field(data: &[u8]) -> Result<Self, DecodeError> {
+        parse_int(data)
+    }
+}
+
+impl FixFieldDecode for f64 {
+    const DATA_TYPE: DataType = DataType::Float;
+
+    fn decode_fix_field(data: &[u8]) -> Result<Self, DecodeError> {
+        DtfDecimal::decode_fix_field(data).map(|decimal| decimal.to_f64())
+    }
+}
+
+impl FixFieldDecode for DtfDecimal {
+    const DATA_TYPE: DataType = DataType::Float;
+
+    fn decode_fix_field(data: &[u8]) -> Result<Self, DecodeError> {
+        DtfDecimal::parse(data).ok_or(DecodeError::Invalid)
+    }
+}
+
+impl FixFieldDecode for DtfDate {
+    const DATA_TYPE: DataType = DataType::UtcDateOnly;
+
+    fn decode_fix_field(data: &[u8]) -> Result<Self, DecodeError> {
+        DtfDate::parse(data).ok_or(DecodeError::Invalid)
+    }
+}
+
+impl FixFieldDecode for DtfLocalMktDate {
+    const DATA_TYPE: DataType = DataType::LocalMktDate;
+
+    fn decode_fix_field(data: &[u8]) -> Result<Self, DecodeError> {
+        DtfLocalMktDate::parse(data).ok_or(DecodeError::Invalid)
+    }
+}
+
+impl FixFieldDecode for bool {
+    const DATA_TYPE: DataType = DataType::Boolean;
+
+    fn decode_fix_field(data: &[u8]) -> Result<Self, DecodeError> {
+        match data {
+            b"Y" => Ok(true),
+            b"N" => Ok(false),
+            _ => Err(DecodeError::Invalid),
+        }
+    }
+}
+
+impl FixFieldDecode for char {
+    const DATA_TYPE: DataType = DataType::Char;
+
+    fn decode_fix_field(data: &[u8]) -> Result<Self, DecodeError> {
+        match data {
+            [c] if c.is_ascii() => Ok(*c as char),
+            _ => Err(DecodeError::Invalid),
+        }
+    }
+}
+
+impl FixFieldDecode for String {
+    fn decode_fix_field(data: &[u8]) -> Result<Self, DecodeError> {
+        std::str::from_utf8(data)
+            .map(|s| s.to_string())
+            .map_err(|_| DecodeError::Invalid)
+    }
+}
+
+impl FixFieldDecode for Vec<u8> {
+    const DATA_TYPE: DataType = DataType::Data;
+
+    fn decode_fix_field(data: &[u8]) -> Result<Self, DecodeError> {
+        Ok(data.to_vec())
+    }
+}
+
+/// Returns an [`Iterator`] over the `tag=value` pairs in `data`, where each
+/// pair is terminated by `separator`.
+///
+/// Field values are never inspected, so values that contain `separator`
+/// (e.g. [`DataType::Data`](crate::DataType::Data)) are not supported unless
+/// [`FieldScanner::with_data_fields`] is used.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::scan_fields;
+///
+/// let mut fields = scan_fields(b"35=D|54=1|", b'|');
+/// assert_eq!(fields.next(), Some(Ok((35, &b"D"[..]))));
+/// assert_eq!(fields.next(), Some(Ok((54, &b"1"[..]))));
+/// assert_eq!(fields.next(), None);
+/// ```
+pub fn scan_fields(data: &[u8], separator: u8) -> FieldScanner<'_> {
+    FieldScanner {
+        data,
+        separator,
+        failed: false,
+        data_fields: false,
+        data_field: None,
+    }
+}
+
+/// An [`Iterator`] over raw `tag=value` pairs. See [`scan_fields`].
+#[derive(Debug, Clone)]
+pub struct FieldScanner<'a> {
+    data: &'a [u8],
+    separator: u8,
+    failed: bool,
+    data_fields: bool,
+    // The tag and declared length of the next data field, if any.
+    data_field: Option<(u32, usize)>,
+}
+
+impl<'a> FieldScanner<'a> {
+    /// Reads the values of standard data fields (see
+    /// [`length_tag_for`](super::length_tag_for)) according to the length
+    /// field that precedes them, so that they may contain the separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::scan_fields;
+    ///
+    /// let mut fields = scan_fields(b"95=3|96=a|b|55=X|", b'|').with_data_fields();
+    /// assert_eq!(fields.next(), Some(Ok((95, &b"3"[..]))));
+    /// assert_eq!(fields.next(), Some(Ok((96, &b"a|b"[..]))));
+    /// assert_eq!(fields.next(), Some(Ok((55, &b"X"[..]))));
+    /// ```
+    pub fn with_data_fields(mut self) -> Self {
+        self.data_fields = true;
+        self
+    }
+
+    /// Returns the position of the separator that ends the next field, if it's
+    /// `data_tag` and its value is `len` bytes long.
+    fn data_field_end(&self, data_tag: u32, len: usize) -> Option<usize> {
+        let equal_sign = self.data.iter().position(|b| *b == b'=')?;
+        let tag = parse_int(&self.data[..equal_sign]).ok()?;
+        let end = equal_sign + 1 + len;
+        if tag == i64::from(data_tag) && self.data.get(end) == Some(&self.separator) {
+            Some(end)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> Iterator for FieldScanner<'a> {
+    type Item = Result<(u32, &'a [u8]), DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() || self.failed {
+            return None;
+        }
+        let data_field_end = match self.data_field.take() {
+            Some((data_tag, len)) => self.data_field_end(data_tag, len),
+            None => None,
+        };
+        let field = data_field_end
+            .or_else(|| self.data.iter().position(|b| *b == self.separator))
+            .and_then(|end| {
+                let field = &self.data[..end];
+                self.data = &self.data[end + 1..];
+                let equal_sign = field.iter().position(|b| *b == b'=')?;
+                let tag = u32::try_from(parse_int(&field[..equal_sign]).ok()?).ok()?;
+                Some((tag, &field[equal_sign + 1..]))
+            })
+            .ok_or(DecodeError::Invalid);
+        if let Ok((tag, value)) = field {
+            if self.data_fields {
+                self.data_field =
+                    data_tag_for(tag).and_then(|data_tag| Some((data_tag, parse_len(value)?)));
+            }
+        }
+        self.failed = field.is_err();
+        Some(field)
+    }
+}
+
+fn parse_len(data: &[u8]) -> Option<usize> {
+    usize::try_from(parse_int(data).ok()?).ok()
+}
+
+/// Decodes `message` and calls `visitor` once per body field, in order, without
+/// allocating. `visitor` can stop the iteration early by returning
+/// [`ControlFlow::Break`], whose value is then returned.
+///
+/// Header fields `BeginString <8>` and `BodyLength <9>` and the trailer
+/// `CheckSum <10>` are validated according to `config` but not visited.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::{decode_visit, Config};
+/// use std::ops::ControlFlow;
+///
+/// let config = Config::default().with_separator(b'|');
+/// let msg = b"8=FIX.4.4|9=18|35=D|55=AAPL|54=1|10=240|";
+/// let symbol = decode_visit(msg, &config, |tag, value| {
+///     if tag == 55 {
+///         ControlFlow::Break(value.to_vec())
+///     } else {
+///         ControlFlow::Continue(())
+///     }
+/// });
+/// assert_eq!(symbol, Ok(ControlFlow::Break(b"AAPL".to_vec())));
+/// ```
+pub fn decode_visit<C, F, B>(
+    message: &[u8],
+    config: &C,
+    mut visitor: F,
+) -> Result<ControlFlow<B>, DecodeError>
+where
+    C: Configure,
+    F: FnMut(u32, &[u8]) -> ControlFlow<B>,
+{
+    let frame = RawDecoder::with_config(config.clone()).decode(message)?;
+    for field in scan_fields(frame.payload(), config.separator()) {
+        let (tag, value) = field?;
+        if let ControlFlow::Break(b) = visitor(tag, value) {
+            return Ok(ControlFlow::Break(b));
+        }
+    }
+    Ok(ControlFlow::Continue(()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tagvalue::{Config, EncodeError};
+    use crate::{ArrayBuffer, FixDecode, FixEncode};
+
+    #[derive(Debug, PartialEq, FixDecode)]
+    struct NewOrderSingle {
+        #[fix(tag = 35)]
+        msg_type: String,
+        #[fix(tag = 11)]
+        cl_ord_id: Option<String>,
+        #[fix(tag = 55)]
+        symbol: String,
+        #[fix(tag = 54)]
+        side: char,
+        #[fix(tag = 38)]
+        order_qty: u64,
+        #[fix(tag = 44)]
+        price: Option<DtfDecimal>,
+    }
+
+    // Like `NewOrderSingle`, but `MsgType <35>` is checked (and written)
+    // instead of being a field.
+    #[derive(Debug, PartialEq, FixDecode, FixEncode)]
+    #[fix(msg_type = "D")]
+    struct TypedNewOrderSingle {
+        #[fix(tag = 11)]
+        cl_ord_id: Option<String>,
+        #[fix(tag = 55)]
+        symbol: String,
+        #[fix(tag = 54)]
+        side: char,
+        #[fix(tag = 38)]
+        order_qty: u64,
+        #[fix(tag = 44)]
+        price: Option<DtfDecimal>,
+    }
+
+    fn config() -> Config {
+        Config::default().with_separator(b'|')
+    }
+
+    #[test]
+    fn derive_decode_with_optional_fields() {
+        let msg = b"8=FIX.4.4|9=42|35=D|11=ord-1|55=AAPL|54=1|38=100|44=1.50|10=069|";
+        let order = NewOrderSingle::decode(msg, &config()).unwrap();
+        assert_eq!(order.msg_type, "D");
+        assert_eq!(order.cl_ord_id.as_deref(), Some("ord-1"));
+        assert_eq!(order.symbol, "AAPL");
+        assert_eq!(order.side, '1');
+        assert_eq!(order.order_qty, 100);
+        assert_eq!(order.price, DtfDecimal::parse(b"1.50"));
+    }
+
+    #[test]
+    fn derive_decode_with_missing_fields() {
+        let msg = b"8=FIX.4.4|9=25|35=D|55=AAPL|54=1|38=100|10=163|";
+        let order = NewOrderSingle::decode(msg, &config()).unwrap();
+        assert_eq!(order.cl_ord_id, None);
+        assert_eq!(order.price, None);
+        let msg = b"8=FIX.4.4|9=13|35=D|55=AAPL|10=152|";
+        assert_eq!(
+            NewOrderSingle::decode(msg, &config()),
+            Err(DecodeError::FieldPresence)
+        );
+    }
+
+    #[test]
+    fn derive_decode_with_wrong_msg_type() {
+        let msg = b"8=FIX.4.4|9=25|35=8|55=AAPL|54=1|38=100|10=151|";
+        assert_eq!(
+            TypedNewOrderSingle::decode(msg, &config()),
+            Err(DecodeError::InvalidValue(35))
+        );
+    }
+
+    #[test]
+    fn derive_validate_reports_missing_fields() {
+        let msg = b"8=FIX.4.4|9=25|35=D|55=AAPL|54=1|38=100|10=163|";
+        assert_eq!(TypedNewOrderSingle::validate(msg, &config()), Ok(()));
+        let msg = b"8=FIX.4.4|9=13|35=D|55=AAPL|10=152|";
+        assert_eq!(
+            TypedNewOrderSingle::validate(msg, &config()),
+            Err(vec![
+                DecodeError::MissingField(54),
+                DecodeError::MissingField(38)
+            ])
+        );
+    }
+
+    #[test]
+    fn derive_validate_reports_all_errors() {
+        let msg = b"8=FIX.4.4|9=33|35=D|55=AAPL|38=abc|44=x|55=MSFT|10=218|";
+        assert_eq!(
+            TypedNewOrderSingle::validate(msg, &config()),
+            Err(vec![
+                DecodeError::InvalidValue(38),
+                DecodeError::InvalidValue(44),
+                DecodeError::DuplicateTag(55),
+                DecodeError::MissingField(54),
+            ])
+        );
+        let msg = b"8=FIX.4.4|9=25|35=8|55=AAPL|54=1|38=100|10=151|";
+        assert_eq!(
+            TypedNewOrderSingle::validate(msg, &config()),
+            Err(vec![DecodeError::InvalidValue(35)])
+        );
+    }
+
+    #[test]
+    fn derive_validate_skips_data_fields() {
+        let msg = b"8=FIX.4.4|9=40|35=D|55=AAPL|54=1|38=100|95=6|96=1|54=x|10=240|";
+        assert_eq!(TypedNewOrderSingle::validate(msg, &config()), Ok(()));
+        assert!(TypedNewOrderSingle::decode(msg, &config()).is_ok());
+    }
+
+    #[test]
+    fn derive_encode_then_decode() {
+        let order = TypedNewOrderSingle {
+            cl_ord_id: Some("ord-1".to_string()),
+            symbol: "AAPL".to_string(),
+            side: '1',
+            order_qty: 100,
+            price: DtfDecimal::parse(b"1.50"),
+        };
+        let mut buffer = Vec::new();
+        let len = order.encode(&mut buffer, &config()).unwrap();
+        assert_eq!(len, buffer.len());
+        assert_eq!(
+            &buffer[..],
+            b"8=FIX.4.4|9=000042|35=D|11=ord-1|55=AAPL|54=1|38=100|44=1.50|10=005|" as &[u8]
+        );
+        let decoded = TypedNewOrderSingle::decode(&buffer[..], &config());
+        assert_eq!(decoded, Ok(order));
+    }
+
+    #[test]
+    fn derive_encode_into_full_buffer() {
+        let order = TypedNewOrderSingle {
+            cl_ord_id: None,
+            symbol: "AAPL".to_string(),
+            side: '1',
+            order_qty: 100,
+            price: DtfDecimal::parse(b"1.50"),
+        };
+        let mut buffer = ArrayBuffer::<32>::new();
+        let result = order.encode(&mut buffer, &config());
+        assert_eq!(result, Err(EncodeError::BufferFull));
+    }
+
+    #[test]
+    fn decode_primitive_fields() {
+        assert_eq!(u32::decode_fix_field(b"00042"), Ok(42));
+        assert_eq!(u8::decode_fix_field(b"256"), Err(DecodeError::IntOverflow));
+        assert_eq!(f64::decode_fix_field(b"1.5"), Ok(1.5));
+        assert_eq!(char::decode_fix_field(b"1"), Ok('1'));
+        assert_eq!(String::decode_fix_field(b"AAPL"), Ok("AAPL".to_string()));
+        assert!(bool::decode_fix_field(b"y").is_err());
+    }
+
+    #[test]
+    fn decode_visit_stops_early() {
+        let msg = b"8=FIX.4.4|9=42|35=D|11=ord-1|55=AAPL|54=1|38=100|44=1.50|10=069|";
+        let mut side = None;
+        let mut visited = 0;
+        let flow = decode_visit(msg, &config(), |tag, value| {
+            visited += 1;
+            match tag {
+                54 => side = Some(value[0]),
+                44 => return ControlFlow::Break(value.to_vec()),
+                _ => {}
+            }
+            ControlFlow::Continue(())
+        });
+        assert_eq!(flow, Ok(ControlFlow::Break(b"1.50".to_vec())));
+        assert_eq!(side, Some(b'1'));
+        assert_eq!(visited, 6);
+    }
+
+    #[test]
+    fn decode_visit_all_fields() {
+        let msg = b"8=FIX.4.4|9=18|35=D|55=AAPL|54=1|10=240|";
+        let mut tags = Vec::new();
+        let flow = decode_visit(msg, &config(), |tag, _| -> ControlFlow<()> {
+            tags.push(tag);
+            ControlFlow::Continue(())
+        });
+        assert_eq!(flow, Ok(ControlFlow::Continue(())));
+        assert_eq!(tags, vec![35, 55, 54]);
+    }
+
+    #[test]
+    fn scan_fields_stops_after_error() {
+        let mut fields = scan_fields(b"35=D|foo|54=1|", b'|');
+        assert_eq!(fields.next(), Some(Ok((35, &b"D"[..]))));
+        assert_eq!(fields.next(), Some(Err(DecodeError::Invalid)));
+        assert_eq!(fields.next(), None);
+    }
+
+    #[test]
+    fn scan_fields_splits_on_first_equal_sign() {
+        let mut fields = scan_fields(b"58=a=b=c|55==|", b'|');
+        assert_eq!(fields.next(), Some(Ok((58, &b"a=b=c"[..]))));
+        assert_eq!(fields.next(), Some(Ok((55, &b"="[..]))));
+        assert_eq!(fields.next(), None);
+    }
+
+    #[test]
+    fn scan_fields_falls_back_on_wrong_data_length() {
+        let mut fields = scan_fields(b"95=9|96=ab|55=X|", b'|').with_data_fields();
+        assert_eq!(fields.next(), Some(Ok((95, &b"9"[..]))));
+        assert_eq!(fields.next(), Some(Ok((96, &b"ab"[..]))));
+        assert_eq!(fields.next(), Some(Ok((55, &b"X"[..]))));
+        assert_eq!(fields.next(), None);
+    }
+
+    #[test]
+    fn scan_fields_without_trailing_separator() {
+        let mut fields = scan_fields(b"35=D", b'|');
+        assert_eq!(fields.next(), Some(Err(DecodeError::Invalid)));
+        assert_eq!(fields.next(), None);
+    }
+}
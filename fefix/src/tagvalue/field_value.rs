@@ -119,6 +119,212 @@ impl<'a> FieldValue<'a> {
     }
 }
 
+/// Compact binary representation of [`FieldValue`]s, used by
+/// [`FixMessage::to_compact`](crate::FixMessage::to_compact). Numbers are
+/// stored in little-endian byte order and lengths as LEB128 varints.
+impl<'a> FieldValue<'a> {
+    /// Returns the [`DataType`] of `self`, or `None` for
+    /// [`FieldValue::Phantom`].
+    pub fn data_type(&self) -> Option<DataType> {
+        Some(match self {
+            Self::Char(_) => DataType::Char,
+            Self::Boolean(_) => DataType::Boolean,
+            Self::Float(_) => DataType::Float,
+            Self::Amt(_) => DataType::Amt,
+            Self::Price(_) => DataType::Price,
+            Self::PriceOffset(_) => DataType::PriceOffset,
+            Self::Qty(_) => DataType::Qty,
+            Self::Percentage(_) => DataType::Percentage,
+            Self::Int(_) => DataType::Int,
+            Self::DayOfMonth(_) => DataType::DayOfMonth,
+            Self::Length(_) => DataType::Length,
+            Self::NumInGroup(_) => DataType::NumInGroup,
+            Self::SeqNum(_) => DataType::SeqNum,
+            Self::TagNum(_) => DataType::TagNum,
+            Self::String(_) => DataType::String,
+            Self::MultipleCharValue(_) => DataType::MultipleCharValue,
+            Self::Data(_) => DataType::Data,
+            Self::MonthYear(_) => DataType::MonthYear,
+            Self::Currency(_) => DataType::Currency,
+            Self::Exchange(_) => DataType::Exchange,
+            Self::LocalMktDate(_) => DataType::LocalMktDate,
+            Self::MultipleStringValue(_) => DataType::MultipleStringValue,
+            Self::UtcDate(_) => DataType::UtcDateOnly,
+            Self::UtcTimeOnly(_) => DataType::UtcTimeOnly,
+            Self::UtcTimestamp(_) => DataType::UtcTimestamp,
+            Self::XmlData(_) => DataType::XmlData,
+            Self::Country(_) => DataType::Country,
+            Self::Phantom(_) => return None,
+        })
+    }
+
+    /// Appends the compact representation of `self` to `buffer`, without its
+    /// [`DataType`]. [`FieldValue::Phantom`] is written as nothing at all.
+    pub(crate) fn write_compact(&self, buffer: &mut Vec<u8>) {
+        match self {
+            Self::Char(Char(c)) => buffer.extend_from_slice(&u32::from(*c).to_le_bytes()),
+            Self::Boolean(Boolean(b)) => buffer.push(u8::from(*b)),
+            Self::Float(x)
+            | Self::Amt(Amt(x))
+            | Self::Price(Price(x))
+            | Self::PriceOffset(PriceOffset(Price(x)))
+            | Self::Qty(Qty(x)) => buffer.extend_from_slice(&x.0.to_le_bytes()),
+            Self::Percentage(Percentage(n)) | Self::UtcTimestamp(UtcTimestamp(n)) => {
+                buffer.extend_from_slice(&n.to_le_bytes())
+            }
+            Self::Int(Int(n)) => buffer.extend_from_slice(&n.to_le_bytes()),
+            Self::DayOfMonth(DayOfMonth(n)) => buffer.push(*n),
+            Self::Length(Length(n))
+            | Self::NumInGroup(NumInGroup(n))
+            | Self::LocalMktDate(LocalMktDate(n))
+            | Self::UtcDate(UtcDate(n))
+            | Self::UtcTimeOnly(UtcTimeOnly(n)) => buffer.extend_from_slice(&n.to_le_bytes()),
+            Self::SeqNum(SeqNum(n)) => buffer.extend_from_slice(&n.to_le_bytes()),
+            Self::TagNum(TagNum(n)) => buffer.extend_from_slice(&n.to_le_bytes()),
+            Self::String(String(s))
+            | Self::MultipleCharValue(MultipleCharValue(String(s)))
+            | Self::Exchange(Exchange(s)) => write_compact_bytes(buffer, s.as_bytes()),
+            Self::Data(data) | Self::XmlData(XmlData(data)) => write_compact_bytes(buffer, data),
+            Self::MonthYear(month_year) => {
+                buffer.extend_from_slice(&month_year.year.to_le_bytes());
+                buffer.extend_from_slice(&[month_year.month, month_year.day_or_week]);
+            }
+            Self::Currency(Currency(code)) => buffer.extend_from_slice(code),
+            Self::Country(Country(code)) => buffer.extend_from_slice(code),
+            Self::MultipleStringValue(MultipleStringValue(values)) => {
+                write_compact_len(buffer, values.len());
+                for String(s) in values {
+                    write_compact_bytes(buffer, s.as_bytes());
+                }
+            }
+            Self::Phantom(_) => {}
+        }
+    }
+
+    /// Reads a value of type `data_type` that was written by
+    /// [`FieldValue::write_compact`] from the start of `data`, then advances
+    /// `data` past it.
+    pub(crate) fn read_compact(data_type: DataType, data: &mut &[u8]) -> Option<Self> {
+        let string = |data: &mut &[u8]| {
+            let bytes = read_compact_bytes(data)?;
+            std::str::from_utf8(bytes).ok().map(str::to_string)
+        };
+        Some(match data_type {
+            DataType::Char => Self::Char(Char(std::char::from_u32(u32::from_le_bytes(
+                read_array(data)?,
+            ))?)),
+            DataType::Boolean => match read_array(data)? {
+                [0] => Self::Boolean(Boolean(false)),
+                [1] => Self::Boolean(Boolean(true)),
+                _ => return None,
+            },
+            DataType::Float => Self::Float(Float(f32::from_le_bytes(read_array(data)?))),
+            DataType::Amt => Self::Amt(Amt(Float(f32::from_le_bytes(read_array(data)?)))),
+            DataType::Price => Self::Price(Price(Float(f32::from_le_bytes(read_array(data)?)))),
+            DataType::PriceOffset => Self::PriceOffset(PriceOffset(Price(Float(
+                f32::from_le_bytes(read_array(data)?),
+            )))),
+            DataType::Qty => Self::Qty(Qty(Float(f32::from_le_bytes(read_array(data)?)))),
+            DataType::Percentage => {
+                Self::Percentage(Percentage(i64::from_le_bytes(read_array(data)?)))
+            }
+            DataType::Int => Self::Int(Int(i32::from_le_bytes(read_array(data)?))),
+            DataType::DayOfMonth => Self::DayOfMonth(DayOfMonth(read_array::<1>(data)?[0])),
+            DataType::Length => Self::Length(Length(u32::from_le_bytes(read_array(data)?))),
+            DataType::NumInGroup => {
+                Self::NumInGroup(NumInGroup(u32::from_le_bytes(read_array(data)?)))
+            }
+            DataType::SeqNum => Self::SeqNum(SeqNum(u64::from_le_bytes(read_array(data)?))),
+            DataType::TagNum => Self::TagNum(TagNum(u16::from_le_bytes(read_array(data)?))),
+            DataType::String => Self::String(String(string(data)?)),
+            DataType::MultipleCharValue => {
+                Self::MultipleCharValue(MultipleCharValue(String(string(data)?)))
+            }
+            DataType::Exchange => Self::Exchange(Exchange(string(data)?)),
+            DataType::Data => Self::Data(read_compact_bytes(data)?.to_vec()),
+            DataType::XmlData => Self::XmlData(XmlData(read_compact_bytes(data)?.to_vec())),
+            DataType::MonthYear => {
+                let year = u16::from_le_bytes(read_array(data)?);
+                let [month, day_or_week] = read_array(data)?;
+                Self::MonthYear(MonthYear {
+                    year,
+                    month,
+                    day_or_week,
+                })
+            }
+            DataType::Currency => Self::Currency(Currency(read_array(data)?)),
+            DataType::Country => Self::Country(Country(read_array(data)?)),
+            DataType::LocalMktDate => {
+                Self::LocalMktDate(LocalMktDate(u32::from_le_bytes(read_array(data)?)))
+            }
+            DataType::UtcDateOnly => Self::UtcDate(UtcDate(u32::from_le_bytes(read_array(data)?))),
+            DataType::UtcTimeOnly => {
+                Self::UtcTimeOnly(UtcTimeOnly(u32::from_le_bytes(read_array(data)?)))
+            }
+            DataType::UtcTimestamp => {
+                Self::UtcTimestamp(UtcTimestamp(i64::from_le_bytes(read_array(data)?)))
+            }
+            DataType::MultipleStringValue => {
+                let len = read_compact_len(data)?;
+                let mut values = Vec::new();
+                for _ in 0..len {
+                    values.push(String(string(data)?));
+                }
+                Self::MultipleStringValue(MultipleStringValue(values))
+            }
+            // There's no `FieldValue` for these data types.
+            DataType::Language | DataType::TzTimeOnly | DataType::TzTimestamp => return None,
+        })
+    }
+}
+
+/// Appends `len` to `buffer` as an unsigned LEB128 varint.
+pub(crate) fn write_compact_len(buffer: &mut Vec<u8>, mut len: usize) {
+    while len >= 0x80 {
+        buffer.push((len as u8 & 0x7f) | 0x80);
+        len >>= 7;
+    }
+    buffer.push(len as u8);
+}
+
+/// Reads a length written by [`write_compact_len`] and advances `data` past it.
+pub(crate) fn read_compact_len(data: &mut &[u8]) -> Option<usize> {
+    let mut len = 0usize;
+    for (i, byte) in data.iter().copied().enumerate() {
+        let shift = 7 * i as u32;
+        len |= usize::from(byte & 0x7f).checked_shl(shift)?;
+        if byte & 0x80 == 0 {
+            *data = &data[i + 1..];
+            return Some(len);
+        }
+    }
+    None
+}
+
+fn write_compact_bytes(buffer: &mut Vec<u8>, bytes: &[u8]) {
+    write_compact_len(buffer, bytes.len());
+    buffer.extend_from_slice(bytes);
+}
+
+fn read_compact_bytes<'b>(data: &mut &'b [u8]) -> Option<&'b [u8]> {
+    let len = read_compact_len(data)?;
+    if data.len() < len {
+        return None;
+    }
+    let (bytes, rest) = data.split_at(len);
+    *data = rest;
+    Some(bytes)
+}
+
+fn read_array<const N: usize>(data: &mut &[u8]) -> Option<[u8; N]> {
+    if data.len() < N {
+        return None;
+    }
+    let (bytes, rest) = data.split_at(N);
+    *data = rest;
+    bytes.try_into().ok()
+}
+
 impl<'a> fmt::Display for FieldValue<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -404,6 +610,102 @@ impl DerivedDataType for Boolean {
     type Primitive = Char;
 }
 
+/// The value of a `Data` field, together with the tag of the `Length` field
+/// that must precede it on the wire, e.g. `RawDataLength <95>` for
+/// `RawData <96>`. Only the value is stored: the length is always derived from
+/// it, so it can't go stale when the value changes.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::field_value::DataField;
+///
+/// let mut data = DataField::new(95, b"foo".to_vec());
+/// data.value_mut().extend_from_slice(b"|bar");
+/// assert_eq!(data.length_tag(), 95);
+/// assert_eq!(data.len(), 7);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataField {
+    length_tag: u32,
+    value: Vec<u8>,
+}
+
+impl DataField {
+    pub fn new(length_tag: u32, value: Vec<u8>) -> Self {
+        Self { length_tag, value }
+    }
+
+    /// Returns the tag of the `Length` field associated with `self`.
+    pub fn length_tag(&self) -> u32 {
+        self.length_tag
+    }
+
+    /// Returns the raw bytes of `self`.
+    pub fn value(&self) -> &[u8] {
+        &self.value[..]
+    }
+
+    /// Returns a mutable reference to the raw bytes of `self`.
+    pub fn value_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.value
+    }
+
+    /// Returns the value of the `Length` field associated with `self`.
+    pub fn len(&self) -> usize {
+        self.value.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+}
+
+/// The raw value of an `Encoded*` field, e.g. `EncodedText <355>`, together
+/// with the character set declared by `MessageEncoding <347>`, if any.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::field_value::EncodedValue;
+///
+/// let text = EncodedValue::new("caf\u{e9}".as_bytes(), Some("UTF-8"));
+/// assert_eq!(text.encoding(), Some("UTF-8"));
+/// assert_eq!(text.value(), b"caf\xc3\xa9");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct EncodedValue<'a> {
+    value: &'a [u8],
+    encoding: Option<&'a str>,
+}
+
+impl<'a> EncodedValue<'a> {
+    pub fn new(value: &'a [u8], encoding: Option<&'a str>) -> Self {
+        Self { value, encoding }
+    }
+
+    /// Returns the raw bytes of `self`, in the character set given by
+    /// [`EncodedValue::encoding`].
+    pub fn value(&self) -> &'a [u8] {
+        self.value
+    }
+
+    /// Returns the value of `MessageEncoding <347>`, e.g. `Shift_JIS`.
+    pub fn encoding(&self) -> Option<&'a str> {
+        self.encoding
+    }
+
+    /// Decodes `self` according to [`EncodedValue::encoding`]. Returns `None`
+    /// if there's no encoding, if it's unknown, or if the value is malformed.
+    #[cfg(feature = "encoding")]
+    pub fn decode(&self) -> Option<std::string::String> {
+        let encoding = encoding_rs::Encoding::for_label(self.encoding?.as_bytes())?;
+        encoding
+            .decode_without_bom_handling_and_without_replacement(self.value)
+            .map(|text| text.into_owned())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct XmlData(Vec<u8>);
 
@@ -463,6 +765,12 @@ impl From<u16> for TagNum {
     }
 }
 
+impl From<TagNum> for u16 {
+    fn from(value: TagNum) -> Self {
+        value.0
+    }
+}
+
 impl From<&[u8]> for TagNum {
     fn from(bytes: &[u8]) -> Self {
         debug_assert!(bytes.len() >= std::mem::size_of::<u16>());
@@ -1,6 +1,7 @@
 use crate::models::Error;
 use crate::tags;
-use crate::{DtfDate, DtfMonthYear, DtfMulCharIter, DtfTime};
+use crate::tagvalue::field_value::{DataField, EncodedValue};
+use crate::{AppVersion, DtfDate, DtfMonthYear, DtfMulCharIter, DtfTime, FixVersion};
 use std::{collections::HashMap, ops::Range};
 
 const DEFAULT_FIELDS_LEN: usize = 64;
@@ -48,21 +49,31 @@ impl<'a> FixMessageRef<'a> {
         self.builder
             .fields
             .get(&tag)
-            .map(|field| &self.bytes[field.range.clone()])
+            .map(|field| self.value_of(field))
     }
 
     pub fn field_as_char(&self, tag: u32) -> Option<char> {
         self.builder
             .fields
             .get(&tag)
-            .map(|field| self.bytes[field.range.start] as char)
+            .and_then(|field| self.value_of(field).first())
+            .map(|byte| *byte as char)
     }
 
     pub fn field_as_bool(&self, tag: u32) -> Option<bool> {
         self.builder
             .fields
             .get(&tag)
-            .map(|field| self.bytes[field.range.start] == b'Y')
+            .map(|field| self.value_of(field).first() == Some(&b'Y'))
+    }
+
+    /// Returns the value of `field`, taking
+    /// [normalization](super::Configure::normalize_case) into account.
+    fn value_of(&self, field: &Field) -> &'a [u8] {
+        match &field.normalized {
+            Some(range) => &self.builder.owned_data[range.clone()],
+            None => &self.bytes[field.range.clone()],
+        }
     }
 
     pub fn field_as_i64(&self, tag: u32) -> Option<i64> {
@@ -70,11 +81,71 @@ impl<'a> FixMessageRef<'a> {
             .and_then(|s| str::parse::<i64>(s).ok())
     }
 
+    /// Returns the value of the standard `Data` field `data_tag` (see
+    /// [`length_tag_for`](super::length_tag_for)) as a
+    /// [`DataField`](super::field_value::DataField), which can be mutated and
+    /// re-encoded without worrying about its `Length` field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Decoder};
+    /// use fefix::{AppVersion, Dictionary};
+    ///
+    /// let dict = Dictionary::from_version(AppVersion::Fix44);
+    /// let mut decoder = Decoder::with_config(dict, Config::default().with_separator(b'|'));
+    /// let msg = b"8=FIX.4.4|9=19|35=0|95=5|96=a|b|c|10=235|";
+    /// let data = decoder.decode(msg).unwrap().field_as_data(96).unwrap();
+    /// assert_eq!(data.length_tag(), 95);
+    /// assert_eq!(data.value(), b"a|b|c");
+    /// ```
+    pub fn field_as_data(&self, data_tag: u32) -> Option<DataField> {
+        let length_tag = super::length_tag_for(data_tag)?;
+        let value = self.field_raw(data_tag)?;
+        Some(DataField::new(length_tag, value.to_vec()))
+    }
+
+    /// Returns the value of the standard `Encoded*` field `data_tag`, e.g.
+    /// `EncodedText <355>`, together with the character set declared by
+    /// `MessageEncoding <347>`. Returns `None` if `data_tag` is missing or if
+    /// it isn't length-prefixed (see [`length_tag_for`](super::length_tag_for)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Decoder};
+    /// use fefix::{AppVersion, Dictionary};
+    ///
+    /// let dict = Dictionary::from_version(AppVersion::Fix44);
+    /// let mut decoder = Decoder::with_config(dict, Config::default().with_separator(b'|'));
+    /// let msg = b"8=FIX.4.4|9=29|35=B|347=UTF-8|354=3|355=a|b|10=036|";
+    /// let message = decoder.decode(msg).unwrap();
+    /// let text = message.field_as_encoded(355).unwrap();
+    /// assert_eq!(text.value(), b"a|b");
+    /// assert_eq!(text.encoding(), Some("UTF-8"));
+    /// ```
+    pub fn field_as_encoded(&self, data_tag: u32) -> Option<EncodedValue<'_>> {
+        super::length_tag_for(data_tag)?;
+        let value = self.field_raw(data_tag)?;
+        Some(EncodedValue::new(
+            value,
+            self.field_as_str(tags::MESSAGE_ENCODING),
+        ))
+    }
+
     pub fn field_as_str(&self, tag: u32) -> Option<&str> {
         self.field_raw(tag)
             .and_then(|data| std::str::from_utf8(data).ok())
     }
 
+    /// Returns the value of `tag` as interned by the
+    /// [`Interner`](super::Interner) of the decoder's
+    /// [`Configure`](super::Configure), if any. Unlike
+    /// [`FixMessageRef::field_as_str`], the returned string outlives `self`.
+    pub fn field_interned(&self, tag: u32) -> Option<&'static str> {
+        self.builder.fields.get(&tag)?.interned
+    }
+
     pub fn field_as_chrono_dt(&self, tag: u32) -> Option<chrono::DateTime<chrono::Utc>> {
         let s = self.field_as_str(tag)?;
         let naive = chrono::NaiveDateTime::parse_from_str(s, "%Y%m%d-%H:%M:%S.%.3f").ok()?;
@@ -82,16 +153,161 @@ impl<'a> FixMessageRef<'a> {
         Some(dt)
     }
 
-    pub fn group(&self, _tag: u32) -> Option<GroupRef> {
-        None
+    /// Returns the repeating group of the `NumInGroup` field `tag`, if present
+    /// and recognized by the decoder's dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Decoder};
+    /// use fefix::{AppVersion, Dictionary};
+    ///
+    /// let dict = Dictionary::from_version(AppVersion::Fix44);
+    /// let mut decoder = Decoder::with_config(dict, Config::default().with_separator(b'|'));
+    /// let msg = b"8=FIX.4.4|9=50|35=W|55=EUR/USD|268=2|269=0|270=1.1|269=1|270=1.2|10=250|";
+    /// let msg = decoder.decode(msg).unwrap();
+    /// let entries = msg.group(268).unwrap();
+    /// assert_eq!(entries.len(), 2);
+    /// assert_eq!(entries.get(1).unwrap().field_raw(270), Some(&b"1.2"[..]));
+    /// ```
+    pub fn group(&self, tag: u32) -> Option<GroupRef<'a>> {
+        let entries = self.builder.groups.get(&tag)?;
+        Some(GroupRef {
+            message: self.clone(),
+            entries,
+        })
+    }
+
+    /// Returns all values of `tag` in order of appearance, including every
+    /// occurrence within repeating groups, e.g. the `MDEntryPx <270>` of all
+    /// `NoMDEntries <268>` entries. Values are returned exactly as they appear
+    /// on the wire, i.e. without normalization.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Decoder};
+    /// use fefix::{AppVersion, Dictionary};
+    ///
+    /// let dict = Dictionary::from_version(AppVersion::Fix44);
+    /// let mut decoder = Decoder::with_config(dict, Config::default().with_separator(b'|'));
+    /// let msg = b"8=FIX.4.4|9=53|35=A|49=A|56=B|34=1|52=20210101-00:00:00|98=0|108=30|10=237|";
+    /// let msg = decoder.decode(msg).unwrap();
+    /// assert_eq!(msg.all_values(108).collect::<Vec<_>>(), vec![b"30"]);
+    /// assert_eq!(msg.all_values(270).count(), 0);
+    /// ```
+    pub fn all_values(&self, tag: u32) -> impl Iterator<Item = &'a [u8]> + 'a {
+        let bytes = self.bytes;
+        let entries = self.builder.entries.iter();
+        let mut ranges: Vec<Range<usize>> = self
+            .builder
+            .fields
+            .get(&tag)
+            .into_iter()
+            .chain(entries.filter_map(move |entry| entry.fields.get(&tag)))
+            .map(|field| field.range.clone())
+            .collect();
+        ranges.sort_by_key(|range| range.start);
+        ranges.into_iter().map(move |range| &bytes[range])
+    }
+
+    /// Returns the tag and the byte range of the value of each field in `self`,
+    /// including fields within repeating groups, in order of appearance.
+    /// Ranges are relative to the start of the message.
+    ///
+    /// This is useful for patching a few field values in place, followed by
+    /// [`recompute_trailer`](super::recompute_trailer).
+    pub fn field_ranges(&self) -> Vec<(u32, Range<usize>)> {
+        self.builder.insertion_order.clone()
+    }
+
+    /// Returns `false` if and only if `self` was decoded under
+    /// [`ChecksumPolicy::Warn`](super::ChecksumPolicy::Warn) and its
+    /// `CheckSum(10)` is invalid.
+    pub fn checksum_ok(&self) -> bool {
+        self.builder.checksum_ok
+    }
+
+    /// Returns the value of `MsgType <35>`, or an empty slice if it's missing.
+    /// Like all other hot header fields (see [`FixMessageRef::seq_num`],
+    /// [`FixMessageRef::sender`] and [`FixMessageRef::target`]), it's located
+    /// while decoding, so this takes constant time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Decoder};
+    /// use fefix::{AppVersion, Dictionary};
+    ///
+    /// let dict = Dictionary::from_version(AppVersion::Fix44);
+    /// let mut decoder = Decoder::with_config(dict, Config::default().with_separator(b'|'));
+    /// let msg = b"8=FIX.4.4|9=53|35=A|49=A|56=B|34=1|52=20210101-00:00:00|98=0|108=30|10=237|";
+    /// let msg = decoder.decode(msg).unwrap();
+    /// assert_eq!(msg.msg_type(), b"A");
+    /// assert_eq!(msg.seq_num(), Some(1));
+    /// assert_eq!(msg.sender(), Some(&b"A"[..]));
+    /// assert_eq!(msg.target(), Some(&b"B"[..]));
+    /// ```
+    pub fn msg_type(&self) -> &[u8] {
+        self.hot_field(&self.builder.hot_fields.msg_type)
+            .unwrap_or(&[])
+    }
+
+    /// Returns the value of `MsgSeqNum <34>`, if present and valid.
+    pub fn seq_num(&self) -> Option<u64> {
+        let data = self.hot_field(&self.builder.hot_fields.seq_num)?;
+        std::str::from_utf8(data).ok()?.parse().ok()
+    }
+
+    /// Returns the value of `SenderCompID <49>`, if present.
+    pub fn sender(&self) -> Option<&[u8]> {
+        self.hot_field(&self.builder.hot_fields.sender)
+    }
+
+    /// Returns the value of `TargetCompID <56>`, if present.
+    pub fn target(&self) -> Option<&[u8]> {
+        self.hot_field(&self.builder.hot_fields.target)
+    }
+
+    /// Returns the protocol version announced by `BeginString <8>`. FIX 5.0
+    /// (and later) messages always report
+    /// [`AppVersion::Fixt11`](crate::AppVersion::Fixt11) here: see
+    /// [`FixMessageRef::appl_ver_id`] for their application version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, Decoder};
+    /// use fefix::{AppVersion, Dictionary, FixVersion};
+    ///
+    /// let dict = Dictionary::from_version(AppVersion::Fix50SP2);
+    /// let mut decoder = Decoder::with_config(dict, Config::default().with_separator(b'|'));
+    /// let msg = b"8=FIXT.1.1|9=48|35=0|49=A|56=B|34=1|52=20210101-00:00:00|1128=9|10=221|";
+    /// let msg = decoder.decode(msg).unwrap();
+    /// assert_eq!(msg.resolved_version(), FixVersion::Standard(AppVersion::Fixt11));
+    /// assert_eq!(msg.appl_ver_id(), Some(AppVersion::Fix50SP2));
+    /// ```
+    pub fn resolved_version(&self) -> FixVersion<'_> {
+        FixVersion::from_begin_string(self.field_raw(tags::BEGIN_STRING).unwrap_or(&[]))
+    }
+
+    /// Returns the application version announced by `ApplVerID <1128>`, if
+    /// present and valid. See [`AppVersion::from_appl_ver_id`].
+    pub fn appl_ver_id(&self) -> Option<AppVersion> {
+        AppVersion::from_appl_ver_id(self.field_raw(tags::fixt11::APPL_VER_ID)?)
+    }
+
+    fn hot_field(&self, range: &Option<Range<usize>>) -> Option<&[u8]> {
+        range.clone().map(|range| &self.bytes[range])
     }
 
     pub fn f_msg_type(&self) -> Option<&str> {
-        self.field_as_str(tags::MSG_TYPE)
+        self.hot_field(&self.builder.hot_fields.msg_type)
+            .and_then(|data| std::str::from_utf8(data).ok())
     }
 
     pub fn f_seq_num(&self) -> Option<u64> {
-        self.field_as_i64(tags::MSG_SEQ_NUM).map(|x| x as u64)
+        self.seq_num()
     }
 
     pub fn f_test_indicator(&self) -> Option<bool> {
@@ -103,18 +319,57 @@ impl<'a> FixMessageRef<'a> {
 struct Field {
     i: usize,
     range: Range<usize>,
+    interned: Option<&'static str>,
+    // The normalized value, if any, as a range within `owned_data`.
+    normalized: Option<Range<usize>>,
+}
+
+/// The fields of a single repeating group entry, and its nested groups.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Entry {
+    fields: HashMap<u32, Field>,
+    // Indices into `FixMessageRefBuilder::entries`.
+    groups: HashMap<u32, Vec<usize>>,
+}
+
+/// A repeating group that is being filled, see
+/// [`FixMessageRefBuilder::enter_group`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct OpenGroup {
+    tag: u32,
+    // The entry that contains the group, if it's nested.
+    parent: Option<usize>,
+    // The entry that is being filled, if any.
+    entry: Option<usize>,
+}
+
+/// The byte ranges of frequently accessed header fields, recorded while
+/// decoding to skip lookups in [`FixMessageRefBuilder::fields`]. These fields
+/// are never normalized, so the original bytes are always correct.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct HotFields {
+    msg_type: Option<Range<usize>>,
+    seq_num: Option<Range<usize>>,
+    sender: Option<Range<usize>>,
+    target: Option<Range<usize>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FixMessageRefBuilder {
     fields: HashMap<u32, Field>,
-    insertion_order: Vec<u32>,
+    hot_fields: HotFields,
+    insertion_order: Vec<(u32, Range<usize>)>,
+    // Top-level repeating groups, as indices into `entries`.
+    groups: HashMap<u32, Vec<usize>>,
+    entries: Vec<Entry>,
+    open_groups: Vec<OpenGroup>,
     owned_data: Vec<u8>,
     i_first_cell: usize,
     i_last_cell: usize,
     len_end_header: usize,
     len_end_body: usize,
     len_end_trailer: usize,
+    checksum_ok: bool,
 }
 
 impl FixMessageRefBuilder {
@@ -130,13 +385,18 @@ impl FixMessageRefBuilder {
     pub fn new() -> Self {
         Self {
             fields: HashMap::new(),
+            hot_fields: HotFields::default(),
             insertion_order: Vec::with_capacity(DEFAULT_FIELDS_LEN),
+            groups: HashMap::new(),
+            entries: Vec::new(),
+            open_groups: Vec::new(),
             owned_data: Vec::new(),
             i_first_cell: 0,
             i_last_cell: 0,
             len_end_header: 0,
             len_end_body: 0,
             len_end_trailer: 0,
+            checksum_ok: true,
         }
     }
 
@@ -144,27 +404,129 @@ impl FixMessageRefBuilder {
     pub fn clear(&mut self) {
         self.fields.clear();
         self.fields.shrink_to_fit();
+        self.hot_fields = HotFields::default();
         self.insertion_order.clear();
         self.insertion_order.reserve_exact(DEFAULT_FIELDS_LEN);
+        self.groups.clear();
+        self.entries.clear();
+        self.open_groups.clear();
+        self.owned_data.clear();
         self.i_first_cell = 0;
         self.i_last_cell = 0;
         self.len_end_body = 0;
         self.len_end_header = 0;
         self.len_end_trailer = 0;
+        self.checksum_ok = true;
+    }
+
+    /// Records whether the message passed `CheckSum(10)` verification. See
+    /// [`FixMessageRef::checksum_ok`].
+    pub fn set_checksum_ok(&mut self, checksum_ok: bool) {
+        self.checksum_ok = checksum_ok;
+    }
+
+    /// Records the interned value of the field `tag`, which must have already
+    /// been added to `self`. See [`FixMessageRef::field_interned`].
+    pub fn set_interned(&mut self, tag: u32, value: &'static str) {
+        if let Some(field) = self.current_fields().get_mut(&tag) {
+            field.interned = Some(value);
+        }
     }
 
-    /// Adds a field to `self`.
+    /// Overrides the value of the field `tag`, which must have already been
+    /// added to `self`, with `value`. Its byte range within the message is left
+    /// unchanged. See [`Configure::normalize_case`](super::Configure::normalize_case).
+    pub fn set_normalized(&mut self, tag: u32, value: &[u8]) {
+        let start = self.owned_data.len();
+        let end = start + value.len();
+        if let Some(field) = self.current_fields().get_mut(&tag) {
+            field.normalized = Some(start..end);
+            self.owned_data.extend_from_slice(value);
+        }
+    }
+
+    /// Adds a field to `self`, within the current group entry if any (see
+    /// [`FixMessageRefBuilder::add_entry`]).
     pub fn add_field(&mut self, tag: u32, start: usize, len: usize) -> Result<(), Error> {
-        if self.fields.contains_key(&tag) {
-            Err(Error::Duplicate)
-        } else {
-            let field = Field {
-                i: self.insertion_order.len(),
-                range: start..start + len,
-            };
-            self.fields.insert(tag, field);
-            self.insertion_order.push(tag);
-            Ok(())
+        let i = self.insertion_order.len();
+        let range = start..start + len;
+        let in_group = self.current_entry().is_some();
+        let fields = self.current_fields();
+        if fields.contains_key(&tag) {
+            return Err(Error::Duplicate);
+        }
+        let field = Field {
+            i,
+            range: range.clone(),
+            interned: None,
+            normalized: None,
+        };
+        fields.insert(tag, field);
+        let hot_field = match tag {
+            _ if in_group => None,
+            tags::MSG_TYPE => Some(&mut self.hot_fields.msg_type),
+            tags::MSG_SEQ_NUM => Some(&mut self.hot_fields.seq_num),
+            tags::SENDER_COMP_ID => Some(&mut self.hot_fields.sender),
+            tags::TARGET_COMP_ID => Some(&mut self.hot_fields.target),
+            _ => None,
+        };
+        if let Some(hot_field) = hot_field {
+            *hot_field = Some(range.clone());
+        }
+        self.insertion_order.push((tag, range));
+        Ok(())
+    }
+
+    /// Starts the repeating group of the `NumInGroup` field `tag`, which must
+    /// have already been added to `self`. Its entries are added with
+    /// [`FixMessageRefBuilder::add_entry`].
+    pub fn enter_group(&mut self, tag: u32) {
+        let parent = self.current_entry();
+        let groups = match parent {
+            Some(i) => &mut self.entries[i].groups,
+            None => &mut self.groups,
+        };
+        groups.insert(tag, Vec::new());
+        self.open_groups.push(OpenGroup {
+            tag,
+            parent,
+            entry: None,
+        });
+    }
+
+    /// Starts a new entry of the innermost group. All fields up to the next
+    /// call to [`FixMessageRefBuilder::add_entry`] or
+    /// [`FixMessageRefBuilder::exit_group`] belong to it.
+    pub fn add_entry(&mut self) {
+        let i = self.entries.len();
+        let group = match self.open_groups.last_mut() {
+            Some(group) => group,
+            None => return,
+        };
+        group.entry = Some(i);
+        let groups = match group.parent {
+            Some(parent) => &mut self.entries[parent].groups,
+            None => &mut self.groups,
+        };
+        if let Some(entries) = groups.get_mut(&group.tag) {
+            entries.push(i);
+        }
+        self.entries.push(Entry::default());
+    }
+
+    /// Ends the innermost group.
+    pub fn exit_group(&mut self) {
+        self.open_groups.pop();
+    }
+
+    fn current_entry(&self) -> Option<usize> {
+        self.open_groups.last().and_then(|group| group.entry)
+    }
+
+    fn current_fields(&mut self) -> &mut HashMap<u32, Field> {
+        match self.current_entry() {
+            Some(i) => &mut self.entries[i].fields,
+            None => &mut self.fields,
         }
     }
 
@@ -184,15 +546,15 @@ pub struct FieldRef<'a> {
 
 impl<'a> FieldAccess<()> for FieldRef<'a> {
     fn raw(&self) -> Result<&[u8], ()> {
-        Ok(&self.message.bytes[self.field.range.clone()])
+        Ok(self.message.value_of(self.field))
     }
 
     fn as_char(&self) -> Result<u8, ()> {
-        Ok(self.message.bytes[self.field.range.start])
+        self.raw()?.first().copied().ok_or(())
     }
 
     fn as_bool(&self) -> Result<bool, ()> {
-        Ok(self.message.bytes[self.field.range.start] == b'Y')
+        Ok(self.raw()?.first() == Some(&b'Y'))
     }
 
     fn as_i64(&self) -> Result<i64, ()> {
@@ -246,25 +608,89 @@ impl<'a> FieldAccess<()> for FieldRef<'a> {
     }
 }
 
+/// A repeating group within a [`FixMessageRef`]. See
+/// [`FixMessageRef::group`].
 #[derive(Debug, Clone)]
 pub struct GroupRef<'a> {
-    message: &'a FixMessageRef<'a>,
-    len: usize,
-    field_len: u32,
+    message: FixMessageRef<'a>,
+    entries: &'a [usize],
 }
 
 impl<'a> GroupRef<'a> {
+    /// Returns the number of entries of `self`.
     pub fn len(&self) -> usize {
-        self.len
+        self.entries.len()
+    }
+
+    /// Returns `true` if `self` has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the `i`-th entry of `self`, if any.
+    pub fn get(&self, i: usize) -> Option<GroupEntryRef<'a>> {
+        let entry = &self.message.builder.entries[*self.entries.get(i)?];
+        Some(GroupEntryRef {
+            message: self.message.clone(),
+            entry,
+        })
     }
 
-    pub fn iter(&self) -> GroupRefIter {
-        GroupRefIter { group: self, i: 0 }
+    /// Returns an [`Iterator`] over the entries of `self`.
+    pub fn iter(&self) -> GroupRefIter<'a> {
+        GroupRefIter {
+            group: self.clone(),
+            i: 0,
+        }
     }
 }
 
+/// An [`Iterator`] over the entries of a [`GroupRef`].
 #[derive(Debug, Clone)]
 pub struct GroupRefIter<'a> {
-    group: &'a GroupRef<'a>,
+    group: GroupRef<'a>,
     i: usize,
 }
+
+impl<'a> Iterator for GroupRefIter<'a> {
+    type Item = GroupEntryRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.group.get(self.i)?;
+        self.i += 1;
+        Some(entry)
+    }
+}
+
+/// A single entry of a [`GroupRef`].
+#[derive(Debug, Clone)]
+pub struct GroupEntryRef<'a> {
+    message: FixMessageRef<'a>,
+    entry: &'a Entry,
+}
+
+impl<'a> GroupEntryRef<'a> {
+    /// Returns the value of the field `tag` of `self`, if present.
+    pub fn field_raw(&self, tag: u32) -> Option<&'a [u8]> {
+        self.entry
+            .fields
+            .get(&tag)
+            .map(|field| self.message.value_of(field))
+    }
+
+    /// Like [`GroupEntryRef::field_raw`], but the value must be UTF-8.
+    pub fn field_as_str(&self, tag: u32) -> Option<&'a str> {
+        self.field_raw(tag)
+            .and_then(|data| std::str::from_utf8(data).ok())
+    }
+
+    /// Returns the nested repeating group of the `NumInGroup` field `tag`, if
+    /// present.
+    pub fn group(&self, tag: u32) -> Option<GroupRef<'a>> {
+        let entries = self.entry.groups.get(&tag)?;
+        Some(GroupRef {
+            message: self.message.clone(),
+            entries,
+        })
+    }
+}
@@ -1,11 +1,37 @@
-use crate::dictionary::LayoutItemKind;
+use crate::dictionary::{LayoutItem, LayoutItemKind};
 use crate::Dictionary;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// The layout of a FIX repeating group, according to a [`Dictionary`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupLayout {
+    delimiter: u32,
+    members: HashSet<u32>,
+}
+
+impl GroupLayout {
+    /// Returns the tag of the first field of every entry of `self`.
+    pub fn delimiter(&self) -> u32 {
+        self.delimiter
+    }
+
+    /// Returns `true` if and only if `tag` may appear within an entry of
+    /// `self`. Fields of nested groups don't count, but their `NumInGroup`
+    /// fields do.
+    pub fn contains(&self, tag: u32) -> bool {
+        self.members.contains(&tag)
+    }
+}
 
 /// A utility data structure that helps to parse FIX repeating groups.
 #[derive(Debug, Clone)]
 pub struct GroupDelimiter {
-    group_items: HashSet<(String, u32, u32)>,
+    // Groups by `MsgType <35>` and `NumInGroup` tag.
+    layouts: HashMap<String, HashMap<u32, Arc<GroupLayout>>>,
+    // Groups of `StandardHeader` and `StandardTrailer`, which are shared by
+    // all messages.
+    common_layouts: HashMap<u32, Arc<GroupLayout>>,
     entered_groups: Vec<u32>,
     current_msg_type: String,
 }
@@ -13,38 +39,47 @@ pub struct GroupDelimiter {
 impl GroupDelimiter {
     /// Crates a new [`GroupDelimiter`] following the specification of `dictionary`.
     pub fn new(dictionary: Dictionary) -> Self {
-        let mut group_items = HashSet::new();
-        for message in dictionary.iter_messages() {
-            for item in message.layout() {
-                match item.kind() {
-                    LayoutItemKind::Component(component) if component.is_group() => {
-                        for item in component.items() {
-                            match item.kind() {
-                                LayoutItemKind::Field(f) => {
-                                    group_items.insert((
-                                        message.msg_type().to_string(),
-                                        component.id(),
-                                        f.tag(),
-                                    ));
-                                }
-                                _ => {}
-                            }
-                        }
-                    }
-                    _ => (),
-                }
+        let mut common_layouts = HashMap::new();
+        for name in ["StandardHeader", "StandardTrailer"].iter() {
+            if let Some(component) = dictionary.component_by_name(name) {
+                collect_layouts(
+                    component.items(),
+                    &mut common_layouts,
+                    &mut Members::default(),
+                );
             }
         }
+        let mut layouts = HashMap::new();
+        for message in dictionary.iter_messages() {
+            let mut message_layouts = HashMap::new();
+            collect_layouts(
+                message.layout(),
+                &mut message_layouts,
+                &mut Members::default(),
+            );
+            layouts.insert(message.msg_type().to_string(), message_layouts);
+        }
         Self {
             current_msg_type: "".to_string(),
-            group_items,
+            layouts,
+            common_layouts,
             entered_groups: Vec::new(),
         }
     }
 
+    /// Returns the [`GroupLayout`] of the `NumInGroup` field `tag` within
+    /// messages of type `msg_type`, if known.
+    pub fn layout(&self, msg_type: &str, tag: u32) -> Option<&Arc<GroupLayout>> {
+        self.layouts
+            .get(msg_type)
+            .and_then(|layouts| layouts.get(&tag))
+            .or_else(|| self.common_layouts.get(&tag))
+    }
+
     /// Assumes for all subsequent operations that `self` is parsing `msg_type`.
     pub fn set_msg_type(&mut self, msg_type: &str) {
         self.current_msg_type = msg_type.to_string();
+        self.entered_groups.clear();
     }
 
     /// Notifies `self` that currently it's parsing a `group`.
@@ -52,19 +87,73 @@ impl GroupDelimiter {
         self.entered_groups.push(group);
     }
 
+    /// Notifies `self` that the innermost group has ended.
+    pub fn exit_group(&mut self) {
+        self.entered_groups.pop();
+    }
+
     /// Calculates wheather or not the next `tag` is part of the current group.
+    /// Tags of unknown groups are always outside of them.
     pub fn is_outside_group(&self, tag: u32) -> bool {
-        if let Some(group_tag) = self.entered_groups.last() {
-            self.group_items
-                .contains(&(self.current_msg_type.clone(), *group_tag, tag))
-        } else {
-            true
+        match self.entered_groups.last() {
+            Some(group_tag) => match self.layout(&self.current_msg_type, *group_tag) {
+                Some(layout) => !layout.contains(tag),
+                None => true,
+            },
+            None => true,
         }
     }
 
     pub fn debug(&self) {
-        for (msg_type, group_tag, tag) in self.group_items.iter() {
-            println!("{}: {} {}", msg_type, group_tag, tag);
+        for (msg_type, layouts) in self.layouts.iter() {
+            for (group_tag, layout) in layouts.iter() {
+                println!("{}: {} {:?}", msg_type, group_tag, layout);
+            }
+        }
+    }
+}
+
+/// The fields of the layout that is being collected.
+#[derive(Default)]
+struct Members {
+    first: Option<u32>,
+    tags: HashSet<u32>,
+}
+
+impl Members {
+    fn insert(&mut self, tag: u32) {
+        self.first.get_or_insert(tag);
+        self.tags.insert(tag);
+    }
+}
+
+/// Adds the tags of `items` (and of their components) to `members`, and the
+/// layouts of all groups within `items` to `layouts`.
+fn collect_layouts<'a, I>(
+    items: I,
+    layouts: &mut HashMap<u32, Arc<GroupLayout>>,
+    members: &mut Members,
+) where
+    I: IntoIterator<Item = LayoutItem<'a>>,
+{
+    for item in items {
+        match item.kind() {
+            LayoutItemKind::Field(field) => members.insert(field.tag()),
+            LayoutItemKind::Component(component) => {
+                collect_layouts(component.items(), layouts, members)
+            }
+            LayoutItemKind::Group(len_field, group_items) => {
+                members.insert(len_field.tag());
+                let mut group_members = Members::default();
+                collect_layouts(group_items, layouts, &mut group_members);
+                if let Some(delimiter) = group_members.first {
+                    let layout = GroupLayout {
+                        delimiter,
+                        members: group_members.tags,
+                    };
+                    layouts.insert(len_field.tag(), Arc::new(layout));
+                }
+            }
         }
     }
 }
@@ -85,4 +174,20 @@ mod test {
         assert_eq!(group_delimiter.is_outside_group(285), false);
         assert_eq!(group_delimiter.is_outside_group(8), true);
     }
+
+    #[test]
+    fn layouts_follow_the_dictionary() {
+        let dict = Dictionary::from_version(AppVersion::Fix44);
+        let group_delimiter = GroupDelimiter::new(dict);
+        // `MDIncGrp` entries start with `MDUpdateAction <279>` and include
+        // the `Instrument` component.
+        let layout = group_delimiter.layout("X", 268).unwrap();
+        assert_eq!(layout.delimiter(), 279);
+        assert!(layout.contains(55));
+        // `MDFullGrp` entries start with `MDEntryType <269>` instead.
+        assert_eq!(group_delimiter.layout("W", 268).unwrap().delimiter(), 269);
+        // `NoHops <627>` is part of the header of all messages.
+        assert_eq!(group_delimiter.layout("W", 627).unwrap().delimiter(), 628);
+        assert!(group_delimiter.layout("0", 268).is_none());
+    }
 }
@@ -0,0 +1,129 @@
+use crate::tags;
+use std::collections::HashMap;
+
+const CURRENCY_TAGS: &[u32] = &[tags::CURRENCY, tags::SETTL_CURRENCY];
+
+const EXCHANGE_TAGS: &[u32] = &[
+    tags::LAST_MKT,
+    tags::EX_DESTINATION,
+    tags::SECURITY_EXCHANGE,
+];
+
+const COMMON_MSG_TYPES: &[&str] = &[
+    "0", "1", "2", "3", "4", "5", "8", "9", "A", "D", "E", "F", "G", "H", "V", "W", "X", "Y", "j",
+    "AD", "AE", "AR", "BE", "BF",
+];
+
+const COMMON_CURRENCIES: &[&str] = &[
+    "USD", "EUR", "JPY", "GBP", "CHF", "CAD", "AUD", "NZD", "CNY", "HKD", "SGD", "SEK", "NOK",
+    "DKK", "KRW", "INR", "BRL", "MXN", "ZAR",
+];
+
+const COMMON_EXCHANGES: &[&str] = &[
+    "XNYS", "XNAS", "ARCX", "BATS", "XLON", "XPAR", "XETR", "XAMS", "XSWX", "XTKS", "XHKG", "XASX",
+    "XTSE", "XCME", "XCBT", "XEUR",
+];
+
+/// A table of well-known string values, e.g. currencies and exchange codes,
+/// which the [`Decoder`](super::Decoder) can map to `&'static str`s. Interned
+/// values outlive the decoded message without any allocation, see
+/// [`FixMessageRef::field_interned`](super::FixMessageRef::field_interned).
+///
+/// Values are known per tag, so that e.g. a currency is never interned as a
+/// `MsgType <35>`, and fields without any known values don't pay for lookups.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::Interner;
+///
+/// let interner = Interner::common().with_value(6000, "FOO");
+/// assert_eq!(interner.get(15, b"EUR"), Some("EUR"));
+/// assert_eq!(interner.get(6000, b"FOO"), Some("FOO"));
+/// assert_eq!(interner.get(6000, b"BAR"), None);
+/// assert_eq!(interner.get(55, b"EUR"), None);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Interner {
+    values: HashMap<u32, HashMap<&'static [u8], &'static str>>,
+}
+
+impl Interner {
+    /// Creates an empty [`Interner`], without any values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an [`Interner`] with common values of `MsgType <35>`,
+    /// `Currency <15>`, `SettlCurrency <120>`, `LastMkt <30>`,
+    /// `ExDestination <100>` and `SecurityExchange <207>`.
+    pub fn common() -> Self {
+        let mut interner = Self::new();
+        for value in COMMON_MSG_TYPES {
+            interner.insert_value(tags::MSG_TYPE, value);
+        }
+        for (tags, values) in [
+            (CURRENCY_TAGS, COMMON_CURRENCIES),
+            (EXCHANGE_TAGS, COMMON_EXCHANGES),
+        ]
+        .iter()
+        {
+            for tag in tags.iter() {
+                for value in values.iter() {
+                    interner.insert_value(*tag, value);
+                }
+            }
+        }
+        interner
+    }
+
+    /// Adds `value` to the known values of `tag`. Values that are only known
+    /// at runtime can be added with [`Box::leak`], as long as there's a small
+    /// number of them.
+    pub fn insert_value(&mut self, tag: u32, value: &'static str) {
+        self.values
+            .entry(tag)
+            .or_default()
+            .insert(value.as_bytes(), value);
+    }
+
+    pub fn with_value(mut self, tag: u32, value: &'static str) -> Self {
+        self.insert_value(tag, value);
+        self
+    }
+
+    /// Returns the interned string equal to `value`, if it's a known value of
+    /// `tag`.
+    pub fn get(&self, tag: u32, value: &[u8]) -> Option<&'static str> {
+        self.values.get(&tag)?.get(value).copied()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn empty_interner_knows_nothing() {
+        let interner = Interner::new().with_value(tags::SETTL_CURRENCY, "USD");
+        assert_eq!(interner.get(tags::CURRENCY, b"USD"), None);
+    }
+
+    #[test]
+    fn values_are_only_known_for_their_tags() {
+        let interner = Interner::common();
+        assert_eq!(interner.get(tags::MSG_TYPE, b"USD"), None);
+        assert_eq!(interner.get(tags::CURRENCY, b"AE"), None);
+        assert_eq!(interner.get(tags::LAST_MKT, b"EUR"), None);
+    }
+
+    #[test]
+    fn common_interner_knows_msg_types_and_currencies() {
+        let interner = Interner::common();
+        assert_eq!(interner.get(tags::MSG_TYPE, b"AE"), Some("AE"));
+        assert_eq!(interner.get(tags::SETTL_CURRENCY, b"JPY"), Some("JPY"));
+        assert_eq!(interner.get(tags::SECURITY_EXCHANGE, b"XNAS"), Some("XNAS"));
+        assert_eq!(interner.get(tags::CURRENCY, b"XYZ"), None);
+        assert_eq!(interner.get(tags::SYMBOL, b"USD"), None);
+    }
+}
@@ -4,39 +4,113 @@
 //! currently used by the FIX session layer.
 
 use crate::tagvalue::field_value as val;
-use std::collections::BTreeMap;
+use crate::BufferFull;
 use std::fmt;
 use std::fmt::Debug;
 use std::io;
 use std::time::SystemTime;
 
+mod base_value;
+mod canonicalize;
 mod config;
 mod decoder;
+mod diff;
 mod encoder;
+mod field_decode;
 mod field_setter;
 pub mod field_value;
 mod fix_message_ref;
 mod group_delimiter;
+mod interner;
+#[cfg(feature = "quickfix-xml")]
+mod quickfix_xml;
 mod raw_decoder;
 mod raw_encoder;
+mod required_fields;
 mod serialize_field;
+mod sorted_fields;
+mod static_schema;
+#[cfg(feature = "metrics")]
+mod stats;
 mod taglookup;
+mod unescape;
 mod utils;
+mod validation;
 
-pub use config::{Config, Configure};
-pub use decoder::{Decoder, DecoderBuffered};
+pub use base_value::{parse_int, parse_int_i128, parse_percentage, parse_value, BaseValue};
+pub use canonicalize::canonicalize;
+pub use config::{BodyLengthPolicy, ChecksumPolicy, Config, Configure, EmbeddedSeparatorPolicy};
+pub use decoder::{length_tag_for, Decoder, DecoderBuffered};
+pub use diff::{diff, FieldDiff};
 pub use encoder::Encoder;
+pub use field_decode::{decode_visit, scan_fields, FieldScanner, FixFieldDecode};
 pub use field_setter::MessageAccumulator;
-pub use fix_message_ref::{FixMessageRef, FixMessageRefBuilder, GroupRef, GroupRefIter};
-pub use group_delimiter::GroupDelimiter;
-pub use raw_decoder::{RawDecoder, RawDecoderBuffered, RawFrame};
-pub use raw_encoder::RawEncoder;
-pub use serialize_field::SerializeField;
-pub use taglookup::{TagLookup, TagLookupSingleAppVersion};
-pub use utils::{checksum_10, encode_raw};
+pub use fix_message_ref::{
+    FixMessageRef, FixMessageRefBuilder, GroupEntryRef, GroupRef, GroupRefIter,
+};
+pub use group_delimiter::{GroupDelimiter, GroupLayout};
+pub use interner::Interner;
+#[cfg(feature = "quickfix-xml")]
+pub use quickfix_xml::{QuickFixXmlError, TagLookupFromQuickFixXml};
+pub use raw_decoder::{
+    detect_separator, peek_begin_string, split_messages, RawDecoder, RawDecoderBuffered, RawFrame,
+    SplitMessages,
+};
+pub use raw_encoder::{HeaderTemplate, RawEncoder};
+pub use required_fields::{required_fields, verify_required_fields};
+pub use serialize_field::{
+    encode_amt, encode_field, encode_percentage, encode_price, encode_qty, write_int, write_uint,
+    SerializeField,
+};
+pub use sorted_fields::{decode_sorted, SortedFields};
+pub use static_schema::StaticSchema;
+#[cfg(feature = "metrics")]
+pub use stats::DecoderStats;
+pub use taglookup::{TagLookup, TagLookupError, TagLookupSingleAppVersion};
+pub use unescape::{scan_fields_unescaped, unescape, UnescapePolicy, UnescapedFields};
+pub use utils::{
+    checksum_10, checksum_10_as_soh, checksum_field, encode_raw, encoded_len, expected_body_length,
+    patch_checksum, recompute_trailer, repair, start_of_checksum_field, verify_checksum_format,
+    Checksum10,
+};
+pub use validation::{validate_message, ValidationReport};
 
 /// The type returned in the event of an error during message encoding.
-type EncodeError = ();
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EncodeError {
+    /// The [`Buffer`](crate::Buffer) ran out of storage. See
+    /// [`ArrayBuffer`](crate::ArrayBuffer).
+    BufferFull,
+    /// The value can't be represented in the tag-value format, e.g. a `NaN`
+    /// or infinite `Price`.
+    InvalidValue,
+    /// The tag is too large to be encoded from a [`FixMessage`](crate::FixMessage),
+    /// which only supports tags up to 65535.
+    InvalidTag(u32),
+    /// A field that is necessary for encoding, e.g. `BeginString <8>`, is
+    /// missing.
+    MissingField(u32),
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BufferFull => write!(f, "The buffer is full."),
+            Self::InvalidValue => write!(f, "The value can't be encoded."),
+            Self::InvalidTag(tag) => write!(f, "The tag <{}> can't be encoded.", tag),
+            Self::MissingField(tag) => write!(f, "The field <{}> is missing.", tag),
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+impl From<BufferFull> for EncodeError {
+    fn from(_err: BufferFull) -> Self {
+        Self::BufferFull
+    }
+}
 
 /// The type returned in the event of an error during message decoding.
 #[derive(Clone, Debug, PartialEq)]
@@ -45,6 +119,44 @@ pub enum DecodeError {
     /// Invalid FIX message syntax.
     Invalid,
     CheckSum,
+    /// The value of `CheckSum <10>` is not exactly three digits. Only checked
+    /// with [`Configure::checksum_format_strict`].
+    CheckSumFormat,
+    /// The tag is neither in the dictionary nor overridden by configuration.
+    UnknownTag(u32),
+    /// The field value is not valid for the data type of its tag.
+    InvalidValue(u32),
+    /// The tag appears more than once.
+    DuplicateTag(u32),
+    /// A required field is missing.
+    MissingField(u32),
+    /// The tag is not where it's supposed to be, e.g. `MsgType <35>` is not
+    /// the third field. Only checked with [`Configure::verify_field_order`].
+    TagOutOfOrder(u32),
+    /// The `XmlData` field is not well-formed XML. Only checked with the
+    /// `validate-xml` feature.
+    InvalidXml(u32),
+    /// The message is `size` bytes long, which exceeds
+    /// [`Configure::max_message_size`] (`limit`).
+    MessageTooLarge {
+        size: usize,
+        limit: usize,
+    },
+    /// An `Int` value doesn't fit into the requested integer type.
+    IntOverflow,
+    /// The `Data` field `data_tag` is not immediately preceded by its length
+    /// field. See [`length_tag_for`].
+    MissingLengthPrefix {
+        data_tag: u32,
+    },
+    /// The `NumInGroup` field `tag` has a `value` which is not a valid number
+    /// of group entries: it's either garbage, negative, zero (unless allowed
+    /// by [`Configure::empty_groups_allowed`]) or different from the number of
+    /// entries in the message.
+    GroupCount {
+        tag: u32,
+        value: String,
+    },
 }
 
 impl fmt::Display for DecodeError {
@@ -69,7 +181,13 @@ impl From<io::Error> for DecodeError {
 #[derive(Clone, Debug, PartialEq)]
 pub enum FixFieldValue {
     Atom(val::FieldValue<'static>),
-    Group(Vec<BTreeMap<i64, FixFieldValue>>),
+    /// The entries of a repeating group. The fields of each entry are stored
+    /// in the same order as they appear on the wire, starting with the
+    /// delimiter field.
+    Group(Vec<Vec<(u32, FixFieldValue)>>),
+    /// A `Data` field. Its `Length` field is not stored separately, but
+    /// it's encoded right before it.
+    Data(val::DataField),
 }
 
 impl FixFieldValue {
@@ -0,0 +1,207 @@
+use super::{TagLookup, TagLookupError};
+use crate::{DataType, Dictionary};
+use quick_xml::events::{BytesStart, Event};
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+
+/// A [`TagLookup`] backed by the `<fields>` section of a QuickFIX data
+/// dictionary (e.g. `FIX44.xml`), loaded at runtime. Custom tags are supported
+/// as long as they're declared in the dictionary. Only available with the
+/// `quickfix-xml` feature.
+///
+/// Tags that are not in the dictionary are looked up as
+/// [`DataType::String`], like [`TagLookupSingleAppVersion`](super::TagLookupSingleAppVersion)
+/// does.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::{TagLookup, TagLookupFromQuickFixXml};
+/// use fefix::DataType;
+///
+/// let xml = r#"
+///     <fix major="4" minor="4">
+///         <fields>
+///             <field number="44" name="Price" type="PRICE"/>
+///             <field number="5001" name="StrategyName" type="STRING"/>
+///         </fields>
+///     </fix>
+/// "#;
+/// let mut lookup = TagLookupFromQuickFixXml::from_reader(xml.as_bytes()).unwrap();
+/// assert_eq!(lookup.name(5001), Some("StrategyName"));
+/// assert_eq!(lookup.lookup(44).unwrap(), DataType::Price);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TagLookupFromQuickFixXml {
+    fields: HashMap<u32, (String, DataType)>,
+}
+
+impl TagLookupFromQuickFixXml {
+    /// Parses the `<fields>` section of the QuickFIX data dictionary in
+    /// `reader`. Field types are parsed with [`DataType::from_quickfix_name`].
+    pub fn from_reader<R>(reader: R) -> Result<Self, QuickFixXmlError>
+    where
+        R: io::Read,
+    {
+        let mut reader = quick_xml::Reader::from_reader(io::BufReader::new(reader));
+        let mut buffer = Vec::new();
+        let mut fields = HashMap::new();
+        let mut inside_fields = false;
+        loop {
+            match reader.read_event(&mut buffer)? {
+                Event::Start(e) if e.name() == b"fields" => inside_fields = true,
+                Event::End(e) if e.name() == b"fields" => inside_fields = false,
+                Event::Start(e) | Event::Empty(e) if inside_fields && e.name() == b"field" => {
+                    let (tag, name, data_type) = parse_field(&e)?;
+                    fields.insert(tag, (name, data_type));
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+            buffer.clear();
+        }
+        Ok(Self { fields })
+    }
+
+    /// Returns the number of fields in `self`.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Returns `true` if `self` contains no fields.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Returns the name of the field `tag`, if present in the dictionary.
+    pub fn name(&self, tag: u32) -> Option<&str> {
+        self.fields.get(&tag).map(|(name, _)| name.as_str())
+    }
+
+    /// Returns the [`DataType`] of the field `tag`, if present in the
+    /// dictionary.
+    pub fn data_type(&self, tag: u32) -> Option<DataType> {
+        self.fields.get(&tag).map(|(_, data_type)| *data_type)
+    }
+}
+
+impl TagLookup for TagLookupFromQuickFixXml {
+    type Error = TagLookupError;
+
+    fn from_dict(dict: &Dictionary) -> Self {
+        let fields = dict
+            .iter_fields()
+            .map(|field| {
+                let data_type = field.data_type().basetype();
+                (field.tag(), (field.name().to_string(), data_type))
+            })
+            .collect();
+        Self { fields }
+    }
+
+    fn lookup(&mut self, tag: u32) -> Result<DataType, Self::Error> {
+        Ok(self.data_type(tag).unwrap_or(DataType::String))
+    }
+}
+
+/// Returns the tag, name and [`DataType`] of a `<field>` element.
+fn parse_field(element: &BytesStart) -> Result<(u32, String, DataType), QuickFixXmlError> {
+    let mut tag = None;
+    let mut name = None;
+    let mut type_name = None;
+    for attribute in element.attributes() {
+        let attribute = attribute?;
+        let value = String::from_utf8_lossy(&attribute.unescaped_value()?).into_owned();
+        match attribute.key {
+            b"number" => tag = value.parse::<u32>().ok(),
+            b"name" => name = Some(value),
+            b"type" => type_name = Some(value),
+            _ => {}
+        }
+    }
+    let name = name.ok_or_else(|| QuickFixXmlError::InvalidField("<no name>".to_string()))?;
+    let tag = tag.ok_or_else(|| QuickFixXmlError::InvalidField(name.clone()))?;
+    let data_type = type_name
+        .and_then(DataType::from_quickfix_name)
+        .ok_or_else(|| QuickFixXmlError::InvalidField(name.clone()))?;
+    Ok((tag, name, data_type))
+}
+
+/// The error type returned by [`TagLookupFromQuickFixXml::from_reader`].
+#[derive(Debug)]
+pub enum QuickFixXmlError {
+    /// The XML document is malformed.
+    Xml(quick_xml::Error),
+    /// The `<field>` with this name has no valid `number` or `type` attribute.
+    InvalidField(String),
+}
+
+impl fmt::Display for QuickFixXmlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Xml(err) => write!(f, "Invalid XML: {}", err),
+            Self::InvalidField(name) => write!(f, "Invalid field definition: {}", name),
+        }
+    }
+}
+
+impl std::error::Error for QuickFixXmlError {}
+
+impl From<quick_xml::Error> for QuickFixXmlError {
+    fn from(err: quick_xml::Error) -> Self {
+        Self::Xml(err)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<fix major="4" minor="4">
+  <header>
+    <field name="BeginString" required="Y"/>
+  </header>
+  <messages>
+    <message name="NewOrderSingle" msgtype="D" msgcat="app">
+      <field name="Symbol" required="Y"/>
+    </message>
+  </messages>
+  <fields>
+    <field number="8" name="BeginString" type="STRING"/>
+    <field number="38" name="OrderQty" type="QTY"/>
+    <field number="55" name="Symbol" type="STRING"/>
+    <field number="9001" name="InternalDeskId" type="INT">
+      <value enum="1" description="EQUITIES"/>
+    </field>
+  </fields>
+</fix>
+"#;
+
+    #[test]
+    fn custom_tag_is_loaded() {
+        let mut lookup = TagLookupFromQuickFixXml::from_reader(XML.as_bytes()).unwrap();
+        assert_eq!(lookup.len(), 4);
+        assert_eq!(lookup.name(9001), Some("InternalDeskId"));
+        assert_eq!(lookup.lookup(9001).unwrap(), DataType::Int);
+        assert_eq!(lookup.lookup(38).unwrap(), DataType::Qty);
+        assert_eq!(lookup.data_type(9002), None);
+        assert_eq!(lookup.lookup(9002).unwrap(), DataType::String);
+    }
+
+    #[test]
+    fn field_with_unknown_type() {
+        let xml = r#"<fix><fields><field number="1" name="Foo" type="WAT"/></fields></fix>"#;
+        match TagLookupFromQuickFixXml::from_reader(xml.as_bytes()) {
+            Err(QuickFixXmlError::InvalidField(name)) => assert_eq!(name, "Foo"),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn malformed_xml() {
+        let xml = r#"<fix><fields><field number="1" name="Foo" type="INT"></fix>"#;
+        assert!(TagLookupFromQuickFixXml::from_reader(xml.as_bytes()).is_err());
+    }
+}
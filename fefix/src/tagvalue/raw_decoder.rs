@@ -1,4 +1,7 @@
-use crate::tagvalue::{utils, Config, Configure, DecodeError};
+use crate::tags;
+use crate::tagvalue::{
+    scan_fields, utils, BodyLengthPolicy, ChecksumPolicy, Config, Configure, DecodeError,
+};
 use std::ops::Range;
 
 /// An immutable view over the raw contents of a FIX message.
@@ -8,6 +11,9 @@ pub struct RawFrame<'a> {
     begin_string: &'a [u8],
     payload: &'a [u8],
     payload_offset: usize,
+    end_of_header: usize,
+    checksum_ok: bool,
+    body_length_ok: bool,
 }
 
 impl<'a> RawFrame<'a> {
@@ -16,12 +22,30 @@ impl<'a> RawFrame<'a> {
         begin_string: &'a [u8],
         payload_offset: usize,
         payload_len: usize,
+        separator: u8,
+        checksum_ok: bool,
+        body_length_ok: bool,
     ) -> Self {
+        let payload = &data[payload_offset..payload_offset + payload_len];
+        // `MsgType <35>` is expected to be the first field of the payload.
+        // Without it, the header only contains `BeginString <8>` and
+        // `BodyLength <9>`.
+        let msg_type_len = if payload.starts_with(b"35=") {
+            payload
+                .iter()
+                .position(|byte| *byte == separator)
+                .map_or(0, |i| i + 1)
+        } else {
+            0
+        };
         Self {
             data,
             begin_string,
-            payload: &data[payload_offset..payload_offset + payload_len],
+            payload,
             payload_offset,
+            end_of_header: payload_offset + msg_type_len,
+            checksum_ok,
+            body_length_ok,
         }
     }
 
@@ -90,6 +114,127 @@ impl<'a> RawFrame<'a> {
     pub fn payload_offset(&self) -> usize {
         self.payload_offset
     }
+
+    /// Returns an immutable reference to the leading fields of `self`, up to
+    /// and including `MsgType <35>` and its separator. This is useful for fast
+    /// routing decisions without re-scanning the whole message.
+    ///
+    /// `MsgType <35>` must immediately follow `BodyLength <9>`, i.e. it must be
+    /// the first field of [`RawFrame::payload`]; if it isn't, the header only
+    /// contains `BeginString <8>` and `BodyLength <9>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, RawDecoder};
+    ///
+    /// let mut decoder = RawDecoder::<Config>::new();
+    /// decoder.config_mut().set_separator(b'|');
+    /// let data = b"8=FIX.4.2|9=42|35=0|49=A|56=B|34=12|52=20100304-07:59:30|10=022|";
+    /// let message = decoder.decode(data).unwrap();
+    ///
+    /// assert_eq!(message.header_bytes(), b"8=FIX.4.2|9=42|35=0|");
+    /// ```
+    pub fn header_bytes(&self) -> &'a [u8] {
+        &self.data[..self.end_of_header]
+    }
+
+    /// Returns the value of `MsgType <35>`, if present. `MsgType <35>` is
+    /// expected to be the third field of the message, but, unless
+    /// [`Configure::verify_field_order`] is enabled, it's looked for among all
+    /// fields if it isn't. Some FIXT counterparties e.g. put
+    /// `ApplVerID <1128>` first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, RawDecoder};
+    ///
+    /// let mut decoder = RawDecoder::<Config>::new();
+    /// decoder.config_mut().set_separator(b'|');
+    /// let data = b"8=FIXT.1.1|9=17|1128=9|35=D|49=A|10=206|";
+    /// let message = decoder.decode(data).unwrap();
+    ///
+    /// assert_eq!(message.msg_type(), Some(&b"D"[..]));
+    /// assert_eq!(message.header_bytes(), b"8=FIXT.1.1|9=17|");
+    /// ```
+    pub fn msg_type(&self) -> Option<&'a [u8]> {
+        // Fast path: `MsgType <35>` is the first field of the payload.
+        let end_of_header = self.end_of_header - self.payload_offset;
+        if end_of_header > 0 {
+            return Some(&self.payload[3..end_of_header - 1]);
+        }
+        let separator = self.data[self.payload_offset - 1];
+        scan_fields(self.payload, separator)
+            .filter_map(Result::ok)
+            .find(|(tag, _)| *tag == tags::MSG_TYPE)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns an immutable reference to all fields that follow
+    /// [`RawFrame::header_bytes`], excluding `CheckSum <10>`. Together, the
+    /// two slices make up the whole message except for `CheckSum <10>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, RawDecoder};
+    ///
+    /// let mut decoder = RawDecoder::<Config>::new();
+    /// decoder.config_mut().set_separator(b'|');
+    /// let data = b"8=FIX.4.2|9=42|35=0|49=A|56=B|34=12|52=20100304-07:59:30|10=022|";
+    /// let message = decoder.decode(data).unwrap();
+    ///
+    /// assert_eq!(message.body_bytes(), b"49=A|56=B|34=12|52=20100304-07:59:30|");
+    /// ```
+    pub fn body_bytes(&self) -> &'a [u8] {
+        &self.data[self.end_of_header..self.payload_offset + self.payload.len()]
+    }
+
+    /// Returns `false` if and only if `CheckSum(10)` was verified and found to
+    /// be invalid. This can only happen under [`ChecksumPolicy::Warn`]; with
+    /// [`ChecksumPolicy::Ignore`] the checksum is never verified and this
+    /// method always returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{ChecksumPolicy, Config, RawDecoder};
+    ///
+    /// let config = Config::default()
+    ///     .with_separator(b'|')
+    ///     .with_checksum_policy(ChecksumPolicy::Warn);
+    /// let decoder = RawDecoder::with_config(config);
+    /// let data = b"8=FIX.4.2|9=42|35=0|49=A|56=B|34=12|52=20100304-07:59:30|10=000|";
+    /// let message = decoder.decode(data).unwrap();
+    ///
+    /// assert_eq!(message.checksum_ok(), false);
+    /// ```
+    pub fn checksum_ok(&self) -> bool {
+        self.checksum_ok
+    }
+
+    /// Returns `false` if and only if `BodyLength(9)` was verified and found to
+    /// be invalid. This can only happen under [`BodyLengthPolicy::Warn`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{BodyLengthPolicy, Config, RawDecoder};
+    ///
+    /// let config = Config::default()
+    ///     .with_separator(b'|')
+    ///     .with_body_length_policy(BodyLengthPolicy::Warn);
+    /// let decoder = RawDecoder::with_config(config);
+    /// let data = b"8=FIX.4.2|9=40|35=0|49=A|56=B|34=12|52=20100304-07:59:30|10=020|";
+    /// let message = decoder.decode(data).unwrap();
+    ///
+    /// assert_eq!(message.body_length_ok(), false);
+    /// assert_eq!(message.payload().len(), 42);
+    /// ```
+    pub fn body_length_ok(&self) -> bool {
+        self.body_length_ok
+    }
 }
 
 /// A bare-bones FIX decoder for low-level message handling.
@@ -142,24 +287,108 @@ where
     }
 
     /// Does minimal parsing on `data` and returns a [`RawFrame`] if it's valid.
+    ///
+    /// Messages longer than [`Configure::max_message_size`] are rejected
+    /// upfront with [`DecodeError::MessageTooLarge`].
     pub fn decode<'a>(&self, data: &'a [u8]) -> Result<RawFrame<'a>, DecodeError> {
+        check_message_size(data.len(), self.config())?;
         if data.len() < utils::MIN_FIX_MESSAGE_LEN_IN_BYTES {
             return Err(DecodeError::Invalid);
         }
-        let info = HeaderInfo::parse(data, self.config().separator())?;
-        utils::verify_body_length(data, info.start_of_body(), info.body_range().len())?;
-        if self.config().verify_checksum() {
-            utils::verify_checksum(data)?;
+        let separator = self.config().separator();
+        if self.config().checksum_format_strict() {
+            utils::verify_checksum_format(data, separator)?;
         }
-        Ok(RawFrame::new(
+        let info = HeaderInfo::parse(data, separator)?;
+        let body_length_ok = match self.config().body_length_policy() {
+            BodyLengthPolicy::Enforce => {
+                utils::verify_body_length(
+                    data,
+                    separator,
+                    info.start_of_body(),
+                    info.body_range().len(),
+                )?;
+                true
+            }
+            BodyLengthPolicy::Warn => utils::verify_body_length(
+                data,
+                separator,
+                info.start_of_body(),
+                info.body_range().len(),
+            )
+            .is_ok(),
+            BodyLengthPolicy::Ignore => true,
+        };
+        // Don't trust `BodyLength <9>` unless it's been verified: the body
+        // always ends right before `CheckSum <10>`.
+        let end_of_body =
+            utils::start_of_checksum_field(data, separator).ok_or(DecodeError::Invalid)?;
+        if info.start_of_body() > end_of_body {
+            return Err(DecodeError::Invalid);
+        }
+        if self.config().require_trailing_separator() && data.last() != Some(&separator) {
+            return Err(DecodeError::Invalid);
+        }
+        let checksum_ok = match self.config().checksum_policy() {
+            ChecksumPolicy::Enforce => {
+                self.verify_checksum(data)?;
+                true
+            }
+            ChecksumPolicy::Warn => self.verify_checksum(data).is_ok(),
+            ChecksumPolicy::Ignore => true,
+        };
+        let frame = RawFrame::new(
             data,
             &data[info.begin_string_range()],
-            info.body_range().start,
-            info.body_range().len(),
-        ))
+            info.start_of_body(),
+            end_of_body - info.start_of_body(),
+            separator,
+            checksum_ok,
+            body_length_ok,
+        );
+        // `MsgType <35>` must be the third field, i.e. part of the header.
+        if self.config().verify_field_order() && frame.end_of_header == frame.payload_offset {
+            return Err(DecodeError::TagOutOfOrder(tags::MSG_TYPE));
+        }
+        Ok(frame)
+    }
+
+    /// Like [`RawDecoder::decode`], but ignores any bytes before the first
+    /// occurrence of `8=`, e.g. a UTF-8 BOM or other garbage left over in
+    /// file captures. Returns the number of skipped bytes alongside the frame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, RawDecoder};
+    ///
+    /// let decoder = RawDecoder::with_config(Config::default().with_separator(b'|'));
+    /// let data = b"\xEF\xBB\xBF8=FIX.4.2|9=15|35=0|49=A|56=B|10=016|";
+    /// assert!(decoder.decode(data).is_err());
+    /// let (skipped, frame) = decoder.decode_lenient(data).unwrap();
+    /// assert_eq!(skipped, 3);
+    /// assert_eq!(frame.begin_string(), b"FIX.4.2");
+    /// ```
+    pub fn decode_lenient<'a>(&self, data: &'a [u8]) -> Result<(usize, RawFrame<'a>), DecodeError> {
+        let skipped = start_of_begin_string(data).ok_or(DecodeError::Invalid)?;
+        let frame = self.decode(&data[skipped..])?;
+        Ok((skipped, frame))
+    }
+
+    fn verify_checksum(&self, data: &[u8]) -> Result<(), DecodeError> {
+        if self.config().checksum_assumes_soh() {
+            utils::verify_checksum_as_soh(data, self.config().separator())
+        } else {
+            utils::verify_checksum(data, self.config().separator())
+        }
     }
 }
 
+/// Returns the index of the first occurrence of `8=` within `data`, if any.
+pub(crate) fn start_of_begin_string(data: &[u8]) -> Option<usize> {
+    data.windows(2).position(|window| window == b"8=")
+}
+
 /// A bare-bones FIX decoder for byte streams.
 #[derive(Debug, Clone)]
 pub struct RawDecoderBuffered<C = Config>
@@ -202,6 +431,11 @@ where
                     let start_of_body = info.start_of_body();
                     let body_len = info.body_range().len();
                     let total_len = start_of_body + body_len + utils::FIELD_CHECKSUM_LEN_IN_BYTES;
+                    // Don't even attempt to read messages that are too large.
+                    if let Err(e) = check_message_size(total_len, self.config()) {
+                        self.error = Some(e);
+                        return &mut [];
+                    }
                     let current_len = self.buffer.as_slice().len();
                     self.buffer.resize(total_len, 0);
                     &mut self.buffer.as_mut_slice()[current_len..]
@@ -228,6 +462,268 @@ where
     }
 }
 
+fn check_message_size<C>(size: usize, config: &C) -> Result<(), DecodeError>
+where
+    C: Configure,
+{
+    match config.max_message_size() {
+        Some(limit) if size > limit => Err(DecodeError::MessageTooLarge { size, limit }),
+        _ => Ok(()),
+    }
+}
+
+/// Returns the value of `BeginString <8>` at the start of `data`, without
+/// decoding the rest of the message. Any value is accepted, including
+/// non-standard ones: see [`FixVersion`](crate::FixVersion) to tell them apart.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::peek_begin_string;
+///
+/// let data = b"8=MYPROTO.1.0|9=5|35=0|10=000|";
+/// assert_eq!(peek_begin_string(data, b'|'), Some(&b"MYPROTO.1.0"[..]));
+/// assert_eq!(peek_begin_string(b"9=5|", b'|'), None);
+/// assert_eq!(peek_begin_string(b"8=FIX.4", b'|'), None);
+/// ```
+pub fn peek_begin_string(data: &[u8], separator: u8) -> Option<&[u8]> {
+    if !data.starts_with(b"8=") {
+        return None;
+    }
+    let rest = &data[2..];
+    let end = rest.iter().position(|byte| *byte == separator)?;
+    Some(&rest[..end])
+}
+
+/// Separators commonly found in captures and logs, in order of preference.
+const SEPARATOR_CANDIDATES: &[u8] = &[0x1, b'|', b'^'];
+
+/// Guesses the field separator of the message at the start of `buf` by looking
+/// at the byte right after the value of `BeginString <8>`. Returns `None` if
+/// `buf` doesn't start with `8=` or if that byte is neither SOH, `|` nor `^`,
+/// or it's not followed by `9=`.
+///
+/// Multi-byte renditions of SOH such as `^A` or `\x01` can't be detected,
+/// as they aren't a single byte. Replace them with SOH first.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::detect_separator;
+///
+/// assert_eq!(detect_separator(b"8=FIX.4.4\x019=5\x0135=0\x0110=005\x01"), Some(0x1));
+/// assert_eq!(detect_separator(b"8=FIX.4.4|9=5|35=0|10=005|"), Some(b'|'));
+/// assert_eq!(detect_separator(b"8=FIX.4.4,9=5,35=0,10=005,"), None);
+/// ```
+pub fn detect_separator(buf: &[u8]) -> Option<u8> {
+    if !buf.starts_with(b"8=") {
+        return None;
+    }
+    let i = buf[2..]
+        .iter()
+        .position(|byte| SEPARATOR_CANDIDATES.contains(byte))?
+        + 2;
+    if buf[i + 1..].starts_with(b"9=") {
+        Some(buf[i])
+    } else {
+        None
+    }
+}
+
+/// Splits `buf`, a concatenation of zero or more tag-value messages, into
+/// individual message slices. Framing relies solely on `BodyLength <9>` and the
+/// position of `CheckSum <10>`; no other validation is performed, except for
+/// [`Configure::max_message_size`].
+///
+/// Iteration stops as soon as a trailing partial message is found. The
+/// unconsumed bytes are then available through [`SplitMessages::remainder`],
+/// so that callers can retain them until more data arrives.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::{split_messages, Config};
+///
+/// let config = Config::default().with_separator(b'|');
+/// let data = b"8=?|9=5|35=?|10=183|8=?|9=5|35=?|10=183|8=?|9=5|";
+/// let mut messages = split_messages(data, &config);
+/// assert_eq!(messages.next().unwrap().unwrap(), b"8=?|9=5|35=?|10=183|");
+/// assert_eq!(messages.next().unwrap().unwrap(), b"8=?|9=5|35=?|10=183|");
+/// assert!(messages.next().is_none());
+/// assert_eq!(messages.remainder(), b"8=?|9=5|");
+/// ```
+pub fn split_messages<'a, C>(buf: &'a [u8], config: &C) -> SplitMessages<'a>
+where
+    C: Configure,
+{
+    SplitMessages {
+        buf,
+        separator: config.separator(),
+        max_message_size: config.max_message_size(),
+        i: 0,
+        failed: false,
+        boundary: None,
+        skipped_len: 0,
+    }
+}
+
+/// An [`Iterator`] over the messages in a byte buffer. See [`split_messages`].
+#[derive(Debug, Clone)]
+pub struct SplitMessages<'a> {
+    buf: &'a [u8],
+    separator: u8,
+    max_message_size: Option<usize>,
+    i: usize,
+    failed: bool,
+    boundary: Option<&'a [u8]>,
+    skipped_len: usize,
+}
+
+impl<'a> SplitMessages<'a> {
+    /// Turns on recovery mode: after an invalid message, instead of stopping,
+    /// `self` skips forward to the next occurrence of `boundary` (typically
+    /// `b"8=FIX"`) and resumes from there. The error is still yielded, and the
+    /// number of skipped bytes is available through
+    /// [`SplitMessages::skipped_len`].
+    ///
+    /// This is mostly useful to replay logs with partially corrupted content.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{split_messages, Config};
+    ///
+    /// let config = Config::default().with_separator(b'|');
+    /// let data = b"8=?|9=5|35=?|10=183|8=?|9=x|garbage8=?|9=5|35=?|10=183|";
+    /// let mut messages = split_messages(data, &config).with_recovery(b"8=");
+    /// assert!(messages.next().unwrap().is_ok());
+    /// assert!(messages.next().unwrap().is_err());
+    /// assert_eq!(messages.skipped_len(), 15);
+    /// assert!(messages.next().unwrap().is_ok());
+    /// assert!(messages.next().is_none());
+    /// ```
+    pub fn with_recovery(mut self, boundary: &'a [u8]) -> Self {
+        self.boundary = Some(boundary);
+        self
+    }
+
+    /// Returns the total number of bytes skipped in recovery mode so far. See
+    /// [`SplitMessages::with_recovery`].
+    pub fn skipped_len(&self) -> usize {
+        self.skipped_len
+    }
+
+    /// Returns the bytes that have not been consumed yet, i.e. a trailing
+    /// partial message or, after an error, everything starting from the invalid
+    /// message.
+    pub fn remainder(&self) -> &'a [u8] {
+        &self.buf[self.i..]
+    }
+
+    /// Returns the length of [`SplitMessages::remainder`].
+    pub fn remainder_len(&self) -> usize {
+        self.buf.len() - self.i
+    }
+}
+
+impl<'a> Iterator for SplitMessages<'a> {
+    type Item = Result<&'a [u8], DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let data = self.remainder();
+        if self.failed || data.is_empty() {
+            return None;
+        }
+        match self.message_len(data)? {
+            Ok(len) => {
+                self.i += len;
+                Some(Ok(&data[..len]))
+            }
+            Err(e) => {
+                match self.boundary {
+                    Some(_) => {
+                        let skipped = self.next_boundary(data).unwrap_or(data.len());
+                        self.i += skipped;
+                        self.skipped_len += skipped;
+                    }
+                    None => self.failed = true,
+                }
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl<'a> SplitMessages<'a> {
+    /// Returns the length of the message at the start of `data`, or `None` if
+    /// it's incomplete.
+    fn message_len(&self, data: &[u8]) -> Option<Result<usize, DecodeError>> {
+        let info = match HeaderInfo::parse(data, self.separator) {
+            Ok(info) => info,
+            // Both `BeginString <8>` and `BodyLength <9>` must be complete
+            // before we can tell whether the header is malformed.
+            Err(_) if bytecount(data, self.separator) < 2 => return self.incomplete(data),
+            Err(e) => return Some(Err(e)),
+        };
+        let end_of_body = info.body_range().end;
+        let len = end_of_body + utils::FIELD_CHECKSUM_LEN_IN_BYTES;
+        // Checked before waiting for the rest of the message, which might
+        // never fit into memory.
+        match self.max_message_size {
+            Some(limit) if len > limit => {
+                return Some(Err(DecodeError::MessageTooLarge { size: len, limit }))
+            }
+            _ => {}
+        }
+        if data.len() < len {
+            return self.incomplete(data);
+        }
+        if &data[end_of_body..end_of_body + 3] != b"10=" || data[len - 1] != self.separator {
+            return Some(Err(DecodeError::Invalid));
+        }
+        Some(Ok(len))
+    }
+
+    /// In recovery mode, an incomplete message followed by another boundary is
+    /// actually corrupt (e.g. its `BodyLength <9>` is too large).
+    fn incomplete(&self, data: &[u8]) -> Option<Result<usize, DecodeError>> {
+        self.next_boundary(data).map(|_| Err(DecodeError::Invalid))
+    }
+
+    /// Returns the index of the first boundary in `data` (except at its very
+    /// start), if any.
+    fn next_boundary(&self, data: &[u8]) -> Option<usize> {
+        let boundary = self.boundary.filter(|b| !b.is_empty())?;
+        data.windows(boundary.len())
+            .skip(1)
+            .position(|w| w == boundary)
+            .map(|i| i + 1)
+    }
+}
+
+/// Returns `true` if `data` has a well-formed header but its `BodyLength <9>`
+/// doesn't match the actual body length.
+#[cfg(feature = "metrics")]
+pub(crate) fn body_length_is_wrong(data: &[u8], separator: u8) -> bool {
+    if data.len() < utils::MIN_FIX_MESSAGE_LEN_IN_BYTES {
+        return false;
+    }
+    match HeaderInfo::parse(data, separator) {
+        Ok(info) => utils::verify_body_length(
+            data,
+            separator,
+            info.start_of_body(),
+            info.body_range().len(),
+        )
+        .is_err(),
+        Err(_) => false,
+    }
+}
+
+fn bytecount(data: &[u8], byte: u8) -> usize {
+    data.iter().filter(|b| **b == byte).count()
+}
+
 // Information regarding the indices of "important" parts of the FIX message.
 struct HeaderInfo {
     i_equal_sign: [usize; 2],
@@ -305,6 +801,22 @@ mod test {
         assert!(matches!(decoder.decode(&[]), Err(DecodeError::Invalid)));
     }
 
+    #[test]
+    fn body_length_with_non_standard_trailer() {
+        // `CheckSum <10>` has only two digits, so the trailer is one byte
+        // shorter than usual.
+        let config = Config::default()
+            .with_separator(b'|')
+            .with_checksum_policy(ChecksumPolicy::Ignore);
+        let decoder = RawDecoder::with_config(config);
+        let frame = decoder.decode(b"8=FIX.4.4|9=10|35=0|49=A|10=53|").unwrap();
+        assert_eq!(frame.payload(), b"35=0|49=A|");
+        assert!(matches!(
+            decoder.decode(b"8=FIX.4.4|9=9|35=0|49=A|10=53|"),
+            Err(DecodeError::Invalid)
+        ));
+    }
+
     #[test]
     fn sample_message_is_valid() {
         let decoder = new_decoder();
@@ -323,6 +835,117 @@ mod test {
         assert_eq!(frame.payload(), b"35=?|");
     }
 
+    #[test]
+    fn header_and_body_are_split_after_msg_type() {
+        let decoder = new_decoder();
+        let msg = b"8=FIX.4.2|9=40|35=D|49=AFUNDMGR|56=ABROKER|15=USD|59=0|10=091|";
+        let frame = decoder.decode(msg).unwrap();
+        assert_eq!(frame.header_bytes(), b"8=FIX.4.2|9=40|35=D|");
+        assert_eq!(frame.body_bytes(), b"49=AFUNDMGR|56=ABROKER|15=USD|59=0|");
+        let msg = b"8=?|9=5|35=?|10=183|";
+        let frame = decoder.decode(msg).unwrap();
+        assert_eq!(frame.header_bytes(), b"8=?|9=5|35=?|");
+        assert_eq!(frame.body_bytes(), b"");
+    }
+
+    #[test]
+    fn header_without_msg_type_ends_after_body_length() {
+        let decoder = new_decoder();
+        let msg = b"8=FIX.4.2|9=40|49=AFUNDMGR|35=D|56=ABROKER|15=USD|59=0|10=091|";
+        let frame = decoder.decode(msg).unwrap();
+        assert_eq!(frame.header_bytes(), b"8=FIX.4.2|9=40|");
+        assert_eq!(frame.body_bytes(), frame.payload());
+    }
+
+    #[test]
+    fn message_one_byte_over_max_message_size() {
+        let msg = b"8=FIX.4.2|9=40|35=D|49=AFUNDMGR|56=ABROKER|15=USD|59=0|10=091|";
+        let mut decoder = new_decoder();
+        decoder.config_mut().set_max_message_size(Some(msg.len()));
+        assert!(decoder.decode(msg).is_ok());
+        decoder
+            .config_mut()
+            .set_max_message_size(Some(msg.len() - 1));
+        assert_eq!(
+            decoder.decode(msg).unwrap_err(),
+            DecodeError::MessageTooLarge {
+                size: msg.len(),
+                limit: msg.len() - 1
+            }
+        );
+        let mut messages = split_messages(msg, decoder.config());
+        assert!(matches!(
+            messages.next(),
+            Some(Err(DecodeError::MessageTooLarge { .. }))
+        ));
+        assert!(messages.next().is_none());
+    }
+
+    #[test]
+    fn buffered_decoder_stops_reading_past_max_message_size() {
+        let msg = b"8=FIX.4.2|9=40|35=D|49=AFUNDMGR|56=ABROKER|15=USD|59=0|10=091|";
+        let mut decoder = new_decoder_buffered();
+        decoder
+            .config_mut()
+            .set_max_message_size(Some(msg.len() - 1));
+        let buf = decoder.supply_buffer();
+        let len = buf.len();
+        buf.clone_from_slice(&msg[..len]);
+        assert!(decoder.supply_buffer().is_empty());
+        assert!(matches!(
+            decoder.current_frame(),
+            Err(DecodeError::MessageTooLarge { size: 62, .. })
+        ));
+    }
+
+    #[test]
+    fn fixt_msg_type_after_appl_ver_id() {
+        let msg = b"8=FIXT.1.1|9=17|1128=9|35=D|49=A|10=206|";
+        let frame = new_decoder().decode(msg).unwrap();
+        assert_eq!(frame.msg_type(), Some(&b"D"[..]));
+        let mut decoder = new_decoder();
+        decoder.config_mut().set_verify_field_order(true);
+        assert_eq!(
+            decoder.decode(msg).unwrap_err(),
+            DecodeError::TagOutOfOrder(35)
+        );
+        let msg = b"8=FIXT.1.1|9=17|35=D|1128=9|49=A|10=206|";
+        assert_eq!(decoder.decode(msg).unwrap().msg_type(), Some(&b"D"[..]));
+    }
+
+    #[test]
+    fn msg_type_is_missing() {
+        let msg = b"8=FIX.4.2|9=5|49=A|10=040|";
+        let frame = new_decoder().decode(msg).unwrap();
+        assert_eq!(frame.msg_type(), None);
+    }
+
+    #[test]
+    fn detect_each_separator_candidate() {
+        let msg = b"8=FIXT.1.1|9=17|1128=9|35=D|49=A|10=206|";
+        for separator in SEPARATOR_CANDIDATES {
+            let msg: Vec<u8> = msg
+                .iter()
+                .map(|byte| if *byte == b'|' { *separator } else { *byte })
+                .collect();
+            assert_eq!(detect_separator(&msg[..]), Some(*separator));
+        }
+        assert_eq!(detect_separator(b"8=FIX.4.4^A9=5^A35=0^A10=005^A"), None);
+        assert_eq!(detect_separator(b"8=FIX.4.4|"), None);
+        assert_eq!(detect_separator(b"9=5|8=FIX.4.4|"), None);
+        assert_eq!(detect_separator(b""), None);
+    }
+
+    #[test]
+    fn custom_begin_string_is_valid() {
+        let decoder = new_decoder();
+        let msg = b"8=MYPROTO.1.0|9=10|35=0|49=A|10=243|";
+        assert_eq!(peek_begin_string(msg, b'|'), Some(&b"MYPROTO.1.0"[..]));
+        let frame = decoder.decode(msg).unwrap();
+        assert_eq!(frame.begin_string(), b"MYPROTO.1.0");
+        assert_eq!(frame.payload(), b"35=0|49=A|");
+    }
+
     #[test]
     fn message_with_empty_payload_is_invalid() {
         let decoder = new_decoder();
@@ -337,6 +960,101 @@ mod test {
         assert!(matches!(decoder.decode(msg), Err(DecodeError::CheckSum)));
     }
 
+    #[test]
+    fn message_with_bad_checksum_is_flagged_under_warn_policy() {
+        let mut decoder = new_decoder();
+        decoder
+            .config_mut()
+            .set_checksum_policy(ChecksumPolicy::Warn);
+        let msg = b"8=FIX.4.2|9=40|35=D|49=AFUNDMGR|56=ABROKER|15=USD|59=0|10=000|";
+        let frame = decoder.decode(msg).unwrap();
+        assert!(!frame.checksum_ok());
+        assert_eq!(frame.payload(), b"35=D|49=AFUNDMGR|56=ABROKER|15=USD|59=0|");
+        let msg = b"8=FIX.4.2|9=40|35=D|49=AFUNDMGR|56=ABROKER|15=USD|59=0|10=091|";
+        assert!(decoder.decode(msg).unwrap().checksum_ok());
+    }
+
+    #[test]
+    fn wrong_body_length_under_each_policy() {
+        // `BodyLength <9>` is off by 5.
+        let msg = b"8=FIX.4.2|9=37|35=0|49=A|56=B|34=12|52=20100304-07:59:30|10=026|";
+        let decoder = &mut new_decoder();
+        assert_eq!(decoder.decode(msg).err(), Some(DecodeError::Invalid));
+        decoder
+            .config_mut()
+            .set_body_length_policy(BodyLengthPolicy::Warn);
+        let frame = decoder.decode(msg).unwrap();
+        assert!(!frame.body_length_ok());
+        assert_eq!(
+            frame.payload(),
+            b"35=0|49=A|56=B|34=12|52=20100304-07:59:30|"
+        );
+        decoder
+            .config_mut()
+            .set_body_length_policy(BodyLengthPolicy::Ignore);
+        let frame = decoder.decode(msg).unwrap();
+        assert!(frame.body_length_ok());
+        assert_eq!(frame.payload().len(), 42);
+    }
+
+    #[test]
+    fn checksum_computed_over_soh_form() {
+        let msg = b"8=FIX.4.4|9=5|35=0|10=163|";
+        let decoder = &mut new_decoder();
+        assert_eq!(decoder.decode(msg).err(), Some(DecodeError::CheckSum));
+        decoder.config_mut().set_checksum_assumes_soh(true);
+        assert!(decoder.decode(msg).is_ok());
+    }
+
+    #[test]
+    fn checksum_with_wrong_number_of_digits_under_strict_format() {
+        let decoder = &mut new_decoder();
+        decoder.config_mut().set_checksum_format_strict(true);
+        for msg in [
+            &b"8=FIX.4.4|9=5|35=0|10=5|"[..],
+            b"8=FIX.4.4|9=5|35=0|10=0012|",
+        ]
+        .iter()
+        {
+            assert_eq!(decoder.decode(msg).err(), Some(DecodeError::CheckSumFormat));
+        }
+        let msg = b"8=FIX.4.2|9=40|35=D|49=AFUNDMGR|56=ABROKER|15=USD|59=0|10=091|";
+        assert!(decoder.decode(msg).is_ok());
+    }
+
+    #[test]
+    fn trailing_separator_is_optional_if_configured() {
+        let with_separator = b"8=FIX.4.4|9=5|35=0|10=020|";
+        let without_separator = &with_separator[..with_separator.len() - 1];
+        let decoder = &mut new_decoder();
+        assert!(decoder.decode(with_separator).is_ok());
+        assert_eq!(
+            decoder.decode(without_separator).err(),
+            Some(DecodeError::Invalid)
+        );
+        decoder.config_mut().set_require_trailing_separator(false);
+        decoder.config_mut().set_checksum_format_strict(true);
+        for msg in [&with_separator[..], without_separator].iter() {
+            let frame = decoder.decode(msg).unwrap();
+            assert_eq!(frame.payload(), b"35=0|");
+            assert!(frame.checksum_ok());
+        }
+        assert_eq!(
+            decoder.decode(b"8=FIX.4.4|9=5|35=0|10=021").err(),
+            Some(DecodeError::CheckSum)
+        );
+    }
+
+    #[test]
+    fn message_with_bad_checksum_is_not_flagged_under_ignore_policy() {
+        let mut decoder = new_decoder();
+        decoder
+            .config_mut()
+            .set_checksum_policy(ChecksumPolicy::Ignore);
+        let msg = b"8=FIX.4.2|9=40|35=D|49=AFUNDMGR|56=ABROKER|15=USD|59=0|10=000|";
+        assert!(decoder.decode(msg).unwrap().checksum_ok());
+    }
+
     #[test]
     fn edge_cases_dont_cause_panic() {
         let decoder = new_decoder();
@@ -352,6 +1070,60 @@ mod test {
         assert!(decoder.decode(b"|999=999999999999999999|=").is_err());
     }
 
+    #[test]
+    fn split_three_messages_and_a_half() {
+        let config = Config::default().with_separator(b'|');
+        let msg = b"8=FIX.4.2|9=40|35=D|49=AFUNDMGR|56=ABROKER|15=USD|59=0|10=091|";
+        let mut stream = Vec::new();
+        for _ in 0..3 {
+            stream.extend_from_slice(msg);
+        }
+        stream.extend_from_slice(&msg[..msg.len() / 2]);
+        let mut messages = split_messages(&stream[..], &config);
+        for _ in 0..3 {
+            assert_eq!(messages.next().unwrap().unwrap(), &msg[..]);
+        }
+        assert!(messages.next().is_none());
+        assert_eq!(messages.remainder_len(), msg.len() / 2);
+        assert_eq!(messages.remainder(), &msg[..msg.len() / 2]);
+    }
+
+    #[test]
+    fn split_stops_after_invalid_message() {
+        let config = Config::default().with_separator(b'|');
+        let stream = b"8=?|9=5|35=?|10=183|8=?|9=2|35=?|10=183|8=?|9=5|35=?|10=183|";
+        let mut messages = split_messages(&stream[..], &config);
+        assert!(messages.next().unwrap().is_ok());
+        assert_eq!(messages.next(), Some(Err(DecodeError::Invalid)));
+        assert!(messages.next().is_none());
+        assert_eq!(messages.remainder_len(), 40);
+    }
+
+    #[test]
+    fn split_resyncs_after_garbled_message_in_recovery_mode() {
+        let config = Config::default().with_separator(b'|');
+        let msg = b"8=FIX.4.2|9=40|35=D|49=AFUNDMGR|56=ABROKER|15=USD|59=0|10=091|";
+        let garbled = b"8=FIX.4.2|9=4000|35=D|49=AFUN\x00\xff";
+        let mut stream = Vec::new();
+        stream.extend_from_slice(msg);
+        stream.extend_from_slice(garbled);
+        stream.extend_from_slice(msg);
+        let mut messages = split_messages(&stream[..], &config).with_recovery(b"8=FIX");
+        assert_eq!(messages.next(), Some(Ok(&msg[..])));
+        assert_eq!(messages.next(), Some(Err(DecodeError::Invalid)));
+        assert_eq!(messages.skipped_len(), garbled.len());
+        assert_eq!(messages.next(), Some(Ok(&msg[..])));
+        assert!(messages.next().is_none());
+        assert_eq!(messages.remainder_len(), 0);
+    }
+
+    #[test]
+    fn split_empty_buffer() {
+        let mut messages = split_messages(b"", &Config::default());
+        assert!(messages.next().is_none());
+        assert_eq!(messages.remainder_len(), 0);
+    }
+
     fn new_decoder_buffered() -> RawDecoderBuffered {
         let config = Config::default().with_separator(b'|');
         RawDecoder::with_config(config).buffered()
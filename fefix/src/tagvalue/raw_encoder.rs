@@ -95,6 +95,36 @@ where
         self.body_start_i = self.buffer.len();
     }
 
+    /// Starts a new message with the precomputed fields of `template`, right
+    /// after `MsgType <35>` with `msg_type`. This replaces
+    /// [`RawEncoder::set_begin_string`] when most header fields are the same
+    /// across messages, e.g. within a session.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fefix::tagvalue::{Config, HeaderTemplate, RawEncoder};
+    ///
+    /// let template = HeaderTemplate::new(b"FIX.4.4", b'|')
+    ///     .with_field(49, b"A")
+    ///     .with_field(56, b"B");
+    /// let encoder = &mut RawEncoder::<_, Config>::from_buffer(Vec::new());
+    /// encoder.config_mut().set_separator(b'|');
+    /// encoder.start_with_template(&template, b"0");
+    /// encoder.extend_from_slice(b"34=12|52=20100304-07:59:30|");
+    /// let data = encoder.finalize();
+    /// assert_eq!(data, b"8=FIX.4.4|9=000042|35=0|49=A|56=B|34=12|52=20100304-07:59:30|10=216|");
+    /// ```
+    pub fn start_with_template(&mut self, template: &HeaderTemplate, msg_type: &[u8]) {
+        self.buffer.clear();
+        self.buffer.extend_from_slice(&template.prefix[..]);
+        self.body_start_i = self.buffer.len();
+        self.buffer.extend_from_slice(b"35=");
+        self.buffer.extend_from_slice(msg_type);
+        self.buffer.extend_from_slice(&[template.separator]);
+        self.buffer.extend_from_slice(&template.fields[..]);
+    }
+
     /// Adds `data` to the payload part of the FIX message.
     ///
     /// # Examples
@@ -133,12 +163,12 @@ where
         let body_length = self.body_length();
         let body_length_range = self.body_length_writable_range();
         let slice = &mut self.buffer.as_mut_slice()[body_length_range];
-        slice[0] = to_digit((body_length / 100000) as u8 % 10);
-        slice[1] = to_digit((body_length / 10000) as u8 % 10);
-        slice[2] = to_digit((body_length / 1000) as u8 % 10);
-        slice[3] = to_digit((body_length / 100) as u8 % 10);
-        slice[4] = to_digit((body_length / 10) as u8 % 10);
-        slice[5] = to_digit((body_length / 1) as u8 % 10);
+        slice[0] = to_digit(((body_length / 100000) % 10) as u8);
+        slice[1] = to_digit(((body_length / 10000) % 10) as u8);
+        slice[2] = to_digit(((body_length / 1000) % 10) as u8);
+        slice[3] = to_digit(((body_length / 100) % 10) as u8);
+        slice[4] = to_digit(((body_length / 10) % 10) as u8);
+        slice[5] = to_digit((body_length % 10) as u8);
     }
 
     fn write_checksum(&mut self) {
@@ -158,3 +188,87 @@ where
 fn to_digit(byte: u8) -> u8 {
     byte + b'0'
 }
+
+/// The constant leading fields of a series of messages, serialized once and
+/// then copied by [`RawEncoder::start_with_template`] at the start of each
+/// message: `BeginString <8>`, a placeholder for `BodyLength <9>` and any
+/// header fields that follow `MsgType <35>`, typically `SenderCompID <49>` and
+/// `TargetCompID <56>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeaderTemplate {
+    prefix: Vec<u8>,
+    fields: Vec<u8>,
+    separator: u8,
+}
+
+impl HeaderTemplate {
+    /// Creates a new [`HeaderTemplate`] with `begin_string` and no other
+    /// fields. `separator` must be the same as the encoder's.
+    pub fn new(begin_string: &[u8], separator: u8) -> Self {
+        let mut prefix = Vec::with_capacity(begin_string.len() + 12);
+        prefix.extend_from_slice(b"8=");
+        prefix.extend_from_slice(begin_string);
+        prefix.push(separator);
+        prefix.extend_from_slice(b"9=000000");
+        prefix.push(separator);
+        Self {
+            prefix,
+            fields: Vec::new(),
+            separator,
+        }
+    }
+
+    /// Appends the field `tag` with `value` to `self`. Fields are encoded in
+    /// the order they're added, right after `MsgType <35>`.
+    pub fn add_field(&mut self, tag: u32, value: &[u8]) {
+        self.fields.extend_from_slice(tag.to_string().as_bytes());
+        self.fields.push(b'=');
+        self.fields.extend_from_slice(value);
+        self.fields.push(self.separator);
+    }
+
+    pub fn with_field(mut self, tag: u32, value: &[u8]) -> Self {
+        self.add_field(tag, value);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn encoder() -> RawEncoder {
+        let mut encoder = RawEncoder::<_, Config>::from_buffer(Vec::new());
+        encoder.config_mut().set_separator(b'|');
+        encoder
+    }
+
+    #[test]
+    fn template_matches_full_encode() {
+        let template = HeaderTemplate::new(b"FIX.4.4", b'|')
+            .with_field(49, b"SENDER")
+            .with_field(56, b"TARGET");
+        let with_template = &mut encoder();
+        let from_scratch = &mut encoder();
+        // Long enough bodies exercise all digits of `BodyLength <9>`.
+        for (seq_num, text_len) in [(1, 0), (2, 300), (3, 3000)].iter() {
+            let body = format!("34={}|58={}|", seq_num, "x".repeat(*text_len));
+            with_template.start_with_template(&template, b"D");
+            with_template.extend_from_slice(body.as_bytes());
+            from_scratch.set_begin_string(b"FIX.4.4");
+            from_scratch.extend_from_slice(b"35=D|49=SENDER|56=TARGET|");
+            from_scratch.extend_from_slice(body.as_bytes());
+            let expected = from_scratch.finalize().to_vec();
+            assert_eq!(with_template.finalize(), &expected[..]);
+            utils::verify_checksum(&expected[..], b'|').unwrap();
+        }
+    }
+
+    #[test]
+    fn body_length_has_six_digits() {
+        let encoder = &mut encoder();
+        encoder.set_begin_string(b"FIX.4.4");
+        encoder.extend_from_slice("58=".repeat(1000).as_bytes());
+        assert!(encoder.finalize().starts_with(b"8=FIX.4.4|9=003000|"));
+    }
+}
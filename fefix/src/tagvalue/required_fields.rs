@@ -0,0 +1,155 @@
+use super::{scan_fields, Configure, DecodeError, RawDecoder};
+use crate::tags;
+
+/// Required `StandardHeader <header>` fields, besides `BeginString <8>` and
+/// `BodyLength <9>` which are always checked during framing.
+const REQUIRED_HEADER_FIELDS: &[u32] = &[
+    tags::MSG_TYPE,
+    tags::SENDER_COMP_ID,
+    tags::TARGET_COMP_ID,
+    tags::MSG_SEQ_NUM,
+    tags::SENDING_TIME,
+];
+
+// Generated from the FIX 4.4 dictionary: required fields of each message type,
+// including those of required components, in layout order.
+const REQUIRED_FIELDS_FIX44: &[(&[u8], &[u32])] = &[
+    // Heartbeat
+    (b"0", &[]),
+    // TestRequest
+    (b"1", &[112]),
+    // ResendRequest
+    (b"2", &[7, 16]),
+    // Reject
+    (b"3", &[45]),
+    // SequenceReset
+    (b"4", &[36]),
+    // Logout
+    (b"5", &[]),
+    // ExecutionReport
+    (b"8", &[37, 17, 150, 39, 54, 151, 14, 6]),
+    // OrderCancelReject
+    (b"9", &[37, 11, 41, 39, 434]),
+    // Logon
+    (b"A", &[98, 108]),
+    // NewOrderSingle
+    (b"D", &[11, 54, 60, 40]),
+    // OrderCancelRequest
+    (b"F", &[41, 11, 54, 60]),
+    // OrderCancelReplaceRequest
+    (b"G", &[41, 11, 54, 60, 40]),
+    // OrderStatusRequest
+    (b"H", &[11, 54]),
+    // MarketDataRequest
+    (b"V", &[262, 263, 264]),
+    // BusinessMessageReject
+    (b"j", &[372, 380]),
+];
+
+/// Returns the tags of all body fields required by the FIX 4.4 message type
+/// `msg_type`, or `None` if there's no data about `msg_type`.
+///
+/// Only the most common message types are covered for now.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::required_fields;
+///
+/// assert_eq!(required_fields(b"D"), Some(&[11, 54, 60, 40][..]));
+/// assert_eq!(required_fields(b"AE"), None);
+/// ```
+pub fn required_fields(msg_type: &[u8]) -> Option<&'static [u32]> {
+    REQUIRED_FIELDS_FIX44
+        .iter()
+        .find(|(t, _)| *t == msg_type)
+        .map(|(_, tags)| *tags)
+}
+
+/// Checks that `message` contains all required `StandardHeader <header>`
+/// fields, as well as all fields required by its `MsgType <35>` (see
+/// [`required_fields`]). Returns the tags of missing fields, which is empty
+/// if nothing is missing.
+///
+/// Repeating groups are not inspected. If `message` can't be framed or
+/// contains a malformed field, the [`DecodeError`] is returned instead.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::{verify_required_fields, Config};
+///
+/// let config = Config::default().with_separator(b'|');
+/// let msg = b"8=FIX.4.4|9=18|35=D|55=AAPL|54=1|10=240|";
+/// assert_eq!(
+///     verify_required_fields(msg, &config),
+///     Ok(vec![49, 56, 34, 52, 11, 60, 40])
+/// );
+/// ```
+pub fn verify_required_fields<C>(message: &[u8], config: &C) -> Result<Vec<u32>, DecodeError>
+where
+    C: Configure,
+{
+    let frame = RawDecoder::with_config(config.clone()).decode(message)?;
+    let mut tags = Vec::new();
+    let mut msg_type: &[u8] = &[];
+    for field in scan_fields(frame.payload(), config.separator()) {
+        let (tag, value) = field?;
+        if tag == tags::MSG_TYPE {
+            msg_type = value;
+        }
+        tags.push(tag);
+    }
+    Ok(missing_required_fields(msg_type, |tag| tags.contains(&tag)))
+}
+
+/// Returns the tags of all fields required by messages of type `msg_type`
+/// (header fields included) for which `is_present` returns `false`.
+pub(super) fn missing_required_fields<F>(msg_type: &[u8], is_present: F) -> Vec<u32>
+where
+    F: Fn(u32) -> bool,
+{
+    let body_fields = required_fields(msg_type).unwrap_or(&[]);
+    REQUIRED_HEADER_FIELDS
+        .iter()
+        .chain(body_fields.iter())
+        .copied()
+        .filter(|tag| !is_present(*tag))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tagvalue::Config;
+    use crate::test_vectors::fix44;
+
+    #[test]
+    fn canonical_messages_have_all_required_fields() {
+        let config = Config::default();
+        let messages = [
+            fix44::LOGON,
+            fix44::NEW_ORDER_SINGLE,
+            fix44::EXECUTION_REPORT,
+        ];
+        for msg in messages.iter() {
+            assert_eq!(verify_required_fields(msg, &config), Ok(vec![]));
+        }
+    }
+
+    #[test]
+    fn unknown_msg_type_only_checks_header() {
+        let config = Config::default().with_separator(b'|');
+        let msg = b"8=FIX.4.4|9=11|35=ZZ|49=A|10=044|";
+        assert_eq!(verify_required_fields(msg, &config), Ok(vec![56, 34, 52]));
+    }
+
+    #[test]
+    fn invalid_message_is_a_decode_error() {
+        let config = Config::default();
+        assert_eq!(
+            verify_required_fields(b"foobar", &config),
+            Err(DecodeError::Invalid)
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::tagvalue::EncodeError;
 use crate::Buffer;
 
 /// A trait for serializing data directly into a [`Buffer`].
@@ -23,6 +24,38 @@ impl SerializeField for bool {
     }
 }
 
+impl SerializeField for char {
+    fn serialize<B>(&self, buffer: &mut B) -> usize
+    where
+        B: Buffer,
+    {
+        let mut bytes = [0; 4];
+        let s = self.encode_utf8(&mut bytes);
+        buffer.extend_from_slice(s.as_bytes());
+        s.len()
+    }
+}
+
+impl SerializeField for String {
+    fn serialize<B>(&self, buffer: &mut B) -> usize
+    where
+        B: Buffer,
+    {
+        buffer.extend_from_slice(self.as_bytes());
+        self.len()
+    }
+}
+
+impl SerializeField for Vec<u8> {
+    fn serialize<B>(&self, buffer: &mut B) -> usize
+    where
+        B: Buffer,
+    {
+        buffer.extend_from_slice(self);
+        self.len()
+    }
+}
+
 impl SerializeField for &[u8] {
     fn serialize<B>(&self, buffer: &mut B) -> usize
     where
@@ -48,9 +81,7 @@ impl SerializeField for u32 {
     where
         B: Buffer,
     {
-        let s = self.to_string();
-        buffer.extend_from_slice(s.as_bytes());
-        s.len()
+        write_uint(buffer, u64::from(*self))
     }
 }
 
@@ -59,9 +90,7 @@ impl SerializeField for i32 {
     where
         B: Buffer,
     {
-        let s = self.to_string();
-        buffer.extend_from_slice(s.as_bytes());
-        s.len()
+        write_int(buffer, i64::from(*self))
     }
 }
 
@@ -70,9 +99,7 @@ impl SerializeField for u64 {
     where
         B: Buffer,
     {
-        let s = self.to_string();
-        buffer.extend_from_slice(s.as_bytes());
-        s.len()
+        write_uint(buffer, *self)
     }
 }
 
@@ -81,16 +108,262 @@ impl SerializeField for i64 {
     where
         B: Buffer,
     {
-        let s = self.to_string();
-        buffer.extend_from_slice(s.as_bytes());
-        s.len()
+        write_int(buffer, *self)
     }
 }
 
+/// Two ASCII digits for every number in `0..100`.
+const DIGIT_PAIRS: &[u8; 200] = b"00010203040506070809101112131415161718192021222324252627282930313233343536373839404142434445464748495051525354555657585960616263646566676869707172737475767778798081828384858687888990919293949596979899";
+
+/// Writes the decimal representation of `value` to `buffer`, without going
+/// through [`std::fmt`]. Returns the number of bytes written.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::write_uint;
+///
+/// let mut buffer = Vec::new();
+/// assert_eq!(write_uint(&mut buffer, 1337), 4);
+/// assert_eq!(&buffer[..], b"1337" as &[u8]);
+/// ```
+pub fn write_uint<B>(buffer: &mut B, mut value: u64) -> usize
+where
+    B: Buffer,
+{
+    // `u64::MAX` has 20 digits. They're written right to left, two at a time.
+    let mut digits = [0u8; 20];
+    let mut i = digits.len();
+    while value >= 100 {
+        let pair = (value % 100) as usize * 2;
+        value /= 100;
+        i -= 2;
+        digits[i..i + 2].copy_from_slice(&DIGIT_PAIRS[pair..pair + 2]);
+    }
+    if value >= 10 {
+        let pair = value as usize * 2;
+        i -= 2;
+        digits[i..i + 2].copy_from_slice(&DIGIT_PAIRS[pair..pair + 2]);
+    } else {
+        i -= 1;
+        digits[i] = b'0' + value as u8;
+    }
+    buffer.extend_from_slice(&digits[i..]);
+    digits.len() - i
+}
+
+/// Like [`write_uint`], but for signed integers. Negative values are prefixed
+/// with `-`.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::write_int;
+///
+/// let mut buffer = Vec::new();
+/// assert_eq!(write_int(&mut buffer, -42), 3);
+/// assert_eq!(&buffer[..], b"-42" as &[u8]);
+/// ```
+pub fn write_int<B>(buffer: &mut B, value: i64) -> usize
+where
+    B: Buffer,
+{
+    if value < 0 {
+        buffer.extend_from_slice(b"-");
+        1 + write_uint(buffer, value.unsigned_abs())
+    } else {
+        write_uint(buffer, value as u64)
+    }
+}
+
+/// Serializes a whole `tag=value` field, followed by `separator`. Returns the
+/// number of bytes written.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::encode_field;
+///
+/// let mut buffer = Vec::new();
+/// assert_eq!(encode_field(54, &1u32, &mut buffer, b'|'), 5);
+/// assert_eq!(&buffer[..], b"54=1|" as &[u8]);
+/// ```
+pub fn encode_field<B, T>(tag: u32, value: &T, buffer: &mut B, separator: u8) -> usize
+where
+    B: Buffer,
+    T: SerializeField + ?Sized,
+{
+    let tag_len = tag.serialize(buffer);
+    buffer.extend_from_slice(b"=");
+    let value_len = value.serialize(buffer);
+    buffer.extend_from_slice(&[separator]);
+    tag_len + value_len + 2
+}
+
+/// Serializes a `Price <44>`-like value with exactly `decimals` digits after
+/// the decimal point, rounding to the nearest representable value. Negative
+/// values are allowed, as they are for `Price` and `PriceOffset` fields.
+///
+/// Scientific notation is never used, no matter how large or small `value` is.
+/// Returns the number of bytes written, or [`EncodeError::InvalidValue`] if
+/// `value` is `NaN` or infinite, in which case nothing is written.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::{encode_price, EncodeError};
+///
+/// let mut buffer = Vec::new();
+/// assert_eq!(encode_price(1.5, 2, &mut buffer), Ok(4));
+/// assert_eq!(&buffer[..], b"1.50" as &[u8]);
+/// assert_eq!(encode_price(f64::NAN, 2, &mut buffer), Err(EncodeError::InvalidValue));
+/// ```
+pub fn encode_price<B>(value: f64, decimals: u8, buffer: &mut B) -> Result<usize, EncodeError>
+where
+    B: Buffer,
+{
+    encode_decimal(value, decimals, buffer)
+}
+
+/// Serializes a `Qty` value with exactly `decimals` digits after the decimal
+/// point. See [`encode_price`] for details.
+pub fn encode_qty<B>(value: f64, decimals: u8, buffer: &mut B) -> Result<usize, EncodeError>
+where
+    B: Buffer,
+{
+    encode_decimal(value, decimals, buffer)
+}
+
+/// Serializes an `Amt` value with exactly `decimals` digits after the decimal
+/// point. See [`encode_price`] for details.
+pub fn encode_amt<B>(value: f64, decimals: u8, buffer: &mut B) -> Result<usize, EncodeError>
+where
+    B: Buffer,
+{
+    encode_decimal(value, decimals, buffer)
+}
+
+/// Serializes a `Percentage` value, i.e. a fraction such as `0.05` for 5%
+/// (see [`parse_percentage`](super::parse_percentage)). Unlike
+/// [`encode_price`], the number of decimals isn't fixed: the shortest
+/// representation that parses back to `value` is used, and scientific
+/// notation is never used. Returns the number of bytes written, or
+/// [`EncodeError::InvalidValue`] if `value` is `NaN` or infinite.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::encode_percentage;
+///
+/// let mut buffer = Vec::new();
+/// assert_eq!(encode_percentage(0.9525, &mut buffer), Ok(6));
+/// assert_eq!(&buffer[..], b"0.9525" as &[u8]);
+/// ```
+pub fn encode_percentage<B>(value: f64, buffer: &mut B) -> Result<usize, EncodeError>
+where
+    B: Buffer,
+{
+    if !value.is_finite() {
+        return Err(EncodeError::InvalidValue);
+    }
+    let s = value.to_string();
+    buffer.extend_from_slice(s.as_bytes());
+    Ok(s.len())
+}
+
+fn encode_decimal<B>(value: f64, decimals: u8, buffer: &mut B) -> Result<usize, EncodeError>
+where
+    B: Buffer,
+{
+    if !value.is_finite() {
+        return Err(EncodeError::InvalidValue);
+    }
+    let s = format!("{:.*}", decimals as usize, value);
+    // Values that round to zero must not keep their sign, e.g. "-0.00".
+    let s = if s.starts_with('-') && s.bytes().all(|b| matches!(b, b'-' | b'0' | b'.')) {
+        &s[1..]
+    } else {
+        &s[..]
+    };
+    buffer.extend_from_slice(s.as_bytes());
+    Ok(s.len())
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn write_integers() {
+        let uints = [
+            0,
+            1,
+            9,
+            10,
+            99,
+            100,
+            101,
+            999,
+            1000,
+            12345,
+            u32::MAX as u64,
+            u64::MAX,
+        ];
+        for value in uints.iter().copied() {
+            let mut buffer = Vec::new();
+            assert_eq!(write_uint(&mut buffer, value), value.to_string().len());
+            assert_eq!(buffer, value.to_string().into_bytes());
+        }
+        let ints = [
+            0,
+            -1,
+            -10,
+            42,
+            -100,
+            7654321,
+            i64::MAX,
+            i64::MIN,
+            i64::MIN + 1,
+        ];
+        for value in ints.iter().copied() {
+            let mut buffer = Vec::new();
+            assert_eq!(write_int(&mut buffer, value), value.to_string().len());
+            assert_eq!(buffer, value.to_string().into_bytes());
+        }
+    }
+
+    #[test]
+    fn serialize_integers() {
+        let mut buffer = Vec::new();
+        assert_eq!(i32::MIN.serialize(&mut buffer), 11);
+        assert_eq!(0u32.serialize(&mut buffer), 1);
+        assert_eq!(u64::MAX.serialize(&mut buffer), 20);
+        assert_eq!(&buffer[..], b"-2147483648018446744073709551615" as &[u8]);
+    }
+
+    #[test]
+    fn percentage_round_trip() {
+        use crate::tagvalue::parse_percentage;
+
+        let value = parse_percentage(b"0.9525").unwrap();
+        assert_eq!(value, 0.9525);
+        let mut buffer = Vec::new();
+        assert_eq!(encode_percentage(value, &mut buffer), Ok(6));
+        assert_eq!(&buffer[..], b"0.9525" as &[u8]);
+        buffer.clear();
+        encode_percentage(parse_percentage(b"1.5").unwrap(), &mut buffer).unwrap();
+        assert_eq!(&buffer[..], b"1.5" as &[u8]);
+    }
+
+    #[test]
+    fn non_finite_percentage_is_rejected() {
+        let mut buffer = Vec::new();
+        let invalid = Err(EncodeError::InvalidValue);
+        assert_eq!(encode_percentage(f64::NAN, &mut buffer), invalid);
+        assert_eq!(encode_percentage(f64::INFINITY, &mut buffer), invalid);
+        assert!(buffer.is_empty());
+    }
+
     #[test]
     fn serialize_bools() {
         let mut buffer = Vec::new();
@@ -109,6 +382,14 @@ mod test {
         assert_eq!(&buffer[..], b"hello foo" as &[u8]);
     }
 
+    #[test]
+    fn serialize_chars_and_strings() {
+        let mut buffer = Vec::new();
+        assert_eq!('1'.serialize(&mut buffer), 1);
+        assert_eq!("AAPL".to_string().serialize(&mut buffer), 4);
+        assert_eq!(&buffer[..], b"1AAPL" as &[u8]);
+    }
+
     #[test]
     fn serialize_country() {
         let mut buffer = Vec::new();
@@ -122,4 +403,39 @@ mod test {
         assert_eq!(b"USD".serialize(&mut buffer), 3);
         assert_eq!(&buffer[..], b"USD" as &[u8]);
     }
+
+    #[test]
+    fn serialize_price_rounds_to_decimals() {
+        let mut buffer = Vec::new();
+        assert_eq!(encode_price(-0.005, 2, &mut buffer), Ok(5));
+        assert_eq!(&buffer[..], b"-0.01" as &[u8]);
+        buffer.clear();
+        encode_price(-0.004, 2, &mut buffer).unwrap();
+        assert_eq!(&buffer[..], b"0.00" as &[u8]);
+        buffer.clear();
+        encode_price(42.0, 0, &mut buffer).unwrap();
+        assert_eq!(&buffer[..], b"42" as &[u8]);
+    }
+
+    #[test]
+    fn serialize_large_qty_without_scientific_notation() {
+        let mut buffer = Vec::new();
+        encode_qty(1e15, 2, &mut buffer).unwrap();
+        assert_eq!(&buffer[..], b"1000000000000000.00" as &[u8]);
+        buffer.clear();
+        encode_amt(123456789012.5, 1, &mut buffer).unwrap();
+        assert_eq!(&buffer[..], b"123456789012.5" as &[u8]);
+    }
+
+    #[test]
+    fn non_finite_decimals_are_rejected() {
+        let mut buffer = Vec::new();
+        let invalid = Err(EncodeError::InvalidValue);
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY].iter() {
+            assert_eq!(encode_price(*value, 2, &mut buffer), invalid);
+            assert_eq!(encode_qty(*value, 0, &mut buffer), invalid);
+            assert_eq!(encode_amt(*value, 1, &mut buffer), invalid);
+        }
+        assert!(buffer.is_empty());
+    }
 }
@@ -0,0 +1,148 @@
+use super::{parse_value, scan_fields, BaseValue, Configure, DecodeError, RawDecoder};
+use crate::DataType;
+
+/// A fixed list of fields with known [`DataType`]s, for consumers that only
+/// ever need a handful of fields from a single message type.
+///
+/// Decoding with a [`StaticSchema`] skips [`TagLookup`](super::TagLookup) and
+/// the [`Dictionary`](crate::Dictionary) entirely: field values are parsed
+/// straight into [`BaseValue`]s and all other fields are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::{BaseValue, Config, StaticSchema};
+/// use fefix::DataType;
+///
+/// let schema = StaticSchema::new(&[(55, DataType::String), (54, DataType::Char)]);
+/// let config = Config::default().with_separator(b'|');
+/// let msg = b"8=FIX.4.4|9=18|35=D|55=AAPL|54=1|10=240|";
+/// let values = schema.decode(msg, &config).unwrap();
+/// assert_eq!(values[0], Some(BaseValue::Str(b"AAPL")));
+/// assert_eq!(values[1], Some(BaseValue::Char(b'1')));
+/// ```
+#[derive(Debug, Clone)]
+pub struct StaticSchema {
+    fields: Vec<(u32, DataType)>,
+    // (tag, index into `fields`), sorted by tag.
+    index: Vec<(u32, usize)>,
+}
+
+impl StaticSchema {
+    /// Compiles a [`StaticSchema`] from a list of `(tag, data type)` pairs.
+    /// Decoded values will be in the same order as `fields`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `fields` contains the same tag more than once.
+    pub fn new(fields: &[(u32, DataType)]) -> Self {
+        let mut index: Vec<(u32, usize)> = fields
+            .iter()
+            .enumerate()
+            .map(|(i, (tag, _))| (*tag, i))
+            .collect();
+        index.sort_unstable();
+        assert!(
+            index.windows(2).all(|w| w[0].0 != w[1].0),
+            "Duplicate tags in StaticSchema."
+        );
+        Self {
+            fields: fields.to_vec(),
+            index,
+        }
+    }
+
+    /// Returns the number of fields in `self`.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Returns `true` if `self` contains no fields.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Returns the position of `tag` within `self`, if present.
+    pub fn position(&self, tag: u32) -> Option<usize> {
+        self.index
+            .binary_search_by_key(&tag, |(t, _)| *t)
+            .ok()
+            .map(|i| self.index[i].1)
+    }
+
+    /// Decodes `message` and extracts the values of all fields in `self`, in
+    /// the same order as they were given to [`StaticSchema::new`]. Missing
+    /// fields are `None`.
+    pub fn decode<'a, C>(
+        &self,
+        message: &'a [u8],
+        config: &C,
+    ) -> Result<Vec<Option<BaseValue<'a>>>, DecodeError>
+    where
+        C: Configure,
+    {
+        let frame = RawDecoder::with_config(config.clone()).decode(message)?;
+        let mut values = vec![None; self.fields.len()];
+        for field in scan_fields(frame.payload(), config.separator()) {
+            let (tag, value) = field?;
+            if let Some(i) = self.position(tag) {
+                if values[i].is_some() {
+                    return Err(DecodeError::DuplicateTag(tag));
+                }
+                let data_type = self.fields[i].1;
+                values[i] = Some(
+                    parse_value(data_type, value).map_err(|_| DecodeError::InvalidValue(tag))?,
+                );
+            }
+        }
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tagvalue::Config;
+    use crate::DtfDecimal;
+
+    fn config() -> Config {
+        Config::default().with_separator(b'|')
+    }
+
+    #[test]
+    fn values_follow_schema_order() {
+        let schema = StaticSchema::new(&[
+            (44, DataType::Price),
+            (38, DataType::Qty),
+            (11, DataType::String),
+            (58, DataType::String),
+        ]);
+        let msg = b"8=FIX.4.4|9=42|35=D|11=ord-1|55=AAPL|54=1|38=100|44=1.50|10=069|";
+        let values = schema.decode(msg, &config()).unwrap();
+        assert_eq!(
+            values,
+            vec![
+                Some(BaseValue::Float(DtfDecimal::parse(b"1.50").unwrap())),
+                Some(BaseValue::Float(DtfDecimal::parse(b"100").unwrap())),
+                Some(BaseValue::Str(b"ord-1")),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_value_in_schema() {
+        let schema = StaticSchema::new(&[(54, DataType::Int)]);
+        let msg = b"8=FIX.4.4|9=18|35=D|55=AAPL|54=X|10=023|";
+        assert_eq!(
+            schema.decode(msg, &config()),
+            Err(DecodeError::InvalidValue(54))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn duplicate_tags_in_schema() {
+        StaticSchema::new(&[(54, DataType::Int), (54, DataType::Char)]);
+    }
+}
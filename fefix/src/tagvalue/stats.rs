@@ -0,0 +1,109 @@
+use crate::tagvalue::{BodyLengthPolicy, Configure, DecodeError, RawFrame};
+
+/// Plain counters about the activity of a [`Decoder`](super::Decoder), meant
+/// to be exported to a metrics system (e.g. Prometheus). Updating them costs a
+/// few integer increments per message, without any locking.
+///
+/// Only available with the `metrics` feature.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::{Config, Decoder};
+/// use fefix::{AppVersion, Dictionary};
+///
+/// let dict = Dictionary::from_version(AppVersion::Fix44);
+/// let decoder = &mut Decoder::with_config(dict, Config::default().with_separator(b'|'));
+/// decoder.decode(b"8=FIX.4.4|9=5|35=0|10=000|").ok();
+/// decoder.decode(b"8=FIX.4.4|9=14|35=0|9999=foo|10=037|").ok();
+///
+/// let stats = decoder.stats();
+/// assert_eq!(stats.messages_decoded(), 1);
+/// assert_eq!(stats.decode_errors(), 1);
+/// assert_eq!(stats.checksum_failures(), 1);
+/// assert_eq!(stats.unknown_tags(), 1);
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct DecoderStats {
+    messages_decoded: u64,
+    decode_errors: u64,
+    checksum_failures: u64,
+    body_length_failures: u64,
+    unknown_tags: u64,
+}
+
+impl DecoderStats {
+    /// Returns the number of messages that were decoded without errors.
+    pub fn messages_decoded(&self) -> u64 {
+        self.messages_decoded
+    }
+
+    /// Returns the number of messages that couldn't be decoded, or that were
+    /// decoded with errors by
+    /// [`Decoder::decode_collecting_errors`](super::Decoder::decode_collecting_errors).
+    pub fn decode_errors(&self) -> u64 {
+        self.decode_errors
+    }
+
+    /// Returns the number of messages with an invalid `CheckSum <10>`,
+    /// regardless of [`ChecksumPolicy`](super::ChecksumPolicy).
+    pub fn checksum_failures(&self) -> u64 {
+        self.checksum_failures
+    }
+
+    /// Returns the number of messages with an invalid `BodyLength <9>`. Only
+    /// messages verified according to [`BodyLengthPolicy`] are counted.
+    pub fn body_length_failures(&self) -> u64 {
+        self.body_length_failures
+    }
+
+    /// Returns the number of fields with a tag that is neither in the
+    /// dictionary nor in [`Configure::tag_type`].
+    pub fn unknown_tags(&self) -> u64 {
+        self.unknown_tags
+    }
+
+    /// Sets all counters back to zero.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub(crate) fn record_frame<C>(
+        &mut self,
+        frame: Result<&RawFrame, &DecodeError>,
+        data: &[u8],
+        config: &C,
+    ) where
+        C: Configure,
+    {
+        match frame {
+            Ok(frame) => {
+                self.checksum_failures += !frame.checksum_ok() as u64;
+                self.body_length_failures += !frame.body_length_ok() as u64;
+            }
+            Err(DecodeError::CheckSum) | Err(DecodeError::CheckSumFormat) => {
+                self.checksum_failures += 1
+            }
+            // `BodyLength <9>` mismatches are reported as generic errors.
+            Err(DecodeError::Invalid)
+                if config.body_length_policy() == BodyLengthPolicy::Enforce
+                    && super::raw_decoder::body_length_is_wrong(data, config.separator()) =>
+            {
+                self.body_length_failures += 1
+            }
+            Err(_) => {}
+        }
+    }
+
+    pub(crate) fn record_unknown_tag(&mut self) {
+        self.unknown_tags += 1;
+    }
+
+    pub(crate) fn record_outcome(&mut self, ok: bool) {
+        if ok {
+            self.messages_decoded += 1;
+        } else {
+            self.decode_errors += 1;
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use super::DecodeError;
+use std::borrow::Cow;
+
+const SOH: u8 = 0x1;
+
+/// How separators and other bytes are escaped in a log format. See
+/// [`unescape`] and [`scan_fields_unescaped`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum UnescapePolicy {
+    /// `\xHH` sequences stand for the byte with hexadecimal value `HH`, e.g.
+    /// `\x01` for SOH.
+    HexEscapes,
+    /// `|` stands for SOH.
+    PipeAsSoh,
+}
+
+impl UnescapePolicy {
+    /// Returns the length of the (escaped or not) separator at the start of
+    /// `data`, if any.
+    fn separator_len(self, data: &[u8]) -> Option<usize> {
+        match (self, data) {
+            (_, [SOH, ..]) => Some(1),
+            (Self::HexEscapes, [b'\\', b'x', b'0', b'1', ..]) => Some(4),
+            (Self::PipeAsSoh, [b'|', ..]) => Some(1),
+            _ => None,
+        }
+    }
+
+    fn unescape_value(self, data: &[u8]) -> Cow<'_, [u8]> {
+        match self {
+            Self::HexEscapes => unescape_hex(data),
+            // Values can't contain separators, so there's nothing to do.
+            Self::PipeAsSoh => Cow::Borrowed(data),
+        }
+    }
+}
+
+/// Converts an escaped log line into a plain tag-value message with SOH
+/// separators, which can then go through the normal decoding pipeline.
+/// `data` is returned as-is if it doesn't contain any escapes.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::{unescape, Config, RawDecoder, UnescapePolicy};
+///
+/// let line = br"8=FIX.4.4\x019=5\x0135=0\x0110=163\x01";
+/// let message = unescape(line, UnescapePolicy::HexEscapes);
+/// let decoder = RawDecoder::with_config(Config::default());
+/// assert_eq!(decoder.decode(&message).unwrap().payload(), b"35=0\x01");
+/// ```
+pub fn unescape(data: &[u8], policy: UnescapePolicy) -> Cow<'_, [u8]> {
+    match policy {
+        UnescapePolicy::HexEscapes => unescape_hex(data),
+        UnescapePolicy::PipeAsSoh if data.contains(&b'|') => Cow::Owned(
+            data.iter()
+                .map(|byte| if *byte == b'|' { SOH } else { *byte })
+                .collect(),
+        ),
+        UnescapePolicy::PipeAsSoh => Cow::Borrowed(data),
+    }
+}
+
+/// Returns an [`Iterator`] over the `(tag, value)` pairs of the escaped log
+/// line `data`. Values are only copied if they contain escapes, otherwise they
+/// are borrowed from `data`.
+///
+/// Unlike [`scan_fields`](super::scan_fields), `data` must contain a whole
+/// message or field list, i.e. the last field must be terminated by a
+/// separator. Iteration stops after the first error.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::{scan_fields_unescaped, UnescapePolicy};
+/// use std::borrow::Cow;
+///
+/// let line = br"35=0\x0158=Hi\x21\x01";
+/// let fields: Vec<_> = scan_fields_unescaped(line, UnescapePolicy::HexEscapes)
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(fields[0], (35, Cow::Borrowed(&b"0"[..])));
+/// assert_eq!(fields[1], (58, Cow::Owned(b"Hi!".to_vec())));
+/// ```
+pub fn scan_fields_unescaped(data: &[u8], policy: UnescapePolicy) -> UnescapedFields<'_> {
+    UnescapedFields {
+        data,
+        policy,
+        i: 0,
+        failed: false,
+    }
+}
+
+/// An [`Iterator`] over unescaped fields. See [`scan_fields_unescaped`].
+#[derive(Debug, Clone)]
+pub struct UnescapedFields<'a> {
+    data: &'a [u8],
+    policy: UnescapePolicy,
+    i: usize,
+    failed: bool,
+}
+
+impl<'a> UnescapedFields<'a> {
+    fn next_field(&mut self) -> Result<(u32, Cow<'a, [u8]>), DecodeError> {
+        let data = &self.data[self.i..];
+        let i_equal_sign = data
+            .iter()
+            .position(|byte| *byte == b'=')
+            .ok_or(DecodeError::Invalid)?;
+        let tag = parse_tag(&data[..i_equal_sign]).ok_or(DecodeError::Invalid)?;
+        let (end_of_value, separator_len) = (i_equal_sign + 1..data.len())
+            .find_map(|i| self.policy.separator_len(&data[i..]).map(|len| (i, len)))
+            .ok_or(DecodeError::Invalid)?;
+        self.i += end_of_value + separator_len;
+        let value = &data[i_equal_sign + 1..end_of_value];
+        Ok((tag, self.policy.unescape_value(value)))
+    }
+}
+
+impl<'a> Iterator for UnescapedFields<'a> {
+    type Item = Result<(u32, Cow<'a, [u8]>), DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.i >= self.data.len() {
+            return None;
+        }
+        let field = self.next_field();
+        self.failed = field.is_err();
+        Some(field)
+    }
+}
+
+fn parse_tag(digits: &[u8]) -> Option<u32> {
+    if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    std::str::from_utf8(digits).ok()?.parse().ok()
+}
+
+fn unescape_hex(data: &[u8]) -> Cow<'_, [u8]> {
+    if !data.windows(2).any(|w| w == b"\\x") {
+        return Cow::Borrowed(data);
+    }
+    let mut unescaped = Vec::with_capacity(data.len());
+    let mut i = 0;
+    while i < data.len() {
+        match hex_escape(&data[i..]) {
+            Some(byte) => {
+                unescaped.push(byte);
+                i += 4;
+            }
+            None => {
+                unescaped.push(data[i]);
+                i += 1;
+            }
+        }
+    }
+    Cow::Owned(unescaped)
+}
+
+/// Decodes the `\xHH` escape sequence at the start of `data`, if any.
+fn hex_escape(data: &[u8]) -> Option<u8> {
+    match data {
+        [b'\\', b'x', high, low, ..] => {
+            let digits = [*high, *low];
+            u8::from_str_radix(std::str::from_utf8(&digits).ok()?, 16).ok()
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn pipes_become_soh() {
+        let line = b"8=FIX.4.4|9=5|35=0|10=163|";
+        assert_eq!(
+            unescape(line, UnescapePolicy::PipeAsSoh),
+            Cow::Owned::<[u8]>(b"8=FIX.4.4\x019=5\x0135=0\x0110=163\x01".to_vec())
+        );
+        let fields: Vec<_> = scan_fields_unescaped(line, UnescapePolicy::PipeAsSoh)
+            .map(|field| field.unwrap().0)
+            .collect();
+        assert_eq!(fields, vec![8, 9, 35, 10]);
+    }
+
+    #[test]
+    fn unescaped_data_is_borrowed() {
+        let data = b"8=FIX.4.4\x019=5\x01";
+        assert!(matches!(
+            unescape(data, UnescapePolicy::HexEscapes),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(
+            unescape(data, UnescapePolicy::PipeAsSoh),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn invalid_escapes_are_kept_verbatim() {
+        assert_eq!(
+            &unescape(br"a\xZZb\x4", UnescapePolicy::HexEscapes)[..],
+            br"a\xZZb\x4"
+        );
+    }
+
+    #[test]
+    fn missing_final_separator() {
+        let mut fields = scan_fields_unescaped(br"35=0\x0158=foo", UnescapePolicy::HexEscapes);
+        assert!(fields.next().unwrap().is_ok());
+        assert_eq!(fields.next(), Some(Err(DecodeError::Invalid)));
+        assert!(fields.next().is_none());
+    }
+}
@@ -1,5 +1,5 @@
 use crate::buffer::Buffer;
-use crate::tagvalue::{DecodeError, EncodeError};
+use crate::tagvalue::{Configure, DecodeError, EncodeError};
 use std::convert::TryInto;
 
 // A tag-value message can't possibly be shorter than this.
@@ -14,16 +14,7 @@ pub const MIN_FIX_MESSAGE_LEN_IN_BYTES: usize = 20;
 /// Total: 7 characters.
 pub const FIELD_CHECKSUM_LEN_IN_BYTES: usize = 7;
 
-/// Parses an `u8` from `digits` and returns the result.
-///
-/// No error detection is performed. Values less than 100 must be zero-padded.
-pub fn parse_u8_from_decimal(digits: [u8; 3]) -> u8 {
-    digits[0]
-        .wrapping_sub(b'0')
-        .wrapping_mul(100)
-        .wrapping_add(digits[1].wrapping_sub(b'0').wrapping_mul(10))
-        .wrapping_add(digits[2].wrapping_sub(b'0'))
-}
+const SOH: u8 = 0x1;
 
 /// Returns the `CheckSum <10>` value of `data`.
 ///
@@ -36,42 +27,230 @@ pub fn parse_u8_from_decimal(digits: [u8; 3]) -> u8 {
 /// assert_eq!(checksum_10(b"hunter2"), 0xc8);
 /// ```
 pub fn checksum_10(data: &[u8]) -> u8 {
+    let mut checksum = Checksum10::new();
+    checksum.update(data);
+    checksum.value()
+}
+
+/// An accumulator for `CheckSum <10>` values, for messages that are not stored
+/// in a single contiguous buffer (e.g. header and body in separate
+/// [`Buffer`]s). Feeding each slice in order gives the same result as
+/// [`checksum_10`] over their concatenation.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::{checksum_10, Checksum10};
+///
+/// let mut checksum = Checksum10::new();
+/// checksum.update(b"8=FIX.4.4|9=5|");
+/// checksum.update(b"35=0|");
+/// assert_eq!(checksum.value(), checksum_10(b"8=FIX.4.4|9=5|35=0|"));
+/// assert_eq!(&checksum.digits(), b"020");
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Checksum10 {
+    value: u8,
+}
+
+impl Checksum10 {
+    /// Creates a new [`Checksum10`] for an empty message.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the bytes in `data` to `self`.
+    pub fn update(&mut self, data: &[u8]) {
+        for byte in data {
+            self.value = self.value.wrapping_add(*byte);
+        }
+    }
+
+    /// Returns the `CheckSum <10>` value of all data fed to `self` so far.
+    pub fn value(&self) -> u8 {
+        self.value
+    }
+
+    /// Returns the zero-padded decimal digits of [`Checksum10::value`], as
+    /// they appear in `CheckSum <10>`.
+    pub fn digits(&self) -> [u8; 3] {
+        [
+            (self.value / 100) + b'0',
+            ((self.value / 10) % 10) + b'0',
+            (self.value % 10) + b'0',
+        ]
+    }
+}
+
+/// Returns the zero-padded `CheckSum <10>` digits of `data`, i.e. the value
+/// of `10=` for a message that starts with `data` and ends right before its
+/// trailer.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::checksum_field;
+///
+/// assert_eq!(&checksum_field(b"8=FIX.4.4|9=5|35=0|"), b"020");
+/// ```
+pub fn checksum_field(data_before_trailer: &[u8]) -> [u8; 3] {
+    let mut checksum = Checksum10::new();
+    checksum.update(data_before_trailer);
+    checksum.digits()
+}
+
+/// Returns the `CheckSum <10>` value of `data` as if all occurrences of
+/// `separator` were SOH (`0x1`), i.e. the checksum of the canonical form of a
+/// message that has been logged with a different separator.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::{checksum_10, checksum_10_as_soh};
+///
+/// assert_eq!(checksum_10_as_soh(b"35=0|", b'|'), checksum_10(b"35=0\x01"));
+/// ```
+pub fn checksum_10_as_soh(data: &[u8], separator: u8) -> u8 {
     let mut value = 0u8;
     for byte in data {
-        value = value.wrapping_add(*byte);
+        let byte = if *byte == separator { SOH } else { *byte };
+        value = value.wrapping_add(byte);
     }
     value
 }
 
-/// Returns a copy of the `CheckSum <10>` digits of `message`.
+/// Returns a copy of the first three bytes of the `CheckSum <10>` value of
+/// `message`, as located by [`start_of_checksum_field`]. The separator is
+/// inferred with [`inferred_separator`].
+#[allow(dead_code)]
 pub fn checksum_digits(message: &[u8]) -> [u8; 3] {
     debug_assert!(message.len() >= MIN_FIX_MESSAGE_LEN_IN_BYTES);
-    message[message.len() - 4..message.len() - 1]
-        .try_into()
-        .unwrap()
+    let separator = inferred_separator(message).unwrap_or(SOH);
+    let start = start_of_checksum_field(message, separator).unwrap_or(message.len() - 7);
+    message[start + 3..start + 6].try_into().unwrap()
+}
+
+/// Returns the index of `CheckSum <10>` in `message` (see
+/// [`start_of_checksum_field`]) together with its value, which ends at the
+/// next separator, if any.
+fn checksum_value(message: &[u8], separator: u8) -> Option<(usize, &[u8])> {
+    let start = start_of_checksum_field(message, separator)?;
+    let value = message[start + 3..]
+        .split(|byte| *byte == separator)
+        .next()
+        .unwrap_or(&[]);
+    Some((start, value))
+}
+
+/// Returns the separator of `message`, i.e. its last byte. If the final
+/// separator is missing, the byte right before the last `10=` is used
+/// instead.
+fn inferred_separator(message: &[u8]) -> Option<u8> {
+    match message.last() {
+        Some(byte) if !byte.is_ascii_digit() => Some(*byte),
+        _ => message
+            .windows(4)
+            .rposition(|window| &window[1..] == b"10=")
+            .map(|i| message[i]),
+    }
+}
+
+/// Checks that the last field of `message` is `CheckSum <10>` and that its
+/// value is exactly three ASCII digits, e.g. `10=005` but not `10=5` nor
+/// `10=0005`. The field is located with [`start_of_checksum_field`], so no
+/// assumptions are made about its length. The final separator may be missing.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::{verify_checksum_format, DecodeError};
+///
+/// assert_eq!(verify_checksum_format(b"8=FIX.4.4|9=5|35=0|10=005|", b'|'), Ok(()));
+/// assert_eq!(
+///     verify_checksum_format(b"8=FIX.4.4|9=5|35=0|10=5|", b'|'),
+///     Err(DecodeError::CheckSumFormat)
+/// );
+/// ```
+pub fn verify_checksum_format(message: &[u8], separator: u8) -> Result<(), DecodeError> {
+    let (start, digits) = checksum_value(message, separator).ok_or(DecodeError::Invalid)?;
+    let end = message.len() - usize::from(message.last() == Some(&separator));
+    // `CheckSum <10>` must be the last field.
+    if start + 3 + digits.len() != end {
+        Err(DecodeError::Invalid)
+    } else if digits.len() == 3 && digits.iter().all(u8::is_ascii_digit) {
+        Ok(())
+    } else {
+        Err(DecodeError::CheckSumFormat)
+    }
+}
+
+/// Checks that the value of `CheckSum <10>`, wherever it is (see
+/// [`start_of_checksum_field`]), matches the contents of `message` before it.
+/// Its value may have any number of digits (see [`verify_checksum_format`]
+/// for stricter checks) and the final separator may be missing.
+pub fn verify_checksum(message: &[u8], separator: u8) -> Result<(), DecodeError> {
+    verify_checksum_with(message, separator, checksum_10)
+}
+
+/// Like [`verify_checksum`], but the checksum is computed with
+/// [`checksum_10_as_soh`].
+pub fn verify_checksum_as_soh(message: &[u8], separator: u8) -> Result<(), DecodeError> {
+    verify_checksum_with(message, separator, |data| {
+        checksum_10_as_soh(data, separator)
+    })
 }
 
-pub fn verify_checksum(message: &[u8]) -> Result<(), DecodeError> {
-    let nominal_checksum = parse_u8_from_decimal(checksum_digits(message));
-    let actual_checksum = checksum_10(&message[..message.len() - FIELD_CHECKSUM_LEN_IN_BYTES]);
-    if nominal_checksum != actual_checksum {
+fn verify_checksum_with<F>(message: &[u8], separator: u8, checksum: F) -> Result<(), DecodeError>
+where
+    F: Fn(&[u8]) -> u8,
+{
+    let (start, value) = checksum_value(message, separator).ok_or(DecodeError::Invalid)?;
+    let nominal_checksum = if !value.is_empty() && value.iter().all(u8::is_ascii_digit) {
+        std::str::from_utf8(value)
+            .ok()
+            .and_then(|s| s.parse::<u8>().ok())
+    } else {
+        None
+    };
+    if nominal_checksum != Some(checksum(&message[..start])) {
         Err(DecodeError::CheckSum)
     } else {
         Ok(())
     }
 }
 
-/// Verifies the `BodyLength(9)` field of the FIX message in `data`.
+/// Returns the index of the `CheckSum <10>` field in `data`, i.e. of the `1`
+/// in the last `10=` that immediately follows `separator`. Whatever comes
+/// after it is considered part of the trailer, so that its length doesn't
+/// matter.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::start_of_checksum_field;
+///
+/// let message = b"8=FIX.4.4|9=5|35=0|10=005|";
+/// assert_eq!(start_of_checksum_field(message, b'|'), Some(19));
+/// assert_eq!(start_of_checksum_field(b"8=FIX.4.4|9=5|35=0|", b'|'), None);
+/// ```
+pub fn start_of_checksum_field(data: &[u8], separator: u8) -> Option<usize> {
+    data.windows(4)
+        .rposition(|window| window[0] == separator && &window[1..] == b"10=")
+        .map(|i| i + 1)
+}
+
+/// Verifies the `BodyLength(9)` field of the FIX message in `data`. The body
+/// ends right before `CheckSum <10>`, wherever that is (see
+/// [`start_of_checksum_field`]), so e.g. trailing bytes after the checksum
+/// field don't affect the result.
 pub fn verify_body_length(
     data: &[u8],
+    separator: u8,
     start_of_body: usize,
     nominal_body_length: usize,
 ) -> Result<(), DecodeError> {
-    let body_length = data
-        .len()
-        .wrapping_sub(FIELD_CHECKSUM_LEN_IN_BYTES)
-        .wrapping_sub(start_of_body);
-    let end_of_body = data.len() - FIELD_CHECKSUM_LEN_IN_BYTES;
+    let end_of_body = start_of_checksum_field(data, separator).ok_or(DecodeError::Invalid)?;
+    let body_length = end_of_body.wrapping_sub(start_of_body);
     if start_of_body > end_of_body || nominal_body_length != body_length {
         dbglog!(
             "BodyLength mismatch: expected {} but is {}.",
@@ -85,6 +264,16 @@ pub fn verify_body_length(
     }
 }
 
+/// Encodes a whole message into `buffer`: `BeginString <8>` with
+/// `begin_string`, `BodyLength <9>`, whatever `body_writer` writes (it must
+/// return the number of bytes it wrote), and `CheckSum <10>`. Returns the
+/// length of `buffer` afterwards.
+///
+/// # Errors
+///
+/// This function fails with [`EncodeError::BufferFull`] if `buffer` runs out
+/// of storage, which is only possible with fixed-capacity [`Buffer`]s such as
+/// [`ArrayBuffer`](crate::ArrayBuffer).
 pub fn encode_raw<B, F>(
     begin_string: &[u8],
     body_writer: F,
@@ -95,15 +284,20 @@ where
     B: Buffer,
     F: Fn(&mut B) -> usize,
 {
-    let start_i = buffer.as_slice().len();
+    let start = buffer.mark();
     // First, write `BeginString(8)`.
-    buffer.extend_from_slice(b"8=");
-    buffer.extend_from_slice(begin_string);
-    buffer.extend_from_slice(&[
+    buffer.try_extend_from_slice(b"8=")?;
+    buffer.try_extend_from_slice(begin_string)?;
+    buffer.try_extend_from_slice(&[
         separator, b'9', b'=', b'0', b'0', b'0', b'0', b'0', b'0', separator,
-    ]);
+    ])?;
     let body_length_writable_range = buffer.as_slice().len() - 7..buffer.as_slice().len() - 1;
     let body_length = body_writer(buffer);
+    // `body_writer` can't report errors, so fixed-capacity buffers must tell
+    // us whether they've discarded anything.
+    if buffer.overflowed() {
+        return Err(EncodeError::BufferFull);
+    }
     {
         let slice = &mut buffer.as_mut_slice()[body_length_writable_range];
         // The second field is supposed to be `BodyLength(9)`, but obviously
@@ -129,23 +323,256 @@ where
         slice[5] = (body_length % 10) as u8 + b'0';
     }
     {
-        let checksum = checksum_10(&buffer.as_slice()[start_i..]);
-        buffer.extend_from_slice(&[
-            b'1',
-            b'0',
-            b'=',
-            (checksum / 100) + b'0',
-            ((checksum / 10) % 10) + b'0',
-            (checksum % 10) + b'0',
-            separator,
-        ]);
+        let mut checksum = Checksum10::new();
+        checksum.update(buffer.slice_from(start));
+        let digits = checksum.digits();
+        buffer.try_extend_from_slice(&[
+            b'1', b'0', b'=', digits[0], digits[1], digits[2], separator,
+        ])?;
     }
     Ok(buffer.as_slice().len())
 }
 
+/// Returns the number of bytes that [`encode_raw`] writes for a message with
+/// `begin_string` and a body of `body_len` bytes, so that buffers can be
+/// allocated upfront. `BodyLength <9>` always takes six zero-padded digits.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::{encode_raw, encoded_len};
+///
+/// let mut buffer = Vec::with_capacity(encoded_len(b"FIX.4.4", 5));
+/// encode_raw(b"FIX.4.4", |buffer: &mut Vec<u8>| {
+///     buffer.extend_from_slice(b"35=0|");
+///     5
+/// }, &mut buffer, b'|').unwrap();
+/// assert_eq!(&buffer[..], b"8=FIX.4.4|9=000005|35=0|10=004|" as &[u8]);
+/// assert_eq!(buffer.len(), encoded_len(b"FIX.4.4", 5));
+/// ```
+pub fn encoded_len(begin_string: &[u8], body_len: usize) -> usize {
+    // "8=" + `begin_string` + separator + "9=000000" + separator.
+    let header_len = 2 + begin_string.len() + 1 + 8 + 1;
+    header_len + body_len + FIELD_CHECKSUM_LEN_IN_BYTES
+}
+
+/// Returns the `BodyLength <9>` value that [`encode_raw`] would write for the
+/// message in `message`, regardless of its actual `BodyLength <9>` value. The
+/// separator is inferred from the last byte of `message` (or from the byte
+/// before `10=`, if the final separator is missing) and the body ends at
+/// [`start_of_checksum_field`].
+///
+/// This is useful to tell by how much a counterparty's `BodyLength <9>` is
+/// off. It takes a whole message, rather than `BeginString <8>` and the body
+/// apart, because `BodyLength <9>` doesn't depend on the former and is simply
+/// the length of the latter.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::expected_body_length;
+///
+/// let message = b"8=FIX.4.2|9=1|35=0|49=A|10=165|";
+/// assert_eq!(expected_body_length(message), Ok(10));
+/// ```
+pub fn expected_body_length(message: &[u8]) -> Result<usize, DecodeError> {
+    TrailerLayout::new(message).map(|layout| layout.body_length())
+}
+
+/// Positions of `BodyLength <9>` and `CheckSum <10>` within a message.
+struct TrailerLayout {
+    separator: u8,
+    /// Index of the `9` in `9=`.
+    i_body_length: usize,
+    /// Index of the separator after `BodyLength <9>`.
+    end_body_length: usize,
+    /// Index of the `1` in `10=`.
+    start_checksum: usize,
+    /// Index right after the value of `CheckSum <10>`.
+    end_checksum: usize,
+}
+
+impl TrailerLayout {
+    fn new(message: &[u8]) -> Result<Self, DecodeError> {
+        if message.len() < MIN_FIX_MESSAGE_LEN_IN_BYTES {
+            return Err(DecodeError::Invalid);
+        }
+        let separator = inferred_separator(message).ok_or(DecodeError::Invalid)?;
+        let mut separators = message
+            .iter()
+            .enumerate()
+            .filter(|(_, byte)| **byte == separator)
+            .map(|(i, _)| i);
+        let i_body_length = separators.next().ok_or(DecodeError::Invalid)? + 1;
+        let end_body_length = separators.next().ok_or(DecodeError::Invalid)?;
+        let (start_checksum, value) =
+            checksum_value(message, separator).ok_or(DecodeError::Invalid)?;
+        if !message[i_body_length..].starts_with(b"9=") || end_body_length >= start_checksum {
+            return Err(DecodeError::Invalid);
+        }
+        Ok(Self {
+            separator,
+            i_body_length,
+            end_body_length,
+            start_checksum,
+            end_checksum: start_checksum + 3 + value.len(),
+        })
+    }
+
+    fn body_length(&self) -> usize {
+        self.start_checksum - self.end_body_length - 1
+    }
+}
+
+/// Fixes `BodyLength <9>` and `CheckSum <10>` of the message in `buffer` after
+/// some of its field values have been edited in place. The separator is
+/// inferred like in [`expected_body_length`] and `CheckSum <10>` is located
+/// with [`start_of_checksum_field`], so its current value may have any width.
+/// It's always rewritten as three digits followed by the separator.
+///
+/// A zero-padded `BodyLength <9>` keeps its original width if possible.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::recompute_trailer;
+///
+/// let mut buffer = b"8=FIX.4.2|9=5|35=0|49=A|10=000|".to_vec();
+/// recompute_trailer(&mut buffer).unwrap();
+/// assert_eq!(&buffer[..], b"8=FIX.4.2|9=10|35=0|49=A|10=165|" as &[u8]);
+/// ```
+pub fn recompute_trailer(buffer: &mut Vec<u8>) -> Result<(), DecodeError> {
+    let layout = TrailerLayout::new(&buffer[..])?;
+    let body_length = layout.body_length();
+    let delta = rewrite_body_length(
+        buffer,
+        layout.i_body_length + 2,
+        layout.end_body_length,
+        body_length,
+    );
+    // Rewrite `CheckSum <10>`, adding the final separator if it's missing.
+    let start_checksum = (layout.start_checksum as isize + delta) as usize;
+    let end_checksum = (layout.end_checksum as isize + delta) as usize;
+    if buffer.get(end_checksum) != Some(&layout.separator) {
+        buffer.insert(end_checksum, layout.separator);
+    }
+    buffer.splice(start_checksum + 3..end_checksum, b"000".iter().copied());
+    write_checksum(&mut buffer[..], start_checksum);
+    Ok(())
+}
+
+/// Replaces the `BodyLength <9>` digits at `start..end` with `body_length`,
+/// keeping their width if they're zero-padded. Returns by how many bytes
+/// `buffer` has grown (or shrunk, if negative).
+fn rewrite_body_length(
+    buffer: &mut Vec<u8>,
+    start: usize,
+    end: usize,
+    body_length: usize,
+) -> isize {
+    let old_digits = &buffer[start..end];
+    let width = if old_digits.len() > 1 && old_digits[0] == b'0' {
+        old_digits.len()
+    } else {
+        0
+    };
+    let mut digits = format!("{:0width$}", body_length, width = width).into_bytes();
+    let delta = digits.len() as isize - old_digits.len() as isize;
+    buffer.splice(start..end, digits.drain(..));
+    delta
+}
+
+/// Turns the hand-edited message in `buffer` into a valid one according to
+/// `config`: `BodyLength <9>` and `CheckSum <10>` are recomputed, whatever
+/// their current values, and the final separator is added if it's missing.
+/// `CheckSum <10>` is appended if there's none at all.
+///
+/// Unlike [`recompute_trailer`], the separator is taken from `config` and
+/// [`Configure::checksum_assumes_soh`] is honored.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::{repair, Config};
+///
+/// let config = Config::default().with_separator(b'|');
+/// let mut buffer = b"8=FIX.4.2|9=0|35=0|49=HANDWRITTEN|10=???|".to_vec();
+/// repair(&mut buffer, &config).unwrap();
+/// assert_eq!(&buffer[..], b"8=FIX.4.2|9=20|35=0|49=HANDWRITTEN|10=173|" as &[u8]);
+/// ```
+pub fn repair<C>(buffer: &mut Vec<u8>, config: &C) -> Result<(), DecodeError>
+where
+    C: Configure,
+{
+    let separator = config.separator();
+    if buffer.last() != Some(&separator) {
+        buffer.push(separator);
+    }
+    // The whole `CheckSum <10>` field is rewritten, so its value may be
+    // anything at all.
+    let start_checksum = start_of_checksum_field(buffer, separator).unwrap_or(buffer.len());
+    buffer.truncate(start_checksum);
+    let mut separators = buffer
+        .iter()
+        .enumerate()
+        .filter(|(_, byte)| **byte == separator)
+        .map(|(i, _)| i);
+    let i_body_length = separators.next().ok_or(DecodeError::Invalid)? + 1;
+    let end_body_length = separators.next().ok_or(DecodeError::Invalid)?;
+    if !buffer[..i_body_length].starts_with(b"8=") || !buffer[i_body_length..].starts_with(b"9=") {
+        return Err(DecodeError::Invalid);
+    }
+    let body_length = start_checksum - end_body_length - 1;
+    rewrite_body_length(buffer, i_body_length + 2, end_body_length, body_length);
+    let value = if config.checksum_assumes_soh() {
+        checksum_10_as_soh(buffer, separator)
+    } else {
+        checksum_10(buffer)
+    };
+    buffer.extend_from_slice(b"10=");
+    buffer.extend_from_slice(&Checksum10 { value }.digits());
+    buffer.push(separator);
+    Ok(())
+}
+
+/// Recomputes `CheckSum <10>` of the message in `buffer` and overwrites its
+/// three digits in place. Unlike [`recompute_trailer`], `BodyLength <9>` is
+/// left untouched, so this is only enough after edits that don't change the
+/// length of the message. The separator is inferred from the last byte of
+/// `buffer` and `CheckSum <10>` is located with [`start_of_checksum_field`].
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::patch_checksum;
+///
+/// let mut buffer = b"8=FIX.4.2|9=10|35=0|49=A|10=165|".to_vec();
+/// buffer[23] = b'B';
+/// patch_checksum(&mut buffer[..]).unwrap();
+/// assert_eq!(&buffer[..], b"8=FIX.4.2|9=10|35=0|49=B|10=166|" as &[u8]);
+/// ```
+pub fn patch_checksum(buffer: &mut [u8]) -> Result<(), DecodeError> {
+    let separator = *buffer.last().ok_or(DecodeError::Invalid)?;
+    let start_checksum = start_of_checksum_field(buffer, separator).ok_or(DecodeError::Invalid)?;
+    // Exactly three digits, followed by the final separator.
+    if buffer.len() - start_checksum != FIELD_CHECKSUM_LEN_IN_BYTES {
+        return Err(DecodeError::Invalid);
+    }
+    write_checksum(buffer, start_checksum);
+    Ok(())
+}
+
+fn write_checksum(buffer: &mut [u8], start_checksum: usize) {
+    let checksum = checksum_10(&buffer[..start_checksum]);
+    buffer[start_checksum + 3] = (checksum / 100) + b'0';
+    buffer[start_checksum + 4] = ((checksum / 10) % 10) + b'0';
+    buffer[start_checksum + 5] = (checksum % 10) + b'0';
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::tagvalue::{Config, RawDecoder};
 
     #[test]
     fn edges_cases_of_checksum_calculation() {
@@ -155,6 +582,71 @@ mod test {
         assert_eq!(checksum_10(&[128, 128]), 0);
     }
 
+    #[test]
+    fn body_length_ignores_trailer_length() {
+        let msg = b"8=FIX.4.4|9=5|35=0|10=005|";
+        assert_eq!(verify_body_length(msg, b'|', 14, 5), Ok(()));
+        assert_eq!(
+            verify_body_length(msg, b'|', 14, 6),
+            Err(DecodeError::Invalid)
+        );
+        // Non-standard checksum value and trailing bytes.
+        let msg = b"8=FIX.4.4|9=5|35=0|10=5|\r\n";
+        assert_eq!(verify_body_length(msg, b'|', 14, 5), Ok(()));
+        // Body lengths that include `CheckSum <10>` are wrong.
+        assert_eq!(
+            verify_body_length(msg, b'|', 14, 12),
+            Err(DecodeError::Invalid)
+        );
+        assert_eq!(
+            verify_body_length(b"8=FIX.4.4|9=5|35=0|", b'|', 14, 5),
+            Err(DecodeError::Invalid)
+        );
+    }
+
+    #[test]
+    fn checksum_field_is_zero_padded() {
+        assert_eq!(&checksum_field(b""), b"000");
+        assert_eq!(&checksum_field(b"\x07"), b"007");
+        // 5 * 200 = 1000, and 1000 mod 256 = 232.
+        assert_eq!(&checksum_field(&[200; 5]), b"232");
+        // 256 + 42 wraps around to 42.
+        assert_eq!(&checksum_field(&[255, 1, 42]), b"042");
+    }
+
+    #[test]
+    fn repaired_message_can_be_decoded() {
+        let config = Config::default().with_separator(b'|');
+        let decoder = RawDecoder::with_config(config.clone());
+        let mut buffer = b"8=FIX.4.4|9=5|35=0|10=020|".to_vec();
+        assert!(decoder.decode(&buffer[..]).is_ok());
+        // Hand-edit a field so that the message becomes longer.
+        buffer.splice(14..18, b"35=1|112=TEST".iter().copied());
+        assert!(decoder.decode(&buffer[..]).is_err());
+        repair(&mut buffer, &config).unwrap();
+        assert_eq!(
+            &buffer[..],
+            b"8=FIX.4.4|9=14|35=1|112=TEST|10=210|" as &[u8]
+        );
+        let frame = decoder.decode(&buffer[..]).unwrap();
+        assert_eq!(frame.payload(), b"35=1|112=TEST|");
+    }
+
+    #[test]
+    fn repair_without_trailer() {
+        let config = Config::default()
+            .with_separator(b'|')
+            .with_checksum_assumes_soh(true);
+        let mut buffer = b"8=FIX.4.4|9=000000|35=0".to_vec();
+        repair(&mut buffer, &config).unwrap();
+        assert_eq!(&buffer[..], b"8=FIX.4.4|9=000005|35=0|10=147|" as &[u8]);
+        assert!(RawDecoder::with_config(config.clone())
+            .decode(&buffer[..])
+            .is_ok());
+        let mut buffer = b"9=5|8=FIX.4.4|35=0|10=000|".to_vec();
+        assert_eq!(repair(&mut buffer, &config), Err(DecodeError::Invalid));
+    }
+
     #[test]
     fn correct_retrieval_of_checksum_digits() {
         assert_eq!(
@@ -166,4 +658,133 @@ mod test {
             b"ABC"
         );
     }
+
+    #[test]
+    fn recompute_trailer_keeps_zero_padding() {
+        let mut buffer = b"8=FIX.4.4|9=000005|35=0|49=AB|10=000|".to_vec();
+        recompute_trailer(&mut buffer).unwrap();
+        assert!(buffer.starts_with(b"8=FIX.4.4|9=000011|"));
+        verify_checksum(&buffer[..], b'|').unwrap();
+    }
+
+    #[test]
+    fn recompute_trailer_with_short_checksum() {
+        let mut buffer = b"8=FIX.4.4|9=5|35=0|49=AB|10=5|".to_vec();
+        recompute_trailer(&mut buffer).unwrap();
+        assert_eq!(&buffer[..], b"8=FIX.4.4|9=11|35=0|49=AB|10=234|" as &[u8]);
+    }
+
+    #[test]
+    fn recompute_trailer_without_final_separator() {
+        let mut buffer = b"8=FIX.4.4|9=5|35=0|49=AB|10=000".to_vec();
+        recompute_trailer(&mut buffer).unwrap();
+        assert_eq!(&buffer[..], b"8=FIX.4.4|9=11|35=0|49=AB|10=234|" as &[u8]);
+    }
+
+    #[test]
+    fn verify_checksum_of_nonstandard_trailers() {
+        verify_checksum(b"8=FIX.4.4|9=11|35=0|49=AB|10=234|", b'|').unwrap();
+        verify_checksum(b"8=FIX.4.4|9=11|35=0|49=AB|10=0234|", b'|').unwrap();
+        verify_checksum(b"8=FIX.4.4|9=11|35=0|49=AB|10=234", b'|').unwrap();
+        verify_checksum_as_soh(b"8=FIX.4.4|9=11|35=0|49=AB|10=254", b'|').unwrap();
+        assert_eq!(
+            verify_checksum(b"8=FIX.4.4|9=11|35=0|49=AB|10=|", b'|'),
+            Err(DecodeError::CheckSum)
+        );
+    }
+
+    #[test]
+    fn expected_body_length_matches_encode_raw() {
+        let mut buffer = Vec::new();
+        encode_raw(
+            b"FIX.4.4",
+            |buffer: &mut Vec<u8>| {
+                buffer.extend_from_slice(b"35=0|49=AB|");
+                11
+            },
+            &mut buffer,
+            b'|',
+        )
+        .unwrap();
+        assert!(buffer.starts_with(b"8=FIX.4.4|9=000011|"));
+        assert_eq!(expected_body_length(&buffer[..]), Ok(11));
+        let wrong = b"8=FIX.4.4|9=5|35=0|49=AB|10=000|";
+        assert_eq!(expected_body_length(wrong), Ok(11));
+        assert!(expected_body_length(b"8=FIX.4.4|35=0|49=AB|10=000|").is_err());
+    }
+
+    #[test]
+    fn encoded_len_matches_encode_raw() {
+        for body in [&b""[..], b"35=0|49=AB|", b"35=D|49=AB|56=CD|55=EUR/USD|"].iter() {
+            let mut buffer = Vec::new();
+            let len = encode_raw(
+                b"FIXT.1.1",
+                |buffer: &mut Vec<u8>| {
+                    buffer.extend_from_slice(body);
+                    body.len()
+                },
+                &mut buffer,
+                b'|',
+            )
+            .unwrap();
+            assert_eq!(len, buffer.len());
+            assert_eq!(encoded_len(b"FIXT.1.1", body.len()), len);
+        }
+    }
+
+    #[test]
+    fn scattered_checksum_matches_single_buffer_encode() {
+        let mut buffer = Vec::new();
+        encode_raw(
+            b"FIX.4.4",
+            |buffer: &mut Vec<u8>| {
+                buffer.extend_from_slice(b"35=D|49=AB|56=CD|55=EUR/USD|");
+                27
+            },
+            &mut buffer,
+            b'|',
+        )
+        .unwrap();
+        let header: Vec<u8> = b"8=FIX.4.4|9=000027|".to_vec();
+        let body: Vec<u8> = b"35=D|49=AB|56=CD|55=EUR/USD|".to_vec();
+        let mut checksum = Checksum10::new();
+        for slice in [&header, &body].iter() {
+            checksum.update(slice.as_slice());
+        }
+        let mut message = header.clone();
+        message.extend_from_slice(&body);
+        message.extend_from_slice(b"10=");
+        message.extend_from_slice(&checksum.digits());
+        message.push(b'|');
+        assert_eq!(message, buffer);
+        verify_checksum(&message[..], b'|').unwrap();
+    }
+
+    #[test]
+    fn patch_checksum_after_editing_a_byte() {
+        let mut buffer = b"8=FIX.4.4|9=17|35=D|49=AB|56=CD|10=175|".to_vec();
+        verify_checksum(&buffer[..], b'|').unwrap();
+        let i = buffer.len() - 9;
+        buffer[i] = b'X';
+        assert!(verify_checksum(&buffer[..], b'|').is_err());
+        patch_checksum(&mut buffer[..]).unwrap();
+        verify_checksum(&buffer[..], b'|').unwrap();
+        assert!(buffer.starts_with(b"8=FIX.4.4|9=17|35=D|49=AB|56=CX|10="));
+    }
+
+    #[test]
+    fn patch_checksum_of_garbage_is_err() {
+        assert!(patch_checksum(&mut []).is_err());
+        assert!(patch_checksum(&mut b"8=FIX.4.4|9=5|35=0|49=AB|11=000|".to_vec()).is_err());
+        assert!(patch_checksum(&mut b"8=FIX.4.4|9=5|35=0|49=AB|10=00|".to_vec()).is_err());
+        // The trailing separator is also the separator of the whole message.
+        assert!(patch_checksum(&mut b"8=FIX.4.4|9=5|35=0|49=AB|10=000|\n".to_vec()).is_err());
+    }
+
+    #[test]
+    fn recompute_trailer_of_garbage_is_err() {
+        assert!(recompute_trailer(&mut b"".to_vec()).is_err());
+        assert!(recompute_trailer(&mut b"8=FIX.4.4|35=0|49=AB|10=000|".to_vec()).is_err());
+        assert!(recompute_trailer(&mut b"8=FIX.4.4|9=5|35=0|49=AB|11=000|".to_vec()).is_err());
+    }
 }
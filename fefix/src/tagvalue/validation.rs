@@ -0,0 +1,121 @@
+use super::required_fields::missing_required_fields;
+use super::{Configure, DecodeError, Decoder};
+use crate::tags;
+
+/// All conformance issues found by [`validate_message`], in the order they
+/// were found.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationReport {
+    issues: Vec<DecodeError>,
+}
+
+impl ValidationReport {
+    /// Returns `true` if and only if no issues were found.
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Returns all issues found, as [`DecodeError`]s.
+    pub fn issues(&self) -> &[DecodeError] {
+        &self.issues[..]
+    }
+}
+
+/// Checks `message` for conformance and reports every issue found, rather
+/// than stopping at the first one like [`Decoder::decode`] does. Fields are
+/// walked exactly like [`Decoder::decode_collecting_errors`] does, so
+/// length-prefixed values, extension packs and repeating groups are all
+/// resolved by `decoder`. In particular:
+///
+/// 1. The message must be properly framed, e.g. `BodyLength <9>` and
+///    `CheckSum <10>` must be valid according to the configuration of
+///    `decoder`. Otherwise, no other checks are performed.
+/// 2. `MsgType <35>` must be the third field, if present
+///    ([`DecodeError::TagOutOfOrder`]).
+/// 3. All tags must be known ([`DecodeError::UnknownTag`]) and all field
+///    values must be valid for their [`DataType`](crate::DataType)
+///    ([`DecodeError::InvalidValue`]).
+/// 4. Repeating groups must have as many entries as their `NumInGroup` field
+///    says ([`DecodeError::GroupCount`]).
+/// 5. All required fields must be present ([`DecodeError::MissingField`]). See
+///    [`verify_required_fields`](super::verify_required_fields).
+///
+/// # Examples
+///
+/// ```
+/// use fefix::tagvalue::{validate_message, Config, DecodeError, Decoder};
+/// use fefix::{AppVersion, Dictionary};
+///
+/// let dict = Dictionary::from_version(AppVersion::Fix44);
+/// let decoder = &mut Decoder::with_config(dict, Config::default().with_separator(b'|'));
+/// let msg = b"8=FIX.4.4|9=84|35=D|49=A|56=B|34=1|52=20210101-00:00:00|11=X|54=1|60=20210101-00:00:00|38=100|40=1|10=021|";
+/// assert!(validate_message(msg, decoder).is_ok());
+/// let msg = b"8=FIX.4.4|9=84|35=D|49=A|56=B|34=1|52=20210101-00:00:00|11=X|54=1|60=20210101-00:00:00|38=100|40=1|10=000|";
+/// assert_eq!(validate_message(msg, decoder).issues(), &[DecodeError::CheckSum]);
+/// ```
+pub fn validate_message<C>(message: &[u8], decoder: &mut Decoder<C>) -> ValidationReport
+where
+    C: Configure,
+{
+    let (decoded, mut issues) = decoder.decode_collecting_errors(message);
+    let decoded = match decoded {
+        Some(decoded) => decoded,
+        None => return ValidationReport { issues },
+    };
+    // Field ranges start with `BeginString <8>`, and `BodyLength <9>` is not
+    // part of the payload.
+    let position_of_msg_type = decoded
+        .field_ranges()
+        .iter()
+        .position(|(tag, _)| *tag == tags::MSG_TYPE);
+    if let Some(i) = position_of_msg_type {
+        if i != 1 {
+            issues.insert(0, DecodeError::TagOutOfOrder(tags::MSG_TYPE));
+        }
+    }
+    let is_present = |tag| decoded.field_raw(tag).is_some();
+    let missing = missing_required_fields(decoded.msg_type(), is_present);
+    issues.extend(missing.into_iter().map(DecodeError::MissingField));
+    ValidationReport { issues }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tagvalue::Config;
+    use crate::{AppVersion, Dictionary};
+
+    fn decoder() -> Decoder<Config> {
+        let dict = Dictionary::from_version(AppVersion::Fix44);
+        Decoder::with_config(dict, Config::default().with_separator(b'|'))
+    }
+
+    #[test]
+    fn all_issues_are_reported() {
+        let decoder = &mut decoder();
+        let msg = b"8=FIX.4.4|9=91|49=A|35=D|56=B|34=1|52=20210101-00:00:00|11=X|54=1|60=20210101-00:00:00|38=abc|78=2|79=ACC|10=164|";
+        let report = validate_message(msg, decoder);
+        assert!(!report.is_ok());
+        assert_eq!(
+            report.issues(),
+            &[
+                DecodeError::TagOutOfOrder(35),
+                DecodeError::InvalidValue(38),
+                DecodeError::GroupCount {
+                    tag: 78,
+                    value: "2".to_string()
+                },
+                DecodeError::MissingField(40),
+            ]
+        );
+    }
+
+    #[test]
+    fn data_fields_are_skipped_and_unknown_tags_reported() {
+        let decoder = &mut decoder();
+        // `RawData <96>` contains something that looks like a field.
+        let msg = b"8=FIX.4.4|9=106|35=D|49=A|56=B|34=1|52=20210101-00:00:00|95=6|96=a|38=b|11=X|54=1|60=20210101-00:00:00|38=100|40=1|9999=x|10=193|";
+        let report = validate_message(msg, decoder);
+        assert_eq!(report.issues(), &[DecodeError::UnknownTag(9999)]);
+    }
+}
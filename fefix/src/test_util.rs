@@ -0,0 +1,107 @@
+//! Helpers to turn human-pasted captures into byte buffers, e.g. for test
+//! fixtures. Only available with the `test_util` feature.
+
+/// Converts a hex dump into bytes. Whitespace is ignored, as well as `0x` and
+/// `0X` prefixes, and hex digits can be either uppercase or lowercase.
+///
+/// # Panics
+///
+/// This function panics if `hex` contains anything else than hex digits,
+/// prefixes and whitespace, or if the number of hex digits is odd.
+///
+/// # Examples
+///
+/// ```
+/// use fefix::test_util::from_hex;
+///
+/// assert_eq!(from_hex("38 3D\n0x46 0x49 58"), b"8=FIX".to_vec());
+/// ```
+pub fn from_hex(hex: &str) -> Vec<u8> {
+    let digits: Vec<u8> = hex
+        .split_whitespace()
+        .flat_map(|token| {
+            token
+                .split("0x")
+                .flat_map(|s| s.split("0X"))
+                .flat_map(|s| s.bytes())
+        })
+        .map(|c| match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            b'A'..=b'F' => c - b'A' + 10,
+            _ => panic!("Invalid hex digit '{}'.", c as char),
+        })
+        .collect();
+    let pairs = digits.chunks_exact(2);
+    assert!(pairs.remainder().is_empty(), "Odd number of hex digits.");
+    pairs.map(|pair| (pair[0] << 4) | pair[1]).collect()
+}
+
+/// Extracts the FIX message bytes from the output of `tcpdump -X` (or `-XX`).
+///
+/// Offsets (e.g. `0x0010:`) and the ASCII column are ignored. Everything
+/// before the first `8=FIX` (i.e. link, IP and TCP headers) is skipped; if
+/// there's no `8=FIX` at all, all captured bytes are returned.
+///
+/// # Panics
+///
+/// This function panics if the hex column of `dump` is malformed. See
+/// [`from_hex`].
+///
+/// # Examples
+///
+/// ```
+/// use fefix::test_util::from_tcpdump;
+///
+/// let dump = "
+///     0x0000:  4500 0029 0000 4000 4006 0000 7f00 0001  E..)..@.@.......
+///     0x0010:  7f00 0001 0000 0000 0000 0000 0000 0000  ................
+///     0x0020:  0000 0000 0000 0000 383d 4649 58         ........8=FIX
+/// ";
+/// assert_eq!(from_tcpdump(dump), b"8=FIX".to_vec());
+/// ```
+pub fn from_tcpdump(dump: &str) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for line in dump.lines() {
+        let line = line.trim_start();
+        if !line.starts_with("0x") {
+            continue;
+        }
+        let hex_and_ascii = match line.find(':') {
+            Some(i) => line[i + 1..].trim_start(),
+            None => continue,
+        };
+        // The ASCII column is separated by (at least) two spaces.
+        let hex = hex_and_ascii.split("  ").next().unwrap_or("");
+        bytes.extend(from_hex(hex));
+    }
+    match bytes.windows(5).position(|w| w == b"8=FIX") {
+        Some(start) => bytes.split_off(start),
+        None => bytes,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_hex_with_mixed_spacing_and_case() {
+        let bytes = from_hex("  0x38\t0X3d46 49\n\n58 2e 34 2E 34 ");
+        assert_eq!(bytes, b"8=FIX.4.4".to_vec());
+        assert_eq!(from_hex(""), Vec::<u8>::new());
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_hex_with_odd_length() {
+        from_hex("383");
+    }
+
+    #[test]
+    fn from_tcpdump_without_headers() {
+        let dump = "\t0x0000:  383d 4649 582e 342e 3401 393d 3501 3335  8=FIX.4.4.9=5.35\n\
+                    \t0x0010:  3d30 01                                 =0.";
+        assert_eq!(from_tcpdump(dump), b"8=FIX.4.4\x019=5\x0135=0\x01".to_vec());
+    }
+}
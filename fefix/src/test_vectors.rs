@@ -0,0 +1,141 @@
+//! Canonical, valid tag-value messages for use as test fixtures. Only
+//! available with the `test_util` feature.
+//!
+//! All messages use the standard `SOH` (`0x1`) separator and come with correct
+//! `BodyLength <9>` and `CheckSum <10>` values, so they decode cleanly with
+//! [`Config::default`](crate::tagvalue::Config::default).
+//!
+//! # Examples
+//!
+//! ```
+//! use fefix::tagvalue::{Config, RawDecoder};
+//! use fefix::test_vectors::fix44;
+//!
+//! let decoder = RawDecoder::with_config(Config::default());
+//! let frame = decoder.decode(fix44::NEW_ORDER_SINGLE).unwrap();
+//! assert_eq!(frame.begin_string(), b"FIX.4.4");
+//! ```
+
+use crate::AppVersion;
+
+/// Messages for FIX 4.2.
+pub mod fix42 {
+    /// `Logon <A>`.
+    pub const LOGON: &[u8] = b"8=FIX.4.2\x019=67\x0135=A\x0149=CLIENT\x01\
+        56=BROKER\x0134=1\x0152=20210101-12:00:00.000\x0198=0\x01\
+        108=30\x0110=095\x01";
+
+    /// `NewOrderSingle <D>`.
+    pub const NEW_ORDER_SINGLE: &[u8] = b"8=FIX.4.2\x019=129\x0135=D\x0149=CLIENT\x01\
+        56=BROKER\x0134=2\x0152=20210101-12:00:00.000\x0111=ORD-1\x01\
+        21=1\x0155=AAPL\x0154=1\x0160=20210101-12:00:00.000\x01\
+        38=100\x0140=2\x0144=150.25\x0110=088\x01";
+
+    /// `ExecutionReport <8>`.
+    pub const EXECUTION_REPORT: &[u8] = b"8=FIX.4.2\x019=151\x0135=8\x0149=BROKER\x01\
+        56=CLIENT\x0134=2\x0152=20210101-12:00:00.000\x0137=BRK-1\x01\
+        11=ORD-1\x0117=EXEC-1\x0120=0\x01150=0\x01\
+        39=0\x0155=AAPL\x0154=1\x0138=100\x01\
+        40=2\x0144=150.25\x01151=100\x0114=0\x01\
+        6=0\x0110=097\x01";
+}
+
+/// Messages for FIX 4.4.
+pub mod fix44 {
+    /// `Logon <A>`.
+    pub const LOGON: &[u8] = b"8=FIX.4.4\x019=73\x0135=A\x0149=CLIENT\x01\
+        56=BROKER\x0134=1\x0152=20210101-12:00:00.000\x0198=0\x01\
+        108=30\x01141=Y\x0110=139\x01";
+
+    /// `NewOrderSingle <D>`.
+    pub const NEW_ORDER_SINGLE: &[u8] = b"8=FIX.4.4\x019=129\x0135=D\x0149=CLIENT\x01\
+        56=BROKER\x0134=2\x0152=20210101-12:00:00.000\x0111=ORD-1\x01\
+        21=1\x0155=AAPL\x0154=1\x0160=20210101-12:00:00.000\x01\
+        38=100\x0140=2\x0144=150.25\x0110=090\x01";
+
+    /// `ExecutionReport <8>`.
+    pub const EXECUTION_REPORT: &[u8] = b"8=FIX.4.4\x019=146\x0135=8\x0149=BROKER\x01\
+        56=CLIENT\x0134=2\x0152=20210101-12:00:00.000\x0137=BRK-1\x01\
+        11=ORD-1\x0117=EXEC-1\x01150=0\x0139=0\x01\
+        55=AAPL\x0154=1\x0138=100\x0140=2\x01\
+        44=150.25\x01151=100\x0114=0\x016=0\x01\
+        10=151\x01";
+}
+
+/// Messages for FIX 5.0 (over FIXT 1.1).
+pub mod fix50 {
+    /// `Logon <A>`.
+    pub const LOGON: &[u8] = b"8=FIXT.1.1\x019=80\x0135=A\x0149=CLIENT\x01\
+        56=BROKER\x0134=1\x0152=20210101-12:00:00.000\x0198=0\x01\
+        108=30\x01141=Y\x011137=7\x0110=024\x01";
+
+    /// `NewOrderSingle <D>`.
+    pub const NEW_ORDER_SINGLE: &[u8] = b"8=FIXT.1.1\x019=136\x0135=D\x011128=7\x01\
+        49=CLIENT\x0156=BROKER\x0134=2\x0152=20210101-12:00:00.000\x01\
+        11=ORD-1\x0121=1\x0155=AAPL\x0154=1\x01\
+        60=20210101-12:00:00.000\x0138=100\x0140=2\x0144=150.25\x01\
+        10=231\x01";
+
+    /// `ExecutionReport <8>`.
+    pub const EXECUTION_REPORT: &[u8] = b"8=FIXT.1.1\x019=153\x0135=8\x011128=7\x01\
+        49=BROKER\x0156=CLIENT\x0134=2\x0152=20210101-12:00:00.000\x01\
+        37=BRK-1\x0111=ORD-1\x0117=EXEC-1\x01150=0\x01\
+        39=0\x0155=AAPL\x0154=1\x0138=100\x01\
+        40=2\x0144=150.25\x01151=100\x0114=0\x01\
+        6=0\x0110=036\x01";
+}
+
+/// All test vectors, alongside the [`AppVersion`] they belong to.
+pub const ALL: &[(AppVersion, &[u8])] = &[
+    (AppVersion::Fix42, fix42::LOGON),
+    (AppVersion::Fix42, fix42::NEW_ORDER_SINGLE),
+    (AppVersion::Fix42, fix42::EXECUTION_REPORT),
+    (AppVersion::Fix44, fix44::LOGON),
+    (AppVersion::Fix44, fix44::NEW_ORDER_SINGLE),
+    (AppVersion::Fix44, fix44::EXECUTION_REPORT),
+    (AppVersion::Fix50, fix50::LOGON),
+    (AppVersion::Fix50, fix50::NEW_ORDER_SINGLE),
+    (AppVersion::Fix50, fix50::EXECUTION_REPORT),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tagvalue::{encode_field, recompute_trailer, scan_fields, Config, RawDecoder};
+
+    #[test]
+    fn all_vectors_decode_cleanly() {
+        let decoder = RawDecoder::with_config(Config::default());
+        for (_, message) in ALL {
+            let frame = decoder.decode(message).unwrap();
+            assert!(frame.checksum_ok());
+        }
+    }
+
+    #[test]
+    fn all_vectors_round_trip() {
+        for (_, message) in ALL {
+            let mut buffer = Vec::new();
+            for field in scan_fields(message, b'\x01') {
+                let (tag, value) = field.unwrap();
+                encode_field(tag, &value, &mut buffer, b'\x01');
+            }
+            recompute_trailer(&mut buffer).unwrap();
+            assert_eq!(&buffer[..], *message);
+        }
+    }
+
+    #[test]
+    fn begin_string_matches_app_version() {
+        let decoder = RawDecoder::with_config(Config::default());
+        for (app_version, message) in ALL {
+            let frame = decoder.decode(message).unwrap();
+            let expected = match app_version {
+                AppVersion::Fix42 => &b"FIX.4.2"[..],
+                AppVersion::Fix44 => b"FIX.4.4",
+                _ => b"FIXT.1.1",
+            };
+            assert_eq!(frame.begin_string(), expected);
+        }
+    }
+}
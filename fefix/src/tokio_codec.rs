@@ -0,0 +1,308 @@
+//! [`tokio_util::codec`] support for SOFH-framed tag-value messages.
+//!
+//! [`TagValueDecoder`] and [`TagValueEncoder`] take care of both SOFH framing
+//! and tag-value decoding/encoding, so that they can be plugged straight into
+//! [`Framed`](tokio_util::codec::Framed) and its fellow types. Only available
+//! with the `tokio-codec` feature.
+
+use crate::sofh::{self, EncodingType, SofhError, SofhHeader};
+use crate::tagvalue::{
+    self, field_value as val, Config, Configure, DecodeError, EncodeError, FixFieldValue,
+};
+use crate::{tags, BytesMutBuffer, DataType, Dictionary, FixMessage};
+use bytes::BytesMut;
+use std::convert::TryFrom;
+use std::{fmt, io};
+use tokio_util::codec;
+
+/// A [`codec::Decoder`] for SOFH-framed tag-value messages.
+///
+/// Decoded messages are converted to owned [`FixMessage`]s, with field values
+/// typed according to [`Configure::tag_type`] or else the dictionary.
+/// `BodyLength <9>` and `CheckSum <10>` are dropped, since
+/// [`TagValueEncoder`] always computes them from scratch.
+#[derive(Debug)]
+pub struct TagValueDecoder<C = Config>
+where
+    C: Configure,
+{
+    dict: Dictionary,
+    // `tagvalue` is borrowed by decoded messages, so field types are looked up
+    // through copies of its dictionary and configuration.
+    config: C,
+    sofh: sofh::Decoder<Vec<u8>>,
+    tagvalue: tagvalue::Decoder<C>,
+}
+
+impl TagValueDecoder<Config> {
+    /// Creates a new [`TagValueDecoder`] with the default configuration.
+    pub fn new(dict: Dictionary) -> Self {
+        Self::with_config(dict, Config::default())
+    }
+}
+
+impl<C> TagValueDecoder<C>
+where
+    C: Configure,
+{
+    /// Creates a new [`TagValueDecoder`] with `config` as a [`Configure`]
+    /// implementor.
+    pub fn with_config(dict: Dictionary, config: C) -> Self {
+        Self {
+            dict: dict.clone(),
+            config: config.clone(),
+            sofh: sofh::Decoder::from_buffer(Vec::new()),
+            tagvalue: tagvalue::Decoder::with_config(dict, config),
+        }
+    }
+
+    /// Returns a mutable reference to the underlying SOFH [`sofh::Decoder`],
+    /// e.g. to change its [`Endianness`](sofh::Endianness).
+    pub fn sofh_mut(&mut self) -> &mut sofh::Decoder<Vec<u8>> {
+        &mut self.sofh
+    }
+}
+
+impl<C> codec::Decoder for TagValueDecoder<C>
+where
+    C: Configure,
+{
+    type Item = FixMessage;
+    type Error = CodecError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let (encoding_type, payload) = match self.sofh.decode_frame(src)? {
+            Some(frame) => frame,
+            None => return Ok(None),
+        };
+        if encoding_type != EncodingType::TagValue {
+            return Err(CodecError::EncodingType(encoding_type));
+        }
+        let msg = self.tagvalue.decode(&payload[..])?;
+        let owned = to_owned_message(&self.dict, &self.config, &msg)?;
+        Ok(Some(owned))
+    }
+}
+
+/// A [`codec::Encoder`] for SOFH-framed tag-value messages. See
+/// [`tagvalue::Encoder::encode`].
+#[derive(Debug, Clone)]
+pub struct TagValueEncoder<C = Config>
+where
+    C: Configure,
+{
+    tagvalue: tagvalue::Encoder<C>,
+}
+
+impl<C> TagValueEncoder<C>
+where
+    C: Configure,
+{
+    /// Creates a new [`TagValueEncoder`] with `config` as a [`Configure`]
+    /// implementor.
+    pub fn new(config: C) -> Self {
+        Self {
+            tagvalue: tagvalue::Encoder::new(config),
+        }
+    }
+}
+
+impl<C> codec::Encoder<FixMessage> for TagValueEncoder<C>
+where
+    C: Configure,
+{
+    type Error = CodecError;
+
+    fn encode(&mut self, item: FixMessage, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        let start = dst.len();
+        // Reserve space for the header, which we can only fill in once the
+        // message length is known.
+        dst.extend_from_slice(&[0; SofhHeader::LEN]);
+        let mut buffer = BytesMutBuffer(std::mem::take(dst));
+        let result = self.tagvalue.encode(&mut buffer, &item);
+        *dst = buffer.into_inner();
+        let message_length = result.map_err(CodecError::from).and_then(|_| {
+            u32::try_from(dst.len() - start).map_err(|_| SofhError::InvalidLength.into())
+        });
+        let message_length = match message_length {
+            Ok(len) => len,
+            Err(err) => {
+                dst.truncate(start);
+                return Err(err);
+            }
+        };
+        let header = SofhHeader {
+            message_length,
+            encoding_type: EncodingType::TagValue.into(),
+        };
+        dst[start..start + SofhHeader::LEN].copy_from_slice(&header.to_bytes());
+        Ok(())
+    }
+}
+
+/// The error type of [`TagValueDecoder`] and [`TagValueEncoder`].
+#[derive(Debug)]
+pub enum CodecError {
+    /// Invalid SOFH framing, or an I/O error.
+    Sofh(SofhError),
+    /// The SOFH frame doesn't contain a tag-value message.
+    EncodingType(EncodingType),
+    /// The tag-value message couldn't be decoded.
+    Decode(DecodeError),
+    /// The tag-value message couldn't be encoded.
+    Encode(EncodeError),
+}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sofh(err) => err.fmt(f),
+            Self::EncodingType(encoding_type) => write!(
+                f,
+                "Unexpected SOFH encoding type {:#06x}.",
+                u16::from(*encoding_type)
+            ),
+            Self::Decode(err) => err.fmt(f),
+            Self::Encode(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for CodecError {}
+
+impl From<SofhError> for CodecError {
+    fn from(err: SofhError) -> Self {
+        Self::Sofh(err)
+    }
+}
+
+impl From<io::Error> for CodecError {
+    fn from(err: io::Error) -> Self {
+        Self::Sofh(SofhError::Io(err))
+    }
+}
+
+impl From<DecodeError> for CodecError {
+    fn from(err: DecodeError) -> Self {
+        Self::Decode(err)
+    }
+}
+
+impl From<EncodeError> for CodecError {
+    fn from(err: EncodeError) -> Self {
+        Self::Encode(err)
+    }
+}
+
+fn to_owned_message<C>(
+    dict: &Dictionary,
+    config: &C,
+    msg: &tagvalue::FixMessageRef,
+) -> Result<FixMessage, CodecError>
+where
+    C: Configure,
+{
+    let mut owned = FixMessage::new();
+    for (tag, _) in msg.field_ranges() {
+        if tag == tags::BODY_LENGTH || tag == tags::CHECK_SUM {
+            continue;
+        }
+        let data = msg.field_raw(tag).unwrap_or_default();
+        // Same precedence as during decoding.
+        let data_type = config
+            .tag_type(tag)
+            .or_else(|| dict.field_by_tag(tag).map(|field| field.basetype()));
+        let value = typed_value(data_type, data).ok_or(DecodeError::InvalidValue(tag))?;
+        owned
+            .add_field(tag, value)
+            .map_err(|_| DecodeError::DuplicateTag(tag))?;
+    }
+    Ok(owned)
+}
+
+fn typed_value(data_type: Option<DataType>, data: &[u8]) -> Option<FixFieldValue> {
+    match (data_type, data) {
+        (Some(DataType::Int), _) => tagvalue::parse_int(data).ok().map(FixFieldValue::from),
+        (Some(DataType::Boolean), b"Y") => Some(FixFieldValue::Atom(val::FieldValue::bool(true))),
+        (Some(DataType::Boolean), b"N") => Some(FixFieldValue::Atom(val::FieldValue::bool(false))),
+        (Some(DataType::Char), [c]) => Some(FixFieldValue::from(*c as char)),
+        _ => FixFieldValue::string(data),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{AppVersion, FixFieldAccess};
+    use tokio::io::duplex;
+    use tokio_util::codec::{FramedRead, FramedWrite};
+
+    fn heartbeat() -> FixMessage {
+        let mut msg = FixMessage::new();
+        msg.add_str(tags::BEGIN_STRING, "FIX.4.4");
+        msg.add_str(tags::MSG_TYPE, "0");
+        msg.add_str(tags::SENDER_COMP_ID, "A");
+        msg.add_str(tags::TARGET_COMP_ID, "B");
+        msg.add_i64(tags::MSG_SEQ_NUM, 42);
+        msg.add_str(tags::SENDING_TIME, "20210307-09:05:02.041");
+        msg
+    }
+
+    #[tokio::test]
+    async fn loopback() {
+        use futures::{SinkExt, StreamExt};
+
+        let (client, server) = duplex(1024);
+        let mut sink = FramedWrite::new(client, TagValueEncoder::new(Config::default()));
+        let dict = Dictionary::from_version(AppVersion::Fix44);
+        let mut stream = FramedRead::new(server, TagValueDecoder::new(dict));
+        sink.send(heartbeat()).await.unwrap();
+        let msg = stream.next().await.unwrap().unwrap();
+        assert_eq!(msg.field_str(tags::BEGIN_STRING), Some("FIX.4.4"));
+        assert_eq!(msg.f_msg_type(), Some("0"));
+        assert_eq!(msg.field_str(tags::SENDER_COMP_ID), Some("A"));
+        assert_eq!(msg.field_str(tags::TARGET_COMP_ID), Some("B"));
+        assert_eq!(msg.f_seq_num(), Some(42));
+        assert!(msg.field(tags::BODY_LENGTH).is_none());
+        assert!(msg.field(tags::CHECK_SUM).is_none());
+    }
+
+    #[test]
+    fn frame_with_another_encoding_type() {
+        let mut src = BytesMut::from(&[0, 0, 0, 7, 0x5B, 0xE0, 0][..]);
+        let dict = Dictionary::from_version(AppVersion::Fix44);
+        let result = codec::Decoder::decode(&mut TagValueDecoder::new(dict), &mut src);
+        assert!(matches!(
+            result,
+            Err(CodecError::EncodingType(
+                EncodingType::SimpleBinaryEncodingV10BE
+            ))
+        ));
+    }
+
+    #[test]
+    fn tag_type_overrides_the_dictionary() {
+        let mut msg = heartbeat();
+        msg.add_str(6000, "17");
+        let mut buffer = BytesMut::new();
+        let encoder = &mut TagValueEncoder::new(Config::default());
+        codec::Encoder::encode(encoder, msg, &mut buffer).unwrap();
+        let dict = Dictionary::from_version(AppVersion::Fix44);
+        let config = Config::default().with_tag_type(6000, DataType::Int);
+        let decoder = &mut TagValueDecoder::with_config(dict, config);
+        let msg = codec::Decoder::decode(decoder, &mut buffer).unwrap();
+        assert_eq!(msg.unwrap().field_i64(6000), Some(17));
+    }
+
+    #[test]
+    fn message_without_begin_string_is_an_error() {
+        let mut msg = FixMessage::new();
+        msg.add_str(tags::MSG_TYPE, "0");
+        let mut buffer = BytesMut::new();
+        let encoder = &mut TagValueEncoder::new(Config::default());
+        let result = codec::Encoder::encode(encoder, msg, &mut buffer);
+        let missing_begin_string = EncodeError::MissingField(tags::BEGIN_STRING);
+        assert!(matches!(result, Err(CodecError::Encode(err)) if err == missing_begin_string));
+        assert!(buffer.is_empty());
+    }
+}
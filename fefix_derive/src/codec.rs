@@ -0,0 +1,297 @@
+//! Code generation for `#[derive(FixDecode)]` and `#[derive(FixEncode)]`.
+
+use darling::{FromDeriveInput, FromField};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+
+#[derive(Debug, Clone, FromField)]
+#[darling(attributes(fix))]
+pub struct CodecField {
+    ident: Option<syn::Ident>,
+    ty: syn::Type,
+    tag: u32,
+}
+
+impl CodecField {
+    /// Returns `T` if the field has type `Option<T>`, `None` otherwise.
+    fn optional_inner_type(&self) -> Option<&syn::Type> {
+        let path = match &self.ty {
+            syn::Type::Path(type_path) if type_path.qself.is_none() => &type_path.path,
+            _ => return None,
+        };
+        let segment = path.segments.last()?;
+        if segment.ident != "Option" {
+            return None;
+        }
+        match &segment.arguments {
+            syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+                match args.args.first()? {
+                    syn::GenericArgument::Type(ty) => Some(ty),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, FromDeriveInput)]
+#[darling(attributes(fix), supports(struct_named))]
+pub struct CodecStructure {
+    ident: syn::Ident,
+    data: darling::ast::Data<darling::util::Ignored, CodecField>,
+    #[darling(default)]
+    msg_type: Option<String>,
+    #[darling(default)]
+    begin_string: Option<String>,
+}
+
+const MSG_TYPE_TAG: u32 = 35;
+
+impl CodecStructure {
+    fn fields(&self) -> Vec<&CodecField> {
+        self.data.as_ref().take_struct().unwrap().fields
+    }
+
+    pub fn gen_decode(&self) -> TokenStream2 {
+        let name = &self.ident;
+        let fields = self.fields();
+        let idents: Vec<_> = fields.iter().map(|f| f.ident.as_ref().unwrap()).collect();
+        let vars: Vec<_> = idents.iter().map(|i| format_ident!("f_{}", i)).collect();
+        let declarations = fields.iter().zip(vars.iter()).map(|(field, var)| {
+            let ty = field.optional_inner_type().unwrap_or(&field.ty);
+            quote! {
+                let mut #var: ::std::option::Option<#ty> = ::std::option::Option::None;
+            }
+        });
+        let match_arms = fields.iter().zip(vars.iter()).map(|(field, var)| {
+            let tag = field.tag;
+            quote! {
+                #tag => {
+                    if #var.is_some() {
+                        return ::std::result::Result::Err(
+                            ::fefix::tagvalue::DecodeError::DuplicateTag(#tag),
+                        );
+                    }
+                    #var = ::std::option::Option::Some(
+                        ::fefix::tagvalue::FixFieldDecode::decode_fix_field(value)
+                            .map_err(|_| ::fefix::tagvalue::DecodeError::InvalidValue(#tag))?,
+                    );
+                }
+            }
+        });
+        // Check `MsgType <35>` against `#[fix(msg_type = ...)]`, unless a
+        // field already takes care of it.
+        let msg_type_arm = match &self.msg_type {
+            Some(msg_type) if fields.iter().all(|f| f.tag != MSG_TYPE_TAG) => quote! {
+                #MSG_TYPE_TAG => {
+                    if value != #msg_type.as_bytes() {
+                        return ::std::result::Result::Err(
+                            ::fefix::tagvalue::DecodeError::InvalidValue(#MSG_TYPE_TAG),
+                        );
+                    }
+                }
+            },
+            _ => quote! {},
+        };
+        let initializers =
+            fields
+                .iter()
+                .zip(idents.iter().zip(vars.iter()))
+                .map(|(field, (ident, var))| {
+                    if field.optional_inner_type().is_some() {
+                        quote! { #ident: #var }
+                    } else {
+                        quote! {
+                            #ident: #var.ok_or(::fefix::tagvalue::DecodeError::FieldPresence)?
+                        }
+                    }
+                });
+        quote! {
+            impl #name {
+                /// Decodes a tag-value message into a new instance of `Self`.
+                /// Fields that are not part of `Self` are ignored.
+                pub fn decode<C>(
+                    data: &[u8],
+                    config: &C,
+                ) -> ::std::result::Result<Self, ::fefix::tagvalue::DecodeError>
+                where
+                    C: ::fefix::tagvalue::Configure,
+                {
+                    let decoder = ::fefix::tagvalue::RawDecoder::with_config(config.clone());
+                    let frame = decoder.decode(data)?;
+                    #(#declarations)*
+                    let fields = ::fefix::tagvalue::scan_fields(frame.payload(), config.separator())
+                        .with_data_fields();
+                    for field in fields {
+                        let (tag, value) = field?;
+                        match tag {
+                            #(#match_arms)*
+                            #msg_type_arm
+                            _ => {}
+                        }
+                    }
+                    ::std::result::Result::Ok(Self {
+                        #(#initializers),*
+                    })
+                }
+            }
+        }
+    }
+
+    pub fn gen_validate(&self) -> TokenStream2 {
+        let name = &self.ident;
+        let fields = self.fields();
+        let num_fields = fields.len();
+        let match_arms = fields.iter().enumerate().map(|(i, field)| {
+            let tag = field.tag;
+            let ty = field.optional_inner_type().unwrap_or(&field.ty);
+            quote! {
+                #tag => {
+                    if seen[#i] {
+                        errors.push(::fefix::tagvalue::DecodeError::DuplicateTag(#tag));
+                    }
+                    seen[#i] = true;
+                    if !<#ty as ::fefix::tagvalue::FixFieldDecode>::DATA_TYPE.validate(value, true) {
+                        errors.push(::fefix::tagvalue::DecodeError::InvalidValue(#tag));
+                    }
+                }
+            }
+        });
+        let msg_type_arm = match &self.msg_type {
+            Some(msg_type) if fields.iter().all(|f| f.tag != MSG_TYPE_TAG) => quote! {
+                #MSG_TYPE_TAG => {
+                    if value != #msg_type.as_bytes() {
+                        errors.push(::fefix::tagvalue::DecodeError::InvalidValue(#MSG_TYPE_TAG));
+                    }
+                }
+            },
+            _ => quote! {},
+        };
+        let presence_checks = fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| field.optional_inner_type().is_none())
+            .map(|(i, field)| {
+                let tag = field.tag;
+                quote! {
+                    if !seen[#i] {
+                        errors.push(::fefix::tagvalue::DecodeError::MissingField(#tag));
+                    }
+                }
+            });
+        quote! {
+            impl #name {
+                /// Checks that a tag-value message could be decoded into
+                /// `Self`, i.e. that all required fields are present and all
+                /// fields have valid values, without actually building
+                /// `Self`. All problems are reported, not just the first one.
+                pub fn validate<C>(
+                    data: &[u8],
+                    config: &C,
+                ) -> ::std::result::Result<(), ::std::vec::Vec<::fefix::tagvalue::DecodeError>>
+                where
+                    C: ::fefix::tagvalue::Configure,
+                {
+                    let decoder = ::fefix::tagvalue::RawDecoder::with_config(config.clone());
+                    let frame = decoder.decode(data).map_err(|err| ::std::vec![err])?;
+                    let mut errors = ::std::vec::Vec::new();
+                    let mut seen = [false; #num_fields];
+                    let fields = ::fefix::tagvalue::scan_fields(frame.payload(), config.separator())
+                        .with_data_fields();
+                    for field in fields {
+                        let (tag, value) = match field {
+                            ::std::result::Result::Ok(field) => field,
+                            ::std::result::Result::Err(err) => {
+                                errors.push(err);
+                                break;
+                            }
+                        };
+                        match tag {
+                            #(#match_arms)*
+                            #msg_type_arm
+                            _ => {}
+                        }
+                    }
+                    #(#presence_checks)*
+                    if errors.is_empty() {
+                        ::std::result::Result::Ok(())
+                    } else {
+                        ::std::result::Result::Err(errors)
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn gen_encode(&self) -> TokenStream2 {
+        let name = &self.ident;
+        let msg_type = match &self.msg_type {
+            Some(msg_type) => msg_type,
+            None => {
+                return syn::Error::new(
+                    name.span(),
+                    "`FixEncode` requires `#[fix(msg_type = \"...\")]`",
+                )
+                .to_compile_error()
+            }
+        };
+        let begin_string = match &self.begin_string {
+            Some(begin_string) => quote! { #begin_string.as_bytes() },
+            None => quote! { ::fefix::BEGIN_STRING_FIX44 },
+        };
+        let field_writers = self.fields().into_iter().map(|field| {
+            let ident = field.ident.as_ref().unwrap();
+            let tag = field.tag;
+            if field.optional_inner_type().is_some() {
+                quote! {
+                    if let ::std::option::Option::Some(value) = &self.#ident {
+                        len += ::fefix::tagvalue::encode_field(#tag, value, buffer, separator);
+                    }
+                }
+            } else {
+                quote! {
+                    len += ::fefix::tagvalue::encode_field(#tag, &self.#ident, buffer, separator);
+                }
+            }
+        });
+        quote! {
+            impl #name {
+                /// Encodes `self` as a tag-value message with `MsgType <35>`
+                /// first and all other fields in declaration order. Returns
+                /// the number of bytes written to `buffer`, or
+                /// [`EncodeError::BufferFull`](::fefix::tagvalue::EncodeError::BufferFull)
+                /// if `buffer` runs out of storage.
+                pub fn encode<B, C>(
+                    &self,
+                    buffer: &mut B,
+                    config: &C,
+                ) -> ::std::result::Result<usize, ::fefix::tagvalue::EncodeError>
+                where
+                    B: ::fefix::Buffer,
+                    C: ::fefix::tagvalue::Configure,
+                {
+                    let separator = config.separator();
+                    let start = buffer.as_slice().len();
+                    let body_writer = |buffer: &mut B| {
+                        let mut len = ::fefix::tagvalue::encode_field(
+                            #MSG_TYPE_TAG,
+                            &#msg_type.as_bytes(),
+                            buffer,
+                            separator,
+                        );
+                        #(#field_writers)*
+                        len
+                    };
+                    ::fefix::tagvalue::encode_raw(
+                        #begin_string,
+                        body_writer,
+                        buffer,
+                        separator,
+                    )?;
+                    ::std::result::Result::Ok(buffer.as_slice().len() - start)
+                }
+            }
+        }
+    }
+}
@@ -2,6 +2,8 @@
 
 #![deny(missing_debug_implementations, clippy::useless_conversion)]
 
+mod codec;
+
 use darling::{FromDeriveInput, FromField};
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
@@ -21,6 +23,44 @@ pub fn derive_tsr_message(input: TokenStream) -> TokenStream {
     gen.into()
 }
 
+/// Generates a `decode(data: &[u8], config: &C) -> Result<Self, DecodeError>`
+/// associated function for a struct with named fields, as well as a
+/// `validate(data: &[u8], config: &C) -> Result<(), Vec<DecodeError>>`
+/// associated function that only checks the message and reports all problems
+/// it finds.
+///
+/// Every field must be annotated with `#[fix(tag = ...)]` and its type must
+/// implement `FixFieldDecode`. Fields of type `Option<T>` are optional, all
+/// others are required. Repeating groups are not supported.
+#[proc_macro_derive(FixDecode, attributes(fix))]
+pub fn derive_fix_decode(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+    match codec::CodecStructure::from_derive_input(&ast) {
+        Ok(structure) => {
+            let mut tokens = structure.gen_decode();
+            tokens.extend(structure.gen_validate());
+            tokens.into()
+        }
+        Err(err) => err.write_errors().into(),
+    }
+}
+
+/// Generates an `encode(&self, buffer: &mut B, config: &C) -> Result<usize,
+/// EncodeError>` method for a struct with named fields.
+///
+/// The struct must be annotated with `#[fix(msg_type = "...")]` and may set
+/// `begin_string` (`"FIX.4.4"` by default). Every field must be annotated with
+/// `#[fix(tag = ...)]` and its type must implement `SerializeField`; `None`
+/// fields are skipped.
+#[proc_macro_derive(FixEncode, attributes(fix))]
+pub fn derive_fix_encode(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+    match codec::CodecStructure::from_derive_input(&ast) {
+        Ok(structure) => structure.gen_encode().into(),
+        Err(err) => err.write_errors().into(),
+    }
+}
+
 #[derive(Debug, Clone, FromField)]
 #[darling(attributes(fefix))]
 struct MessageStructureField {